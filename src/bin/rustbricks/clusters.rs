@@ -0,0 +1,77 @@
+use crate::output::{print_json, OutputFormat, Table};
+use clap::Subcommand;
+use rustbricks::{
+    models::{ClusterInfo, ClusterSummary},
+    services::DatabricksSession,
+};
+use std::error::Error;
+
+#[derive(Subcommand)]
+pub enum ClustersCommand {
+    /// Show the details of a cluster
+    Get {
+        /// The cluster ID
+        cluster_id: String,
+    },
+    /// List all clusters in the workspace
+    List,
+    /// Start a terminated cluster
+    Start {
+        /// The cluster ID
+        cluster_id: String,
+    },
+}
+
+pub async fn run(
+    session: &DatabricksSession,
+    command: ClustersCommand,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        ClustersCommand::Get { cluster_id } => {
+            let cluster: ClusterInfo = session.get_cluster_info(&cluster_id).await?;
+            match output {
+                OutputFormat::Table => print!("{}", cluster),
+                OutputFormat::Json => print_json(&cluster)?,
+                OutputFormat::Csv => {
+                    let mut table: Table =
+                        Table::new(&["cluster_id", "cluster_name", "state", "spark_version"]);
+                    table.push(vec![
+                        Some(cluster.cluster_id),
+                        Some(cluster.cluster_name),
+                        Some(cluster.state),
+                        Some(cluster.spark_version),
+                    ]);
+                    table.print(output)?;
+                }
+            }
+        }
+        ClustersCommand::List => {
+            let clusters: Vec<ClusterSummary> = session.list_clusters().await?;
+            if output == OutputFormat::Json {
+                return print_json(&clusters);
+            }
+            let mut table: Table =
+                Table::new(&["cluster_id", "cluster_name", "state", "spark_version"]);
+            for cluster in clusters {
+                table.push(vec![
+                    Some(cluster.cluster_id),
+                    cluster.cluster_name,
+                    cluster.state,
+                    cluster.spark_version,
+                ]);
+            }
+            table.print(output)?;
+        }
+        ClustersCommand::Start { cluster_id } => {
+            session.start_cluster(&cluster_id).await?;
+            match output {
+                OutputFormat::Json => print_json(
+                    &serde_json::json!({ "cluster_id": cluster_id, "state": "PENDING" }),
+                )?,
+                _ => println!("Starting cluster {}", cluster_id),
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,234 @@
+use crate::output::{print_json, OutputFormat};
+use clap::Subcommand;
+use indicatif::{ProgressBar, ProgressStyle};
+use rustbricks::services::DatabricksSession;
+use std::{
+    error::Error,
+    io,
+    path::{Path, PathBuf},
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+
+#[derive(Subcommand)]
+pub enum FsCommand {
+    /// Copy a file between the local filesystem and DBFS or a Unity Catalog volume
+    ///
+    /// Remote paths use the `dbfs:` scheme, e.g. `dbfs:/tmp/data.csv` or
+    /// `dbfs:/Volumes/main/default/landing/data.csv`.
+    Cp {
+        /// The source path
+        source: String,
+        /// The destination path; a trailing `/` or an existing local directory keeps the file name
+        target: String,
+        /// Replace the destination if it exists
+        #[arg(long)]
+        overwrite: bool,
+    },
+}
+
+/// Where a path given on the command line lives.
+enum Location {
+    Local(PathBuf),
+    Dbfs(String),
+    Volume(String),
+}
+
+impl Location {
+    fn parse(path: &str) -> Self {
+        match path.strip_prefix("dbfs:") {
+            Some(remote) if remote.starts_with("/Volumes/") => Location::Volume(remote.to_string()),
+            Some(remote) => Location::Dbfs(remote.to_string()),
+            None => Location::Local(PathBuf::from(path)),
+        }
+    }
+}
+
+pub async fn run(
+    session: &DatabricksSession,
+    command: FsCommand,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        FsCommand::Cp {
+            source,
+            target,
+            overwrite,
+        } => {
+            let file_name: &str = source.rsplit('/').next().unwrap_or(&source);
+            let bytes: u64 = match (Location::parse(&source), Location::parse(&target)) {
+                (Location::Local(local), remote @ (Location::Dbfs(_) | Location::Volume(_))) => {
+                    upload(session, &local, remote, file_name, overwrite).await?
+                }
+                (remote @ (Location::Dbfs(_) | Location::Volume(_)), Location::Local(local)) => {
+                    download(session, remote, &local, file_name, overwrite).await?
+                }
+                (Location::Local(_), Location::Local(_)) => {
+                    return Err(
+                        "Neither path is remote; prefix DBFS and volume paths with dbfs:".into(),
+                    )
+                }
+                _ => return Err("Copying between two remote paths is not supported".into()),
+            };
+
+            if output == OutputFormat::Json {
+                print_json(&serde_json::json!({
+                    "source": source,
+                    "target": target,
+                    "bytes": bytes,
+                }))?;
+            }
+            Ok(())
+        }
+    }
+}
+
+async fn upload(
+    session: &DatabricksSession,
+    local: &Path,
+    remote: Location,
+    file_name: &str,
+    overwrite: bool,
+) -> Result<u64, Box<dyn Error>> {
+    let file: tokio::fs::File = tokio::fs::File::open(local).await?;
+    let size: u64 = file.metadata().await?.len();
+    let progress: ProgressBar = progress_bar(Some(size));
+    let mut reader: ProgressReader<tokio::fs::File> = ProgressReader {
+        inner: file,
+        progress: progress.clone(),
+    };
+
+    match remote {
+        Location::Volume(path) => {
+            session
+                .upload_file(
+                    &remote_target(&path, file_name),
+                    reader,
+                    Some(size),
+                    overwrite,
+                )
+                .await?
+        }
+        Location::Dbfs(path) => session
+            .upload_dbfs_file(&remote_target(&path, file_name), &mut reader, overwrite)
+            .await
+            .map(|_| ())?,
+        Location::Local(_) => unreachable!("upload target is always remote"),
+    }
+
+    progress.finish();
+    Ok(size)
+}
+
+async fn download(
+    session: &DatabricksSession,
+    remote: Location,
+    local: &Path,
+    file_name: &str,
+    overwrite: bool,
+) -> Result<u64, Box<dyn Error>> {
+    let local: PathBuf = if local.is_dir() {
+        local.join(file_name)
+    } else {
+        local.to_path_buf()
+    };
+    if local.exists() && !overwrite {
+        return Err(format!("{} exists; pass --overwrite to replace it", local.display()).into());
+    }
+
+    let size: Option<u64> = match &remote {
+        Location::Volume(path) => session.get_file_metadata(path).await?.content_length,
+        Location::Dbfs(path) => session.get_dbfs_status(path).await?.file_size,
+        Location::Local(_) => None,
+    };
+    let progress: ProgressBar = progress_bar(size);
+    let mut writer: ProgressWriter<tokio::fs::File> = ProgressWriter {
+        inner: tokio::fs::File::create(&local).await?,
+        progress: progress.clone(),
+    };
+
+    let bytes: u64 = match remote {
+        Location::Volume(path) => session.download_file(&path, &mut writer).await?,
+        Location::Dbfs(path) => session.download_dbfs_file(&path, &mut writer).await?,
+        Location::Local(_) => unreachable!("download source is always remote"),
+    };
+
+    progress.finish();
+    Ok(bytes)
+}
+
+/// Appends the source file name to remote directory paths ending in `/`.
+fn remote_target(path: &str, file_name: &str) -> String {
+    if path.ends_with('/') {
+        format!("{}{}", path, file_name)
+    } else {
+        path.to_string()
+    }
+}
+
+/// A progress bar on stderr; hidden automatically when stderr is not a terminal.
+fn progress_bar(size: Option<u64>) -> ProgressBar {
+    match size {
+        Some(size) => {
+            let progress: ProgressBar = ProgressBar::new(size);
+            progress.set_style(
+                ProgressStyle::with_template(
+                    "{bar:40} {bytes}/{total_bytes} {binary_bytes_per_sec} eta {eta}",
+                )
+                .unwrap_or_else(|_| ProgressStyle::default_bar()),
+            );
+            progress
+        }
+        None => ProgressBar::new_spinner(),
+    }
+}
+
+/// Advances a progress bar as bytes are read.
+struct ProgressReader<R> {
+    inner: R,
+    progress: ProgressBar,
+}
+
+impl<R: AsyncRead + Unpin> AsyncRead for ProgressReader<R> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        let before: usize = buf.filled().len();
+        let poll: Poll<io::Result<()>> = Pin::new(&mut self.inner).poll_read(cx, buf);
+        if let Poll::Ready(Ok(())) = &poll {
+            self.progress.inc((buf.filled().len() - before) as u64);
+        }
+        poll
+    }
+}
+
+/// Advances a progress bar as bytes are written.
+struct ProgressWriter<W> {
+    inner: W,
+    progress: ProgressBar,
+}
+
+impl<W: AsyncWrite + Unpin> AsyncWrite for ProgressWriter<W> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let poll: Poll<io::Result<usize>> = Pin::new(&mut self.inner).poll_write(cx, buf);
+        if let Poll::Ready(Ok(written)) = &poll {
+            self.progress.inc(*written as u64);
+        }
+        poll
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
@@ -0,0 +1,174 @@
+use crate::output::{api_name, print_json, OutputFormat, Table};
+use clap::Subcommand;
+use rustbricks::{
+    models::{JobRunRequest, JobRunResponse, Run, RunState},
+    services::DatabricksSession,
+};
+use std::{collections::HashMap, error::Error, time::Duration};
+
+#[derive(Subcommand)]
+pub enum JobsCommand {
+    /// Trigger a run of an existing job
+    Run {
+        /// The job ID
+        #[arg(long)]
+        job_id: i64,
+        /// A job parameter as `key=value`; may be repeated
+        #[arg(long = "param", value_parser = parse_key_value)]
+        params: Vec<(String, String)>,
+        /// Wait for the run to finish, printing state changes; exits non-zero if the run fails
+        #[arg(long)]
+        follow: bool,
+        /// Seconds between status checks when following
+        #[arg(long, default_value_t = 5, requires = "follow")]
+        poll_interval: u64,
+    },
+}
+
+pub async fn run(
+    session: &DatabricksSession,
+    command: JobsCommand,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        JobsCommand::Run {
+            job_id,
+            params,
+            follow,
+            poll_interval,
+        } => {
+            let job_parameters: Option<HashMap<String, String>> =
+                (!params.is_empty()).then(|| params.into_iter().collect());
+            let response: JobRunResponse = session
+                .execute_job_run(JobRunRequest {
+                    job_parameters,
+                    ..JobRunRequest::new(job_id)
+                })
+                .await?;
+            if follow {
+                return follow_run(
+                    session,
+                    response.run_id,
+                    Duration::from_secs(poll_interval),
+                    output,
+                )
+                .await;
+            }
+            if output == OutputFormat::Json {
+                return print_json(&serde_json::json!({
+                    "run_id": response.run_id,
+                    "number_in_job": response.number_in_job,
+                }));
+            }
+            let mut table: Table = Table::new(&["run_id"]);
+            table.push(vec![Some(response.run_id.to_string())]);
+            table.print(output)
+        }
+    }
+}
+
+/// Polls a run until it terminates, printing run and task state transitions to stderr and a
+/// summary of the tasks to stdout. Returns an error if the run did not succeed.
+async fn follow_run(
+    session: &DatabricksSession,
+    run_id: i64,
+    poll_interval: Duration,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    let mut last_states: HashMap<String, String> = HashMap::new();
+    let mut announced: bool = false;
+
+    let run: Run = loop {
+        let run: Run = session.get_run(run_id).await?;
+        if !announced {
+            eprintln!(
+                "Run {} started{}",
+                run_id,
+                run.run_page_url
+                    .as_deref()
+                    .map(|url| format!(": {}", url))
+                    .unwrap_or_default()
+            );
+            announced = true;
+        }
+
+        let mut report = |key: String, state: Option<&RunState>| {
+            let description: String = describe_state(state);
+            if last_states.get(&key) != Some(&description) {
+                eprintln!(
+                    "[{}] {}: {}",
+                    chrono::Local::now().format("%H:%M:%S"),
+                    key,
+                    description
+                );
+                last_states.insert(key, description);
+            }
+        };
+        report(format!("run {}", run_id), run.state.as_ref());
+        for task in &run.tasks {
+            report(format!("task {}", task.task_key), task.state.as_ref());
+        }
+
+        if run
+            .life_cycle_state()
+            .map(|state| state.is_terminal())
+            .unwrap_or(false)
+        {
+            break run;
+        }
+        tokio::time::sleep(poll_interval).await;
+    };
+
+    if output == OutputFormat::Json {
+        print_json(&run)?;
+    } else {
+        let mut table: Table = Table::new(&["task_key", "life_cycle_state", "result_state"]);
+        for task in &run.tasks {
+            let state: Option<&RunState> = task.state.as_ref();
+            table.push(vec![
+                Some(task.task_key.clone()),
+                state
+                    .and_then(|state| state.life_cycle_state)
+                    .map(|state| api_name(&state)),
+                state
+                    .and_then(|state| state.result_state)
+                    .map(|state| api_name(&state)),
+            ]);
+        }
+        table.print(output)?;
+    }
+
+    match run.result_state() {
+        Some(result) if result.is_success() => Ok(()),
+        result => Err(format!(
+            "Run {} finished with result {}: {}",
+            run_id,
+            result
+                .map(|result| api_name(&result))
+                .unwrap_or_else(|| "UNKNOWN".to_string()),
+            run.state
+                .as_ref()
+                .and_then(|state| state.state_message.as_deref())
+                .unwrap_or_default()
+        )
+        .into()),
+    }
+}
+
+fn describe_state(state: Option<&RunState>) -> String {
+    let life_cycle_state: String = state
+        .and_then(|state| state.life_cycle_state)
+        .map(|state| api_name(&state))
+        .unwrap_or_else(|| "UNKNOWN".to_string());
+    match state.and_then(|state| state.result_state) {
+        Some(result) => format!("{} ({})", life_cycle_state, api_name(&result)),
+        None => life_cycle_state,
+    }
+}
+
+fn parse_key_value(value: &str) -> Result<(String, String), String> {
+    value
+        .split_once('=')
+        .map(|(key, value)| (key.to_string(), value.to_string()))
+        .ok_or_else(|| format!("expected key=value, got {}", value))
+}
@@ -0,0 +1,103 @@
+extern crate rustbricks;
+
+mod clusters;
+mod fs;
+mod jobs;
+mod output;
+mod shell;
+mod sql;
+mod workspace;
+
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::Shell;
+use output::OutputFormat;
+use rustbricks::{config::Config, services::DatabricksSession};
+use std::error::Error;
+
+/// Command-line interface for the Databricks REST API.
+///
+/// The workspace is read from the `--profile` profile in `~/.databrickscfg` if given, otherwise from
+/// `DATABRICKS_HOST`/`DATABRICKS_TOKEN` if set, otherwise from the `DATABRICKS_CONFIG_PROFILE` (or
+/// `DEFAULT`) profile.
+#[derive(Parser)]
+#[command(name = "rustbricks", version, about)]
+struct Cli {
+    /// How to print results
+    #[arg(long, short, global = true, value_enum, default_value = "table")]
+    output: OutputFormat,
+
+    /// The `~/.databrickscfg` profile to connect with
+    #[arg(long, short, global = true)]
+    profile: Option<String>,
+
+    /// Print the changes a command would make instead of making them
+    #[arg(long, global = true)]
+    dry_run: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Execute SQL statements on a SQL warehouse
+    #[command(subcommand)]
+    Sql(sql::SqlCommand),
+    /// Inspect and start clusters
+    #[command(subcommand)]
+    Clusters(clusters::ClustersCommand),
+    /// Trigger job runs
+    #[command(subcommand)]
+    Jobs(jobs::JobsCommand),
+    /// Copy files to and from DBFS and Unity Catalog volumes
+    #[command(subcommand)]
+    Fs(fs::FsCommand),
+    /// Export and import workspace directories
+    #[command(subcommand)]
+    Workspace(workspace::WorkspaceCommand),
+    /// Print a shell completion script, e.g. `rustbricks completions bash > /etc/bash_completion.d/rustbricks`
+    Completions {
+        /// The shell to generate completions for
+        #[arg(value_enum)]
+        shell: Shell,
+    },
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let cli: Cli = Cli::parse();
+
+    if let Command::Completions { shell } = cli.command {
+        clap_complete::generate(
+            shell,
+            &mut Cli::command(),
+            "rustbricks",
+            &mut std::io::stdout(),
+        );
+        return Ok(());
+    }
+
+    let config: Config = Config::from_env_or_profile(cli.profile.as_deref())?;
+    let session: DatabricksSession = DatabricksSession::new(config)?.with_dry_run(cli.dry_run);
+
+    let result: Result<(), Box<dyn Error>> = match cli.command {
+        Command::Sql(command) => sql::run(&session, command, cli.output).await,
+        Command::Clusters(command) => clusters::run(&session, command, cli.output).await,
+        Command::Jobs(command) => jobs::run(&session, command, cli.output).await,
+        Command::Fs(command) => fs::run(&session, command, cli.output).await,
+        Command::Workspace(command) => workspace::run(&session, command, cli.output).await,
+        Command::Completions { .. } => unreachable!("handled before connecting"),
+    };
+    for planned in session.take_planned_requests() {
+        eprintln!(
+            "Would send {} {} {}",
+            planned.method,
+            planned.endpoint,
+            planned
+                .body
+                .map(|body| body.to_string())
+                .unwrap_or_default()
+        );
+    }
+    result
+}
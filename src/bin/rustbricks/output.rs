@@ -0,0 +1,140 @@
+use clap::ValueEnum;
+use serde::Serialize;
+use serde_json::{Map, Value};
+use std::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Aligned columns for humans
+    Table,
+    /// A JSON array of objects, one per row, for scripting
+    Json,
+    /// Comma-separated values with a header line
+    Csv,
+}
+
+/// Tabular command output: named columns and nullable string cells.
+pub struct Table {
+    pub columns: Vec<String>,
+    pub rows: Vec<Vec<Option<String>>>,
+}
+
+impl Table {
+    pub fn new(columns: &[&str]) -> Self {
+        Table {
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    pub fn push(&mut self, row: Vec<Option<String>>) {
+        self.rows.push(row);
+    }
+
+    pub fn print(&self, format: OutputFormat) -> Result<(), Box<dyn Error>> {
+        match format {
+            OutputFormat::Table => print!("{}", self.render_table()),
+            OutputFormat::Json => print_json(&self.to_json())?,
+            OutputFormat::Csv => print!("{}", self.render_csv()),
+        }
+        Ok(())
+    }
+
+    fn render_table(&self) -> String {
+        let cell = |value: &Option<String>| -> String {
+            value.clone().unwrap_or_else(|| "NULL".to_string())
+        };
+
+        let mut widths: Vec<usize> = self
+            .columns
+            .iter()
+            .map(|column| column.chars().count())
+            .collect();
+        for row in &self.rows {
+            for (index, value) in row.iter().enumerate() {
+                if let Some(width) = widths.get_mut(index) {
+                    *width = (*width).max(cell(value).chars().count());
+                }
+            }
+        }
+
+        let line = |values: Vec<String>| -> String {
+            let padded: Vec<String> = values
+                .iter()
+                .zip(&widths)
+                .map(|(value, width)| format!("{:<width$}", value, width = width))
+                .collect();
+            format!("{}\n", padded.join("  ").trim_end())
+        };
+
+        let mut output: String = line(self.columns.clone());
+        output.push_str(&line(
+            widths.iter().map(|width| "-".repeat(*width)).collect(),
+        ));
+        for row in &self.rows {
+            output.push_str(&line(row.iter().map(cell).collect()));
+        }
+        output
+    }
+
+    fn render_csv(&self) -> String {
+        let line = |values: Vec<&str>| -> String {
+            let escaped: Vec<String> = values.into_iter().map(escape_csv).collect();
+            format!("{}\n", escaped.join(","))
+        };
+
+        let mut output: String = line(self.columns.iter().map(String::as_str).collect());
+        for row in &self.rows {
+            output.push_str(&line(
+                row.iter()
+                    .map(|value| value.as_deref().unwrap_or(""))
+                    .collect(),
+            ));
+        }
+        output
+    }
+
+    fn to_json(&self) -> Value {
+        Value::Array(
+            self.rows
+                .iter()
+                .map(|row| {
+                    let object: Map<String, Value> = self
+                        .columns
+                        .iter()
+                        .zip(row)
+                        .map(|(column, value)| {
+                            let value: Value =
+                                value.clone().map(Value::String).unwrap_or(Value::Null);
+                            (column.clone(), value)
+                        })
+                        .collect();
+                    Value::Object(object)
+                })
+                .collect(),
+        )
+    }
+}
+
+/// Prints any serializable value as pretty JSON.
+pub fn print_json<T: Serialize>(value: &T) -> Result<(), Box<dyn Error>> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
+}
+
+fn escape_csv(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// The wire name of a serializable enum value, e.g. `TERMINATED` for `RunLifeCycleState::Terminated`.
+pub fn api_name<T: Serialize>(value: &T) -> String {
+    match serde_json::to_value(value) {
+        Ok(Value::String(name)) => name,
+        Ok(other) => other.to_string(),
+        Err(_) => "UNKNOWN".to_string(),
+    }
+}
@@ -0,0 +1,118 @@
+use crate::{
+    output::OutputFormat,
+    sql::{fetch_all_rows, print_result},
+};
+use rustbricks::{models::SqlStatementResponse, services::DatabricksSession};
+use rustyline::{error::ReadlineError, DefaultEditor};
+use std::{
+    env,
+    error::Error,
+    path::PathBuf,
+    time::{Duration, Instant},
+};
+
+/// Runs an interactive SQL session against a warehouse.
+///
+/// Input is accumulated until a line ends with `;`, so statements may span several lines.
+/// Ctrl-C discards the current statement; Ctrl-D, `exit` or `quit` leave the shell.
+pub async fn run(
+    session: &DatabricksSession,
+    warehouse: &str,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    let mut editor: DefaultEditor = DefaultEditor::new()?;
+    let history: Option<PathBuf> = history_path();
+    if let Some(path) = &history {
+        // A missing history file is expected on first use.
+        let _ = editor.load_history(path);
+    }
+
+    eprintln!(
+        "Connected to warehouse {}. End statements with ';', exit with Ctrl-D.",
+        warehouse
+    );
+
+    let mut buffer: String = String::new();
+    loop {
+        let prompt: &str = if buffer.is_empty() { "sql> " } else { "  -> " };
+        let line: String = match editor.readline(prompt) {
+            Ok(line) => line,
+            Err(ReadlineError::Interrupted) => {
+                buffer.clear();
+                continue;
+            }
+            Err(ReadlineError::Eof) => break,
+            Err(err) => return Err(err.into()),
+        };
+
+        let trimmed: &str = line.trim();
+        if buffer.is_empty() && matches!(trimmed, "exit" | "quit" | "\\q") {
+            break;
+        }
+        if trimmed.is_empty() && buffer.is_empty() {
+            continue;
+        }
+        if !buffer.is_empty() {
+            buffer.push('\n');
+        }
+        buffer.push_str(&line);
+        if !trimmed.ends_with(';') {
+            continue;
+        }
+
+        let statement: String = buffer.trim().trim_end_matches(';').trim().to_string();
+        let _ = editor.add_history_entry(buffer.trim());
+        buffer.clear();
+        if statement.is_empty() {
+            continue;
+        }
+
+        let started: Instant = Instant::now();
+        match execute(session, warehouse, &statement, output).await {
+            Ok(row_count) => eprintln!(
+                "{} row{} in {}",
+                row_count,
+                if row_count == 1 { "" } else { "s" },
+                format_elapsed(started.elapsed())
+            ),
+            Err(err) => eprintln!("Error: {}", err),
+        }
+    }
+
+    if let Some(path) = &history {
+        let _ = editor.save_history(path);
+    }
+    Ok(())
+}
+
+async fn execute(
+    session: &DatabricksSession,
+    warehouse: &str,
+    statement: &str,
+    output: OutputFormat,
+) -> Result<usize, Box<dyn Error>> {
+    let response: SqlStatementResponse = session.execute_sql(warehouse, statement).await?;
+    let rows: Vec<Vec<Option<String>>> = fetch_all_rows(session, &response).await?;
+    let row_count: usize = rows.len();
+    print_result(&response, rows, output)?;
+    Ok(row_count)
+}
+
+fn history_path() -> Option<PathBuf> {
+    env::var("HOME")
+        .or_else(|_| env::var("USERPROFILE"))
+        .ok()
+        .map(|home| PathBuf::from(home).join(".rustbricks_history"))
+}
+
+fn format_elapsed(elapsed: Duration) -> String {
+    if elapsed.as_secs() >= 60 {
+        format!(
+            "{}m {:.1}s",
+            elapsed.as_secs() / 60,
+            elapsed.as_secs_f64() % 60.0
+        )
+    } else {
+        format!("{:.2}s", elapsed.as_secs_f64())
+    }
+}
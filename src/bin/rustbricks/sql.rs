@@ -0,0 +1,120 @@
+use crate::output::{print_json, OutputFormat, Table};
+use clap::Subcommand;
+use rustbricks::{
+    models::{ChunkValidator, ResultData, Schema, SqlStatementResponse},
+    services::DatabricksSession,
+};
+use std::error::Error;
+
+#[derive(Subcommand)]
+pub enum SqlCommand {
+    /// Execute a single statement and print its result
+    Exec {
+        /// The SQL warehouse to run the statement on
+        #[arg(long, env = "DATABRICKS_WAREHOUSE_ID")]
+        warehouse: String,
+        /// The SQL statement
+        statement: String,
+    },
+    /// Start an interactive SQL shell
+    Shell {
+        /// The SQL warehouse to run statements on
+        #[arg(long, env = "DATABRICKS_WAREHOUSE_ID")]
+        warehouse: String,
+    },
+}
+
+pub async fn run(
+    session: &DatabricksSession,
+    command: SqlCommand,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    match command {
+        SqlCommand::Exec {
+            warehouse,
+            statement,
+        } => {
+            let response: SqlStatementResponse =
+                session.execute_sql(&warehouse, &statement).await?;
+            let rows: Vec<Vec<Option<String>>> = fetch_all_rows(session, &response).await?;
+            print_result(&response, rows, output)
+        }
+        SqlCommand::Shell { warehouse } => crate::shell::run(session, &warehouse, output).await,
+    }
+}
+
+/// Collects the inline rows of a statement, fetching any further result chunks.
+///
+/// Prints a warning to stderr if the result was truncated.
+pub async fn fetch_all_rows(
+    session: &DatabricksSession,
+    response: &SqlStatementResponse,
+) -> Result<Vec<Vec<Option<String>>>, Box<dyn Error>> {
+    let mut validator: Option<ChunkValidator> = ChunkValidator::for_response(response);
+    let mut rows: Vec<Vec<Option<String>>> = Vec::new();
+    let mut next_chunk_index: Option<i32> = None;
+    if let Some(result) = &response.result {
+        if let Some(validator) = &mut validator {
+            validator.record_data(result)?;
+        }
+        rows.extend(result.data_array.clone().unwrap_or_default());
+        next_chunk_index = result.next_chunk_index;
+    }
+
+    let statement_id: &str = response.statement_id.as_deref().unwrap_or_default();
+    while let Some(chunk_index) = next_chunk_index {
+        let chunk: ResultData = session
+            .get_sql_statement_result_chunk(statement_id, chunk_index)
+            .await?;
+        if let Some(validator) = &mut validator {
+            validator.record_data(&chunk)?;
+        }
+        rows.extend(chunk.data_array.unwrap_or_default());
+        next_chunk_index = chunk.next_chunk_index;
+    }
+    if let Some(validator) = &validator {
+        validator.finish()?;
+    }
+
+    if response.is_truncated() {
+        eprintln!(
+            "warning: result truncated to {} rows; add a LIMIT or narrow the query",
+            rows.len()
+        );
+    }
+    Ok(rows)
+}
+
+/// Prints the rows of a statement; JSON output uses typed values (numbers, booleans, nested
+/// objects) according to the result schema.
+pub fn print_result(
+    response: &SqlStatementResponse,
+    rows: Vec<Vec<Option<String>>>,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    let schema: Option<&Schema> = response
+        .manifest
+        .as_ref()
+        .and_then(|manifest| manifest.schema.as_ref());
+    match (output, schema) {
+        (OutputFormat::Json, Some(schema)) => print_json(&schema.to_json_rows(&rows)),
+        _ => result_table(response, rows).print(output),
+    }
+}
+
+/// Builds a `Table` from the column names in the statement's result manifest.
+fn result_table(response: &SqlStatementResponse, rows: Vec<Vec<Option<String>>>) -> Table {
+    let columns: Vec<String> = response
+        .manifest
+        .as_ref()
+        .and_then(|manifest| manifest.schema.as_ref())
+        .map(|schema| {
+            schema
+                .columns
+                .iter()
+                .map(|column| column.name.clone())
+                .collect()
+        })
+        .unwrap_or_default();
+    Table { columns, rows }
+}
@@ -0,0 +1,186 @@
+use crate::output::{print_json, OutputFormat, Table};
+use clap::{Subcommand, ValueEnum};
+use rustbricks::{
+    models::{ExportFormat, WorkspaceSyncOptions, WorkspaceSyncReport},
+    services::DatabricksSession,
+};
+use std::{error::Error, path::PathBuf};
+
+#[derive(Subcommand)]
+pub enum WorkspaceCommand {
+    /// Recursively export a workspace directory to a local directory
+    ExportDir {
+        /// The workspace directory, e.g. `/Users/someone@example.com/project`
+        source: String,
+        /// The local directory to write into
+        target: PathBuf,
+        /// Replace local files that already exist
+        #[arg(long)]
+        overwrite: bool,
+        /// The notebook format
+        #[arg(long, value_enum, default_value = "source")]
+        format: NotebookFormat,
+        /// Leave out workspace files that are not notebooks
+        #[arg(long)]
+        skip_files: bool,
+        /// The number of objects transferred at the same time
+        #[arg(long, default_value_t = 8)]
+        parallelism: usize,
+    },
+    /// Recursively import a local directory into a workspace directory
+    ImportDir {
+        /// The local directory to import
+        source: PathBuf,
+        /// The workspace directory to import into
+        target: String,
+        /// Replace workspace objects that already exist
+        #[arg(long)]
+        overwrite: bool,
+        /// The notebook format of the local files
+        #[arg(long, value_enum, default_value = "source")]
+        format: NotebookFormat,
+        /// Leave out local files that are not notebooks
+        #[arg(long)]
+        skip_files: bool,
+        /// The number of objects transferred at the same time
+        #[arg(long, default_value_t = 8)]
+        parallelism: usize,
+    },
+    /// Export a workspace directory as a DBC archive, e.g. for backups
+    ExportDbc {
+        /// The workspace directory
+        source: String,
+        /// The local archive file to write
+        target: PathBuf,
+        /// Replace the archive if it exists
+        #[arg(long)]
+        overwrite: bool,
+    },
+    /// Import a DBC archive into a workspace path that does not exist yet
+    ImportDbc {
+        /// The local archive file
+        source: PathBuf,
+        /// The workspace path to import into
+        target: String,
+    },
+}
+
+/// The notebook formats supported for directory sync.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum NotebookFormat {
+    /// Source files (`.py`, `.scala`, `.sql`, `.r`)
+    Source,
+    /// Jupyter notebooks (`.ipynb`)
+    Jupyter,
+    /// HTML exports (`.html`)
+    Html,
+}
+
+impl From<NotebookFormat> for ExportFormat {
+    fn from(format: NotebookFormat) -> Self {
+        match format {
+            NotebookFormat::Source => ExportFormat::Source,
+            NotebookFormat::Jupyter => ExportFormat::Jupyter,
+            NotebookFormat::Html => ExportFormat::Html,
+        }
+    }
+}
+
+pub async fn run(
+    session: &DatabricksSession,
+    command: WorkspaceCommand,
+    output: OutputFormat,
+) -> Result<(), Box<dyn Error>> {
+    let report: WorkspaceSyncReport = match command {
+        WorkspaceCommand::ExportDir {
+            source,
+            target,
+            overwrite,
+            format,
+            skip_files,
+            parallelism,
+        } => {
+            let options: WorkspaceSyncOptions = WorkspaceSyncOptions {
+                format: format.into(),
+                overwrite,
+                skip_files,
+                concurrency: parallelism,
+                ..Default::default()
+            };
+            session.export_dir(&source, &target, &options).await?
+        }
+        WorkspaceCommand::ImportDir {
+            source,
+            target,
+            overwrite,
+            format,
+            skip_files,
+            parallelism,
+        } => {
+            let options: WorkspaceSyncOptions = WorkspaceSyncOptions {
+                format: format.into(),
+                overwrite,
+                skip_files,
+                concurrency: parallelism,
+                ..Default::default()
+            };
+            session.import_dir(&source, &target, &options).await?
+        }
+        WorkspaceCommand::ExportDbc {
+            source,
+            target,
+            overwrite,
+        } => {
+            if target.exists() && !overwrite {
+                return Err(format!(
+                    "{} exists; pass --overwrite to replace it",
+                    target.display()
+                )
+                .into());
+            }
+            let mut file: tokio::fs::File = tokio::fs::File::create(&target).await?;
+            let bytes: u64 = session.export_workspace_dbc(&source, &mut file).await?;
+            if output == OutputFormat::Json {
+                print_json(&serde_json::json!({
+                    "source": source,
+                    "target": target,
+                    "bytes": bytes,
+                }))?;
+            }
+            return Ok(());
+        }
+        WorkspaceCommand::ImportDbc { source, target } => {
+            let file: tokio::fs::File = tokio::fs::File::open(&source).await?;
+            let bytes: u64 = file.metadata().await?.len();
+            session
+                .import_workspace_dbc(&target, file, Some(bytes))
+                .await?;
+            if output == OutputFormat::Json {
+                print_json(&serde_json::json!({
+                    "source": source,
+                    "target": target,
+                    "bytes": bytes,
+                }))?;
+            }
+            return Ok(());
+        }
+    };
+
+    match output {
+        OutputFormat::Json => print_json(&serde_json::json!({
+            "transferred": report.transferred,
+            "skipped": report.skipped,
+        }))?,
+        format => {
+            let mut table: Table = Table::new(&["path", "status"]);
+            for path in report.transferred {
+                table.push(vec![Some(path), Some("transferred".to_string())]);
+            }
+            for path in report.skipped {
+                table.push(vec![Some(path), Some("skipped".to_string())]);
+            }
+            table.print(format)?;
+        }
+    }
+    Ok(())
+}
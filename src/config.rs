@@ -1,22 +1,335 @@
 use serde::{Deserialize, Serialize};
-use std::env;
+use std::{
+    collections::HashMap,
+    env, fmt, fs,
+    path::{Path, PathBuf},
+};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
     pub databricks_host: String,
     pub databricks_token: String,
+    /// A file the token was read from. Sessions read it again whenever it changes, so rotated
+    /// credentials are picked up; `databricks_token` holds its contents at load time.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub databricks_token_file: Option<PathBuf>,
 }
 
 impl Config {
+    /// Reads `DATABRICKS_HOST` and `DATABRICKS_TOKEN` from the environment.
+    ///
+    /// If `DATABRICKS_TOKEN` is not set, the token is read from the file named by
+    /// `DATABRICKS_TOKEN_FILE`, as with `with_token_file`. The host is validated and normalized
+    /// as described in `from_host_and_token`.
     pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
         let databricks_host = env::var("DATABRICKS_HOST")
             .map_err(|_| "DATABRICKS_HOST must be set in the environment")?;
+        let config: Config = Config {
+            databricks_host: normalize_host(&databricks_host, "DATABRICKS_HOST")?,
+            databricks_token: String::new(),
+            databricks_token_file: None,
+        };
+
+        match (
+            env::var("DATABRICKS_TOKEN"),
+            env::var("DATABRICKS_TOKEN_FILE"),
+        ) {
+            (Ok(databricks_token), _) => Ok(Config {
+                databricks_token,
+                ..config
+            }),
+            (Err(_), Ok(path)) => config.with_token_file(path),
+            (Err(_), Err(_)) => Err(
+                "DATABRICKS_TOKEN or DATABRICKS_TOKEN_FILE must be set in the environment".into(),
+            ),
+        }
+    }
+
+    /// Creates a configuration from a workspace host and a token.
+    ///
+    /// The host may be given with or without `https://`; a host without a scheme is assumed to
+    /// use HTTPS, and trailing slashes are removed so endpoint URLs are not built with `//`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `Config`, or an error if the host is empty, has a scheme
+    ///   other than `https://` or `http://`, or contains a query or fragment.
+    pub fn from_host_and_token(
+        host: &str,
+        token: &str,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        Ok(Config {
+            databricks_host: normalize_host(host, "The host")?,
+            databricks_token: token.to_string(),
+            databricks_token_file: None,
+        })
+    }
+
+    /// Reads the token from a file, such as a Kubernetes secret mounted into the pod, instead of
+    /// using `databricks_token`. Sessions created from the configuration read the file again
+    /// whenever its modification time changes, so rotated credentials are picked up without a
+    /// restart.
+    ///
+    /// Parameters:
+    /// - `path`: The file containing the token; surrounding whitespace is ignored.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `Config`, or an error if the file cannot be read or is empty.
+    pub fn with_token_file(
+        self,
+        path: impl AsRef<Path>,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let path: &Path = path.as_ref();
+        Ok(Config {
+            databricks_token: read_token_file(path)?,
+            databricks_token_file: Some(path.to_path_buf()),
+            ..self
+        })
+    }
+
+    /// Reads `host` and `token` from a profile in the Databricks CLI configuration file.
+    ///
+    /// The file is `~/.databrickscfg`, or the path in `DATABRICKS_CONFIG_FILE` if set.
+    ///
+    /// Parameters:
+    /// - `profile`: The section name, e.g. `DEFAULT`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `Config`, or an error if the file or profile cannot be read or
+    ///   the profile has no host or token.
+    pub fn from_profile(profile: &str) -> Result<Self, Box<dyn std::error::Error>> {
+        let path: PathBuf = config_file_path()?;
+        let contents: String = fs::read_to_string(&path)
+            .map_err(|err| format!("Could not read {}: {}", path.display(), err))?;
+        let mut values: HashMap<String, String> = parse_profile(&contents, profile)
+            .ok_or_else(|| format!("Profile {} not found in {}", profile, path.display()))?;
+
+        let databricks_host = values
+            .remove("host")
+            .ok_or_else(|| format!("Profile {} has no host", profile))?;
+        let databricks_token = values
+            .remove("token")
+            .ok_or_else(|| format!("Profile {} has no token", profile))?;
+
+        Ok(Config {
+            databricks_host: normalize_host(
+                &databricks_host,
+                &format!("The host of profile {}", profile),
+            )?,
+            databricks_token,
+            databricks_token_file: None,
+        })
+    }
+
+    /// Reads the configuration from the environment if `DATABRICKS_HOST` and `DATABRICKS_TOKEN`
+    /// (or `DATABRICKS_TOKEN_FILE`) are set, and from a configuration profile otherwise.
+    ///
+    /// The profile is `profile` if given, else `DATABRICKS_CONFIG_PROFILE`, else `DEFAULT`.
+    /// An explicitly requested profile takes precedence over the environment.
+    pub fn from_env_or_profile(profile: Option<&str>) -> Result<Self, Box<dyn std::error::Error>> {
+        if let Some(profile) = profile {
+            return Self::from_profile(profile);
+        }
+        if env::var("DATABRICKS_HOST").is_ok()
+            && (env::var("DATABRICKS_TOKEN").is_ok() || env::var("DATABRICKS_TOKEN_FILE").is_ok())
+        {
+            return Self::new();
+        }
+        let profile: String =
+            env::var("DATABRICKS_CONFIG_PROFILE").unwrap_or_else(|_| "DEFAULT".to_string());
+        Self::from_profile(&profile)
+    }
+}
+
+fn config_file_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    if let Ok(path) = env::var("DATABRICKS_CONFIG_FILE") {
+        return Ok(PathBuf::from(path));
+    }
+    let home: String = env::var("HOME")
+        .or_else(|_| env::var("USERPROFILE"))
+        .map_err(|_| "Could not determine the home directory")?;
+    Ok(PathBuf::from(home).join(".databrickscfg"))
+}
+
+/// Reads a token from `path`, without surrounding whitespace such as a trailing newline.
+pub(crate) fn read_token_file(path: &Path) -> Result<String, String> {
+    let contents: String = fs::read_to_string(path)
+        .map_err(|err| format!("Could not read token file {}: {}", path.display(), err))?;
+    let token: &str = contents.trim();
+    if token.is_empty() {
+        return Err(format!("Token file {} is empty", path.display()));
+    }
+    Ok(token.to_string())
+}
+
+/// Validates a workspace or account host and brings it into the form `https://name[/path]`.
+///
+/// `source` names where the value came from, e.g. `DATABRICKS_HOST`, for the error message.
+fn normalize_host(host: &str, source: &str) -> Result<String, String> {
+    let host: &str = host.trim();
+    if host.is_empty() {
+        return Err(format!("{} is empty", source));
+    }
+    let (scheme, rest): (&str, &str) = match host.split_once("://") {
+        Some((scheme, rest)) => (scheme, rest),
+        None => ("https", host),
+    };
+    if !scheme.eq_ignore_ascii_case("https") && !scheme.eq_ignore_ascii_case("http") {
+        return Err(format!(
+            "{} {} has an unsupported scheme {}://; expected https://",
+            source, host, scheme
+        ));
+    }
+    if rest.contains(['?', '#']) {
+        return Err(format!(
+            "{} {} must not contain a query or fragment, such as ?o=<workspace id>",
+            source, host
+        ));
+    }
+    if rest.contains(char::is_whitespace) {
+        return Err(format!("{} {} contains whitespace", source, host));
+    }
+    let rest: &str = rest.trim_end_matches('/');
+    if rest.is_empty() || rest.starts_with('/') {
+        return Err(format!("{} {} has no host name", source, host));
+    }
+    Ok(format!("{}://{}", scheme.to_ascii_lowercase(), rest))
+}
+
+/// Extracts the key-value pairs of one `[section]` of an INI file.
+fn parse_profile(contents: &str, profile: &str) -> Option<HashMap<String, String>> {
+    let mut values: Option<HashMap<String, String>> = None;
+    let mut in_profile: bool = false;
+
+    for line in contents.lines() {
+        let line: &str = line.trim();
+        if line.is_empty() || line.starts_with('#') || line.starts_with(';') {
+            continue;
+        }
+        if let Some(section) = line
+            .strip_prefix('[')
+            .and_then(|line| line.strip_suffix(']'))
+        {
+            in_profile = section.trim() == profile;
+            if in_profile {
+                values.get_or_insert_with(HashMap::new);
+            }
+            continue;
+        }
+        if in_profile {
+            if let Some((key, value)) = line.split_once('=') {
+                values
+                    .get_or_insert_with(HashMap::new)
+                    .insert(key.trim().to_string(), value.trim().to_string());
+            }
+        }
+    }
+
+    values
+}
+
+/// Configuration for the account-level API (`accounts.cloud.databricks.com` on AWS).
+#[derive(Debug, Serialize, Deserialize)]
+pub struct AccountConfig {
+    pub account_host: String,
+    pub account_id: String,
+    pub databricks_token: String,
+}
+
+impl AccountConfig {
+    /// Reads `DATABRICKS_ACCOUNT_ID` and `DATABRICKS_TOKEN` from the environment.
+    /// `DATABRICKS_ACCOUNT_HOST` is optional and defaults to the AWS accounts console; like
+    /// `DATABRICKS_HOST`, it may be given with or without `https://`.
+    pub fn new() -> Result<Self, Box<dyn std::error::Error>> {
+        let account_host = match env::var("DATABRICKS_ACCOUNT_HOST") {
+            Ok(host) => normalize_host(&host, "DATABRICKS_ACCOUNT_HOST")?,
+            Err(_) => "https://accounts.cloud.databricks.com".to_string(),
+        };
+        let account_id = env::var("DATABRICKS_ACCOUNT_ID")
+            .map_err(|_| "DATABRICKS_ACCOUNT_ID must be set in the environment")?;
         let databricks_token = env::var("DATABRICKS_TOKEN")
             .map_err(|_| "DATABRICKS_TOKEN must be set in the environment")?;
 
-        Ok(Config {
-            databricks_host,
+        Ok(AccountConfig {
+            account_host,
+            account_id,
             databricks_token,
         })
     }
 }
+
+/// A version of the Databricks REST API, as it appears in endpoint paths (`api/2.1/...`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ApiVersion {
+    #[serde(rename = "2.0")]
+    V2_0,
+    #[serde(rename = "2.1")]
+    V2_1,
+    #[serde(rename = "2.2")]
+    V2_2,
+}
+
+impl ApiVersion {
+    /// Returns the form used in endpoint paths, e.g. `2.1`.
+    pub fn as_path_segment(&self) -> &'static str {
+        match self {
+            ApiVersion::V2_0 => "2.0",
+            ApiVersion::V2_1 => "2.1",
+            ApiVersion::V2_2 => "2.2",
+        }
+    }
+}
+
+impl fmt::Display for ApiVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_path_segment())
+    }
+}
+
+/// A group of endpoints that is published in more than one API version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ApiService {
+    Jobs,
+    Clusters,
+    UnityCatalog,
+}
+
+impl ApiService {
+    /// The version the session uses unless another one is selected.
+    pub fn default_version(&self) -> ApiVersion {
+        match self {
+            ApiService::Jobs => ApiVersion::V2_1,
+            ApiService::Clusters => ApiVersion::V2_1,
+            ApiService::UnityCatalog => ApiVersion::V2_1,
+        }
+    }
+
+    /// Returns `true` if the service's endpoints are published in `version`.
+    pub fn supports(&self, version: ApiVersion) -> bool {
+        match self {
+            ApiService::Jobs => true,
+            ApiService::Clusters => matches!(version, ApiVersion::V2_0 | ApiVersion::V2_1),
+            ApiService::UnityCatalog => version == ApiVersion::V2_1,
+        }
+    }
+}
+
+/// The API version selected for each `ApiService`.
+///
+/// A per-service override wins over the session-level default, which wins over the service's
+/// `default_version`. The session-level default only applies to services that support it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ApiVersions {
+    pub default: Option<ApiVersion>,
+    pub overrides: HashMap<ApiService, ApiVersion>,
+}
+
+impl ApiVersions {
+    /// The version to use for `service`.
+    pub fn version(&self, service: ApiService) -> ApiVersion {
+        self.overrides
+            .get(&service)
+            .copied()
+            .or(self.default.filter(|version| service.supports(*version)))
+            .unwrap_or_else(|| service.default_version())
+    }
+}
@@ -0,0 +1,87 @@
+use std::fmt;
+
+/// How a result chunk disagrees with the manifest or with the chunks received before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ChunkErrorKind {
+    /// Rows between `expected_offset` and `actual_offset` are not covered by any chunk.
+    Gap {
+        expected_offset: i64,
+        actual_offset: i64,
+    },
+    /// The chunk starts at `offset`, inside rows already covered by chunk `other_chunk_index`.
+    Overlap { offset: i64, other_chunk_index: i32 },
+    /// The same chunk index was received twice.
+    Duplicate,
+    /// The chunk's row offset or count differs from the manifest's entry for it.
+    ManifestMismatch {
+        manifest_offset: i64,
+        manifest_count: i64,
+        actual_offset: i64,
+        actual_count: i64,
+    },
+    /// The chunk holds a different number of rows than it declares.
+    RowCountMismatch { expected: i64, actual: i64 },
+    /// A downloaded chunk has a different size than its external link declares.
+    ByteCountMismatch { expected: i64, actual: i64 },
+    /// The result ended after `received` of `expected` chunks.
+    MissingChunks { expected: i32, received: i32 },
+}
+
+/// A result chunk that does not fit the statement's result, detected while reassembling it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChunkError {
+    pub statement_id: String,
+    /// The chunk the problem was detected on, or `None` for checks across the whole result.
+    pub chunk_index: Option<i32>,
+    pub kind: ChunkErrorKind,
+}
+
+impl fmt::Display for ChunkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Invalid result of statement {}", self.statement_id)?;
+        if let Some(chunk_index) = self.chunk_index {
+            write!(f, " at chunk {}", chunk_index)?;
+        }
+        match &self.kind {
+            ChunkErrorKind::Gap {
+                expected_offset,
+                actual_offset,
+            } => write!(
+                f,
+                ": rows {} to {} are missing",
+                expected_offset,
+                actual_offset - 1
+            ),
+            ChunkErrorKind::Overlap {
+                offset,
+                other_chunk_index,
+            } => write!(
+                f,
+                ": row {} is also covered by chunk {}",
+                offset, other_chunk_index
+            ),
+            ChunkErrorKind::Duplicate => write!(f, ": chunk was received twice"),
+            ChunkErrorKind::ManifestMismatch {
+                manifest_offset,
+                manifest_count,
+                actual_offset,
+                actual_count,
+            } => write!(
+                f,
+                ": manifest declares {} rows at offset {}, chunk has {} rows at offset {}",
+                manifest_count, manifest_offset, actual_count, actual_offset
+            ),
+            ChunkErrorKind::RowCountMismatch { expected, actual } => {
+                write!(f, ": expected {} rows, received {}", expected, actual)
+            }
+            ChunkErrorKind::ByteCountMismatch { expected, actual } => {
+                write!(f, ": expected {} bytes, received {}", expected, actual)
+            }
+            ChunkErrorKind::MissingChunks { expected, received } => {
+                write!(f, ": received {} of {} chunks", received, expected)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ChunkError {}
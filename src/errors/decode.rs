@@ -0,0 +1,30 @@
+use std::fmt;
+
+/// A successful response whose body could not be deserialized into the expected model.
+#[derive(Debug)]
+pub struct DecodeError {
+    /// The path of the endpoint that returned the body, e.g. `/api/2.0/clusters/get`.
+    pub endpoint: String,
+    /// Where in the document deserialization failed, e.g. `spec.azure_attributes.availability`.
+    pub path: String,
+    /// The part of the body around the failure, or empty if the body was parsed as a stream.
+    pub snippet: String,
+    /// The underlying serde error.
+    pub message: String,
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Could not decode response of {} at `{}`: {}",
+            self.endpoint, self.path, self.message
+        )?;
+        if !self.snippet.is_empty() {
+            write!(f, " near `{}`", self.snippet)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for DecodeError {}
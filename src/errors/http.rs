@@ -1,5 +1,6 @@
+use super::{ChunkError, DecodeError};
 use serde::{Deserialize, Serialize};
-use std::fmt;
+use std::{fmt, time::Duration};
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ErrorResponse {
@@ -13,23 +14,76 @@ pub enum HttpError {
     Unauthorized(String),
     PermissionDenied(String),
     NotFound(String),
-    RequestLimitExceeded(String),
+    Conflict(String),
+    /// The caller was rate limited. `retry_after` is the delay the server asked for in its
+    /// `Retry-After` header, if it sent one.
+    RequestLimitExceeded {
+        message: String,
+        retry_after: Option<Duration>,
+    },
     InternalServerError(String),
     TemporarilyUnavailable(String),
+    DecodeError(DecodeError),
+    /// A multi-chunk result has gaps, overlaps or chunks that disagree with its manifest.
+    InvalidChunk(ChunkError),
+    /// A successful response body was larger than the session's `max_response_bytes`.
+    ResponseTooLarge {
+        endpoint: String,
+        limit: u64,
+    },
+    /// A composite operation (e.g. execute-and-wait) did not finish within the session's
+    /// `operation_timeout`.
+    OperationTimedOut {
+        operation: String,
+        timeout: Duration,
+    },
     InternalError(Box<dyn std::error::Error>),
 }
 
 impl HttpError {
     pub fn from_error_response(response: ErrorResponse) -> Self {
-        match response.error_code.as_str() {
-            "BAD_REQUEST" | "INVALID_PARAMETER_VALUE" => HttpError::BadRequest(response.message),
-            "UNAUTHORIZED" => HttpError::Unauthorized(response.message),
-            "PERMISSION_DENIED" => HttpError::PermissionDenied(response.message),
-            "NOT_FOUND" => HttpError::NotFound(response.message),
-            "REQUEST_LIMIT_EXCEEDED" => HttpError::RequestLimitExceeded(response.message),
-            "INTERNAL_SERVER_ERROR" => HttpError::InternalServerError(response.message),
-            "TEMPORARILY_UNAVAILABLE" => HttpError::TemporarilyUnavailable(response.message),
-            _ => HttpError::InternalServerError(response.message),
+        HttpError::from_error_code(&response.error_code, response.message)
+            .unwrap_or_else(HttpError::InternalServerError)
+    }
+
+    /// Maps a Databricks `error_code` onto its `HttpError` variant, handing the message back if
+    /// the code is not recognized.
+    pub(crate) fn from_error_code(error_code: &str, message: String) -> Result<Self, String> {
+        match error_code {
+            "BAD_REQUEST" | "INVALID_PARAMETER_VALUE" => Ok(HttpError::BadRequest(message)),
+            "UNAUTHORIZED" => Ok(HttpError::Unauthorized(message)),
+            "PERMISSION_DENIED" => Ok(HttpError::PermissionDenied(message)),
+            "NOT_FOUND" | "RESOURCE_DOES_NOT_EXIST" => Ok(HttpError::NotFound(message)),
+            "RESOURCE_CONFLICT" | "RESOURCE_ALREADY_EXISTS" | "ABORTED" => {
+                Ok(HttpError::Conflict(message))
+            }
+            "REQUEST_LIMIT_EXCEEDED" => Ok(HttpError::RequestLimitExceeded {
+                message,
+                retry_after: None,
+            }),
+            "INTERNAL_SERVER_ERROR" => Ok(HttpError::InternalServerError(message)),
+            "TEMPORARILY_UNAVAILABLE" => Ok(HttpError::TemporarilyUnavailable(message)),
+            _ => Err(message),
+        }
+    }
+}
+
+impl HttpError {
+    /// Maps a bare HTTP status code (e.g. from a presigned cloud storage URL, which does not return
+    /// a Databricks error body) onto the closest `HttpError` variant.
+    pub fn from_status(status: u16, message: String) -> Self {
+        match status {
+            400 => HttpError::BadRequest(message),
+            401 => HttpError::Unauthorized(message),
+            403 => HttpError::PermissionDenied(message),
+            404 => HttpError::NotFound(message),
+            409 => HttpError::Conflict(message),
+            429 => HttpError::RequestLimitExceeded {
+                message,
+                retry_after: None,
+            },
+            503 => HttpError::TemporarilyUnavailable(message),
+            _ => HttpError::InternalServerError(message),
         }
     }
 }
@@ -41,9 +95,23 @@ impl fmt::Display for HttpError {
             | HttpError::Unauthorized(message)
             | HttpError::PermissionDenied(message)
             | HttpError::NotFound(message)
-            | HttpError::RequestLimitExceeded(message)
+            | HttpError::Conflict(message)
+            | HttpError::RequestLimitExceeded { message, .. }
             | HttpError::InternalServerError(message)
             | HttpError::TemporarilyUnavailable(message) => write!(f, "{}", message),
+            HttpError::DecodeError(error) => write!(f, "{}", error),
+            HttpError::InvalidChunk(error) => write!(f, "{}", error),
+            HttpError::ResponseTooLarge { endpoint, limit } => write!(
+                f,
+                "Response of {} exceeds the maximum of {} bytes; use `request_stream` or the \
+                 EXTERNAL_LINKS disposition for large results",
+                endpoint, limit
+            ),
+            HttpError::OperationTimedOut { operation, timeout } => write!(
+                f,
+                "{} did not finish within the operation timeout of {:?}",
+                operation, timeout
+            ),
             HttpError::InternalError(message) => write!(f, "{}", message),
         }
     }
@@ -53,6 +121,8 @@ impl std::error::Error for HttpError {
     fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
         match self {
             // Handle other variants accordingly...
+            HttpError::DecodeError(e) => Some(e),
+            HttpError::InvalidChunk(e) => Some(e),
             HttpError::InternalError(e) => Some(e.as_ref()),
             _ => None,
         }
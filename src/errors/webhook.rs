@@ -0,0 +1,33 @@
+use std::fmt;
+
+/// Why a received webhook request was rejected.
+#[derive(Debug)]
+pub enum WebhookError {
+    /// The request carries no `X-Databricks-Signature` header although a secret is configured.
+    MissingSignature,
+    /// The signature does not match the body, or is not valid hex.
+    InvalidSignature,
+    /// The body is not a valid payload for the webhook.
+    InvalidPayload(serde_json::Error),
+}
+
+impl fmt::Display for WebhookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WebhookError::MissingSignature => write!(f, "Webhook request is not signed"),
+            WebhookError::InvalidSignature => {
+                write!(f, "Webhook signature does not match the request body")
+            }
+            WebhookError::InvalidPayload(err) => write!(f, "Invalid webhook payload: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for WebhookError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WebhookError::InvalidPayload(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -1,25 +1,418 @@
 pub mod config;
 
 pub mod models {
+    mod account_networks;
+    mod account_workspaces;
+    mod ai_gateway;
+    mod audit_logs;
+    mod budget_policies;
     mod cluster_info;
+    mod cluster_policies;
+    mod copy_into;
+    mod dbsql;
+    mod delta_history;
+    mod delta_sharing;
+    mod dry_run;
+    mod external_locations;
+    mod feature_serving;
+    mod file_arrival;
+    mod files;
+    mod foundation_models;
+    mod genie;
+    mod grants;
+    mod identity;
+    mod information_schema;
+    mod inventory;
+    mod janitor;
+    mod job_diff;
+    mod job_queue;
     mod job_run_info;
+    mod jobs;
+    mod lineage;
+    mod log_delivery;
+    mod mlflow_artifacts;
+    mod oauth_integrations;
+    mod permissions;
+    mod permissions_snapshot;
+    mod pipeline_events;
+    mod query_history;
+    mod query_template;
+    mod registry_webhooks;
+    mod repos;
+    mod result_chunks;
+    mod run_job_graph;
+    mod run_retry;
+    mod schema_drift;
+    mod serving_endpoints;
+    mod serving_invocations;
+    mod serving_rollout;
+    mod settings;
     mod sql_statement;
+    mod system_tables;
+    mod table_constraints;
+    mod table_maintenance;
+    mod table_operations;
+    mod temporary_credentials;
+    mod token_usage;
+    mod uc_functions;
+    mod unity_catalog;
+    mod vector_search;
+    mod warehouses;
+    mod webhooks;
+    mod workspace;
+    mod workspace_conf;
+    mod workspace_registry;
 
-    pub use cluster_info::ClusterInfo;
-    pub use job_run_info::{JobRunRequest, JobRunResponse, QueueSettings};
+    pub use account_networks::{
+        CreateNetworkRequest, CreateVpcEndpointRequest, GcpNetworkInfo, GcpVpcEndpointInfo,
+        Network, NetworkHealth, NetworkVpcEndpoints, NetworkWarning, PrivateAccessLevel,
+        PrivateAccessSettings, PrivateAccessSettingsRequest, VpcEndpoint, VpcEndpointUseCase,
+        VpcStatus,
+    };
+    pub use account_workspaces::{
+        AssignmentPrincipal, CreateWorkspaceRequest, PermissionAssignment, PermissionAssignments,
+        PricingTier, UpdateWorkspaceAssignmentRequest, UpdateWorkspaceRequest, Workspace,
+        WorkspacePermission, WorkspaceStatus,
+    };
+    pub use ai_gateway::{
+        AiGatewayConfig, AiGatewayFallbackConfig, AiGatewayGuardrailParameters,
+        AiGatewayGuardrails, AiGatewayInferenceTableConfig, AiGatewayRateLimit,
+        AiGatewayUsageTrackingConfig, PiiBehavior, PiiGuardrail, RateLimitKey,
+        RateLimitRenewalPeriod,
+    };
+    pub use audit_logs::AuditEvent;
+    pub use budget_policies::{
+        BudgetPolicy, CustomPolicyTag, ListBudgetPoliciesResponse, NewBudgetPolicy,
+    };
+    pub(crate) use budget_policies::{
+        BudgetPolicyAssignment, CreateBudgetPolicyRequest, UpdateJobBudgetPolicyRequest,
+    };
+    pub use cluster_info::{ClusterEvent, ClusterInfo, ClusterSummary, ListClustersResponse};
+    pub(crate) use cluster_info::{ClusterEventsRequest, ClusterEventsResponse, ClusterIdRequest};
+    pub use cluster_policies::{
+        ClusterPolicy, ListClusterPoliciesResponse, PolicyRule, PolicyViolation,
+    };
+    pub use copy_into::{CopyCredential, CopyFileFormat, CopyIntoCommand, CopyIntoMetrics};
+    pub(crate) use dbsql::TransferOwnershipRequest;
+    pub use dbsql::{
+        AlertInfo, DashboardInfo, ListAlertsResponse, ListDashboardsResponse, ListQueriesResponse,
+        QueryInfo, SqlObjectType, TransferOwnershipResponse, TransferredSqlObject,
+    };
+    pub use delta_history::{TableDetail, TableHistoryEntry};
+    pub use delta_sharing::{
+        ActivationUrlInfo, CreateProviderRequest, CreateRecipientRequest, CreateShareRequest,
+        IpAccessList, ListProviderSharesResponse, ListProvidersResponse, ListRecipientsResponse,
+        ListSharesResponse, ProviderInfo, ProviderShare, RecipientInfo, RecipientProperties,
+        RecipientTokenInfo, RotateRecipientTokenRequest, ShareInfo, SharedDataObject,
+        SharedDataObjectType, SharedDataObjectUpdate, SharedDataObjectUpdateAction,
+        SharingAuthenticationType, UpdateProviderRequest, UpdateRecipientRequest,
+        UpdateShareRequest,
+    };
+    pub(crate) use dry_run::redact_secrets;
+    pub use dry_run::PlannedRequest;
+    pub use external_locations::{
+        AwsIamRole, AzureManagedIdentity, AzureServicePrincipal, CloudCredential,
+        CloudflareApiToken, CreateExternalLocationRequest, CreateStorageCredentialRequest,
+        DatabricksGcpServiceAccount, ExternalLocationInfo, ListExternalLocationsResponse,
+        ListStorageCredentialsResponse, StorageCredentialInfo, UpdateExternalLocationRequest,
+        UpdateStorageCredentialRequest, ValidateStorageCredentialRequest,
+        ValidateStorageCredentialResponse, ValidationResult, ValidationResultStatus,
+    };
+    pub use feature_serving::{
+        CreateOnlineTableRequest, FeatureValues, OnlineTable, OnlineTableSpec, OnlineTableStatus,
+    };
+    pub use file_arrival::{FileArrivalCheck, FileArrivalTrigger, PauseStatus, TriggerSettings};
+    pub(crate) use files::{DbfsAddBlockRequest, DbfsCreateRequest, DbfsHandle, DbfsReadResponse};
+    pub use files::{DbfsFileInfo, FileMetadata};
+    pub use foundation_models::{
+        ChatChoice, ChatChunkChoice, ChatCompletionChunk, ChatCompletionRequest,
+        ChatCompletionResponse, ChatDelta, ChatMessage, ChatRole, CompletionChoice,
+        CompletionRequest, CompletionResponse, Embedding, EmbeddingsRequest, EmbeddingsResponse,
+        HasTokenUsage, TokenUsage,
+    };
+    pub use genie::{
+        GenieAttachment, GenieConversation, GenieCreateMessageRequest, GenieMessage,
+        GenieMessageError, GenieMessageStatus, GenieQueryAttachment, GenieQueryResultMetadata,
+        GenieQueryResultResponse, GenieStartConversationResponse, GenieTextAttachment,
+    };
+    pub use grants::{
+        EffectivePermissionsList, EffectivePrivilege, EffectivePrivilegeAssignment,
+        PermissionsChange, PermissionsList, Privilege, PrivilegeAssignment, SecurableType,
+        UpdatePermissionsRequest,
+    };
+    pub use identity::{CurrentUser, PingResponse, ScimEmail, ScimGroupRef};
+    pub use information_schema::{
+        InformationSchemaColumn, InformationSchemaFilter, InformationSchemaTable,
+        InformationSchemaTablePrivilege, InformationSchemaView,
+    };
+    pub use inventory::{InventoryOptions, WorkspaceInventory};
+    pub use janitor::{CleanupCandidate, CleanupResourceKind, JanitorOptions, JanitorReport};
+    pub use job_diff::{JobApplyAction, JobApplyOutcome, JobSettingsChange, JobSettingsDiff};
+    pub use job_queue::JobQueueSnapshot;
+    pub use job_run_info::{
+        DbtOutput, DbtTask, JobRunRequest, JobRunResponse, NotebookOutput, NotebookTask,
+        QueueSettings, RepairHistoryItem, Run, RunJobTask, RunLifeCycleState, RunOutput,
+        RunOutputEvent, RunResultState, RunState, RunTask, SparkPythonTask, SqlTask, SqlTaskAlert,
+        SqlTaskDashboard, SqlTaskFile, SqlTaskQuery, SqlTaskSubscription, SubmitRunRequest,
+        SubmitTask, TaskDependency,
+    };
+    pub(crate) use job_run_info::{
+        ListRunsResponse, RepairRunRequest, RepairRunResponse, RunIdRequest,
+    };
+    pub use jobs::{
+        BaseJob, CreateJobResponse, JobCluster, JobParameterDefinition, JobSettings, JobTask,
+        ListJobsResponse,
+    };
+    pub(crate) use jobs::{JobIdRequest, ResetJobRequest};
+    pub use lineage::{
+        ColumnLineageRequest, ColumnLineageResponse, LineageColumnInfo, LineageDashboardInfo,
+        LineageEntity, LineageJobInfo, LineageNotebookInfo, LineagePipelineInfo, LineageQueryInfo,
+        LineageTableInfo, TableLineageRequest, TableLineageResponse,
+    };
+    pub use log_delivery::{
+        CreateLogDeliveryConfiguration, CreateLogDeliveryRequest, DeliveryStatus,
+        LogDeliveryConfigStatus, LogDeliveryConfiguration, LogDeliveryStatus, LogOutputFormat,
+        LogType, UpdateLogDeliveryStatusRequest, WrappedLogDeliveryConfiguration,
+        WrappedLogDeliveryConfigurations,
+    };
+    pub use mlflow_artifacts::{
+        ArtifactCredentialInfo, ArtifactCredentialType, ArtifactCredentialsResponse,
+        ArtifactFileInfo, HttpHeader, ListArtifactsResponse,
+    };
+    pub use oauth_integrations::{
+        CreateCustomAppIntegrationRequest, CreateCustomAppIntegrationResponse,
+        CreatePublishedAppIntegrationRequest, CreatePublishedAppIntegrationResponse,
+        CreateServicePrincipalSecretRequest, CreateServicePrincipalSecretResponse,
+        CustomAppIntegration, ListCustomAppIntegrationsResponse,
+        ListPublishedAppIntegrationsResponse, ListServicePrincipalSecretsResponse,
+        PublishedAppIntegration, SecretInfo, TokenAccessPolicy, UpdateCustomAppIntegrationRequest,
+        UpdatePublishedAppIntegrationRequest,
+    };
+    pub(crate) use permissions::ObjectPermissionsRequest;
+    pub use permissions::{
+        AccessControlRequest, AccessControlResponse, ObjectPermission, ObjectPermissions,
+        PermissionLevel, PermissionObjectType, Principal,
+    };
+    pub use permissions_snapshot::{
+        ObjectAcl, PermissionChange, PermissionEntry, PermissionsDrift, PermissionsSnapshot,
+        PrincipalKind,
+    };
+    pub use pipeline_events::{
+        ListPipelineEventsResponse, PipelineErrorDetail, PipelineEvent, PipelineEventLevel,
+        PipelineEventMaturityLevel, PipelineEventOrigin, PipelineEventSequence,
+        PipelineSerializedException, PipelineStackFrame,
+    };
+    pub use query_history::{ListQueryHistoryResponse, QueryHistoryEntry, QueryMetrics};
+    pub use query_template::QueryTemplate;
+    pub use registry_webhooks::{
+        CreateRegistryWebhookRequest, HttpUrlSpec, HttpUrlSpecWithoutSecret, RegistryWebhook,
+        RegistryWebhookStatus, RegistryWebhookTestResult, WebhookJobSpec,
+        WebhookJobSpecWithoutSecret,
+    };
+    pub(crate) use registry_webhooks::{
+        ListRegistryWebhooksResponse, RegistryWebhookResponse, TestRegistryWebhookRequest,
+        TestRegistryWebhookResponse,
+    };
+    pub use repos::{ListReposResponse, RepoInfo};
+    pub use result_chunks::ChunkValidator;
+    pub use run_job_graph::{RunJobGraph, RunJobStep};
+    pub use run_retry::{RunAttempt, RunRetryOutcome, RunRetryPolicy, RunRetryStrategy};
+    pub use schema_drift::{
+        ColumnNullabilityMismatch, ColumnTypeMismatch, ExpectedColumn, ExpectedSchema,
+        ExpectedTableSchema, SchemaDrift,
+    };
+    pub use serving_endpoints::{
+        AutoCaptureConfig, CreateServingEndpointRequest, EndpointConfigUpdateState,
+        EndpointCoreConfigInput, EndpointCoreConfigOutput, EndpointReadyState, EndpointState,
+        EndpointTag, ListServingEndpointsResponse, ServedEntityInput, ServedEntityOutput,
+        ServedEntityState, ServingEndpointDetailed, TrafficConfig, TrafficRoute, WorkloadSize,
+        WorkloadType,
+    };
+    pub use serving_invocations::{
+        DataframeSplit, QueryServingEndpointRequest, QueryServingEndpointResponse, ServingInput,
+    };
+    pub use serving_rollout::{TrafficRollout, TrafficRolloutOutcome};
+    pub use settings::{
+        AutomaticClusterUpdateMessage, AutomaticClusterUpdateSetting,
+        ComplianceSecurityProfileMessage, ComplianceSecurityProfileSetting,
+        DefaultNamespaceSetting, MaintenanceWindow, RestrictWorkspaceAdminsMessage,
+        RestrictWorkspaceAdminsSetting, RestrictWorkspaceAdminsStatus, StringMessage,
+        WeekDayBasedSchedule, WindowStartTime,
+    };
+    pub(crate) use settings::{UpdateSettingRequest, WorkspaceSetting};
+    pub(crate) use sql_statement::{rows_as, statement_tag_comment};
     pub use sql_statement::{
-        ChunkMetadata, ResultData, SqlParameter, SqlStatementRequest, SqlStatementResponse,
+        ChunkMetadata, ColumnDescription, ExternalLink, Manifest, OnWaitTimeout, ResultData,
+        Schema, SqlParameter, SqlSessionSettings, SqlStatementRequest, SqlStatementResponse,
+        StatementState, StatementStatus,
+    };
+    pub use system_tables::{
+        AuditLogRecord, AuditResponse, AuditUserIdentity, BillingUsageRecord, QueryCompute,
+        QueryHistoryRecord, SystemTableFilter,
+    };
+    pub use table_constraints::{
+        CreateTableConstraintRequest, ForeignKeyConstraint, NamedTableConstraint,
+        PrimaryKeyConstraint, TableConstraint,
+    };
+    pub use table_maintenance::{
+        AnalyzeCommand, AnalyzeScope, FileSizeStats, OptimizeCommand, OptimizeMetrics,
+        VacuumCommand, VacuumOutcome,
     };
+    pub(crate) use table_maintenance::{OptimizeResultRow, VacuumResultRow};
+    pub use table_operations::{TableOperationReport, TableProgress};
+    pub use temporary_credentials::{
+        AwsTemporaryCredentials, AzureActiveDirectoryToken, AzureUserDelegationSas, GcpOauthToken,
+        GenerateTemporaryTableCredentialRequest, R2TemporaryCredentials, TableOperation,
+        TemporaryTableCredentials,
+    };
+    pub use token_usage::{MeteredUsage, TokenUsageMeter};
+    pub use uc_functions::{
+        CreateFunction, CreateFunctionRequest, DependencyList, FunctionDependency, FunctionInfo,
+        FunctionNameDependency, FunctionParameterInfo, FunctionParameterInfos,
+        FunctionParameterMode, FunctionParameterType, ListFunctionsResponse, RoutineBody,
+        SqlDataAccess, TableDependency, UpdateFunctionRequest,
+    };
+    pub use unity_catalog::{
+        CatalogInfo, ColumnInfo, CreateCatalogRequest, CreateSchemaRequest, IsolationMode,
+        ListCatalogsResponse, ListSchemasResponse, ListTablesResponse, SchemaInfo, TableInfo,
+        UpdateCatalogRequest, UpdateSchemaRequest,
+    };
+    pub use vector_search::{
+        CreateVectorIndexRequest, CreateVectorSearchEndpointRequest, DeleteVectorIndexDataRequest,
+        DeltaSyncVectorIndexSpec, DirectAccessVectorIndexSpec, EmbeddingSourceColumn,
+        EmbeddingVectorColumn, ListVectorIndexesResponse, ListVectorSearchEndpointsResponse,
+        QueryVectorIndexRequest, QueryVectorIndexResponse, UpsertVectorIndexDataRequest,
+        VectorIndexInfo, VectorIndexPipelineType, VectorIndexStatus, VectorIndexType,
+        VectorIndexWriteResponse, VectorIndexWriteResult, VectorIndexWriteStatus, VectorQueryType,
+        VectorResultColumn, VectorResultData, VectorResultManifest, VectorSearchEndpointInfo,
+        VectorSearchEndpointStatus, VectorSearchEndpointType, VectorSearchFilter,
+    };
+    pub use warehouses::{
+        ListWarehousesResponse, WarehouseInfo, WarehouseStartProgress, WarehouseState,
+    };
+    pub use webhooks::{
+        JobWebhookEventType, JobWebhookJob, JobWebhookPayload, JobWebhookRun, RegistryWebhookEvent,
+        RegistryWebhookPayload,
+    };
+    pub(crate) use workspace::{DeleteWorkspaceObjectRequest, WorkspacePathRequest};
+    pub use workspace::{
+        ExportFormat, ExportWorkspaceResponse, ImportWorkspaceRequest, ListWorkspaceResponse,
+        NotebookLanguage, ObjectInfo, ObjectType, WorkspaceSyncOptions, WorkspaceSyncReport,
+    };
+    pub use workspace_conf::{WorkspaceConf, WorkspaceConfDrift, WorkspaceConfKey};
+    pub use workspace_registry::{WorkspaceOperationReport, WorkspaceProgress};
 }
 
 pub mod services {
+    mod account_networks;
+    mod account_session;
+    mod account_workspaces;
+    mod body_serializer;
+    mod budget_policies;
+    mod cluster_handle;
+    mod cluster_policies;
+    mod clusters;
+    mod copy_into;
+    mod credentials;
     mod databricks_session;
+    mod dbsql;
+    mod delta_history;
+    mod delta_sharing;
+    mod external_locations;
+    mod feature_serving;
+    mod file_arrival;
+    mod files;
+    mod foundation_models;
+    mod genie;
+    mod grants;
+    mod identity;
+    mod information_schema;
+    mod inventory;
+    mod janitor;
+    mod job_deployment;
+    mod jobs;
+    mod keep_alive;
+    mod lineage;
+    mod log_delivery;
+    mod mlflow_artifacts;
+    mod oauth_integrations;
+    mod on_behalf_of;
+    #[cfg(feature = "parquet")]
+    mod parquet_sink;
+    mod permissions;
+    mod permissions_snapshot;
+    mod pipeline_events;
+    mod query_history;
+    mod registry_webhooks;
+    mod repos;
+    mod response_meta;
+    mod run_handle;
+    mod run_retry;
+    mod schema_drift;
+    mod serving_endpoints;
+    mod serving_invocations;
+    mod serving_rollout;
+    mod settings;
+    mod sse;
+    mod statement_handle;
+    mod system_tables;
+    mod table_maintenance;
+    mod table_metadata;
+    mod table_operations;
+    mod temporary_credentials;
+    mod token_file;
+    mod uc_functions;
+    mod unity_catalog;
+    mod vector_search;
+    mod warehouses;
+    mod workspace;
+    mod workspace_conf;
+    mod workspace_registry;
+    mod workspace_sync;
 
+    pub use account_session::AccountSession;
+    pub use body_serializer::{BodySerializer, OmitNulls, SendNulls};
+    pub use bytes::Bytes;
+    pub use cluster_handle::ClusterHandle;
+    pub use credentials::CredentialsProvider;
     pub use databricks_session::DatabricksSession;
+    pub use keep_alive::KeepAliveHandle;
+    pub use on_behalf_of::{OnBehalfOf, FORWARDED_ACCESS_TOKEN_HEADER};
+    #[cfg(feature = "parquet")]
+    pub use parquet_sink::ParquetSink;
+    pub use reqwest::{Method, StatusCode};
+    pub use response_meta::ResponseMeta;
+    pub use run_handle::RunHandle;
+    pub use sse::SseEvent;
+    pub use statement_handle::StatementHandle;
+    pub use workspace_registry::WorkspaceRegistry;
 }
 
 pub mod errors {
+    mod chunk;
+    mod decode;
     mod http;
+    mod webhook;
 
+    pub use chunk::{ChunkError, ChunkErrorKind};
+    pub use decode::DecodeError;
     pub use http::{ErrorResponse, HttpError};
+    pub use webhook::WebhookError;
+}
+
+pub mod utils {
+    mod audit_log;
+    mod cost;
+    mod sql;
+    mod url;
+    mod webhook;
+
+    pub use audit_log::parse_audit_log;
+    pub use cost::{ComputeSku, CostEstimate, DbuPriceTable};
+    pub use sql::{quote_identifier, quote_literal, quote_qualified_name, split_qualified_name};
+    pub use url::encode_query_value;
+    pub use webhook::{
+        parse_job_webhook, parse_registry_webhook, verify_webhook_signature,
+        WEBHOOK_SIGNATURE_HEADER,
+    };
 }
@@ -0,0 +1,170 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The VPC endpoints a customer-managed VPC uses for PrivateLink.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NetworkVpcEndpoints {
+    #[serde(default)]
+    pub rest_api: Vec<String>, // Front-end (workspace REST API) endpoint ids
+    #[serde(default)]
+    pub dataplane_relay: Vec<String>, // Secure cluster connectivity relay endpoint ids
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GcpNetworkInfo {
+    pub network_project_id: String,
+    pub vpc_id: String,
+    pub subnet_id: String,
+    pub subnet_region: String,
+    pub pod_ip_range_name: String,
+    pub service_ip_range_name: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateNetworkRequest {
+    pub network_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vpc_id: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subnet_ids: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub security_group_ids: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub vpc_endpoints: Option<NetworkVpcEndpoints>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gcp_network_info: Option<GcpNetworkInfo>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VpcStatus {
+    Unattached,
+    Valid,
+    Broken,
+    Warned,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct NetworkHealth {
+    pub error_type: Option<String>,
+    pub error_message: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct NetworkWarning {
+    pub warning_type: Option<String>,
+    pub warning_message: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct Network {
+    pub network_id: String,
+    pub network_name: Option<String>,
+    pub account_id: Option<String>,
+    pub vpc_id: Option<String>,
+    #[serde(default)]
+    pub subnet_ids: Vec<String>,
+    #[serde(default)]
+    pub security_group_ids: Vec<String>,
+    pub vpc_endpoints: Option<NetworkVpcEndpoints>,
+    pub gcp_network_info: Option<GcpNetworkInfo>,
+    pub vpc_status: Option<VpcStatus>,
+    #[serde(default)]
+    pub error_messages: Vec<NetworkHealth>,
+    #[serde(default)]
+    pub warning_messages: Vec<NetworkWarning>,
+    pub workspace_id: Option<i64>,
+    pub creation_time: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GcpVpcEndpointInfo {
+    pub project_id: String,
+    pub psc_endpoint_name: String,
+    pub endpoint_region: String,
+    pub psc_connection_id: Option<String>,
+    pub service_attachment_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateVpcEndpointRequest {
+    pub vpc_endpoint_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_vpc_endpoint_id: Option<String>, // e.g. "vpce-0123456789abcdef"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub gcp_vpc_endpoint_info: Option<GcpVpcEndpointInfo>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VpcEndpointUseCase {
+    DataplaneRelayAccess,
+    WorkspaceAccess,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct VpcEndpoint {
+    pub vpc_endpoint_id: String,
+    pub vpc_endpoint_name: Option<String>,
+    pub account_id: Option<String>,
+    pub aws_account_id: Option<String>,
+    pub aws_vpc_endpoint_id: Option<String>,
+    pub aws_endpoint_service_id: Option<String>,
+    pub gcp_vpc_endpoint_info: Option<GcpVpcEndpointInfo>,
+    pub region: Option<String>,
+    pub state: Option<String>, // AWS endpoint state, e.g. "available"
+    pub use_case: Option<VpcEndpointUseCase>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PrivateAccessLevel {
+    Account,  // Any VPC endpoint registered in the account
+    Endpoint, // Only the endpoints listed in allowed_vpc_endpoint_ids
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PrivateAccessSettingsRequest {
+    pub private_access_settings_name: String,
+    pub region: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub public_access_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_access_level: Option<PrivateAccessLevel>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_vpc_endpoint_ids: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct PrivateAccessSettings {
+    pub private_access_settings_id: String,
+    pub private_access_settings_name: Option<String>,
+    pub account_id: Option<String>,
+    pub region: Option<String>,
+    pub public_access_enabled: Option<bool>,
+    pub private_access_level: Option<PrivateAccessLevel>,
+    #[serde(default)]
+    pub allowed_vpc_endpoint_ids: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,139 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WorkspaceStatus {
+    NotProvisioned,
+    Provisioning,
+    Running,
+    Failed,
+    Banned,
+    Cancelling,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PricingTier {
+    Unknown,
+    Community,
+    Standard,
+    Premium,
+    Enterprise,
+    DedicatedStandard,
+    DedicatedPremium,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateWorkspaceRequest {
+    pub workspace_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cloud: Option<String>, // "aws" or "gcp"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>, // GCP region
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deployment_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credentials_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_configuration_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_access_settings_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub managed_services_customer_managed_key_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_customer_managed_key_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pricing_tier: Option<PricingTier>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateWorkspaceRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aws_region: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credentials_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_configuration_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub network_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub private_access_settings_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub managed_services_customer_managed_key_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_customer_managed_key_id: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct Workspace {
+    pub workspace_id: i64,
+    pub workspace_name: Option<String>,
+    pub account_id: Option<String>,
+    pub deployment_name: Option<String>, // The workspace URL is https://<deployment_name>.cloud.databricks.com
+    pub aws_region: Option<String>,
+    pub cloud: Option<String>,
+    pub location: Option<String>,
+    pub workspace_status: Option<WorkspaceStatus>,
+    pub workspace_status_message: Option<String>,
+    pub pricing_tier: Option<PricingTier>,
+    pub credentials_id: Option<String>,
+    pub storage_configuration_id: Option<String>,
+    pub network_id: Option<String>,
+    pub private_access_settings_id: Option<String>,
+    pub managed_services_customer_managed_key_id: Option<String>,
+    pub storage_customer_managed_key_id: Option<String>,
+    pub creation_time: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WorkspacePermission {
+    User,
+    Admin,
+    Unknown,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AssignmentPrincipal {
+    pub principal_id: Option<i64>,
+    pub display_name: Option<String>,
+    pub user_name: Option<String>,
+    pub group_name: Option<String>,
+    pub service_principal_name: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct PermissionAssignment {
+    pub principal: Option<AssignmentPrincipal>,
+    #[serde(default)]
+    pub permissions: Vec<WorkspacePermission>,
+    pub error: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct PermissionAssignments {
+    #[serde(default)]
+    pub permission_assignments: Vec<PermissionAssignment>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateWorkspaceAssignmentRequest {
+    pub permissions: Vec<WorkspacePermission>,
+}
@@ -0,0 +1,152 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Who a rate limit applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitKey {
+    /// Each user gets the full quota.
+    User,
+    /// All callers of the endpoint share the quota.
+    Endpoint,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitRenewalPeriod {
+    Minute,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AiGatewayRateLimit {
+    pub calls: i64,
+    pub key: RateLimitKey,
+    pub renewal_period: RateLimitRenewalPeriod,
+}
+
+impl AiGatewayRateLimit {
+    /// A limit of `calls` queries per minute for each user.
+    pub fn per_user_per_minute(calls: i64) -> Self {
+        AiGatewayRateLimit {
+            calls,
+            key: RateLimitKey::User,
+            renewal_period: RateLimitRenewalPeriod::Minute,
+        }
+    }
+
+    /// A limit of `calls` queries per minute shared by all callers of the endpoint.
+    pub fn per_endpoint_per_minute(calls: i64) -> Self {
+        AiGatewayRateLimit {
+            calls,
+            key: RateLimitKey::Endpoint,
+            renewal_period: RateLimitRenewalPeriod::Minute,
+        }
+    }
+}
+
+/// What a guardrail does with personally identifiable information.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PiiBehavior {
+    None,
+    Block,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PiiGuardrail {
+    pub behavior: PiiBehavior,
+}
+
+/// The checks applied to requests or to responses of an endpoint.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AiGatewayGuardrailParameters {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub safety: Option<bool>, // Blocks unsafe content
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pii: Option<PiiGuardrail>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub invalid_keywords: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub valid_topics: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AiGatewayGuardrails {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub input: Option<AiGatewayGuardrailParameters>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub output: Option<AiGatewayGuardrailParameters>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AiGatewayUsageTrackingConfig {
+    pub enabled: bool, // Records token usage in system.serving.endpoint_usage
+}
+
+/// Where requests and responses to the endpoint are logged.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AiGatewayInferenceTableConfig {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub catalog_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema_name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub table_name_prefix: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AiGatewayFallbackConfig {
+    pub enabled: bool, // Retries failed requests on the endpoint's other served entities
+}
+
+/// The AI Gateway settings of a serving endpoint.
+///
+/// Updating them replaces all settings: features left as `None` are turned off.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AiGatewayConfig {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rate_limits: Vec<AiGatewayRateLimit>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub guardrails: Option<AiGatewayGuardrails>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub usage_tracking_config: Option<AiGatewayUsageTrackingConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub inference_table_config: Option<AiGatewayInferenceTableConfig>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fallback_config: Option<AiGatewayFallbackConfig>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl AiGatewayConfig {
+    /// Adds a rate limit.
+    pub fn with_rate_limit(mut self, rate_limit: AiGatewayRateLimit) -> Self {
+        self.rate_limits.push(rate_limit);
+        self
+    }
+
+    /// Sets the guardrails for requests and responses.
+    pub fn with_guardrails(mut self, guardrails: AiGatewayGuardrails) -> Self {
+        self.guardrails = Some(guardrails);
+        self
+    }
+
+    /// Turns token usage tracking on or off.
+    pub fn with_usage_tracking(mut self, enabled: bool) -> Self {
+        self.usage_tracking_config = Some(AiGatewayUsageTrackingConfig { enabled });
+        self
+    }
+
+    /// Logs requests and responses to inference tables in `catalog_name.schema_name`.
+    pub fn with_inference_tables(mut self, catalog_name: &str, schema_name: &str) -> Self {
+        self.inference_table_config = Some(AiGatewayInferenceTableConfig {
+            catalog_name: Some(catalog_name.to_string()),
+            schema_name: Some(schema_name.to_string()),
+            table_name_prefix: None,
+            enabled: Some(true),
+        });
+        self
+    }
+}
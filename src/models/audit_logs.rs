@@ -0,0 +1,99 @@
+use crate::models::{AuditLogRecord, AuditResponse, AuditUserIdentity};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A Databricks audit log event, in the JSON format delivered to storage by log delivery.
+///
+/// Rows of `system.access.audit` convert into the same type with `From<AuditLogRecord>`, so
+/// tooling can handle both sources alike.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default, rename_all = "camelCase")]
+pub struct AuditEvent {
+    pub version: Option<String>,
+    pub audit_level: Option<String>, // "WORKSPACE_LEVEL" or "ACCOUNT_LEVEL"
+    pub timestamp: Option<i64>,      // Milliseconds since the epoch
+    pub org_id: Option<String>,      // The workspace ID
+    pub shard_name: Option<String>,
+    pub account_id: Option<String>,
+    #[serde(rename = "sourceIPAddress")]
+    pub source_ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub session_id: Option<String>,
+    pub user_identity: Option<AuditUserIdentity>,
+    pub service_name: String, // e.g. "clusters", "accounts", "unityCatalog"
+    pub action_name: String,  // e.g. "create", "login", "getTable"
+    pub request_id: Option<String>,
+    pub request_params: Map<String, Value>,
+    pub response: Option<AuditResponse>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl AuditEvent {
+    /// Parses one event from its JSON text, e.g. one line of a delivered log file.
+    pub fn from_json(text: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(text)
+    }
+
+    /// When the event happened.
+    pub fn event_time(&self) -> Option<DateTime<Utc>> {
+        self.timestamp.and_then(DateTime::from_timestamp_millis)
+    }
+
+    /// The email (or service principal ID) of the identity that performed the action.
+    pub fn actor(&self) -> Option<&str> {
+        self.user_identity
+            .as_ref()
+            .and_then(|identity| identity.email.as_deref())
+    }
+
+    /// A request parameter as text; JSON values other than strings are returned as JSON.
+    pub fn request_param(&self, name: &str) -> Option<String> {
+        self.request_params.get(name).map(|value| match value {
+            Value::String(text) => text.clone(),
+            other => other.to_string(),
+        })
+    }
+
+    /// The HTTP status code of the response, if recorded.
+    pub fn status_code(&self) -> Option<i64> {
+        self.response
+            .as_ref()
+            .and_then(|response| response.status_code)
+    }
+
+    /// Whether the action was rejected or failed (a 4xx or 5xx status code).
+    pub fn is_failure(&self) -> bool {
+        self.status_code().is_some_and(|code| code >= 400)
+    }
+}
+
+impl From<AuditLogRecord> for AuditEvent {
+    fn from(record: AuditLogRecord) -> Self {
+        AuditEvent {
+            version: record.version,
+            audit_level: record.audit_level,
+            timestamp: Some(record.event_time.timestamp_millis()),
+            org_id: record.workspace_id,
+            shard_name: None,
+            account_id: Some(record.account_id),
+            source_ip_address: record.source_ip_address,
+            user_agent: record.user_agent,
+            session_id: record.session_id,
+            user_identity: record.user_identity,
+            service_name: record.service_name,
+            action_name: record.action_name,
+            request_id: record.request_id,
+            request_params: record
+                .request_params
+                .unwrap_or_default()
+                .into_iter()
+                .map(|(key, value)| (key, Value::String(value)))
+                .collect(),
+            response: record.response,
+            extra: Map::new(),
+        }
+    }
+}
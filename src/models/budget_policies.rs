@@ -0,0 +1,76 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CustomPolicyTag {
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// A serverless budget policy. Usage of serverless compute attached to the policy is tagged
+/// with its custom tags, which is what budgets and chargeback reports group by.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct BudgetPolicy {
+    pub policy_id: String,
+    pub policy_name: Option<String>,
+    #[serde(default)]
+    pub custom_tags: Vec<CustomPolicyTag>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NewBudgetPolicy {
+    pub policy_name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub custom_tags: Vec<CustomPolicyTag>,
+}
+
+impl NewBudgetPolicy {
+    /// Creates a policy definition without custom tags.
+    pub fn new(policy_name: &str) -> Self {
+        NewBudgetPolicy {
+            policy_name: policy_name.to_string(),
+            custom_tags: Vec::new(),
+        }
+    }
+
+    /// Adds a custom tag that is applied to all usage attributed to the policy.
+    pub fn with_tag(mut self, key: &str, value: &str) -> Self {
+        self.custom_tags.push(CustomPolicyTag {
+            key: key.to_string(),
+            value: Some(value.to_string()),
+        });
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct CreateBudgetPolicyRequest {
+    pub policy: NewBudgetPolicy,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListBudgetPoliciesResponse {
+    #[serde(default)]
+    pub policies: Vec<BudgetPolicy>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct BudgetPolicyAssignment {
+    pub budget_policy_id: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct UpdateJobBudgetPolicyRequest {
+    pub job_id: i64,
+    pub new_settings: BudgetPolicyAssignment,
+}
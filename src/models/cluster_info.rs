@@ -1,7 +1,10 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::{collections::HashMap, fmt};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct ClusterInfo {
     pub cluster_id: String,
     pub cluster_name: String,
@@ -39,29 +42,122 @@ pub struct ClusterInfo {
     pub pinned_by_user_name: Option<String>,
     pub init_scripts_safe_mode: bool,
     pub spec: ClusterSpec,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// The subset of cluster fields common to every cloud, as returned by `clusters/list`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ClusterSummary {
+    pub cluster_id: String,
+    pub cluster_name: Option<String>,
+    pub state: Option<String>, // "PENDING", "RUNNING", "TERMINATED", ...
+    pub state_message: Option<String>,
+    pub creator_user_name: Option<String>,
+    pub spark_version: Option<String>,
+    pub node_type_id: Option<String>,
+    pub num_workers: Option<i32>,
+    pub cluster_source: Option<String>,
+    pub start_time: Option<i64>,
+    pub terminated_time: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListClustersResponse {
+    #[serde(default)]
+    pub clusters: Vec<ClusterSummary>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ClusterIdRequest {
+    pub cluster_id: String,
+}
+
+/// A change in a cluster's lifecycle, returned by `clusters/events`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ClusterEvent {
+    pub cluster_id: String,
+    pub timestamp: Option<i64>,
+    #[serde(rename = "type")]
+    pub event_type: Option<String>, // "CREATING", "RUNNING", "RESIZING", "TERMINATING", ...
+    pub details: Map<String, Value>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ClusterEventsRequest {
+    pub cluster_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order: Option<String>, // "ASC" or "DESC"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub offset: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub limit: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub(crate) struct ClusterEventsResponse {
+    #[serde(default)]
+    pub events: Vec<ClusterEvent>,
+    pub next_page: Option<ClusterEventsRequest>,
+    pub total_count: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct AzureAttributes {
     pub first_on_demand: i32,
     pub availability: String,
     pub spot_bid_max_price: f64,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct InstanceSource {
     pub node_type_id: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct TerminationReason {
     pub code: String,
     #[serde(rename = "type")]
     pub type_field: String,
     pub parameters: HashMap<String, String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct ClusterSpec {
     pub cluster_name: String,
     pub spark_version: String,
@@ -77,6 +173,82 @@ pub struct ClusterSpec {
     pub data_security_mode: String,
     pub runtime_engine: String,
     pub num_workers: i32,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The `clusters/create` fields that carry over to a job cluster. Fields such as the cluster name
+/// or `autotermination_minutes` do not apply to job clusters and are left out.
+const JOB_CLUSTER_FIELDS: &[&str] = &[
+    "spark_version",
+    "node_type_id",
+    "driver_node_type_id",
+    "num_workers",
+    "autoscale",
+    "spark_conf",
+    "spark_env_vars",
+    "custom_tags",
+    "aws_attributes",
+    "azure_attributes",
+    "gcp_attributes",
+    "instance_pool_id",
+    "driver_instance_pool_id",
+    "policy_id",
+    "apply_policy_default_values",
+    "init_scripts",
+    "cluster_log_conf",
+    "docker_image",
+    "ssh_public_keys",
+    "enable_elastic_disk",
+    "enable_local_disk_encryption",
+    "data_security_mode",
+    "single_user_name",
+    "runtime_engine",
+];
+
+impl ClusterInfo {
+    /// Converts the cluster's specification into a `new_cluster` spec for a job cluster, e.g.
+    /// for `JobCluster::new`, so a job can run on the same configuration as an interactive
+    /// cluster.
+    ///
+    /// The spec the cluster was created with is used where the API returns it. Fields that do
+    /// not apply to job clusters, empty values and unset Azure attributes are left out, and
+    /// `num_workers` is dropped for autoscaling clusters.
+    pub fn to_job_cluster_spec(&self) -> Value {
+        let source: Value = if self.spec.spark_version.is_empty() {
+            serde_json::to_value(self)
+        } else {
+            serde_json::to_value(&self.spec)
+        }
+        .unwrap_or(Value::Null);
+
+        let mut spec: Map<String, Value> = Map::new();
+        if let Value::Object(fields) = source {
+            for (key, value) in fields {
+                let empty: bool = match &value {
+                    Value::Null => true,
+                    Value::String(text) => text.is_empty(),
+                    Value::Object(map) => map.is_empty(),
+                    Value::Array(items) => items.is_empty(),
+                    _ => false,
+                };
+                if JOB_CLUSTER_FIELDS.contains(&key.as_str()) && !empty {
+                    spec.insert(key, value);
+                }
+            }
+        }
+        if spec.contains_key("autoscale") {
+            spec.remove("num_workers");
+        }
+        if spec.get("azure_attributes")
+            == serde_json::to_value(AzureAttributes::default())
+                .ok()
+                .as_ref()
+        {
+            spec.remove("azure_attributes");
+        }
+        Value::Object(spec)
+    }
 }
 
 impl fmt::Display for ClusterInfo {
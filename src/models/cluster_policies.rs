@@ -0,0 +1,321 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{collections::BTreeMap, fmt};
+
+/// Map-valued cluster attributes whose keys may contain dots, e.g. `spark_conf.spark.sql.shuffle.partitions`.
+const MAP_ATTRIBUTES: &[&str] = &["spark_conf", "spark_env_vars", "custom_tags"];
+
+/// Virtual attributes evaluated by the server that cannot be checked against a cluster spec.
+const VIRTUAL_ATTRIBUTES: &[&str] = &["dbus_per_hour", "cluster_type"];
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ClusterPolicy {
+    pub policy_id: String,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub definition: Option<String>, // JSON object mapping attribute paths to rules
+    pub policy_family_id: Option<String>,
+    pub policy_family_definition_overrides: Option<String>,
+    pub max_clusters_per_user: Option<i64>,
+    pub creator_user_name: Option<String>,
+    pub created_at_timestamp: Option<i64>,
+    pub is_default: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListClusterPoliciesResponse {
+    #[serde(default)]
+    pub policies: Vec<ClusterPolicy>,
+    pub total_count: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A rule of a cluster policy definition, constraining one attribute.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(
+    tag = "type",
+    rename_all = "lowercase",
+    rename_all_fields = "camelCase"
+)]
+pub enum PolicyRule {
+    Fixed {
+        value: Value,
+        hidden: Option<bool>,
+    },
+    Forbidden,
+    Allowlist {
+        values: Vec<Value>,
+        default_value: Option<Value>,
+        is_optional: Option<bool>,
+    },
+    Blocklist {
+        values: Vec<Value>,
+        default_value: Option<Value>,
+        is_optional: Option<bool>,
+    },
+    Regex {
+        pattern: String,
+        default_value: Option<Value>,
+        is_optional: Option<bool>,
+    },
+    Range {
+        min_value: Option<f64>,
+        max_value: Option<f64>,
+        default_value: Option<Value>,
+        is_optional: Option<bool>,
+    },
+    Unlimited {
+        default_value: Option<Value>,
+        is_optional: Option<bool>,
+    },
+    #[serde(other)]
+    Other,
+}
+
+impl PolicyRule {
+    /// Whether a cluster spec must set the attribute, i.e. the rule is limiting, not optional and
+    /// has no default the server could fill in.
+    fn is_required(&self) -> bool {
+        match self {
+            PolicyRule::Allowlist {
+                default_value,
+                is_optional,
+                ..
+            }
+            | PolicyRule::Blocklist {
+                default_value,
+                is_optional,
+                ..
+            }
+            | PolicyRule::Regex {
+                default_value,
+                is_optional,
+                ..
+            }
+            | PolicyRule::Range {
+                default_value,
+                is_optional,
+                ..
+            }
+            | PolicyRule::Unlimited {
+                default_value,
+                is_optional,
+            } => default_value.is_none() && *is_optional != Some(true),
+            PolicyRule::Fixed { .. } | PolicyRule::Forbidden | PolicyRule::Other => false,
+        }
+    }
+
+    /// Why `value` breaks the rule, or `None` if it satisfies it.
+    fn check(&self, value: &Value) -> Option<String> {
+        match self {
+            PolicyRule::Fixed { value: fixed, .. } => (!loosely_equal(value, fixed))
+                .then(|| format!("is {}, but the policy fixes it to {}", value, fixed)),
+            PolicyRule::Forbidden => Some("is set, but the policy forbids it".to_string()),
+            PolicyRule::Allowlist { values, .. } => {
+                (!values.iter().any(|allowed| loosely_equal(value, allowed)))
+                    .then(|| format!("is {}, but the policy only allows {}", value, list(values)))
+            }
+            PolicyRule::Blocklist { values, .. } => values
+                .iter()
+                .any(|blocked| loosely_equal(value, blocked))
+                .then(|| format!("is {}, which the policy blocks", value)),
+            PolicyRule::Regex { pattern, .. } => {
+                let matches: bool = Regex::new(&format!("^(?:{})$", pattern))
+                    .map(|regex| regex.is_match(&as_text(value)))
+                    .unwrap_or(true);
+                (!matches).then(|| format!("is {}, which does not match {}", value, pattern))
+            }
+            PolicyRule::Range {
+                min_value,
+                max_value,
+                ..
+            } => {
+                let Some(number) = as_number(value) else {
+                    return Some(format!("is {}, but the policy expects a number", value));
+                };
+                let below: bool = min_value.is_some_and(|min| number < min);
+                let above: bool = max_value.is_some_and(|max| number > max);
+                (below || above).then(|| {
+                    format!(
+                        "is {}, but the policy requires {}",
+                        value,
+                        range(*min_value, *max_value)
+                    )
+                })
+            }
+            PolicyRule::Unlimited { .. } | PolicyRule::Other => None,
+        }
+    }
+}
+
+/// A cluster spec attribute that breaks a rule of a cluster policy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PolicyViolation {
+    /// The attribute, e.g. `autotermination_minutes` or `init_scripts[0].workspace.destination`.
+    pub attribute: String,
+    pub message: String,
+}
+
+impl fmt::Display for PolicyViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.attribute, self.message)
+    }
+}
+
+impl ClusterPolicy {
+    /// The rules of the policy definition, keyed by attribute path.
+    pub fn rules(&self) -> Result<BTreeMap<String, PolicyRule>, serde_json::Error> {
+        match self.definition.as_deref() {
+            Some(definition) if !definition.trim().is_empty() => serde_json::from_str(definition),
+            _ => Ok(BTreeMap::new()),
+        }
+    }
+
+    /// Checks a cluster create or edit request (any serializable spec, e.g. a `ClusterSpec` or
+    /// a JSON value) against the policy's rules.
+    ///
+    /// Null and empty-string attributes count as unset. Virtual attributes such as
+    /// `dbus_per_hour` are skipped, since only the server can evaluate them.
+    ///
+    /// Returns the violations, which are empty if the spec complies, or an error if the spec or
+    /// the policy definition cannot be converted to JSON.
+    pub fn validate<S: Serialize>(
+        &self,
+        spec: &S,
+    ) -> Result<Vec<PolicyViolation>, serde_json::Error> {
+        let spec: Value = serde_json::to_value(spec)?;
+        let mut violations: Vec<PolicyViolation> = Vec::new();
+
+        for (path, rule) in self.rules()? {
+            if VIRTUAL_ATTRIBUTES.contains(&path.as_str()) {
+                continue;
+            }
+            let values: Vec<(String, &Value)> = lookup(&spec, &segments(&path));
+            if values.is_empty() {
+                if rule.is_required() && !path.contains('*') {
+                    violations.push(PolicyViolation {
+                        attribute: path,
+                        message: "is not set, but the policy requires it".to_string(),
+                    });
+                }
+                continue;
+            }
+            for (attribute, value) in values {
+                if let Some(message) = rule.check(value) {
+                    violations.push(PolicyViolation {
+                        attribute: attribute.trim_start_matches('.').to_string(),
+                        message,
+                    });
+                }
+            }
+        }
+        Ok(violations)
+    }
+}
+
+/// Splits an attribute path into object keys and array indices (`*` for every element).
+fn segments(path: &str) -> Vec<String> {
+    for map in MAP_ATTRIBUTES {
+        if let Some(key) = path
+            .strip_prefix(map)
+            .and_then(|rest| rest.strip_prefix('.'))
+        {
+            return vec![map.to_string(), key.to_string()];
+        }
+    }
+    path.split('.')
+        .flat_map(|segment| match segment.split_once('[') {
+            Some((name, index)) => vec![name.to_string(), index.trim_end_matches(']').to_string()],
+            None => vec![segment.to_string()],
+        })
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+/// The set values at `segments` under `value`, with their concrete attribute paths (each starting
+/// with `.` or `[`).
+fn lookup<'a>(value: &'a Value, segments: &[String]) -> Vec<(String, &'a Value)> {
+    let Some((segment, rest)) = segments.split_first() else {
+        let unset: bool = value.is_null() || value.as_str() == Some("");
+        return if unset {
+            Vec::new()
+        } else {
+            vec![(String::new(), value)]
+        };
+    };
+    let children: Vec<(String, &Value)> = match value {
+        Value::Object(map) => map
+            .get(segment)
+            .map(|child| vec![(format!(".{}", segment), child)])
+            .unwrap_or_default(),
+        Value::Array(items) if segment == "*" => items
+            .iter()
+            .enumerate()
+            .map(|(index, child)| (format!("[{}]", index), child))
+            .collect(),
+        Value::Array(items) => segment
+            .parse::<usize>()
+            .ok()
+            .and_then(|index| {
+                items
+                    .get(index)
+                    .map(|child| (format!("[{}]", index), child))
+            })
+            .into_iter()
+            .collect(),
+        _ => Vec::new(),
+    };
+    children
+        .into_iter()
+        .flat_map(|(prefix, child)| {
+            lookup(child, rest)
+                .into_iter()
+                .map(move |(suffix, value)| (format!("{}{}", prefix, suffix), value))
+        })
+        .collect()
+}
+
+/// Compares policy values leniently, since policies often spell numbers and booleans as strings.
+fn loosely_equal(value: &Value, expected: &Value) -> bool {
+    value == expected || as_text(value) == as_text(expected)
+}
+
+fn as_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+fn as_number(value: &Value) -> Option<f64> {
+    match value {
+        Value::Number(number) => number.as_f64(),
+        Value::String(text) => text.parse::<f64>().ok(),
+        _ => None,
+    }
+}
+
+fn list(values: &[Value]) -> String {
+    values
+        .iter()
+        .map(as_text)
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+fn range(min_value: Option<f64>, max_value: Option<f64>) -> String {
+    match (min_value, max_value) {
+        (Some(min), Some(max)) => format!("a value between {} and {}", min, max),
+        (Some(min), None) => format!("at least {}", min),
+        (None, Some(max)) => format!("at most {}", max),
+        (None, None) => "a number".to_string(),
+    }
+}
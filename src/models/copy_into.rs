@@ -0,0 +1,269 @@
+use super::dry_run::REDACTED;
+use crate::models::SqlStatementRequest;
+use crate::utils::{quote_literal, quote_qualified_name};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// The format of the files loaded by `COPY INTO`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CopyFileFormat {
+    Csv,
+    Json,
+    Avro,
+    Orc,
+    Parquet,
+    Text,
+    BinaryFile,
+}
+
+impl CopyFileFormat {
+    /// The keyword used in the `FILEFORMAT` clause.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CopyFileFormat::Csv => "CSV",
+            CopyFileFormat::Json => "JSON",
+            CopyFileFormat::Avro => "AVRO",
+            CopyFileFormat::Orc => "ORC",
+            CopyFileFormat::Parquet => "PARQUET",
+            CopyFileFormat::Text => "TEXT",
+            CopyFileFormat::BinaryFile => "BINARYFILE",
+        }
+    }
+}
+
+/// The credential used to read the source location.
+///
+/// The `Debug` output redacts every key, secret and token.
+#[derive(Clone, PartialEq, Eq)]
+pub enum CopyCredential {
+    /// A Unity Catalog storage credential, referenced by name.
+    Named(String),
+    /// Temporary AWS credentials.
+    Aws {
+        access_key: String,
+        secret_key: String,
+        session_token: Option<String>,
+    },
+    /// An Azure shared access signature.
+    AzureSas(String),
+}
+
+/// A `COPY INTO` bulk load of files from a cloud storage location into a Delta table.
+///
+/// Temporary credential values are passed as named statement parameters rather than inlined
+/// into the statement text, so they do not show up in the query history.
+#[derive(Clone, PartialEq, Eq)]
+pub struct CopyIntoCommand {
+    pub table: String,
+    pub source: String,
+    pub file_format: CopyFileFormat,
+    pub files: Vec<String>,
+    pub pattern: Option<String>,
+    pub format_options: Vec<(String, String)>,
+    pub copy_options: Vec<(String, String)>,
+    pub credential: Option<CopyCredential>,
+}
+
+impl fmt::Debug for CopyCredential {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CopyCredential::Named(name) => f.debug_tuple("Named").field(name).finish(),
+            CopyCredential::Aws { session_token, .. } => f
+                .debug_struct("Aws")
+                .field("access_key", &REDACTED)
+                .field("secret_key", &REDACTED)
+                .field("session_token", &session_token.as_ref().map(|_| REDACTED))
+                .finish(),
+            CopyCredential::AzureSas(_) => f.debug_tuple("AzureSas").field(&REDACTED).finish(),
+        }
+    }
+}
+
+impl fmt::Debug for CopyIntoCommand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CopyIntoCommand")
+            .field("table", &self.table)
+            .field("source", &self.source)
+            .field("file_format", &self.file_format)
+            .field("files", &self.files)
+            .field("pattern", &self.pattern)
+            .field("format_options", &self.format_options)
+            .field("copy_options", &self.copy_options)
+            .field("credential", &self.credential)
+            .finish()
+    }
+}
+
+impl CopyIntoCommand {
+    /// Loads every file of `file_format` found under `source` (e.g. `s3://bucket/landing/`) into
+    /// `table`.
+    pub fn new(table: &str, source: &str, file_format: CopyFileFormat) -> Self {
+        CopyIntoCommand {
+            table: table.to_string(),
+            source: source.to_string(),
+            file_format,
+            files: Vec::new(),
+            pattern: None,
+            format_options: Vec::new(),
+            copy_options: Vec::new(),
+            credential: None,
+        }
+    }
+
+    /// Loads only the given files, relative to the source location.
+    pub fn with_files(mut self, files: &[&str]) -> Self {
+        self.files = files.iter().map(|file| file.to_string()).collect();
+        self
+    }
+
+    /// Loads only the files matching a glob pattern, e.g. `*.csv`.
+    pub fn with_pattern(mut self, pattern: &str) -> Self {
+        self.pattern = Some(pattern.to_string());
+        self
+    }
+
+    /// Adds a reader option, e.g. `("header", "true")` for CSV.
+    pub fn with_format_option(mut self, key: &str, value: &str) -> Self {
+        self.format_options
+            .push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Adds a load option, e.g. `("mergeSchema", "true")` or `("force", "true")`.
+    pub fn with_copy_option(mut self, key: &str, value: &str) -> Self {
+        self.copy_options.push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// Reads the source location with the given credential.
+    pub fn with_credential(mut self, credential: CopyCredential) -> Self {
+        self.credential = Some(credential);
+        self
+    }
+
+    /// The `COPY INTO` statement. Temporary credential values are referenced as `:aws_access_key`,
+    /// `:aws_secret_key`, `:aws_session_token` or `:azure_sas_token`.
+    pub fn to_sql(&self) -> String {
+        let mut statement: String = format!(
+            "COPY INTO {} FROM {}",
+            quote_qualified_name(&self.table),
+            quote_literal(&self.source)
+        );
+        match &self.credential {
+            Some(CopyCredential::Named(name)) => {
+                statement.push_str(&format!(
+                    " WITH (CREDENTIAL {})",
+                    quote_qualified_name(name)
+                ));
+            }
+            Some(CopyCredential::Aws { session_token, .. }) => {
+                statement.push_str(
+                    " WITH (CREDENTIAL (AWS_ACCESS_KEY = :aws_access_key, AWS_SECRET_KEY = :aws_secret_key",
+                );
+                if session_token.is_some() {
+                    statement.push_str(", AWS_SESSION_TOKEN = :aws_session_token");
+                }
+                statement.push_str("))");
+            }
+            Some(CopyCredential::AzureSas(_)) => {
+                statement.push_str(" WITH (CREDENTIAL (AZURE_SAS_TOKEN = :azure_sas_token))");
+            }
+            None => {}
+        }
+        statement.push_str(&format!(" FILEFORMAT = {}", self.file_format.as_str()));
+        if !self.files.is_empty() {
+            let files: Vec<String> = self.files.iter().map(|file| quote_literal(file)).collect();
+            statement.push_str(&format!(" FILES = ({})", files.join(", ")));
+        } else if let Some(pattern) = &self.pattern {
+            statement.push_str(&format!(" PATTERN = {}", quote_literal(pattern)));
+        }
+        if !self.format_options.is_empty() {
+            statement.push_str(&format!(
+                " FORMAT_OPTIONS ({})",
+                options_clause(&self.format_options)
+            ));
+        }
+        if !self.copy_options.is_empty() {
+            statement.push_str(&format!(
+                " COPY_OPTIONS ({})",
+                options_clause(&self.copy_options)
+            ));
+        }
+        statement
+    }
+
+    /// A statement request for `warehouse_id` with the statement and its credential parameters.
+    pub fn to_request(&self, warehouse_id: &str) -> SqlStatementRequest {
+        let request: SqlStatementRequest = SqlStatementRequest::new(warehouse_id, &self.to_sql());
+        match &self.credential {
+            Some(CopyCredential::Aws {
+                access_key,
+                secret_key,
+                session_token,
+            }) => {
+                let request: SqlStatementRequest = request
+                    .with_parameter("aws_access_key", Some(access_key), None)
+                    .with_parameter("aws_secret_key", Some(secret_key), None);
+                match session_token {
+                    Some(token) => request.with_parameter("aws_session_token", Some(token), None),
+                    None => request,
+                }
+            }
+            Some(CopyCredential::AzureSas(token)) => {
+                request.with_parameter("azure_sas_token", Some(token), None)
+            }
+            _ => request,
+        }
+    }
+}
+
+fn options_clause(options: &[(String, String)]) -> String {
+    options
+        .iter()
+        .map(|(key, value)| format!("{} = {}", quote_literal(key), quote_literal(value)))
+        .collect::<Vec<String>>()
+        .join(", ")
+}
+
+/// The metrics reported by `COPY INTO`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct CopyIntoMetrics {
+    pub num_affected_rows: Option<i64>,
+    pub num_inserted_rows: Option<i64>,
+    pub num_skipped_corrupt_files: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn debug_redacts_credentials() {
+        let command: CopyIntoCommand = CopyIntoCommand::new(
+            "main.raw.events",
+            "s3://bucket/landing/",
+            CopyFileFormat::Json,
+        )
+        .with_credential(CopyCredential::Aws {
+            access_key: "AKIAEXAMPLE".to_string(),
+            secret_key: "secret-key".to_string(),
+            session_token: Some("session-token".to_string()),
+        });
+        let debug: String = format!("{:?}", command);
+        assert!(debug.contains("main.raw.events"));
+        assert!(!debug.contains("AKIAEXAMPLE"));
+        assert!(!debug.contains("secret-key"));
+        assert!(!debug.contains("session-token"));
+
+        let sas: String = format!(
+            "{:?}",
+            CopyCredential::AzureSas("sv=2024&sig=abc".to_string())
+        );
+        assert!(!sas.contains("sig=abc"));
+    }
+}
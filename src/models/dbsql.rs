@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A saved Databricks SQL query.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct QueryInfo {
+    pub id: String,
+    pub display_name: Option<String>,
+    pub owner_user_name: Option<String>,
+    pub warehouse_id: Option<String>,
+    pub parent_path: Option<String>,
+    pub query_text: Option<String>,
+    pub lifecycle_state: Option<String>, // "ACTIVE" or "TRASHED"
+    pub create_time: Option<String>,
+    pub update_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListQueriesResponse {
+    #[serde(default)]
+    pub results: Vec<QueryInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A Databricks SQL alert on the result of a query.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct AlertInfo {
+    pub id: String,
+    pub display_name: Option<String>,
+    pub owner_user_name: Option<String>,
+    pub query_id: Option<String>,
+    pub parent_path: Option<String>,
+    pub state: Option<String>, // "OK", "TRIGGERED" or "UNKNOWN"
+    pub lifecycle_state: Option<String>,
+    pub create_time: Option<String>,
+    pub update_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListAlertsResponse {
+    #[serde(default)]
+    pub results: Vec<AlertInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// An AI/BI (Lakeview) dashboard.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct DashboardInfo {
+    pub dashboard_id: String,
+    pub display_name: Option<String>,
+    pub path: Option<String>,
+    pub parent_path: Option<String>,
+    pub warehouse_id: Option<String>,
+    pub lifecycle_state: Option<String>,
+    pub create_time: Option<String>,
+    pub update_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListDashboardsResponse {
+    #[serde(default)]
+    pub dashboards: Vec<DashboardInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The kind of Databricks SQL object whose ownership can be transferred.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SqlObjectType {
+    Queries,
+    Alerts,
+    Dashboards,
+}
+
+impl SqlObjectType {
+    /// Returns the form used in endpoint paths, e.g. `queries`.
+    pub fn as_path_segment(&self) -> &'static str {
+        match self {
+            SqlObjectType::Queries => "queries",
+            SqlObjectType::Alerts => "alerts",
+            SqlObjectType::Dashboards => "dashboards",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TransferOwnershipRequest {
+    pub new_owner: String,
+}
+
+/// The object whose ownership was transferred, as reported by the transfer endpoint.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct TransferOwnershipResponse {
+    pub object_id: Option<String>,
+    pub object_type: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A query or alert that was handed over by `transfer_sql_objects_owned_by`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransferredSqlObject {
+    pub object_type: SqlObjectType,
+    pub object_id: String,
+    pub display_name: Option<String>,
+}
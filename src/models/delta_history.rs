@@ -0,0 +1,68 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// A row of `DESCRIBE HISTORY`: one commit to a Delta table.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default, rename_all = "camelCase")]
+pub struct TableHistoryEntry {
+    pub version: i64,
+    pub timestamp: Option<DateTime<Utc>>,
+    pub user_id: Option<String>,
+    pub user_name: Option<String>,
+    pub operation: Option<String>, // "WRITE", "MERGE", "OPTIMIZE", "VACUUM START", ...
+    pub operation_parameters: HashMap<String, String>,
+    pub operation_metrics: HashMap<String, String>,
+    pub job: Option<Value>,
+    pub notebook: Option<Value>,
+    pub cluster_id: Option<String>,
+    pub read_version: Option<i64>,
+    pub isolation_level: Option<String>,
+    pub is_blind_append: Option<bool>,
+    pub user_metadata: Option<String>,
+    pub engine_info: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The row of `DESCRIBE DETAIL`: the current snapshot of a Delta table.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default, rename_all = "camelCase")]
+pub struct TableDetail {
+    pub format: Option<String>,
+    pub id: Option<String>,
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub location: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub last_modified: Option<DateTime<Utc>>,
+    pub partition_columns: Vec<String>,
+    pub clustering_columns: Vec<String>,
+    pub num_files: Option<i64>,
+    pub size_in_bytes: Option<i64>,
+    pub properties: HashMap<String, String>,
+    pub min_reader_version: Option<i64>,
+    pub min_writer_version: Option<i64>,
+    pub table_features: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TableDetail {
+    /// The `delta.deletedFileRetentionDuration` table property, e.g. `interval 7 days`, if set.
+    pub fn deleted_file_retention(&self) -> Option<&str> {
+        self.properties
+            .get("delta.deletedFileRetentionDuration")
+            .map(String::as_str)
+    }
+
+    /// The `delta.logRetentionDuration` table property, e.g. `interval 30 days`, if set.
+    pub fn log_retention(&self) -> Option<&str> {
+        self.properties
+            .get("delta.logRetentionDuration")
+            .map(String::as_str)
+    }
+}
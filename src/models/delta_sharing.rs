@@ -0,0 +1,333 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SharingAuthenticationType {
+    Token,
+    Databricks,
+    OauthClientCredentials,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SharedDataObjectType {
+    Table,
+    Schema,
+    View,
+    MaterializedView,
+    StreamingTable,
+    Volume,
+    Model,
+    NotebookFile,
+    Function,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SharedDataObjectUpdateAction {
+    Add,
+    Remove,
+    Update,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SharedDataObject {
+    pub name: String, // Full name, e.g. "main.sales.orders"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_object_type: Option<SharedDataObjectType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub shared_as: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cdf_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_version: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub history_data_sharing_status: Option<String>, // "ENABLED" or "DISABLED"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>, // Output only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added_at: Option<i64>, // Output only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub added_by: Option<String>, // Output only
+}
+
+impl SharedDataObject {
+    /// Creates a table data object with the given full name.
+    pub fn table(name: &str) -> Self {
+        SharedDataObject {
+            name: name.to_string(),
+            data_object_type: Some(SharedDataObjectType::Table),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a schema data object, sharing every table in the schema.
+    pub fn schema(name: &str) -> Self {
+        SharedDataObject {
+            name: name.to_string(),
+            data_object_type: Some(SharedDataObjectType::Schema),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SharedDataObjectUpdate {
+    pub action: SharedDataObjectUpdateAction,
+    pub data_object: SharedDataObject,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateShareRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateShareRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub updates: Vec<SharedDataObjectUpdate>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ShareInfo {
+    pub name: String,
+    pub owner: Option<String>,
+    pub comment: Option<String>,
+    #[serde(default)]
+    pub objects: Vec<SharedDataObject>,
+    pub created_at: Option<i64>,
+    pub created_by: Option<String>,
+    pub updated_at: Option<i64>,
+    pub updated_by: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListSharesResponse {
+    #[serde(default)]
+    pub shares: Vec<ShareInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct IpAccessList {
+    #[serde(default)]
+    pub allowed_ip_addresses: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateRecipientRequest {
+    pub name: String,
+    pub authentication_type: Option<SharingAuthenticationType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub data_recipient_global_metastore_id: Option<String>, // For DATABRICKS authentication
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sharing_code: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_access_list: Option<IpAccessList>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties_kvpairs: Option<RecipientProperties>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RecipientProperties {
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateRecipientRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_access_list: Option<IpAccessList>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expiration_time: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties_kvpairs: Option<RecipientProperties>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct RecipientTokenInfo {
+    pub id: Option<String>,
+    pub activation_url: Option<String>,
+    pub created_at: Option<i64>,
+    pub created_by: Option<String>,
+    pub expiration_time: Option<i64>,
+    pub updated_at: Option<i64>,
+    pub updated_by: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct RecipientInfo {
+    pub name: String,
+    pub authentication_type: Option<SharingAuthenticationType>,
+    pub owner: Option<String>,
+    pub comment: Option<String>,
+    pub activated: Option<bool>,
+    pub activation_url: Option<String>,
+    pub cloud: Option<String>,
+    pub region: Option<String>,
+    pub metastore_id: Option<String>,
+    pub data_recipient_global_metastore_id: Option<String>,
+    pub ip_access_list: Option<IpAccessList>,
+    pub properties_kvpairs: Option<RecipientProperties>,
+    #[serde(default)]
+    pub tokens: Vec<RecipientTokenInfo>,
+    pub created_at: Option<i64>,
+    pub created_by: Option<String>,
+    pub updated_at: Option<i64>,
+    pub updated_by: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl RecipientInfo {
+    /// Returns the activation URL of the most recently created token, if the recipient uses
+    /// token-based authentication and has not yet been activated.
+    pub fn latest_activation_url(&self) -> Option<&str> {
+        self.tokens
+            .iter()
+            .filter(|token| token.activation_url.is_some())
+            .max_by_key(|token| token.created_at.unwrap_or_default())
+            .and_then(|token| token.activation_url.as_deref())
+            .or(self.activation_url.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListRecipientsResponse {
+    #[serde(default)]
+    pub recipients: Vec<RecipientInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RotateRecipientTokenRequest {
+    /// Seconds until the existing token expires; `0` expires it immediately.
+    pub existing_token_expire_in_seconds: i64,
+}
+
+/// Public information returned for an activation URL, used by recipients to download credentials.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ActivationUrlInfo {
+    pub recipient_name: Option<String>,
+    pub provider_name: Option<String>,
+    pub activation_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateProviderRequest {
+    pub name: String,
+    pub authentication_type: Option<SharingAuthenticationType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_profile_str: Option<String>, // Contents of the downloaded credential file
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateProviderRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient_profile_str: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ProviderInfo {
+    pub name: String,
+    pub authentication_type: Option<SharingAuthenticationType>,
+    pub owner: Option<String>,
+    pub comment: Option<String>,
+    pub cloud: Option<String>,
+    pub region: Option<String>,
+    pub metastore_id: Option<String>,
+    pub data_provider_global_metastore_id: Option<String>,
+    pub recipient_profile_str: Option<String>,
+    pub created_at: Option<i64>,
+    pub created_by: Option<String>,
+    pub updated_at: Option<i64>,
+    pub updated_by: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListProvidersResponse {
+    #[serde(default)]
+    pub providers: Vec<ProviderInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ProviderShare {
+    pub name: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListProviderSharesResponse {
+    #[serde(default)]
+    pub shares: Vec<ProviderShare>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
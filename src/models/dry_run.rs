@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// Replaces secret values in recorded bodies.
+pub(crate) const REDACTED: &str = "[REDACTED]";
+
+/// Body fields whose values are credentials, in addition to any field whose name contains
+/// `secret` or `password`.
+const SECRET_FIELDS: &[&str] = &[
+    "token",
+    "token_value",
+    "access_token",
+    "refresh_token",
+    "session_token",
+    "sas_token",
+    "authorization",
+    "string_value", // Secret scope values
+    "bytes_value",
+];
+
+/// A mutating request that a dry-run session recorded instead of sending.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PlannedRequest {
+    pub method: String,
+    /// The endpoint relative to the workspace host, e.g. `api/2.1/jobs/reset`.
+    pub endpoint: String,
+    /// The body as it would have been sent, with credentials and SQL statement parameter values
+    /// (which may carry e.g. `COPY INTO` credentials) replaced by `"[REDACTED]"`.
+    pub body: Option<Value>,
+}
+
+/// Redacts credentials in a request body before it is logged or recorded by a dry run.
+pub(crate) fn redact_secrets(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            for (name, field) in fields.iter_mut() {
+                let name: String = name.to_ascii_lowercase();
+                if SECRET_FIELDS.contains(&name.as_str())
+                    || name.contains("secret")
+                    || name.contains("password")
+                {
+                    *field = Value::String(REDACTED.to_string());
+                } else if name == "parameters" && field.is_array() {
+                    redact_parameter_values(field);
+                } else {
+                    redact_secrets(field);
+                }
+            }
+        }
+        Value::Array(items) => items.iter_mut().for_each(redact_secrets),
+        _ => {}
+    }
+}
+
+/// Redacts the `value` of each SQL statement parameter (`[{"name": ..., "value": ...}]`).
+fn redact_parameter_values(parameters: &mut Value) {
+    for parameter in parameters.as_array_mut().into_iter().flatten() {
+        if let Some(value) = parameter.get_mut("value") {
+            *value = Value::String(REDACTED.to_string());
+        }
+        redact_secrets(parameter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn redacts_statement_parameters_and_secret_fields() {
+        let mut body: Value = json!({
+            "statement": "COPY INTO t FROM 's3://b' WITH (CREDENTIAL (AWS_SECRET_KEY = :aws_secret_key))",
+            "parameters": [{"name": "aws_secret_key", "value": "abc"}],
+            "http_url_spec": {"url": "https://hook", "authorization": "Bearer x"},
+            "client_secret": "s",
+        });
+        redact_secrets(&mut body);
+        assert_eq!(body["parameters"][0]["name"], "aws_secret_key");
+        assert_eq!(body["parameters"][0]["value"], REDACTED);
+        assert_eq!(body["http_url_spec"]["authorization"], REDACTED);
+        assert_eq!(body["http_url_spec"]["url"], "https://hook");
+        assert_eq!(body["client_secret"], REDACTED);
+        assert!(body["statement"].as_str().unwrap().starts_with("COPY INTO"));
+    }
+}
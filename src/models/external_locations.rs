@@ -0,0 +1,244 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AwsIamRole {
+    pub role_arn: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>, // Output only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub unity_catalog_iam_arn: Option<String>, // Output only
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzureManagedIdentity {
+    pub access_connector_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub managed_identity_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credential_id: Option<String>, // Output only
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AzureServicePrincipal {
+    pub directory_id: String,
+    pub application_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_secret: Option<String>, // Never returned by the API
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DatabricksGcpServiceAccount {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>, // Output only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credential_id: Option<String>, // Output only
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CloudflareApiToken {
+    pub account_id: String,
+    pub access_key_id: String,
+    pub secret_access_key: String,
+}
+
+/// The cloud-specific identity a storage credential uses to access object storage.
+///
+/// Serialized as a single top-level key (e.g. `"aws_iam_role": { ... }`) of the enclosing request.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CloudCredential {
+    AwsIamRole(AwsIamRole),
+    AzureManagedIdentity(AzureManagedIdentity),
+    AzureServicePrincipal(AzureServicePrincipal),
+    DatabricksGcpServiceAccount(DatabricksGcpServiceAccount),
+    CloudflareApiToken(CloudflareApiToken),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateStorageCredentialRequest {
+    pub name: String,
+    #[serde(flatten)]
+    pub credential: CloudCredential,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_validation: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateStorageCredentialRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub credential: Option<CloudCredential>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_validation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct StorageCredentialInfo {
+    pub id: Option<String>,
+    pub name: String,
+    pub full_name: Option<String>,
+    pub owner: Option<String>,
+    pub comment: Option<String>,
+    pub read_only: Option<bool>,
+    pub used_for_managed_storage: Option<bool>,
+    pub aws_iam_role: Option<AwsIamRole>,
+    pub azure_managed_identity: Option<AzureManagedIdentity>,
+    pub azure_service_principal: Option<AzureServicePrincipal>,
+    pub databricks_gcp_service_account: Option<DatabricksGcpServiceAccount>,
+    pub metastore_id: Option<String>,
+    pub created_at: Option<i64>,
+    pub created_by: Option<String>,
+    pub updated_at: Option<i64>,
+    pub updated_by: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListStorageCredentialsResponse {
+    #[serde(default)]
+    pub storage_credentials: Vec<StorageCredentialInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateExternalLocationRequest {
+    pub name: String,
+    pub url: String,
+    pub credential_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_validation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fallback: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateExternalLocationRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub credential_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skip_validation: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ExternalLocationInfo {
+    pub name: String,
+    pub url: String,
+    pub credential_name: String,
+    pub credential_id: Option<String>,
+    pub owner: Option<String>,
+    pub comment: Option<String>,
+    pub read_only: Option<bool>,
+    pub fallback: Option<bool>,
+    pub metastore_id: Option<String>,
+    pub created_at: Option<i64>,
+    pub created_by: Option<String>,
+    pub updated_at: Option<i64>,
+    pub updated_by: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListExternalLocationsResponse {
+    #[serde(default)]
+    pub external_locations: Vec<ExternalLocationInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Validates a storage credential, either an existing one (by name) or an ad-hoc cloud credential,
+/// against an external location or URL.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ValidateStorageCredentialRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_credential_name: Option<String>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    pub credential: Option<CloudCredential>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_location_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub read_only: Option<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ValidationResultStatus {
+    Pass,
+    Fail,
+    Skip,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ValidationResult {
+    pub operation: Option<String>, // "READ", "WRITE", "DELETE", "LIST", "PATH_EXISTS"
+    pub result: Option<ValidationResultStatus>,
+    pub message: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ValidateStorageCredentialResponse {
+    pub is_dir: Option<bool>,
+    #[serde(default)]
+    pub results: Vec<ValidationResult>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ValidateStorageCredentialResponse {
+    /// Returns `true` if no validation step failed.
+    pub fn is_valid(&self) -> bool {
+        self.results
+            .iter()
+            .all(|result| result.result != Some(ValidationResultStatus::Fail))
+    }
+}
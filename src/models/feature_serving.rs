@@ -0,0 +1,116 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// How an online table is synced from its source Delta table.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct OnlineTableSpec {
+    pub source_table_full_name: String,
+    #[serde(default)]
+    pub primary_key_columns: Vec<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timeseries_key: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub perform_full_copy: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_triggered: Option<Map<String, Value>>, // Sync on demand or on a schedule
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub run_continuously: Option<Map<String, Value>>, // Sync with streaming latency
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pipeline_id: Option<String>, // Set by the server
+}
+
+impl OnlineTableSpec {
+    /// An online table synced from `source_table_full_name` whenever its sync pipeline is triggered.
+    pub fn triggered(source_table_full_name: &str, primary_key_columns: &[&str]) -> Self {
+        OnlineTableSpec {
+            source_table_full_name: source_table_full_name.to_string(),
+            primary_key_columns: primary_key_columns.iter().map(|c| c.to_string()).collect(),
+            run_triggered: Some(Map::new()),
+            ..Default::default()
+        }
+    }
+
+    /// An online table kept in sync with `source_table_full_name` continuously.
+    pub fn continuous(source_table_full_name: &str, primary_key_columns: &[&str]) -> Self {
+        OnlineTableSpec {
+            run_triggered: None,
+            run_continuously: Some(Map::new()),
+            ..Self::triggered(source_table_full_name, primary_key_columns)
+        }
+    }
+
+    /// Keeps only the latest row per primary key, ordered by `timeseries_key`.
+    pub fn with_timeseries_key(mut self, timeseries_key: &str) -> Self {
+        self.timeseries_key = Some(timeseries_key.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateOnlineTableRequest {
+    pub name: String, // Full name in Unity Catalog, e.g. "main.features.users_online"
+    pub spec: OnlineTableSpec,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct OnlineTableStatus {
+    pub detailed_state: Option<String>, // "ONLINE_NO_PENDING_UPDATE", "PROVISIONING", "OFFLINE_FAILED", ...
+    pub message: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct OnlineTable {
+    pub name: String,
+    pub spec: Option<OnlineTableSpec>,
+    pub status: Option<OnlineTableStatus>,
+    pub table_serving_url: Option<String>,
+    pub unity_catalog_provisioning_state: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl OnlineTable {
+    fn detailed_state(&self) -> &str {
+        self.status
+            .as_ref()
+            .and_then(|status| status.detailed_state.as_deref())
+            .unwrap_or_default()
+    }
+
+    /// Returns `true` once the table can serve lookups.
+    pub fn is_online(&self) -> bool {
+        self.detailed_state().starts_with("ONLINE") && !self.detailed_state().ends_with("FAILED")
+    }
+
+    /// Returns `true` if provisioning or syncing the table failed.
+    pub fn has_failed(&self) -> bool {
+        self.detailed_state().ends_with("FAILED")
+    }
+}
+
+/// The feature values a feature serving endpoint returned, one output per lookup key.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct FeatureValues {
+    #[serde(default)]
+    pub outputs: Vec<Map<String, Value>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl FeatureValues {
+    /// Deserializes the outputs into typed rows, in the order of the lookup keys.
+    pub fn outputs_as<T: DeserializeOwned>(&self) -> Result<Vec<T>, serde_json::Error> {
+        self.outputs
+            .iter()
+            .map(|output| T::deserialize(Value::Object(output.clone())))
+            .collect()
+    }
+}
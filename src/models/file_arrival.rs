@@ -0,0 +1,117 @@
+use crate::models::ValidationResult;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::time::Duration;
+
+/// Whether a job's trigger or schedule is active.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PauseStatus {
+    Paused,
+    Unpaused,
+}
+
+/// When a job is run other than on demand. Only file-arrival triggers are modelled; other
+/// trigger kinds are kept in `extra`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TriggerSettings {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pause_status: Option<PauseStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file_arrival: Option<FileArrivalTrigger>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Runs a job when new files arrive under a storage location: a path of an external location
+/// (e.g. `s3://bucket/landing/`) or of a volume (`/Volumes/main/default/landing/`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileArrivalTrigger {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_time_between_triggers_seconds: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub wait_after_last_change_seconds: Option<i64>,
+}
+
+impl FileArrivalTrigger {
+    /// The shortest interval the service accepts for either setting.
+    pub const MIN_INTERVAL_SECONDS: i64 = 60;
+    /// The longest the service waits for a batch of files to stop changing.
+    pub const MAX_WAIT_AFTER_LAST_CHANGE_SECONDS: i64 = 7200;
+
+    /// Creates a trigger monitoring `url` with the service's default intervals.
+    pub fn new(url: &str) -> Self {
+        FileArrivalTrigger {
+            url: url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the minimum time between two runs started by the trigger.
+    pub fn with_min_time_between_triggers(mut self, interval: Duration) -> Self {
+        self.min_time_between_triggers_seconds = Some(interval.as_secs() as i64);
+        self
+    }
+
+    /// Waits until no file has changed for `interval` before starting a run, so that a batch
+    /// of files arriving together triggers a single run.
+    pub fn with_wait_after_last_change(mut self, interval: Duration) -> Self {
+        self.wait_after_last_change_seconds = Some(interval.as_secs() as i64);
+        self
+    }
+
+    /// Checks the URL and the intervals against the limits the service enforces.
+    pub fn validate(&self) -> Result<(), String> {
+        if self.url.trim().is_empty() {
+            return Err("File arrival trigger has no URL".to_string());
+        }
+        if let Some(seconds) = self.min_time_between_triggers_seconds {
+            if seconds < Self::MIN_INTERVAL_SECONDS {
+                return Err(format!(
+                    "min_time_between_triggers_seconds must be at least {}, got {}",
+                    Self::MIN_INTERVAL_SECONDS,
+                    seconds
+                ));
+            }
+        }
+        if let Some(seconds) = self.wait_after_last_change_seconds {
+            if !(Self::MIN_INTERVAL_SECONDS..=Self::MAX_WAIT_AFTER_LAST_CHANGE_SECONDS)
+                .contains(&seconds)
+            {
+                return Err(format!(
+                    "wait_after_last_change_seconds must be between {} and {}, got {}",
+                    Self::MIN_INTERVAL_SECONDS,
+                    Self::MAX_WAIT_AFTER_LAST_CHANGE_SECONDS,
+                    seconds
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether the trigger monitors a Unity Catalog volume rather than an external location.
+    pub fn is_volume(&self) -> bool {
+        self.url.starts_with("/Volumes/")
+    }
+}
+
+/// The outcome of `check_file_arrival_trigger`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileArrivalCheck {
+    pub url: String,
+    /// The external location containing the URL; `None` for volumes or when no location matches.
+    pub external_location: Option<String>,
+    /// The storage checks the service ran with the location's credential.
+    pub results: Vec<ValidationResult>,
+    /// Why the trigger would not fire, if anything.
+    pub problems: Vec<String>,
+}
+
+impl FileArrivalCheck {
+    /// Returns `true` if the trigger can monitor its URL.
+    pub fn is_ok(&self) -> bool {
+        self.problems.is_empty()
+    }
+}
@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Metadata of a file in a Unity Catalog volume or the workspace, read from the response headers
+/// of the Files API.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileMetadata {
+    pub content_length: Option<u64>,
+    pub content_type: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct DbfsFileInfo {
+    pub path: String,
+    #[serde(default)]
+    pub is_dir: bool,
+    pub file_size: Option<u64>,
+    pub modification_time: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct DbfsReadResponse {
+    pub bytes_read: u64,
+    #[serde(default)]
+    pub data: String, // Base64-encoded
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct DbfsCreateRequest {
+    pub path: String,
+    pub overwrite: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct DbfsHandle {
+    pub handle: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct DbfsAddBlockRequest {
+    pub handle: i64,
+    pub data: String, // Base64-encoded, at most 1 MB before encoding
+}
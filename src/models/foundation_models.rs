@@ -0,0 +1,293 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChatRole {
+    System,
+    User,
+    Assistant,
+    Tool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    pub role: ChatRole,
+    pub content: String,
+}
+
+impl ChatMessage {
+    pub fn system(content: impl Into<String>) -> Self {
+        ChatMessage {
+            role: ChatRole::System,
+            content: content.into(),
+        }
+    }
+
+    pub fn user(content: impl Into<String>) -> Self {
+        ChatMessage {
+            role: ChatRole::User,
+            content: content.into(),
+        }
+    }
+
+    pub fn assistant(content: impl Into<String>) -> Self {
+        ChatMessage {
+            role: ChatRole::Assistant,
+            content: content.into(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ChatCompletionRequest {
+    pub messages: Vec<ChatMessage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_k: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub n: Option<u32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stop: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stream: Option<bool>, // Set by the client when streaming
+}
+
+impl ChatCompletionRequest {
+    pub fn new(messages: Vec<ChatMessage>) -> Self {
+        ChatCompletionRequest {
+            messages,
+            ..Default::default()
+        }
+    }
+}
+
+/// Token usage reported by pay-per-token endpoints.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct TokenUsage {
+    #[serde(default)]
+    pub prompt_tokens: u64,
+    #[serde(default)]
+    pub completion_tokens: u64,
+    #[serde(default)]
+    pub total_tokens: u64,
+}
+
+impl TokenUsage {
+    /// Usage of `prompt_tokens` input and `completion_tokens` output tokens, e.g. counted locally.
+    pub fn new(prompt_tokens: u64, completion_tokens: u64) -> Self {
+        TokenUsage {
+            prompt_tokens,
+            completion_tokens,
+            total_tokens: prompt_tokens + completion_tokens,
+        }
+    }
+}
+
+impl std::ops::Add for TokenUsage {
+    type Output = TokenUsage;
+
+    fn add(self, other: TokenUsage) -> TokenUsage {
+        TokenUsage {
+            prompt_tokens: self.prompt_tokens + other.prompt_tokens,
+            completion_tokens: self.completion_tokens + other.completion_tokens,
+            total_tokens: self.total_tokens + other.total_tokens,
+        }
+    }
+}
+
+impl std::ops::AddAssign for TokenUsage {
+    fn add_assign(&mut self, other: TokenUsage) {
+        *self = *self + other;
+    }
+}
+
+/// A response that may report the tokens its request consumed.
+pub trait HasTokenUsage {
+    /// The token usage reported with the response, if any.
+    fn token_usage(&self) -> Option<TokenUsage>;
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct ChatChoice {
+    pub index: u32,
+    pub message: ChatMessage,
+    pub finish_reason: Option<String>, // "stop", "length", ...
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ChatCompletionResponse {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    pub created: Option<i64>,
+    pub model: Option<String>,
+    #[serde(default)]
+    pub choices: Vec<ChatChoice>,
+    pub usage: Option<TokenUsage>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl HasTokenUsage for ChatCompletionResponse {
+    fn token_usage(&self) -> Option<TokenUsage> {
+        self.usage
+    }
+}
+
+impl ChatCompletionResponse {
+    /// The content of the first choice, which is the only one unless `n > 1` was requested.
+    pub fn content(&self) -> Option<&str> {
+        self.choices
+            .first()
+            .map(|choice| choice.message.content.as_str())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ChatDelta {
+    pub role: Option<ChatRole>,
+    pub content: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ChatChunkChoice {
+    pub index: u32,
+    #[serde(default)]
+    pub delta: ChatDelta,
+    pub finish_reason: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// An incremental chunk of a streamed chat completion.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ChatCompletionChunk {
+    pub id: Option<String>,
+    pub object: Option<String>,
+    pub created: Option<i64>,
+    pub model: Option<String>,
+    #[serde(default)]
+    pub choices: Vec<ChatChunkChoice>,
+    pub usage: Option<TokenUsage>, // Usually only present on the final chunk
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl HasTokenUsage for ChatCompletionChunk {
+    fn token_usage(&self) -> Option<TokenUsage> {
+        self.usage
+    }
+}
+
+impl ChatCompletionChunk {
+    /// The content delta of the first choice.
+    pub fn delta_content(&self) -> Option<&str> {
+        self.choices
+            .first()
+            .and_then(|choice| choice.delta.content.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CompletionRequest {
+    pub prompt: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_tokens: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<f32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub top_p: Option<f32>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub stop: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct CompletionChoice {
+    pub index: u32,
+    pub text: String,
+    pub finish_reason: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct CompletionResponse {
+    pub id: Option<String>,
+    pub model: Option<String>,
+    #[serde(default)]
+    pub choices: Vec<CompletionChoice>,
+    pub usage: Option<TokenUsage>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl HasTokenUsage for CompletionResponse {
+    fn token_usage(&self) -> Option<TokenUsage> {
+        self.usage
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingsRequest {
+    pub input: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct Embedding {
+    pub index: u32,
+    pub embedding: Vec<f32>,
+    pub object: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct EmbeddingsResponse {
+    pub model: Option<String>,
+    #[serde(default)]
+    pub data: Vec<Embedding>,
+    pub usage: Option<TokenUsage>,
+}
+
+impl HasTokenUsage for EmbeddingsResponse {
+    fn token_usage(&self) -> Option<TokenUsage> {
+        self.usage
+    }
+}
+
+impl EmbeddingsResponse {
+    /// The embedding vectors, ordered to match the request's `input`.
+    pub fn vectors(&self) -> Vec<Vec<f32>> {
+        let mut data: Vec<&Embedding> = self.data.iter().collect();
+        data.sort_by_key(|embedding| embedding.index);
+        data.into_iter()
+            .map(|embedding| embedding.embedding.clone())
+            .collect()
+    }
+}
@@ -0,0 +1,170 @@
+use crate::models::SqlStatementResponse;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum GenieMessageStatus {
+    Submitted,
+    FetchingMetadata,
+    FilteringContext,
+    AskingAi,
+    PendingWarehouse,
+    ExecutingQuery,
+    Completed,
+    Failed,
+    Cancelled,
+    QueryResultExpired,
+}
+
+impl GenieMessageStatus {
+    /// Whether Genie has stopped working on the message.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            GenieMessageStatus::Completed
+                | GenieMessageStatus::Failed
+                | GenieMessageStatus::Cancelled
+                | GenieMessageStatus::QueryResultExpired
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenieCreateMessageRequest {
+    pub content: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct GenieTextAttachment {
+    pub id: Option<String>,
+    pub content: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct GenieQueryAttachment {
+    pub id: Option<String>,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub query: Option<String>, // The generated SQL
+    pub statement_id: Option<String>,
+    pub last_updated_timestamp: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A piece of Genie's answer: either explanatory text or a generated query.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct GenieAttachment {
+    pub attachment_id: Option<String>,
+    pub text: Option<GenieTextAttachment>,
+    pub query: Option<GenieQueryAttachment>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct GenieMessageError {
+    pub error: Option<String>,
+    #[serde(rename = "type")]
+    pub error_type: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct GenieQueryResultMetadata {
+    pub statement_id: Option<String>,
+    pub row_count: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct GenieMessage {
+    pub id: String,
+    pub space_id: Option<String>,
+    pub conversation_id: Option<String>,
+    pub user_id: Option<i64>,
+    pub content: Option<String>, // The user's question
+    pub status: Option<GenieMessageStatus>,
+    #[serde(default)]
+    pub attachments: Vec<GenieAttachment>,
+    pub query_result: Option<GenieQueryResultMetadata>,
+    pub error: Option<GenieMessageError>,
+    pub created_timestamp: Option<i64>,
+    pub last_updated_timestamp: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl GenieMessage {
+    /// The concatenated text attachments of Genie's answer.
+    pub fn text(&self) -> Option<String> {
+        let parts: Vec<&str> = self
+            .attachments
+            .iter()
+            .filter_map(|attachment| attachment.text.as_ref())
+            .filter_map(|text| text.content.as_deref())
+            .collect();
+        (!parts.is_empty()).then(|| parts.join("\n"))
+    }
+
+    /// The first query attachment together with its attachment id, if Genie generated a query.
+    pub fn query_attachment(&self) -> Option<(&str, &GenieQueryAttachment)> {
+        self.attachments.iter().find_map(|attachment| {
+            let id: &str = attachment
+                .attachment_id
+                .as_deref()
+                .or_else(|| attachment.query.as_ref()?.id.as_deref())?;
+            attachment.query.as_ref().map(|query| (id, query))
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct GenieConversation {
+    pub id: String,
+    pub space_id: Option<String>,
+    pub user_id: Option<i64>,
+    pub title: Option<String>,
+    pub created_timestamp: Option<i64>,
+    pub last_updated_timestamp: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct GenieStartConversationResponse {
+    pub conversation_id: String,
+    pub message_id: String,
+    pub conversation: Option<GenieConversation>,
+    pub message: Option<GenieMessage>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct GenieQueryResultResponse {
+    pub statement_response: SqlStatementResponse,
+}
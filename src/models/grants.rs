@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// The kind of Unity Catalog object a grant applies to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SecurableType {
+    Metastore,
+    Catalog,
+    Schema,
+    Table,
+    Function,
+    Volume,
+    StorageCredential,
+    ExternalLocation,
+    Connection,
+    Share,
+    Provider,
+    Recipient,
+}
+
+impl SecurableType {
+    /// Returns the lowercase form used in permission endpoint paths.
+    pub fn as_path_segment(&self) -> &'static str {
+        match self {
+            SecurableType::Metastore => "metastore",
+            SecurableType::Catalog => "catalog",
+            SecurableType::Schema => "schema",
+            SecurableType::Table => "table",
+            SecurableType::Function => "function",
+            SecurableType::Volume => "volume",
+            SecurableType::StorageCredential => "storage_credential",
+            SecurableType::ExternalLocation => "external_location",
+            SecurableType::Connection => "connection",
+            SecurableType::Share => "share",
+            SecurableType::Provider => "provider",
+            SecurableType::Recipient => "recipient",
+        }
+    }
+}
+
+impl fmt::Display for SecurableType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_path_segment())
+    }
+}
+
+/// A Unity Catalog privilege. Privileges not modelled here are preserved in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Privilege {
+    AllPrivileges,
+    Manage,
+    Browse,
+    ApplyTag,
+    UseCatalog,
+    UseSchema,
+    UseConnection,
+    Select,
+    Modify,
+    Execute,
+    Refresh,
+    ReadVolume,
+    WriteVolume,
+    ReadFiles,
+    WriteFiles,
+    CreateCatalog,
+    CreateForeignCatalog,
+    CreateSchema,
+    CreateTable,
+    CreateMaterializedView,
+    CreateFunction,
+    CreateModel,
+    CreateVolume,
+    CreateExternalTable,
+    CreateExternalVolume,
+    CreateExternalLocation,
+    CreateStorageCredential,
+    CreateManagedStorage,
+    CreateConnection,
+    CreateShare,
+    CreateRecipient,
+    CreateProvider,
+    UseShare,
+    UseRecipient,
+    UseProvider,
+    SetSharePermission,
+    UseMarketplaceAssets,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The privileges held by a single principal (user, group or service principal).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrivilegeAssignment {
+    pub principal: String,
+    #[serde(default)]
+    pub privileges: Vec<Privilege>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PermissionsList {
+    #[serde(default)]
+    pub privilege_assignments: Vec<PrivilegeAssignment>,
+}
+
+impl PermissionsList {
+    /// Returns the privileges granted directly to `principal`.
+    pub fn privileges_of(&self, principal: &str) -> &[Privilege] {
+        self.privilege_assignments
+            .iter()
+            .find(|assignment| assignment.principal == principal)
+            .map(|assignment| assignment.privileges.as_slice())
+            .unwrap_or(&[])
+    }
+}
+
+/// A set of privileges to add and/or remove for one principal.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct PermissionsChange {
+    pub principal: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub add: Vec<Privilege>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub remove: Vec<Privilege>,
+}
+
+impl PermissionsChange {
+    /// Creates a change granting `privileges` to `principal`.
+    pub fn grant(principal: impl Into<String>, privileges: Vec<Privilege>) -> Self {
+        PermissionsChange {
+            principal: principal.into(),
+            add: privileges,
+            remove: Vec::new(),
+        }
+    }
+
+    /// Creates a change revoking `privileges` from `principal`.
+    pub fn revoke(principal: impl Into<String>, privileges: Vec<Privilege>) -> Self {
+        PermissionsChange {
+            principal: principal.into(),
+            add: Vec::new(),
+            remove: privileges,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdatePermissionsRequest {
+    pub changes: Vec<PermissionsChange>,
+}
+
+/// A privilege as seen by effective-permissions retrieval, including where it was inherited from.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct EffectivePrivilege {
+    pub privilege: Privilege,
+    pub inherited_from_type: Option<SecurableType>,
+    pub inherited_from_name: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct EffectivePrivilegeAssignment {
+    pub principal: String,
+    #[serde(default)]
+    pub privileges: Vec<EffectivePrivilege>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct EffectivePermissionsList {
+    #[serde(default)]
+    pub privilege_assignments: Vec<EffectivePrivilegeAssignment>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ScimEmail {
+    pub value: Option<String>,
+    #[serde(rename = "type")]
+    pub email_type: Option<String>,
+    pub primary: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ScimGroupRef {
+    pub value: Option<String>, // Group ID
+    pub display: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The user or service principal the session authenticates as.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default, rename_all = "camelCase")]
+pub struct CurrentUser {
+    pub id: String,
+    pub user_name: Option<String>, // Email for users, application ID for service principals
+    pub display_name: Option<String>,
+    pub active: Option<bool>,
+    pub emails: Vec<ScimEmail>,
+    pub groups: Vec<ScimGroupRef>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The outcome of `DatabricksSession::ping`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PingResponse {
+    /// The round-trip time of the authenticated request.
+    pub latency: Duration,
+    /// The identity the session's token belongs to.
+    pub user: CurrentUser,
+}
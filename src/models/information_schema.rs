@@ -0,0 +1,195 @@
+use crate::utils::{quote_identifier, quote_literal};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Filters applied when querying a catalog's `information_schema` views.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct InformationSchemaFilter {
+    pub table_schema: Option<String>,
+    pub table_name: Option<String>,
+    pub limit: Option<u32>,
+}
+
+impl InformationSchemaFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restricts the rows to one schema of the catalog.
+    pub fn table_schema(mut self, table_schema: &str) -> Self {
+        self.table_schema = Some(table_schema.to_string());
+        self
+    }
+
+    /// Restricts the rows to one table (or view) name.
+    pub fn table_name(mut self, table_name: &str) -> Self {
+        self.table_name = Some(table_name.to_string());
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Builds a `SELECT` over `catalog.information_schema.view`, ordered by `order_by`.
+    pub(crate) fn to_sql(
+        &self,
+        catalog: &str,
+        view: &str,
+        columns: &[&str],
+        order_by: &[&str],
+    ) -> String {
+        let mut conditions: Vec<String> = Vec::new();
+        if let Some(table_schema) = &self.table_schema {
+            conditions.push(format!("table_schema = {}", quote_literal(table_schema)));
+        }
+        if let Some(table_name) = &self.table_name {
+            conditions.push(format!("table_name = {}", quote_literal(table_name)));
+        }
+
+        let mut statement: String = format!(
+            "SELECT {} FROM {}.information_schema.{}",
+            columns.join(", "),
+            quote_identifier(catalog),
+            view
+        );
+        if !conditions.is_empty() {
+            statement.push_str(&format!(" WHERE {}", conditions.join(" AND ")));
+        }
+        statement.push_str(&format!(" ORDER BY {}", order_by.join(", ")));
+        if let Some(limit) = self.limit {
+            statement.push_str(&format!(" LIMIT {}", limit));
+        }
+        statement
+    }
+}
+
+/// A row of `information_schema.tables`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InformationSchemaTable {
+    pub table_catalog: String,
+    pub table_schema: String,
+    pub table_name: String,
+    pub table_type: Option<String>, // "MANAGED", "EXTERNAL", "VIEW", ...
+    pub table_owner: Option<String>,
+    pub comment: Option<String>,
+    pub created: Option<DateTime<Utc>>,
+    pub created_by: Option<String>,
+    pub last_altered: Option<DateTime<Utc>>,
+    pub last_altered_by: Option<String>,
+    pub data_source_format: Option<String>,
+    pub storage_path: Option<String>,
+}
+
+impl InformationSchemaTable {
+    pub(crate) const COLUMNS: &'static [&'static str] = &[
+        "table_catalog",
+        "table_schema",
+        "table_name",
+        "table_type",
+        "table_owner",
+        "comment",
+        "created",
+        "created_by",
+        "last_altered",
+        "last_altered_by",
+        "data_source_format",
+        "storage_path",
+    ];
+}
+
+/// A row of `information_schema.columns`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InformationSchemaColumn {
+    pub table_catalog: String,
+    pub table_schema: String,
+    pub table_name: String,
+    pub column_name: String,
+    pub ordinal_position: i64,
+    pub is_nullable: Option<String>, // "YES" or "NO"
+    pub column_default: Option<String>,
+    pub data_type: Option<String>,      // e.g. "DECIMAL"
+    pub full_data_type: Option<String>, // e.g. "decimal(10,2)"
+    pub character_maximum_length: Option<i64>,
+    pub numeric_precision: Option<i64>,
+    pub numeric_scale: Option<i64>,
+    pub partition_index: Option<i64>,
+    pub comment: Option<String>,
+}
+
+impl InformationSchemaColumn {
+    pub(crate) const COLUMNS: &'static [&'static str] = &[
+        "table_catalog",
+        "table_schema",
+        "table_name",
+        "column_name",
+        "ordinal_position",
+        "is_nullable",
+        "column_default",
+        "data_type",
+        "full_data_type",
+        "character_maximum_length",
+        "numeric_precision",
+        "numeric_scale",
+        "partition_index",
+        "comment",
+    ];
+
+    /// Whether the column accepts `NULL`s.
+    pub fn nullable(&self) -> bool {
+        self.is_nullable.as_deref() != Some("NO")
+    }
+}
+
+/// A row of `information_schema.views`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InformationSchemaView {
+    pub table_catalog: String,
+    pub table_schema: String,
+    pub table_name: String,
+    pub view_definition: Option<String>,
+    pub check_option: Option<String>,
+    pub is_updatable: Option<String>,
+    pub is_insertable_into: Option<String>,
+    pub sql_path: Option<String>,
+}
+
+impl InformationSchemaView {
+    pub(crate) const COLUMNS: &'static [&'static str] = &[
+        "table_catalog",
+        "table_schema",
+        "table_name",
+        "view_definition",
+        "check_option",
+        "is_updatable",
+        "is_insertable_into",
+        "sql_path",
+    ];
+}
+
+/// A row of `information_schema.table_privileges`: one privilege granted on a table or view.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct InformationSchemaTablePrivilege {
+    pub grantor: Option<String>,
+    pub grantee: String,
+    pub table_catalog: String,
+    pub table_schema: String,
+    pub table_name: String,
+    pub privilege_type: String, // "SELECT", "MODIFY", ...
+    pub is_grantable: Option<String>,
+    pub inherited_from: Option<String>, // "NONE", "CATALOG", "SCHEMA"
+}
+
+impl InformationSchemaTablePrivilege {
+    pub(crate) const COLUMNS: &'static [&'static str] = &[
+        "grantor",
+        "grantee",
+        "table_catalog",
+        "table_schema",
+        "table_name",
+        "privilege_type",
+        "is_grantable",
+        "inherited_from",
+    ];
+}
@@ -0,0 +1,39 @@
+use crate::models::{
+    AlertInfo, BaseJob, ClusterSummary, DashboardInfo, QueryInfo, RepoInfo, WarehouseInfo,
+};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A point-in-time listing of the main assets of a workspace, collected by
+/// `DatabricksSession::collect_inventory`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceInventory {
+    pub host: String,
+    pub collected_at: DateTime<Utc>,
+    pub clusters: Vec<ClusterSummary>,
+    pub jobs: Vec<BaseJob>,
+    pub warehouses: Vec<WarehouseInfo>,
+    pub repos: Vec<RepoInfo>,
+    pub queries: Vec<QueryInfo>,
+    pub alerts: Vec<AlertInfo>,
+    pub dashboards: Vec<DashboardInfo>,
+}
+
+/// How `DatabricksSession::collect_inventory` spreads its requests.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InventoryOptions {
+    /// The number of asset types listed at the same time.
+    pub concurrency: usize,
+    /// How often a listing is retried after a `REQUEST_LIMIT_EXCEEDED` response, with exponential
+    /// backoff starting at one second.
+    pub max_rate_limit_retries: u32,
+}
+
+impl Default for InventoryOptions {
+    fn default() -> Self {
+        InventoryOptions {
+            concurrency: 3,
+            max_rate_limit_retries: 5,
+        }
+    }
+}
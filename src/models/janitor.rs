@@ -0,0 +1,110 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::{fmt, time::Duration};
+
+/// What `DatabricksSession::run_janitor` considers unused.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JanitorOptions {
+    /// Clusters terminated for longer than this are candidates. Job clusters and pinned clusters
+    /// are never candidates.
+    pub terminated_cluster_age: Duration,
+    /// Jobs whose last run started (or, without runs, that were created) longer ago than this are
+    /// candidates. Jobs without a creation time are never candidates.
+    pub job_inactivity: Duration,
+    /// Stopped warehouses whose name starts with this prefix are candidates, e.g. `"ci-"`.
+    pub ephemeral_warehouse_prefix: Option<String>,
+    /// Stopped warehouses carrying a custom tag with this key are candidates.
+    pub ephemeral_warehouse_tag: Option<String>,
+    /// The number of job run lookups made at the same time.
+    pub concurrency: usize,
+    /// How often a request is retried after a `REQUEST_LIMIT_EXCEEDED` response, with exponential
+    /// backoff starting at one second.
+    pub max_rate_limit_retries: u32,
+}
+
+impl Default for JanitorOptions {
+    /// Clusters terminated for 30 days and jobs inactive for 90 days; no warehouses.
+    fn default() -> Self {
+        JanitorOptions {
+            terminated_cluster_age: Duration::from_secs(30 * 24 * 60 * 60),
+            job_inactivity: Duration::from_secs(90 * 24 * 60 * 60),
+            ephemeral_warehouse_prefix: None,
+            ephemeral_warehouse_tag: None,
+            concurrency: 4,
+            max_rate_limit_retries: 5,
+        }
+    }
+}
+
+impl JanitorOptions {
+    /// Sets how long a cluster must have been terminated to be a candidate.
+    pub fn with_terminated_cluster_age(mut self, age: Duration) -> Self {
+        self.terminated_cluster_age = age;
+        self
+    }
+
+    /// Sets how long a job must have been without runs to be a candidate.
+    pub fn with_job_inactivity(mut self, inactivity: Duration) -> Self {
+        self.job_inactivity = inactivity;
+        self
+    }
+
+    /// Treats stopped warehouses whose name starts with `prefix` as ephemeral.
+    pub fn with_ephemeral_warehouse_prefix(mut self, prefix: &str) -> Self {
+        self.ephemeral_warehouse_prefix = Some(prefix.to_string());
+        self
+    }
+
+    /// Treats stopped warehouses tagged with `key` as ephemeral.
+    pub fn with_ephemeral_warehouse_tag(mut self, key: &str) -> Self {
+        self.ephemeral_warehouse_tag = Some(key.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CleanupResourceKind {
+    Cluster,
+    Job,
+    Warehouse,
+}
+
+impl fmt::Display for CleanupResourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name: &str = match self {
+            CleanupResourceKind::Cluster => "cluster",
+            CleanupResourceKind::Job => "job",
+            CleanupResourceKind::Warehouse => "warehouse",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// A resource the janitor found unused.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CleanupCandidate {
+    pub kind: CleanupResourceKind,
+    pub id: String,
+    pub name: Option<String>,
+    /// Why the resource is considered unused, e.g. "terminated 45 days ago".
+    pub reason: String,
+    pub last_activity: Option<DateTime<Utc>>,
+    pub creator: Option<String>,
+}
+
+/// What the janitor found and, unless it only reported, what it deleted.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JanitorReport {
+    pub collected_at: DateTime<Utc>,
+    /// Whether deletion was requested; if not, the report only lists candidates.
+    pub delete: bool,
+    pub candidates: Vec<CleanupCandidate>,
+    /// The candidates that were deleted.
+    pub deleted: Vec<CleanupCandidate>,
+    /// The candidates whose deletion failed, with the error message.
+    pub failed: Vec<(CleanupCandidate, String)>,
+    /// The resources whose activity could not be looked up, by kind and id, with the error
+    /// message. They are not candidates.
+    pub unchecked: Vec<(CleanupResourceKind, String, String)>,
+}
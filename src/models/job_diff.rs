@@ -0,0 +1,195 @@
+use crate::models::JobSettings;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Settings the Jobs API fills in with these values when a job does not set them.
+const SERVER_DEFAULTS: &[(&str, &str)] = &[
+    ("format", "\"MULTI_TASK\""),
+    ("max_concurrent_runs", "1"),
+    ("timeout_seconds", "0"),
+    ("run_if", "\"ALL_SUCCESS\""),
+    ("min_retry_interval_millis", "0"),
+    ("max_retries", "0"),
+    ("retry_on_timeout", "false"),
+];
+
+/// Settings the server assigns when a job does not set them, e.g. `run_as` defaulting to the
+/// creator. They are only compared when the desired settings specify them.
+const SERVER_ASSIGNED: &[&str] = &["run_as", "deployment", "edit_mode"];
+
+/// Keys identifying the elements of arrays such as `tasks` or `job_clusters`, so elements are
+/// matched by key rather than by position.
+const ELEMENT_KEYS: &[&str] = &["task_key", "job_cluster_key", "environment_key", "name"];
+
+/// One setting that differs between a job's current and desired settings.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobSettingsChange {
+    /// The setting, e.g. `tasks[ingest].notebook_task.notebook_path`.
+    pub path: String,
+    /// The current value, or `None` if the job does not set it.
+    pub current: Option<Value>,
+    /// The desired value, or `None` if the desired settings remove it.
+    pub desired: Option<Value>,
+}
+
+/// The semantic difference between a job's current and desired settings.
+///
+/// Null and empty values, settings equal to server-side defaults and server-assigned settings the
+/// desired spec does not mention are ignored, and tasks and job clusters are matched by key, so a
+/// job deployed from the same spec compares as unchanged.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobSettingsDiff {
+    pub changes: Vec<JobSettingsChange>,
+}
+
+impl JobSettingsDiff {
+    /// Compares `current` against `desired`.
+    pub fn between(current: &JobSettings, desired: &JobSettings) -> Self {
+        let mut current: Value = normalize(serde_json::to_value(current).unwrap_or(Value::Null));
+        let desired: Value = normalize(serde_json::to_value(desired).unwrap_or(Value::Null));
+        if let (Value::Object(current), Value::Object(desired)) = (&mut current, &desired) {
+            current.retain(|key, _| {
+                !SERVER_ASSIGNED.contains(&key.as_str()) || desired.contains_key(key)
+            });
+        }
+
+        let mut diff: JobSettingsDiff = JobSettingsDiff::default();
+        diff_values(
+            "",
+            non_null(&current),
+            non_null(&desired),
+            &mut diff.changes,
+        );
+        diff
+    }
+
+    /// Whether the current settings already match the desired ones.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty()
+    }
+}
+
+/// What `apply_job` did to reach the desired settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JobApplyAction {
+    Created,
+    Updated,
+    Unchanged,
+}
+
+/// The outcome of `apply_job`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct JobApplyOutcome {
+    pub job_id: i64,
+    pub action: JobApplyAction,
+    /// The changes applied; empty for created and unchanged jobs.
+    pub diff: JobSettingsDiff,
+}
+
+/// Drops nulls, empty objects and arrays and server-default values, at any depth.
+fn normalize(value: Value) -> Value {
+    match value {
+        Value::Object(map) => Value::Object(
+            map.into_iter()
+                .map(|(key, value)| (key, normalize(value)))
+                .filter(|(key, value)| !is_empty(value) && !is_server_default(key, value))
+                .collect::<Map<String, Value>>(),
+        ),
+        Value::Array(items) => Value::Array(items.into_iter().map(normalize).collect()),
+        other => other,
+    }
+}
+
+fn is_empty(value: &Value) -> bool {
+    match value {
+        Value::Null => true,
+        Value::Object(map) => map.is_empty(),
+        Value::Array(items) => items.is_empty(),
+        _ => false,
+    }
+}
+
+fn is_server_default(key: &str, value: &Value) -> bool {
+    SERVER_DEFAULTS.iter().any(|(default_key, default)| {
+        *default_key == key && serde_json::from_str::<Value>(default).ok().as_ref() == Some(value)
+    })
+}
+
+fn non_null(value: &Value) -> Option<&Value> {
+    (!value.is_null()).then_some(value)
+}
+
+fn diff_values(
+    path: &str,
+    current: Option<&Value>,
+    desired: Option<&Value>,
+    changes: &mut Vec<JobSettingsChange>,
+) {
+    match (current, desired) {
+        (Some(Value::Object(current)), Some(Value::Object(desired))) => {
+            let mut keys: Vec<&String> = current.keys().chain(desired.keys()).collect();
+            keys.sort();
+            keys.dedup();
+            for key in keys {
+                diff_values(
+                    &child_path(path, key),
+                    current.get(key),
+                    desired.get(key),
+                    changes,
+                );
+            }
+        }
+        (Some(Value::Array(current)), Some(Value::Array(desired))) => {
+            match element_key(current, desired) {
+                Some(element_key) => {
+                    let key_of = |item: &Value| -> String {
+                        item.get(element_key)
+                            .map(|key| key.as_str().map_or(key.to_string(), str::to_string))
+                            .unwrap_or_default()
+                    };
+                    let mut keys: Vec<String> = current.iter().chain(desired).map(key_of).collect();
+                    keys.sort();
+                    keys.dedup();
+                    for key in keys {
+                        diff_values(
+                            &format!("{}[{}]", path, key),
+                            current.iter().find(|item| key_of(item) == key),
+                            desired.iter().find(|item| key_of(item) == key),
+                            changes,
+                        );
+                    }
+                }
+                None if current != desired => changes.push(JobSettingsChange {
+                    path: path.to_string(),
+                    current: Some(Value::Array(current.clone())),
+                    desired: Some(Value::Array(desired.clone())),
+                }),
+                None => {}
+            }
+        }
+        (current, desired) if current != desired => changes.push(JobSettingsChange {
+            path: path.to_string(),
+            current: current.cloned(),
+            desired: desired.cloned(),
+        }),
+        _ => {}
+    }
+}
+
+fn child_path(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{}.{}", path, key)
+    }
+}
+
+/// The key shared by every element of both arrays, if they are arrays of keyed objects.
+fn element_key(current: &[Value], desired: &[Value]) -> Option<&'static str> {
+    ELEMENT_KEYS.iter().copied().find(|key| {
+        current
+            .iter()
+            .chain(desired)
+            .all(|item| item.get(key).is_some())
+    })
+}
@@ -0,0 +1,74 @@
+use crate::models::{Run, RunLifeCycleState};
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// The active runs of a job, split by whether they occupy a concurrency slot or wait in the queue.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct JobQueueSnapshot {
+    pub job_id: i64,
+    /// The job's concurrency limit; the Jobs API defaults it to 1.
+    pub max_concurrent_runs: i32,
+    /// Whether new runs wait in the queue at the limit instead of being skipped.
+    pub queue_enabled: bool,
+    /// Runs holding a concurrency slot: pending, running, terminating or waiting for a retry.
+    pub running: Vec<Run>,
+    /// Runs waiting for a slot, oldest first.
+    pub queued: Vec<Run>,
+}
+
+impl JobQueueSnapshot {
+    /// Sorts active runs into running and queued ones.
+    pub fn new(
+        job_id: i64,
+        max_concurrent_runs: Option<i32>,
+        queue_enabled: bool,
+        active_runs: Vec<Run>,
+    ) -> Self {
+        let (mut queued, running): (Vec<Run>, Vec<Run>) = active_runs
+            .into_iter()
+            .filter(|run| {
+                !run.life_cycle_state()
+                    .is_some_and(|state| state.is_terminal())
+            })
+            .partition(|run| run.life_cycle_state() == Some(RunLifeCycleState::Queued));
+        queued.sort_by_key(|run| run.start_time.unwrap_or(i64::MAX));
+
+        JobQueueSnapshot {
+            job_id,
+            max_concurrent_runs: max_concurrent_runs.unwrap_or(1),
+            queue_enabled,
+            running,
+            queued,
+        }
+    }
+
+    /// The number of runs waiting in the queue.
+    pub fn queue_depth(&self) -> usize {
+        self.queued.len()
+    }
+
+    /// The number of runs that could start right now without queueing.
+    pub fn available_slots(&self) -> usize {
+        let limit: usize = usize::try_from(self.max_concurrent_runs).unwrap_or_default();
+        limit.saturating_sub(self.running.len() + self.queued.len())
+    }
+
+    /// Returns `true` if a new run would not start immediately.
+    pub fn is_at_capacity(&self) -> bool {
+        self.available_slots() == 0
+    }
+
+    /// Returns `true` if a new run would be skipped: the job is at capacity and does not queue.
+    pub fn would_skip_new_run(&self) -> bool {
+        self.is_at_capacity() && !self.queue_enabled
+    }
+
+    /// How long the oldest queued run has been waiting at `now`.
+    pub fn oldest_queued_wait(&self, now: DateTime<Utc>) -> Option<Duration> {
+        let queued_since: i64 = self.queued.first()?.start_time?;
+        u64::try_from(now.timestamp_millis() - queued_since)
+            .ok()
+            .map(Duration::from_millis)
+    }
+}
@@ -1,29 +1,740 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::collections::HashMap;
 
-#[derive(Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
 pub struct JobRunRequest {
     pub job_id: i64,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub idempotency_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub queue: Option<QueueSettings>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub jar_params: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub notebook_params: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub python_params: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub spark_submit_params: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub python_named_params: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub pipeline_params: Option<HashMap<String, bool>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub sql_params: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub dbt_commands: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub job_parameters: Option<HashMap<String, String>>,
 }
 
-#[derive(Serialize, Deserialize)]
+impl JobRunRequest {
+    /// Creates a request to run `job_id` with its configured parameters.
+    ///
+    /// Parameters and run options are chained on, e.g.
+    /// `JobRunRequest::new(42).with_job_parameter("env", "prod").with_queue(true)`.
+    pub fn new(job_id: i64) -> Self {
+        JobRunRequest {
+            job_id,
+            ..Default::default()
+        }
+    }
+
+    /// Sets a token that prevents duplicate runs when the request is retried.
+    pub fn with_idempotency_token(mut self, token: &str) -> Self {
+        self.idempotency_token = Some(token.to_string());
+        self
+    }
+
+    /// Sets whether the run is queued when the job is at its concurrency limit.
+    pub fn with_queue(mut self, enabled: bool) -> Self {
+        self.queue = Some(QueueSettings { enabled });
+        self
+    }
+
+    /// Overrides a job-level parameter.
+    pub fn with_job_parameter(mut self, name: &str, value: &str) -> Self {
+        self.job_parameters
+            .get_or_insert_with(HashMap::new)
+            .insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Overrides a notebook task parameter.
+    pub fn with_notebook_param(mut self, name: &str, value: &str) -> Self {
+        self.notebook_params
+            .get_or_insert_with(HashMap::new)
+            .insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Overrides a SQL task parameter.
+    pub fn with_sql_param(mut self, name: &str, value: &str) -> Self {
+        self.sql_params
+            .get_or_insert_with(HashMap::new)
+            .insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Overrides a named Python wheel task parameter.
+    pub fn with_python_named_param(mut self, name: &str, value: &str) -> Self {
+        self.python_named_params
+            .get_or_insert_with(HashMap::new)
+            .insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Replaces the positional arguments of Python tasks.
+    pub fn with_python_params(mut self, params: Vec<String>) -> Self {
+        self.python_params = Some(params);
+        self
+    }
+
+    /// Replaces the arguments of JAR tasks.
+    pub fn with_jar_params(mut self, params: Vec<String>) -> Self {
+        self.jar_params = Some(params);
+        self
+    }
+
+    /// Replaces the arguments of spark-submit tasks.
+    pub fn with_spark_submit_params(mut self, params: Vec<String>) -> Self {
+        self.spark_submit_params = Some(params);
+        self
+    }
+
+    /// Replaces the commands of dbt tasks.
+    pub fn with_dbt_commands(mut self, commands: Vec<String>) -> Self {
+        self.dbt_commands = Some(commands);
+        self
+    }
+
+    /// Requests a full refresh of pipeline tasks.
+    pub fn with_full_refresh(mut self, full_refresh: bool) -> Self {
+        self.pipeline_params
+            .get_or_insert_with(HashMap::new)
+            .insert("full_refresh".to_string(), full_refresh);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
 pub struct QueueSettings {
     pub enabled: bool,
 }
 
-#[derive(Deserialize)]
+/// A one-time run of tasks that are not defined as a job, submitted with `runs/submit`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SubmitRunRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_name: Option<String>,
+    pub tasks: Vec<SubmitTask>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub idempotency_token: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub queue: Option<QueueSettings>,
+}
+
+impl SubmitRunRequest {
+    /// Creates a request for a run named `run_name` without tasks.
+    ///
+    /// Tasks are chained on, e.g.
+    /// `SubmitRunRequest::new("nightly").with_task(SubmitTask::notebook("main", "/Repos/etl/main"))`.
+    pub fn new(run_name: &str) -> Self {
+        SubmitRunRequest {
+            run_name: Some(run_name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a task to the run.
+    pub fn with_task(mut self, task: SubmitTask) -> Self {
+        self.tasks.push(task);
+        self
+    }
+
+    /// Sets a token that prevents duplicate runs when the request is retried.
+    pub fn with_idempotency_token(mut self, token: &str) -> Self {
+        self.idempotency_token = Some(token.to_string());
+        self
+    }
+
+    /// Sets the maximum duration of the run.
+    pub fn with_timeout_seconds(mut self, timeout_seconds: i64) -> Self {
+        self.timeout_seconds = Some(timeout_seconds);
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SubmitTask {
+    pub task_key: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<TaskDependency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub existing_cluster_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_cluster: Option<Value>, // A cluster specification as accepted by `clusters/create`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notebook_task: Option<NotebookTask>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spark_python_task: Option<SparkPythonTask>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dbt_task: Option<DbtTask>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sql_task: Option<SqlTask>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_job_task: Option<RunJobTask>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<i64>,
+}
+
+impl SubmitTask {
+    /// Creates a task that runs the notebook at `notebook_path`.
+    pub fn notebook(task_key: &str, notebook_path: &str) -> Self {
+        SubmitTask {
+            task_key: task_key.to_string(),
+            notebook_task: Some(NotebookTask {
+                notebook_path: notebook_path.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a task that runs the Python file at `python_file` with `parameters`.
+    pub fn python(task_key: &str, python_file: &str, parameters: Vec<String>) -> Self {
+        SubmitTask {
+            task_key: task_key.to_string(),
+            spark_python_task: Some(SparkPythonTask {
+                python_file: python_file.to_string(),
+                parameters,
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a task that runs the dbt `commands` of `dbt_task`.
+    pub fn dbt(task_key: &str, dbt_task: DbtTask) -> Self {
+        SubmitTask {
+            task_key: task_key.to_string(),
+            dbt_task: Some(dbt_task),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a task that runs a saved query, refreshes a dashboard or alert, or runs a SQL
+    /// file on a SQL warehouse.
+    pub fn sql(task_key: &str, sql_task: SqlTask) -> Self {
+        SubmitTask {
+            task_key: task_key.to_string(),
+            sql_task: Some(sql_task),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a task that triggers the job `job_id` and waits for it to finish.
+    pub fn run_job(task_key: &str, job_id: i64) -> Self {
+        SubmitTask {
+            task_key: task_key.to_string(),
+            run_job_task: Some(RunJobTask {
+                job_id,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Runs the task on an existing all-purpose cluster.
+    pub fn on_cluster(mut self, cluster_id: &str) -> Self {
+        self.existing_cluster_id = Some(cluster_id.to_string());
+        self
+    }
+
+    /// Runs the task after the task `task_key` has finished.
+    pub fn depends_on(mut self, task_key: &str) -> Self {
+        self.depends_on.push(TaskDependency {
+            task_key: task_key.to_string(),
+        });
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TaskDependency {
+    pub task_key: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NotebookTask {
+    pub notebook_path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_parameters: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>, // "WORKSPACE" or "GIT"
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SparkPythonTask {
+    pub python_file: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<String>,
+}
+
+/// A task running dbt commands, e.g. `dbt deps` and `dbt run`, against a SQL warehouse.
+///
+/// The task's cluster or environment needs the `dbt-databricks` package, installed through the
+/// task's libraries.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DbtTask {
+    pub commands: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub project_directory: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profiles_directory: Option<String>, // Defaults to a generated profile for the warehouse
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub warehouse_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>, // "WORKSPACE" or "GIT"
+}
+
+impl DbtTask {
+    /// Creates a task running `commands` in order, each starting with `dbt`.
+    pub fn new(commands: &[&str]) -> Self {
+        DbtTask {
+            commands: commands.iter().map(|command| command.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the directory containing `dbt_project.yml`.
+    pub fn with_project_directory(mut self, project_directory: &str) -> Self {
+        self.project_directory = Some(project_directory.to_string());
+        self
+    }
+
+    /// Sets the directory containing `profiles.yml`, instead of the generated profile.
+    pub fn with_profiles_directory(mut self, profiles_directory: &str) -> Self {
+        self.profiles_directory = Some(profiles_directory.to_string());
+        self
+    }
+
+    /// Sets the catalog and schema dbt writes to.
+    pub fn with_target(mut self, catalog: &str, schema: &str) -> Self {
+        self.catalog = Some(catalog.to_string());
+        self.schema = Some(schema.to_string());
+        self
+    }
+
+    /// Runs the commands against the SQL warehouse `warehouse_id`.
+    pub fn with_warehouse_id(mut self, warehouse_id: &str) -> Self {
+        self.warehouse_id = Some(warehouse_id.to_string());
+        self
+    }
+
+    /// Reads the project from the job's Git source rather than the workspace.
+    pub fn from_git(mut self) -> Self {
+        self.source = Some("GIT".to_string());
+        self
+    }
+}
+
+/// A task running SQL on a warehouse. Exactly one of `query`, `dashboard`, `alert` and `file` is
+/// set; the constructors take care of that.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SqlTask {
+    pub warehouse_id: String,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub parameters: HashMap<String, String>, // Referenced as {{name}} in queries and files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query: Option<SqlTaskQuery>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dashboard: Option<SqlTaskDashboard>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub alert: Option<SqlTaskAlert>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<SqlTaskFile>,
+}
+
+impl SqlTask {
+    /// Runs the saved query `query_id`.
+    pub fn query(warehouse_id: &str, query_id: &str) -> Self {
+        SqlTask {
+            warehouse_id: warehouse_id.to_string(),
+            query: Some(SqlTaskQuery {
+                query_id: query_id.to_string(),
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Refreshes the legacy dashboard `dashboard_id` and notifies its subscribers.
+    pub fn dashboard(warehouse_id: &str, dashboard_id: &str) -> Self {
+        SqlTask {
+            warehouse_id: warehouse_id.to_string(),
+            dashboard: Some(SqlTaskDashboard {
+                dashboard_id: dashboard_id.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Evaluates the alert `alert_id` and notifies its subscribers if it triggers.
+    pub fn alert(warehouse_id: &str, alert_id: &str) -> Self {
+        SqlTask {
+            warehouse_id: warehouse_id.to_string(),
+            alert: Some(SqlTaskAlert {
+                alert_id: alert_id.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Runs the statements in the `.sql` file at `path`, a workspace path or, with the job's Git
+    /// source, a path relative to the repository root.
+    pub fn file(warehouse_id: &str, path: &str) -> Self {
+        SqlTask {
+            warehouse_id: warehouse_id.to_string(),
+            file: Some(SqlTaskFile {
+                path: path.to_string(),
+                source: None,
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Sets a parameter, referenced as `{{name}}` in the query or file.
+    pub fn with_parameter(mut self, name: &str, value: &str) -> Self {
+        self.parameters.insert(name.to_string(), value.to_string());
+        self
+    }
+
+    /// Notifies `subscription` when a dashboard is refreshed or an alert triggers; ignored for
+    /// queries and files.
+    pub fn with_subscription(mut self, subscription: SqlTaskSubscription) -> Self {
+        if let Some(dashboard) = &mut self.dashboard {
+            dashboard.subscriptions.push(subscription);
+        } else if let Some(alert) = &mut self.alert {
+            alert.subscriptions.push(subscription);
+        }
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SqlTaskQuery {
+    pub query_id: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SqlTaskDashboard {
+    pub dashboard_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subscriptions: Vec<SqlTaskSubscription>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub custom_subject: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pause_subscriptions: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SqlTaskAlert {
+    pub alert_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub subscriptions: Vec<SqlTaskSubscription>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pause_subscriptions: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SqlTaskFile {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>, // "WORKSPACE" or "GIT"
+}
+
+/// A recipient of dashboard or alert notifications: a user or a notification destination.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SqlTaskSubscription {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub destination_id: Option<String>,
+}
+
+impl SqlTaskSubscription {
+    /// Notifies the workspace user `user_name`.
+    pub fn user(user_name: &str) -> Self {
+        SqlTaskSubscription {
+            user_name: Some(user_name.to_string()),
+            destination_id: None,
+        }
+    }
+
+    /// Notifies the notification destination `destination_id`, e.g. a Slack channel.
+    pub fn destination(destination_id: &str) -> Self {
+        SqlTaskSubscription {
+            user_name: None,
+            destination_id: Some(destination_id.to_string()),
+        }
+    }
+}
+
+/// A task triggering another job, for composing jobs of jobs.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RunJobTask {
+    pub job_id: i64,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub job_parameters: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub(crate) struct RunIdRequest {
+    pub run_id: i64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct JobRunResponse {
     pub run_id: i64,
     pub number_in_job: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RunLifeCycleState {
+    Queued,
+    Pending,
+    Running,
+    Terminating,
+    Terminated,
+    Skipped,
+    InternalError,
+    Blocked,
+    WaitingForRetry,
+}
+
+impl RunLifeCycleState {
+    /// Whether the run (or task) has finished and its state will not change again.
+    pub fn is_terminal(&self) -> bool {
+        matches!(
+            self,
+            RunLifeCycleState::Terminated
+                | RunLifeCycleState::Skipped
+                | RunLifeCycleState::InternalError
+        )
+    }
+
+    /// Whether the run (or task) has begun executing, and so may have produced output.
+    pub fn has_started(&self) -> bool {
+        matches!(
+            self,
+            RunLifeCycleState::Running
+                | RunLifeCycleState::Terminating
+                | RunLifeCycleState::Terminated
+                | RunLifeCycleState::InternalError
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RunResultState {
+    Success,
+    SuccessWithFailures,
+    Failed,
+    Timedout,
+    Canceled,
+    MaximumConcurrentRunsReached,
+    Excluded,
+    UpstreamFailed,
+    UpstreamCanceled,
+    Disabled,
+}
+
+impl RunResultState {
+    pub fn is_success(&self) -> bool {
+        matches!(
+            self,
+            RunResultState::Success
+                | RunResultState::SuccessWithFailures
+                | RunResultState::Excluded
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct RunState {
+    pub life_cycle_state: Option<RunLifeCycleState>,
+    pub result_state: Option<RunResultState>,
+    pub state_message: Option<String>,
+    pub queue_reason: Option<String>, // Why a QUEUED run is waiting, e.g. the concurrency limit
+    pub user_cancelled_or_timedout: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct RunTask {
+    pub task_key: String,
+    pub run_id: Option<i64>,
+    pub state: Option<RunState>,
+    pub attempt_number: Option<i32>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    pub run_page_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The original attempt of a run, or one of its repairs.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct RepairHistoryItem {
+    #[serde(rename = "type")]
+    pub item_type: Option<String>, // "ORIGINAL" or "REPAIR"
+    pub id: Option<i64>, // The repair ID; absent for the original attempt
+    pub state: Option<RunState>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    #[serde(default)]
+    pub task_run_ids: Vec<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct Run {
+    pub run_id: i64,
+    pub job_id: Option<i64>,
+    pub run_name: Option<String>,
+    pub number_in_job: Option<i64>,
+    pub state: Option<RunState>,
+    #[serde(default)]
+    pub tasks: Vec<RunTask>,
+    pub start_time: Option<i64>,
+    pub end_time: Option<i64>,
+    pub run_duration: Option<i64>,
+    pub queue_duration: Option<i64>, // Milliseconds the run spent queued
+    pub run_page_url: Option<String>,
+    pub trigger: Option<String>,
+    #[serde(default)]
+    pub repair_history: Vec<RepairHistoryItem>, // Only returned with include_history=true
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Run {
+    pub fn life_cycle_state(&self) -> Option<RunLifeCycleState> {
+        self.state.as_ref().and_then(|state| state.life_cycle_state)
+    }
+
+    pub fn result_state(&self) -> Option<RunResultState> {
+        self.state.as_ref().and_then(|state| state.result_state)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct RepairRunRequest {
+    pub run_id: i64,
+    pub rerun_all_failed_tasks: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_repair_id: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct RepairRunResponse {
+    pub repair_id: i64,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct ListRunsResponse {
+    pub runs: Vec<Run>,
+    pub has_more: Option<bool>,
+    pub next_page_token: Option<String>,
+}
+
+/// The value a notebook task passed to `dbutils.notebook.exit()`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct NotebookOutput {
+    pub result: Option<String>,
+    pub truncated: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Where the artifacts of a dbt task run (`target/` and logs) can be downloaded.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct DbtOutput {
+    pub artifacts_link: Option<String>, // A short-lived URL of a .tar.gz archive
+    #[serde(default)]
+    pub artifacts_headers: HashMap<String, String>, // Headers to send when downloading
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The output of a single task run, as returned by `jobs/runs/get-output`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct RunOutput {
+    pub notebook_output: Option<NotebookOutput>,
+    pub dbt_output: Option<DbtOutput>,
+    pub logs: Option<String>, // stdout/stderr of Python, wheel and JAR tasks
+    pub logs_truncated: Option<bool>,
+    pub error: Option<String>,
+    pub error_trace: Option<String>,
+    pub metadata: Option<Run>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// An increment of output observed while following a run with `stream_run_output`.
+///
+/// `task_key` is `None` for single-task runs submitted without task keys.
+#[derive(Debug, Clone, PartialEq)]
+pub enum RunOutputEvent {
+    /// Log text appended since the previous event for the same task.
+    Log {
+        task_key: Option<String>,
+        text: String,
+    },
+    /// The exit value of a finished notebook task.
+    NotebookResult {
+        task_key: Option<String>,
+        result: String,
+        truncated: bool,
+    },
+    /// The error a finished task failed with.
+    Error {
+        task_key: Option<String>,
+        message: String,
+        trace: Option<String>,
+    },
+    /// The run reached a terminal state; this is always the last event.
+    Finished(Box<Run>),
 }
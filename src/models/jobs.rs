@@ -0,0 +1,284 @@
+use crate::models::{
+    DbtTask, FileArrivalTrigger, NotebookTask, PauseStatus, QueueSettings, RunJobTask,
+    SparkPythonTask, SqlTask, TaskDependency, TriggerSettings,
+};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// The settings of a job. Schedule definitions and other settings not modeled here are kept in
+/// `extra`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct JobSettings {
+    pub name: Option<String>,
+    #[serde(default)]
+    pub tags: HashMap<String, String>,
+    pub max_concurrent_runs: Option<i32>,
+    pub queue: Option<QueueSettings>, // Whether runs wait for a free slot instead of being skipped
+    pub timeout_seconds: Option<i64>,
+    pub budget_policy_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger: Option<TriggerSettings>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parameters: Vec<JobParameterDefinition>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub job_clusters: Vec<JobCluster>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tasks: Vec<JobTask>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl JobSettings {
+    /// Creates the settings of a job named `name` without tasks.
+    ///
+    /// Clusters and tasks are chained on, e.g.
+    /// `JobSettings::new("nightly").with_job_cluster(JobCluster::new("etl", spec)).with_task(JobTask::notebook("ingest", "/Repos/etl/ingest").on_job_cluster("etl"))`.
+    pub fn new(name: &str) -> Self {
+        JobSettings {
+            name: Some(name.to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// Runs the job whenever new files arrive at the trigger's URL. Use
+    /// `check_file_arrival_trigger` to test the URL before deploying.
+    pub fn with_file_arrival_trigger(mut self, trigger: FileArrivalTrigger) -> Self {
+        self.trigger = Some(TriggerSettings {
+            pause_status: Some(PauseStatus::Unpaused),
+            file_arrival: Some(trigger),
+            ..Default::default()
+        });
+        self
+    }
+
+    /// Adds a cluster that tasks can share by its `job_cluster_key`.
+    pub fn with_job_cluster(mut self, job_cluster: JobCluster) -> Self {
+        self.job_clusters.push(job_cluster);
+        self
+    }
+
+    /// Adds a task to the job.
+    pub fn with_task(mut self, task: JobTask) -> Self {
+        self.tasks.push(task);
+        self
+    }
+
+    /// Moves the `new_cluster` specs of the tasks into `job_clusters`, so tasks with identical
+    /// specs share one cluster instead of each starting their own.
+    ///
+    /// A spec equal to an existing job cluster's reuses that cluster; other specs become job
+    /// clusters keyed `<task_key>_cluster` after the first task using them.
+    pub fn with_shared_job_clusters(mut self) -> Self {
+        for task in &mut self.tasks {
+            let Some(spec) = task.new_cluster.take() else {
+                continue;
+            };
+            let key: String = match self
+                .job_clusters
+                .iter()
+                .find(|job_cluster| job_cluster.new_cluster == spec)
+            {
+                Some(job_cluster) => job_cluster.job_cluster_key.clone(),
+                None => {
+                    let key: String = format!("{}_cluster", task.task_key);
+                    self.job_clusters.push(JobCluster::new(&key, spec));
+                    key
+                }
+            };
+            task.job_cluster_key = Some(key);
+        }
+        self
+    }
+}
+
+/// A job-level parameter, referenced as `{{job.parameters.<name>}}` in task settings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobParameterDefinition {
+    pub name: String,
+    pub default: String,
+}
+
+/// A cluster defined once in a job and used by every task naming its `job_cluster_key`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct JobCluster {
+    pub job_cluster_key: String,
+    pub new_cluster: Value, // A cluster specification as accepted by `clusters/create`
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl JobCluster {
+    /// Creates a job cluster from a cluster specification, e.g. one returned by
+    /// `ClusterInfo::to_job_cluster_spec`.
+    pub fn new(job_cluster_key: &str, new_cluster: Value) -> Self {
+        JobCluster {
+            job_cluster_key: job_cluster_key.to_string(),
+            new_cluster,
+            extra: Map::new(),
+        }
+    }
+}
+
+/// A task of a job. Task types not modeled here are kept in `extra`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct JobTask {
+    pub task_key: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<TaskDependency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub existing_cluster_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_cluster_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_cluster: Option<Value>, // A cluster specification as accepted by `clusters/create`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notebook_task: Option<NotebookTask>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub spark_python_task: Option<SparkPythonTask>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dbt_task: Option<DbtTask>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sql_task: Option<SqlTask>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_job_task: Option<RunJobTask>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timeout_seconds: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl JobTask {
+    /// Creates a task that runs the notebook at `notebook_path`.
+    pub fn notebook(task_key: &str, notebook_path: &str) -> Self {
+        JobTask {
+            task_key: task_key.to_string(),
+            notebook_task: Some(NotebookTask {
+                notebook_path: notebook_path.to_string(),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a task that runs the Python file at `python_file` with `parameters`.
+    pub fn python(task_key: &str, python_file: &str, parameters: Vec<String>) -> Self {
+        JobTask {
+            task_key: task_key.to_string(),
+            spark_python_task: Some(SparkPythonTask {
+                python_file: python_file.to_string(),
+                parameters,
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a task that runs the dbt `commands` of `dbt_task`.
+    pub fn dbt(task_key: &str, dbt_task: DbtTask) -> Self {
+        JobTask {
+            task_key: task_key.to_string(),
+            dbt_task: Some(dbt_task),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a task that runs a saved query, refreshes a dashboard or alert, or runs a SQL
+    /// file on a SQL warehouse.
+    pub fn sql(task_key: &str, sql_task: SqlTask) -> Self {
+        JobTask {
+            task_key: task_key.to_string(),
+            sql_task: Some(sql_task),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a task that triggers the job `job_id` and waits for it to finish. To compose
+    /// several jobs with parameter passing, see `RunJobGraph`.
+    pub fn run_job(task_key: &str, job_id: i64) -> Self {
+        JobTask {
+            task_key: task_key.to_string(),
+            run_job_task: Some(RunJobTask {
+                job_id,
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    /// Runs the task on the job cluster `job_cluster_key`.
+    pub fn on_job_cluster(mut self, job_cluster_key: &str) -> Self {
+        self.job_cluster_key = Some(job_cluster_key.to_string());
+        self
+    }
+
+    /// Runs the task on an existing all-purpose cluster.
+    pub fn on_cluster(mut self, cluster_id: &str) -> Self {
+        self.existing_cluster_id = Some(cluster_id.to_string());
+        self
+    }
+
+    /// Runs the task on a new cluster of its own.
+    pub fn on_new_cluster(mut self, new_cluster: Value) -> Self {
+        self.new_cluster = Some(new_cluster);
+        self
+    }
+
+    /// Runs the task after the task `task_key` has finished.
+    pub fn depends_on(mut self, task_key: &str) -> Self {
+        self.depends_on.push(TaskDependency {
+            task_key: task_key.to_string(),
+        });
+        self
+    }
+}
+
+/// A job as returned by `jobs/list`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct BaseJob {
+    pub job_id: i64,
+    pub creator_user_name: Option<String>,
+    pub created_time: Option<i64>,
+    pub settings: Option<JobSettings>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListJobsResponse {
+    #[serde(default)]
+    pub jobs: Vec<BaseJob>,
+    pub has_more: Option<bool>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct JobIdRequest {
+    pub job_id: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct ResetJobRequest {
+    pub job_id: i64,
+    pub new_settings: JobSettings,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct CreateJobResponse {
+    pub job_id: i64,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
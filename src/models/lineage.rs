@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TableLineageRequest {
+    pub table_name: String, // Full name, e.g. "main.sales.orders"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub include_entity_lineage: Option<bool>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ColumnLineageRequest {
+    pub table_name: String,
+    pub column_name: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct LineageTableInfo {
+    pub name: Option<String>,
+    pub catalog_name: Option<String>,
+    pub schema_name: Option<String>,
+    pub table_type: Option<String>, // "TABLE", "VIEW", "PATH", ...
+    pub lineage_timestamp: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl LineageTableInfo {
+    /// Returns the three-level name (`catalog.schema.table`) when all parts are known.
+    pub fn full_name(&self) -> Option<String> {
+        match (&self.catalog_name, &self.schema_name, &self.name) {
+            (Some(catalog), Some(schema), Some(name)) => {
+                Some(format!("{}.{}.{}", catalog, schema, name))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct LineageNotebookInfo {
+    pub workspace_id: Option<i64>,
+    pub notebook_id: Option<i64>,
+    pub lineage_timestamp: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct LineageJobInfo {
+    pub workspace_id: Option<i64>,
+    pub job_id: Option<i64>,
+    pub lineage_timestamp: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct LineageQueryInfo {
+    pub workspace_id: Option<i64>,
+    pub query_id: Option<String>,
+    pub lineage_timestamp: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct LineageDashboardInfo {
+    pub workspace_id: Option<i64>,
+    pub dashboard_id: Option<String>,
+    pub lineage_timestamp: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct LineagePipelineInfo {
+    pub workspace_id: Option<i64>,
+    pub pipeline_id: Option<String>,
+    pub lineage_timestamp: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// An upstream or downstream node of a table: either another table or an entity (notebook, job,
+/// query, ...) that reads from or writes to it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[non_exhaustive]
+#[serde(default)]
+pub struct LineageEntity {
+    pub table_info: Option<LineageTableInfo>,
+    #[serde(default)]
+    pub notebook_infos: Vec<LineageNotebookInfo>,
+    #[serde(default)]
+    pub job_infos: Vec<LineageJobInfo>,
+    #[serde(default)]
+    pub query_infos: Vec<LineageQueryInfo>,
+    #[serde(default)]
+    pub dashboard_infos: Vec<LineageDashboardInfo>,
+    #[serde(default)]
+    pub pipeline_infos: Vec<LineagePipelineInfo>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct TableLineageResponse {
+    #[serde(default)]
+    pub upstreams: Vec<LineageEntity>,
+    #[serde(default)]
+    pub downstreams: Vec<LineageEntity>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TableLineageResponse {
+    /// Full names of all upstream tables.
+    pub fn upstream_tables(&self) -> Vec<String> {
+        self.upstreams
+            .iter()
+            .filter_map(|entity| entity.table_info.as_ref()?.full_name())
+            .collect()
+    }
+
+    /// Full names of all downstream tables.
+    pub fn downstream_tables(&self) -> Vec<String> {
+        self.downstreams
+            .iter()
+            .filter_map(|entity| entity.table_info.as_ref()?.full_name())
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct LineageColumnInfo {
+    pub name: Option<String>,
+    pub catalog_name: Option<String>,
+    pub schema_name: Option<String>,
+    pub table_name: Option<String>,
+    pub path: Option<String>,
+    pub lineage_timestamp: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ColumnLineageResponse {
+    #[serde(default)]
+    pub upstream_cols: Vec<LineageColumnInfo>,
+    #[serde(default)]
+    pub downstream_cols: Vec<LineageColumnInfo>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,148 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LogType {
+    BillableUsage,
+    AuditLogs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LogOutputFormat {
+    Csv,  // Required for BILLABLE_USAGE
+    Json, // Required for AUDIT_LOGS
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum LogDeliveryConfigStatus {
+    Enabled,
+    Disabled,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum DeliveryStatus {
+    Created,
+    Succeeded,
+    UserFailure,
+    SystemFailure,
+    NotFound,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateLogDeliveryConfiguration {
+    pub config_name: Option<String>,
+    pub log_type: Option<LogType>,
+    pub output_format: Option<LogOutputFormat>,
+    pub credentials_id: String,
+    pub storage_configuration_id: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub workspace_ids_filter: Vec<i64>, // Empty means all workspaces in the account
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_path_prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delivery_start_time: Option<String>, // "YYYY-MM", billable usage only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<LogDeliveryConfigStatus>,
+}
+
+impl CreateLogDeliveryConfiguration {
+    /// Creates a configuration delivering audit logs as JSON for all workspaces.
+    pub fn audit_logs(
+        config_name: &str,
+        credentials_id: &str,
+        storage_configuration_id: &str,
+    ) -> Self {
+        CreateLogDeliveryConfiguration {
+            config_name: Some(config_name.to_string()),
+            log_type: Some(LogType::AuditLogs),
+            output_format: Some(LogOutputFormat::Json),
+            credentials_id: credentials_id.to_string(),
+            storage_configuration_id: storage_configuration_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a configuration delivering billable usage as CSV for all workspaces.
+    pub fn billable_usage(
+        config_name: &str,
+        credentials_id: &str,
+        storage_configuration_id: &str,
+    ) -> Self {
+        CreateLogDeliveryConfiguration {
+            config_name: Some(config_name.to_string()),
+            log_type: Some(LogType::BillableUsage),
+            output_format: Some(LogOutputFormat::Csv),
+            credentials_id: credentials_id.to_string(),
+            storage_configuration_id: storage_configuration_id.to_string(),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateLogDeliveryRequest {
+    pub log_delivery_configuration: CreateLogDeliveryConfiguration,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct LogDeliveryStatus {
+    pub status: Option<DeliveryStatus>,
+    pub message: Option<String>,
+    pub last_attempt_time: Option<String>,
+    pub last_successful_attempt_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct LogDeliveryConfiguration {
+    pub config_id: String,
+    pub config_name: Option<String>,
+    pub account_id: Option<String>,
+    pub log_type: Option<LogType>,
+    pub output_format: Option<LogOutputFormat>,
+    pub credentials_id: Option<String>,
+    pub storage_configuration_id: Option<String>,
+    #[serde(default)]
+    pub workspace_ids_filter: Vec<i64>,
+    pub delivery_path_prefix: Option<String>,
+    pub delivery_start_time: Option<String>,
+    pub status: Option<LogDeliveryConfigStatus>,
+    pub log_delivery_status: Option<LogDeliveryStatus>,
+    pub creation_time: Option<i64>,
+    pub update_time: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct WrappedLogDeliveryConfiguration {
+    pub log_delivery_configuration: LogDeliveryConfiguration,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct WrappedLogDeliveryConfigurations {
+    #[serde(default)]
+    pub log_delivery_configurations: Vec<LogDeliveryConfiguration>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpdateLogDeliveryStatusRequest {
+    pub status: LogDeliveryConfigStatus,
+}
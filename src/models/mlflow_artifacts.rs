@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ArtifactFileInfo {
+    pub path: String, // Relative to the run's artifact root
+    #[serde(default)]
+    pub is_dir: bool,
+    pub file_size: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListArtifactsResponse {
+    pub root_uri: Option<String>,
+    #[serde(default)]
+    pub files: Vec<ArtifactFileInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The kind of presigned URI returned for direct artifact access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ArtifactCredentialType {
+    AzureSasUri,
+    AzureAdlsGen2SasUri,
+    AwsPresignedUrl,
+    GcpSignedUrl,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct HttpHeader {
+    pub name: String,
+    pub value: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A presigned URI (plus required headers) for reading or writing a single artifact.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ArtifactCredentialInfo {
+    pub run_id: Option<String>,
+    pub path: String,
+    pub signed_uri: String,
+    #[serde(default)]
+    pub headers: Vec<HttpHeader>,
+    #[serde(rename = "type")]
+    pub credential_type: Option<ArtifactCredentialType>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ArtifactCredentialsResponse {
+    #[serde(default)]
+    pub credential_infos: Vec<ArtifactCredentialInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,176 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TokenAccessPolicy {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub access_token_ttl_in_minutes: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub refresh_token_ttl_in_minutes: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateCustomAppIntegrationRequest {
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redirect_urls: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub confidential: Option<bool>, // Whether a client secret is issued
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub scopes: Vec<String>, // e.g. "all-apis", "sql", "offline_access"
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub user_authorized_scopes: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_access_policy: Option<TokenAccessPolicy>,
+}
+
+/// Returned once on creation; `client_secret` cannot be retrieved again.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct CreateCustomAppIntegrationResponse {
+    pub integration_id: String,
+    pub client_id: Option<String>,
+    pub client_secret: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateCustomAppIntegrationRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub redirect_urls: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scopes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_authorized_scopes: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_access_policy: Option<TokenAccessPolicy>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct CustomAppIntegration {
+    pub integration_id: String,
+    pub client_id: Option<String>,
+    pub name: Option<String>,
+    #[serde(default)]
+    pub redirect_urls: Vec<String>,
+    pub confidential: Option<bool>,
+    #[serde(default)]
+    pub scopes: Vec<String>,
+    #[serde(default)]
+    pub user_authorized_scopes: Vec<String>,
+    pub token_access_policy: Option<TokenAccessPolicy>,
+    pub create_time: Option<String>,
+    pub created_by: Option<i64>,
+    pub creator_username: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListCustomAppIntegrationsResponse {
+    #[serde(default)]
+    pub apps: Vec<CustomAppIntegration>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreatePublishedAppIntegrationRequest {
+    pub app_id: String, // e.g. "power-bi", "tableau-desktop"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_access_policy: Option<TokenAccessPolicy>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct CreatePublishedAppIntegrationResponse {
+    pub integration_id: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdatePublishedAppIntegrationRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_access_policy: Option<TokenAccessPolicy>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct PublishedAppIntegration {
+    pub integration_id: String,
+    pub app_id: Option<String>,
+    pub name: Option<String>,
+    pub token_access_policy: Option<TokenAccessPolicy>,
+    pub create_time: Option<String>,
+    pub created_by: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListPublishedAppIntegrationsResponse {
+    #[serde(default)]
+    pub apps: Vec<PublishedAppIntegration>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateServicePrincipalSecretRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lifetime: Option<String>, // Seconds with an "s" suffix, e.g. "7776000s"
+}
+
+/// Returned once on creation; `secret` cannot be retrieved again.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct CreateServicePrincipalSecretResponse {
+    pub id: String,
+    pub secret: String,
+    pub secret_hash: Option<String>,
+    pub status: Option<String>,
+    pub create_time: Option<String>,
+    pub update_time: Option<String>,
+    pub expire_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct SecretInfo {
+    pub id: String,
+    pub secret_hash: Option<String>, // Last characters of the secret, for identification
+    pub status: Option<String>,
+    pub create_time: Option<String>,
+    pub update_time: Option<String>,
+    pub expire_time: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListServicePrincipalSecretsResponse {
+    #[serde(default)]
+    pub secrets: Vec<SecretInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
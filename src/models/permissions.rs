@@ -0,0 +1,221 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::fmt;
+
+/// The kind of workspace object an access control list belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum PermissionObjectType {
+    Jobs,
+    Clusters,
+    ClusterPolicies,
+    InstancePools,
+    Pipelines,
+    Notebooks,
+    Directories,
+    Repos,
+    Experiments,
+    RegisteredModels,
+    ServingEndpoints,
+    #[serde(rename = "sql/warehouses")]
+    Warehouses,
+}
+
+impl PermissionObjectType {
+    /// Returns the form used in permission endpoint paths, e.g. `cluster-policies`.
+    pub fn as_path_segment(&self) -> &'static str {
+        match self {
+            PermissionObjectType::Jobs => "jobs",
+            PermissionObjectType::Clusters => "clusters",
+            PermissionObjectType::ClusterPolicies => "cluster-policies",
+            PermissionObjectType::InstancePools => "instance-pools",
+            PermissionObjectType::Pipelines => "pipelines",
+            PermissionObjectType::Notebooks => "notebooks",
+            PermissionObjectType::Directories => "directories",
+            PermissionObjectType::Repos => "repos",
+            PermissionObjectType::Experiments => "experiments",
+            PermissionObjectType::RegisteredModels => "registered-models",
+            PermissionObjectType::ServingEndpoints => "serving-endpoints",
+            PermissionObjectType::Warehouses => "sql/warehouses",
+        }
+    }
+}
+
+impl fmt::Display for PermissionObjectType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_path_segment())
+    }
+}
+
+/// A permission level on a workspace object. Levels not modelled here are preserved in `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PermissionLevel {
+    IsOwner,
+    CanManage,
+    CanManageRun,
+    CanRestart,
+    CanAttachTo,
+    CanUse,
+    CanRun,
+    CanEdit,
+    CanRead,
+    CanView,
+    CanQuery,
+    CanMonitor,
+    CanManageProductionVersions,
+    CanManageStagingVersions,
+    #[serde(untagged)]
+    Other(String),
+}
+
+/// The user, group or service principal an access control entry applies to.
+///
+/// Service principals are identified by their application ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Principal<'a> {
+    User(&'a str),
+    Group(&'a str),
+    ServicePrincipal(&'a str),
+}
+
+impl fmt::Display for Principal<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Principal::User(name) => write!(f, "user {}", name),
+            Principal::Group(name) => write!(f, "group {}", name),
+            Principal::ServicePrincipal(name) => write!(f, "service principal {}", name),
+        }
+    }
+}
+
+/// An access control entry to set: one principal and one permission level.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AccessControlRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub group_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service_principal_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission_level: Option<PermissionLevel>,
+}
+
+impl AccessControlRequest {
+    /// Creates an entry granting `permission_level` to `principal`.
+    pub fn new(principal: Principal<'_>, permission_level: PermissionLevel) -> Self {
+        let mut request: AccessControlRequest = AccessControlRequest {
+            permission_level: Some(permission_level),
+            ..Default::default()
+        };
+        match principal {
+            Principal::User(name) => request.user_name = Some(name.to_string()),
+            Principal::Group(name) => request.group_name = Some(name.to_string()),
+            Principal::ServicePrincipal(name) => {
+                request.service_principal_name = Some(name.to_string())
+            }
+        }
+        request
+    }
+
+    /// The principal this entry applies to.
+    pub fn principal(&self) -> Option<Principal<'_>> {
+        self.user_name
+            .as_deref()
+            .map(Principal::User)
+            .or_else(|| self.group_name.as_deref().map(Principal::Group))
+            .or_else(|| {
+                self.service_principal_name
+                    .as_deref()
+                    .map(Principal::ServicePrincipal)
+            })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ObjectPermission {
+    pub permission_level: Option<PermissionLevel>,
+    pub inherited: bool,
+    pub inherited_from_object: Vec<String>, // e.g. "/directories/1234"
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The permissions one principal holds on an object, both direct and inherited.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct AccessControlResponse {
+    pub user_name: Option<String>,
+    pub group_name: Option<String>,
+    pub service_principal_name: Option<String>,
+    pub display_name: Option<String>,
+    pub all_permissions: Vec<ObjectPermission>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl AccessControlResponse {
+    /// The principal this entry applies to.
+    pub fn principal(&self) -> Option<Principal<'_>> {
+        self.user_name
+            .as_deref()
+            .map(Principal::User)
+            .or_else(|| self.group_name.as_deref().map(Principal::Group))
+            .or_else(|| {
+                self.service_principal_name
+                    .as_deref()
+                    .map(Principal::ServicePrincipal)
+            })
+    }
+
+    /// The permission levels granted directly on the object, as opposed to inherited from a parent.
+    pub fn direct_levels(&self) -> impl Iterator<Item = &PermissionLevel> {
+        self.all_permissions
+            .iter()
+            .filter(|permission| !permission.inherited)
+            .filter_map(|permission| permission.permission_level.as_ref())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ObjectPermissions {
+    pub object_id: Option<String>, // e.g. "/jobs/123"
+    pub object_type: Option<String>,
+    pub access_control_list: Vec<AccessControlResponse>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ObjectPermissions {
+    /// Returns the access control entry of `principal`, if it holds any permission on the object.
+    pub fn entry_of(&self, principal: Principal<'_>) -> Option<&AccessControlResponse> {
+        self.access_control_list
+            .iter()
+            .find(|entry| entry.principal() == Some(principal))
+    }
+
+    /// Converts the direct (non-inherited) permissions into entries for a full replacement with
+    /// `set_object_permissions`, so that only the intended changes are made.
+    pub fn direct_access_control_list(&self) -> Vec<AccessControlRequest> {
+        self.access_control_list
+            .iter()
+            .filter_map(|entry| entry.principal().map(|principal| (principal, entry)))
+            .flat_map(|(principal, entry)| {
+                entry
+                    .direct_levels()
+                    .map(move |level| AccessControlRequest::new(principal, level.clone()))
+            })
+            .collect()
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct ObjectPermissionsRequest {
+    pub access_control_list: Vec<AccessControlRequest>,
+}
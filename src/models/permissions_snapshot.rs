@@ -0,0 +1,160 @@
+use crate::models::{ObjectPermissions, PermissionLevel, PermissionObjectType, Principal};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrincipalKind {
+    User,
+    Group,
+    ServicePrincipal,
+}
+
+/// One permission level held by one principal on an object.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct PermissionEntry {
+    pub principal_kind: PrincipalKind,
+    pub principal: String,
+    pub permission_level: PermissionLevel,
+    /// Whether the level is inherited from a parent object, such as a folder.
+    pub inherited: bool,
+}
+
+/// The access control list of one object, in a stable order.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ObjectAcl {
+    pub object_type: PermissionObjectType,
+    pub object_id: String,
+    pub entries: Vec<PermissionEntry>,
+}
+
+impl ObjectAcl {
+    /// Flattens the permissions returned by `get_object_permissions` into sorted entries.
+    pub fn new(
+        object_type: PermissionObjectType,
+        object_id: &str,
+        permissions: &ObjectPermissions,
+    ) -> Self {
+        let entries: BTreeSet<PermissionEntry> = permissions
+            .access_control_list
+            .iter()
+            .filter_map(|entry| entry.principal().map(|principal| (principal, entry)))
+            .flat_map(|(principal, entry)| {
+                let (principal_kind, name): (PrincipalKind, &str) = match principal {
+                    Principal::User(name) => (PrincipalKind::User, name),
+                    Principal::Group(name) => (PrincipalKind::Group, name),
+                    Principal::ServicePrincipal(name) => (PrincipalKind::ServicePrincipal, name),
+                };
+                entry.all_permissions.iter().filter_map(move |permission| {
+                    Some(PermissionEntry {
+                        principal_kind,
+                        principal: name.to_string(),
+                        permission_level: permission.permission_level.clone()?,
+                        inherited: permission.inherited,
+                    })
+                })
+            })
+            .collect();
+
+        ObjectAcl {
+            object_type,
+            object_id: object_id.to_string(),
+            entries: entries.into_iter().collect(),
+        }
+    }
+}
+
+/// The access control lists of a set of objects at one point in time, e.g. for an access review.
+///
+/// Snapshots serialize to JSON, so one can be stored and compared with a later one using `diff`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermissionsSnapshot {
+    pub host: String,
+    pub taken_at: DateTime<Utc>,
+    /// Sorted by object type and ID.
+    pub objects: Vec<ObjectAcl>,
+}
+
+/// A permission entry that was granted or revoked on an object between two snapshots.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermissionChange {
+    pub object_type: PermissionObjectType,
+    pub object_id: String,
+    pub entry: PermissionEntry,
+}
+
+/// The differences between two `PermissionsSnapshot`s.
+///
+/// Entries of objects that only one snapshot contains are reported as granted or revoked too.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PermissionsDrift {
+    pub objects_added: Vec<(PermissionObjectType, String)>,
+    pub objects_removed: Vec<(PermissionObjectType, String)>,
+    pub granted: Vec<PermissionChange>,
+    pub revoked: Vec<PermissionChange>,
+}
+
+impl PermissionsDrift {
+    /// Returns `true` if the access control lists did not change.
+    pub fn is_empty(&self) -> bool {
+        self.objects_added.is_empty()
+            && self.objects_removed.is_empty()
+            && self.granted.is_empty()
+            && self.revoked.is_empty()
+    }
+}
+
+impl PermissionsSnapshot {
+    /// Compares this snapshot with a `newer` one.
+    pub fn diff(&self, newer: &PermissionsSnapshot) -> PermissionsDrift {
+        let before: BTreeSet<(PermissionObjectType, &str, &PermissionEntry)> = grants(self);
+        let after: BTreeSet<(PermissionObjectType, &str, &PermissionEntry)> = grants(newer);
+        let objects_before: BTreeSet<(PermissionObjectType, &str)> = objects(self);
+        let objects_after: BTreeSet<(PermissionObjectType, &str)> = objects(newer);
+
+        let change =
+            |(object_type, object_id, entry): &(PermissionObjectType, &str, &PermissionEntry)| {
+                PermissionChange {
+                    object_type: *object_type,
+                    object_id: object_id.to_string(),
+                    entry: (*entry).clone(),
+                }
+            };
+
+        PermissionsDrift {
+            objects_added: objects_after
+                .difference(&objects_before)
+                .map(|(object_type, object_id)| (*object_type, object_id.to_string()))
+                .collect(),
+            objects_removed: objects_before
+                .difference(&objects_after)
+                .map(|(object_type, object_id)| (*object_type, object_id.to_string()))
+                .collect(),
+            granted: after.difference(&before).map(change).collect(),
+            revoked: before.difference(&after).map(change).collect(),
+        }
+    }
+}
+
+fn grants(
+    snapshot: &PermissionsSnapshot,
+) -> BTreeSet<(PermissionObjectType, &str, &PermissionEntry)> {
+    snapshot
+        .objects
+        .iter()
+        .flat_map(|acl| {
+            acl.entries
+                .iter()
+                .map(move |entry| (acl.object_type, acl.object_id.as_str(), entry))
+        })
+        .collect()
+}
+
+fn objects(snapshot: &PermissionsSnapshot) -> BTreeSet<(PermissionObjectType, &str)> {
+    snapshot
+        .objects
+        .iter()
+        .map(|acl| (acl.object_type, acl.object_id.as_str()))
+        .collect()
+}
@@ -0,0 +1,120 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PipelineEventLevel {
+    Metrics,
+    Info,
+    Warn,
+    Error,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum PipelineEventMaturityLevel {
+    Stable,
+    Evolving,
+    Deprecated,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct PipelineStackFrame {
+    pub declaring_class: Option<String>,
+    pub method_name: Option<String>,
+    pub file_name: Option<String>,
+    pub line_number: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct PipelineSerializedException {
+    pub class_name: Option<String>,
+    pub message: Option<String>,
+    #[serde(default)]
+    pub stack: Vec<PipelineStackFrame>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct PipelineErrorDetail {
+    pub is_fatal: Option<bool>,
+    #[serde(default)]
+    pub exceptions: Vec<PipelineSerializedException>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl PipelineErrorDetail {
+    /// The message of the outermost exception, if any.
+    pub fn message(&self) -> Option<&str> {
+        self.exceptions
+            .first()
+            .and_then(|exception| exception.message.as_deref())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct PipelineEventOrigin {
+    pub pipeline_id: Option<String>,
+    pub pipeline_name: Option<String>,
+    pub update_id: Option<String>,
+    pub flow_id: Option<String>,
+    pub flow_name: Option<String>,
+    pub dataset_name: Option<String>,
+    pub cluster_id: Option<String>,
+    pub batch_id: Option<i64>,
+    pub request_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct PipelineEventSequence {
+    pub control_plane_seq_no: Option<i64>,
+    pub data_plane_id: Option<Value>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct PipelineEvent {
+    pub id: String,
+    pub event_type: Option<String>, // "update_progress", "flow_progress", "create_update", ...
+    pub level: Option<PipelineEventLevel>,
+    pub maturity_level: Option<PipelineEventMaturityLevel>,
+    pub message: Option<String>,
+    pub timestamp: Option<String>, // RFC 3339
+    pub origin: Option<PipelineEventOrigin>,
+    pub sequence: Option<PipelineEventSequence>,
+    pub error: Option<PipelineErrorDetail>,
+    pub details: Option<Value>, // Shape depends on event_type
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListPipelineEventsResponse {
+    #[serde(default)]
+    pub events: Vec<PipelineEvent>,
+    pub next_page_token: Option<String>,
+    pub prev_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
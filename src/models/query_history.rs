@@ -0,0 +1,96 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Execution metrics of a query, as reported by the query history service with
+/// `include_metrics=true`. Times are in milliseconds.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct QueryMetrics {
+    pub total_time_ms: Option<i64>,
+    pub compilation_time_ms: Option<i64>,
+    pub planning_time_ms: Option<i64>,
+    pub metadata_time_ms: Option<i64>,
+    pub execution_time_ms: Option<i64>,
+    pub result_fetch_time_ms: Option<i64>,
+    pub task_total_time_ms: Option<i64>, // Summed over all tasks, i.e. CPU-like time
+    pub photon_total_time_ms: Option<i64>,
+    pub overloading_queue_start_timestamp: Option<i64>,
+    pub provisioning_queue_start_timestamp: Option<i64>,
+    pub query_compilation_start_timestamp: Option<i64>,
+    pub read_bytes: Option<i64>,
+    pub read_cache_bytes: Option<i64>,
+    pub read_remote_bytes: Option<i64>,
+    pub write_remote_bytes: Option<i64>,
+    pub spill_to_disk_bytes: Option<i64>,
+    pub network_sent_bytes: Option<i64>,
+    pub pruned_bytes: Option<i64>,
+    pub pruned_files_count: Option<i64>,
+    pub read_files_count: Option<i64>,
+    pub read_partitions_count: Option<i64>,
+    pub rows_read_count: Option<i64>,
+    pub rows_produced_count: Option<i64>,
+    pub result_from_cache: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl QueryMetrics {
+    /// The fraction of the scanned table bytes that file pruning skipped, or `None` if the query
+    /// did not report both values or read nothing.
+    pub fn pruned_bytes_ratio(&self) -> Option<f64> {
+        let pruned: i64 = self.pruned_bytes?;
+        let total: i64 = pruned + self.read_bytes?;
+        (total > 0).then(|| pruned as f64 / total as f64)
+    }
+
+    /// The fraction of the summed task time that ran in Photon.
+    pub fn photon_time_ratio(&self) -> Option<f64> {
+        let task_total: i64 = self.task_total_time_ms?;
+        (task_total > 0)
+            .then(|| self.photon_total_time_ms.unwrap_or_default() as f64 / task_total as f64)
+    }
+
+    /// Whether any operator spilled to disk, which usually indicates an undersized warehouse or
+    /// a skewed join or aggregation.
+    pub fn spilled_to_disk(&self) -> bool {
+        self.spill_to_disk_bytes.unwrap_or_default() > 0
+    }
+}
+
+/// A query recorded by the query history service, covering statements run on SQL warehouses
+/// and serverless compute.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct QueryHistoryEntry {
+    pub query_id: String,
+    pub status: Option<String>, // "QUEUED", "RUNNING", "FINISHED", "FAILED", "CANCELED"
+    pub query_text: Option<String>,
+    pub statement_type: Option<String>,
+    pub user_name: Option<String>,
+    pub executed_as_user_name: Option<String>,
+    pub warehouse_id: Option<String>,
+    pub endpoint_id: Option<String>,
+    pub query_start_time_ms: Option<i64>,
+    pub execution_end_time_ms: Option<i64>,
+    pub query_end_time_ms: Option<i64>,
+    pub duration: Option<i64>,
+    pub rows_produced: Option<i64>,
+    pub error_message: Option<String>,
+    pub metrics: Option<QueryMetrics>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListQueryHistoryResponse {
+    #[serde(default)]
+    pub res: Vec<QueryHistoryEntry>,
+    pub has_next_page: Option<bool>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,275 @@
+use crate::models::{SqlParameter, SqlStatementRequest};
+
+/// A Databricks SQL query whose `{{ param }}` placeholders have been turned into named parameter
+/// markers, so values supplied at runtime are bound by the server instead of spliced into the text.
+///
+/// `{{ name }}` becomes `:name`, and a string literal that consists of nothing but a placeholder
+/// (`'{{ name }}'`) becomes `:name` as a whole, since the value is bound as a string anyway. The
+/// `.start` and `.end` placeholders of date-range parameters become `:name_start` and `:name_end`.
+/// Placeholders inside other literals, quoted identifiers and comments are left untouched.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QueryTemplate {
+    statement: String,
+    parameters: Vec<String>,
+    bindings: Vec<SqlParameter>,
+}
+
+impl QueryTemplate {
+    /// Parses query text in Databricks SQL syntax, e.g. the `query_text` of a saved query.
+    ///
+    /// Returns an error if a placeholder is not closed or its name cannot be used as a parameter
+    /// marker.
+    pub fn parse(query_text: &str) -> Result<Self, String> {
+        let chars: Vec<char> = query_text.chars().collect();
+        let mut statement: String = String::with_capacity(query_text.len());
+        let mut parameters: Vec<String> = Vec::new();
+        let mut i: usize = 0;
+
+        while i < chars.len() {
+            let c: char = chars[i];
+            let next: Option<char> = chars.get(i + 1).copied();
+            match c {
+                '-' if next == Some('-') => {
+                    let end: usize = find_from(&chars, i, "\n").map_or(chars.len(), |n| n + 1);
+                    statement.extend(&chars[i..end]);
+                    i = end;
+                }
+                '/' if next == Some('*') => {
+                    let end: usize = find_from(&chars, i + 2, "*/").map_or(chars.len(), |n| n + 2);
+                    statement.extend(&chars[i..end]);
+                    i = end;
+                }
+                '\'' | '"' | '`' => {
+                    let end: usize = literal_end(&chars, i);
+                    let literal: String = chars[i..end].iter().collect();
+                    let inner: &str = literal
+                        .get(1..literal.len().saturating_sub(1))
+                        .unwrap_or_default()
+                        .trim();
+                    match placeholder(inner) {
+                        Some(name) if c != '`' && end - i >= 2 => {
+                            let name: String = marker_name(name)?;
+                            statement.push(':');
+                            statement.push_str(&name);
+                            if !parameters.contains(&name) {
+                                parameters.push(name);
+                            }
+                        }
+                        _ => statement.push_str(&literal),
+                    }
+                    i = end;
+                }
+                '{' if next == Some('{') => {
+                    let close: usize = find_from(&chars, i + 2, "}}")
+                        .ok_or_else(|| "Unclosed {{ placeholder in query text".to_string())?;
+                    let name: String = chars[i + 2..close].iter().collect();
+                    let name: String = marker_name(name.trim())?;
+                    statement.push(':');
+                    statement.push_str(&name);
+                    if !parameters.contains(&name) {
+                        parameters.push(name);
+                    }
+                    i = close + 2;
+                }
+                _ => {
+                    statement.push(c);
+                    i += 1;
+                }
+            }
+        }
+
+        Ok(QueryTemplate {
+            statement,
+            parameters,
+            bindings: Vec::new(),
+        })
+    }
+
+    /// The statement text with named parameter markers.
+    pub fn statement(&self) -> &str {
+        &self.statement
+    }
+
+    /// The parameter names used by the statement, in order of first appearance.
+    pub fn parameters(&self) -> &[String] {
+        &self.parameters
+    }
+
+    /// Binds a value to a parameter as a `STRING`.
+    pub fn bind(self, name: &str, value: &str) -> Self {
+        self.bind_typed(name, Some(value), None)
+    }
+
+    /// Binds a value, or `NULL` with `None`, to a parameter of the given SQL type, e.g. `"DATE"`.
+    pub fn bind_typed(mut self, name: &str, value: Option<&str>, sql_type: Option<&str>) -> Self {
+        self.bindings.retain(|binding| binding.name != name);
+        self.bindings.push(SqlParameter {
+            name: name.to_string(),
+            value: value.map(str::to_string),
+            sql_type: sql_type.map(str::to_string),
+        });
+        self
+    }
+
+    /// Builds a statement request for `warehouse_id` with the bound values.
+    ///
+    /// Returns an error if a parameter of the statement has no value or a value was bound to a
+    /// name the statement does not use.
+    pub fn to_request(&self, warehouse_id: &str) -> Result<SqlStatementRequest, String> {
+        let missing: Vec<&str> = self
+            .parameters
+            .iter()
+            .filter(|name| !self.bindings.iter().any(|binding| &binding.name == *name))
+            .map(String::as_str)
+            .collect();
+        if !missing.is_empty() {
+            return Err(format!("No value bound for {}", missing.join(", ")));
+        }
+        let unknown: Vec<&str> = self
+            .bindings
+            .iter()
+            .filter(|binding| !self.parameters.contains(&binding.name))
+            .map(|binding| binding.name.as_str())
+            .collect();
+        if !unknown.is_empty() {
+            return Err(format!("Query has no parameter {}", unknown.join(", ")));
+        }
+
+        let mut request: SqlStatementRequest =
+            SqlStatementRequest::new(warehouse_id, &self.statement);
+        if !self.bindings.is_empty() {
+            request.parameters = Some(self.bindings.clone());
+        }
+        Ok(request)
+    }
+}
+
+/// The name inside `{{ ... }}` if `text` is exactly one placeholder.
+fn placeholder(text: &str) -> Option<&str> {
+    let inner: &str = text.strip_prefix("{{")?.strip_suffix("}}")?;
+    (!inner.contains("{{") && !inner.contains("}}")).then(|| inner.trim())
+}
+
+/// Maps a placeholder name onto a parameter marker name, e.g. `range.start` onto `range_start`.
+fn marker_name(name: &str) -> Result<String, String> {
+    let marker: String = name.replace('.', "_");
+    let valid: bool = marker
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && marker
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_');
+    if valid {
+        Ok(marker)
+    } else {
+        Err(format!(
+            "Placeholder {{{{ {} }}}} is not a valid parameter name",
+            name
+        ))
+    }
+}
+
+/// The index of the first occurrence of `pattern` at or after `from`.
+fn find_from(chars: &[char], from: usize, pattern: &str) -> Option<usize> {
+    let pattern: Vec<char> = pattern.chars().collect();
+    (from..chars.len()).find(|&i| chars[i..].starts_with(&pattern))
+}
+
+/// The index just past the literal or quoted identifier starting at `start`, honoring backslash
+/// escapes and doubled quotes.
+fn literal_end(chars: &[char], start: usize) -> usize {
+    let quote: char = chars[start];
+    let mut i: usize = start + 1;
+    while i < chars.len() {
+        if chars[i] == '\\' && quote != '`' {
+            i += 2;
+        } else if chars[i] == quote {
+            if chars.get(i + 1) == Some(&quote) {
+                i += 2;
+            } else {
+                return i + 1;
+            }
+        } else {
+            i += 1;
+        }
+    }
+    chars.len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rewrite(query_text: &str) -> (String, Vec<String>) {
+        let template: QueryTemplate = QueryTemplate::parse(query_text).unwrap();
+        (
+            template.statement().to_string(),
+            template.parameters().to_vec(),
+        )
+    }
+
+    #[test]
+    fn rewrites_bare_and_quoted_placeholders() {
+        let (statement, parameters) =
+            rewrite("SELECT * FROM t WHERE a = {{ a }} AND b = '{{b}}' AND c = \"{{ a }}\"");
+        assert_eq!(
+            statement,
+            "SELECT * FROM t WHERE a = :a AND b = :b AND c = :a"
+        );
+        assert_eq!(parameters, ["a", "b"]);
+    }
+
+    #[test]
+    fn rewrites_date_range_placeholders() {
+        let (statement, parameters) =
+            rewrite("WHERE day BETWEEN '{{ range.start }}' AND '{{ range.end }}'");
+        assert_eq!(statement, "WHERE day BETWEEN :range_start AND :range_end");
+        assert_eq!(parameters, ["range_start", "range_end"]);
+    }
+
+    #[test]
+    fn leaves_comments_literals_and_identifiers_untouched() {
+        let query_text: &str = "-- {{ a }}\n/* {{ b }} */ SELECT 'x {{ c }}', 'it''s {{d}}', \
+                                `{{ e }}` FROM t";
+        let (statement, parameters) = rewrite(query_text);
+        assert_eq!(statement, query_text);
+        assert!(parameters.is_empty());
+    }
+
+    #[test]
+    fn rejects_unclosed_and_invalid_placeholders() {
+        assert!(QueryTemplate::parse("SELECT {{ a").is_err());
+        assert!(QueryTemplate::parse("SELECT {{ 1a }}").is_err());
+        assert!(QueryTemplate::parse("SELECT '{{ a b }}'").is_err());
+    }
+
+    #[test]
+    fn rejects_unbound_and_unknown_parameters() {
+        let template: QueryTemplate = QueryTemplate::parse("SELECT {{ a }}").unwrap();
+        assert_eq!(
+            template.to_request("wh").unwrap_err(),
+            "No value bound for a"
+        );
+
+        let template: QueryTemplate = template.bind("a", "1").bind("b", "2");
+        assert_eq!(
+            template.to_request("wh").unwrap_err(),
+            "Query has no parameter b"
+        );
+    }
+
+    #[test]
+    fn binds_values_as_parameters() {
+        let request: SqlStatementRequest = QueryTemplate::parse("SELECT {{ a }}")
+            .unwrap()
+            .bind_typed("a", Some("2024-01-01"), Some("DATE"))
+            .to_request("wh")
+            .unwrap();
+        assert_eq!(request.statement, "SELECT :a");
+        let parameters: Vec<SqlParameter> = request.parameters.unwrap();
+        assert_eq!(parameters.len(), 1);
+        assert_eq!(parameters[0].value.as_deref(), Some("2024-01-01"));
+        assert_eq!(parameters[0].sql_type.as_deref(), Some("DATE"));
+    }
+}
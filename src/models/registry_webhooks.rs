@@ -0,0 +1,162 @@
+use crate::models::RegistryWebhookEvent;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Whether a registry webhook fires on events.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RegistryWebhookStatus {
+    /// Fires on events.
+    Active,
+    /// Fires only when triggered with `test_registry_webhook`.
+    TestMode,
+    Disabled,
+}
+
+/// An HTTPS endpoint that a registry webhook calls.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct HttpUrlSpec {
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>, // Signs payloads; see `parse_registry_webhook`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub authorization: Option<String>, // Sent as the Authorization header, e.g. "Bearer <token>"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enable_ssl_verification: Option<bool>,
+}
+
+/// A job that a registry webhook triggers.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct WebhookJobSpec {
+    pub job_id: String,
+    pub access_token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workspace_url: Option<String>, // Defaults to the webhook's own workspace
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateRegistryWebhookRequest {
+    pub events: Vec<RegistryWebhookEvent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub model_name: Option<String>, // None subscribes to events of every registered model
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<RegistryWebhookStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub http_url_spec: Option<HttpUrlSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub job_spec: Option<WebhookJobSpec>,
+}
+
+impl CreateRegistryWebhookRequest {
+    /// A webhook calling `url` on the given events.
+    pub fn http(events: Vec<RegistryWebhookEvent>, url: &str) -> Self {
+        CreateRegistryWebhookRequest {
+            events,
+            model_name: None,
+            description: None,
+            status: None,
+            http_url_spec: Some(HttpUrlSpec {
+                url: url.to_string(),
+                ..Default::default()
+            }),
+            job_spec: None,
+        }
+    }
+
+    /// A webhook triggering a job on the given events.
+    pub fn job(events: Vec<RegistryWebhookEvent>, job_id: &str, access_token: &str) -> Self {
+        CreateRegistryWebhookRequest {
+            events,
+            model_name: None,
+            description: None,
+            status: None,
+            http_url_spec: None,
+            job_spec: Some(WebhookJobSpec {
+                job_id: job_id.to_string(),
+                access_token: access_token.to_string(),
+                workspace_url: None,
+            }),
+        }
+    }
+
+    /// Restricts the webhook to one registered model.
+    pub fn with_model_name(mut self, model_name: &str) -> Self {
+        self.model_name = Some(model_name.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct HttpUrlSpecWithoutSecret {
+    pub url: String,
+    pub enable_ssl_verification: Option<bool>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct WebhookJobSpecWithoutSecret {
+    pub job_id: String,
+    pub workspace_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct RegistryWebhook {
+    pub id: String,
+    pub model_name: Option<String>,
+    #[serde(default)]
+    pub events: Vec<RegistryWebhookEvent>,
+    pub description: Option<String>,
+    pub status: Option<RegistryWebhookStatus>,
+    pub http_url_spec: Option<HttpUrlSpecWithoutSecret>,
+    pub job_spec: Option<WebhookJobSpecWithoutSecret>,
+    pub creation_timestamp: Option<i64>,
+    pub last_updated_timestamp: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct RegistryWebhookResponse {
+    pub webhook: RegistryWebhook,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct ListRegistryWebhooksResponse {
+    pub webhooks: Vec<RegistryWebhook>,
+    pub next_page_token: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TestRegistryWebhookRequest {
+    pub id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub event: Option<RegistryWebhookEvent>,
+}
+
+/// The response the webhook's endpoint returned to a test event.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct RegistryWebhookTestResult {
+    pub status_code: Option<i32>,
+    pub body: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub(crate) struct TestRegistryWebhookResponse {
+    pub webhook: RegistryWebhookTestResult,
+}
@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A Git folder (repo) in the workspace.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct RepoInfo {
+    pub id: i64,
+    pub path: Option<String>,
+    pub url: Option<String>,
+    pub provider: Option<String>, // "gitHub", "gitLab", "azureDevOpsServices", ...
+    pub branch: Option<String>,
+    pub head_commit_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListReposResponse {
+    #[serde(default)]
+    pub repos: Vec<RepoInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,201 @@
+use crate::{
+    errors::{ChunkError, ChunkErrorKind},
+    models::{ExternalLink, Manifest, ResultData, SqlStatementResponse},
+};
+use std::collections::{BTreeMap, HashMap};
+
+/// Checks the chunks of a statement result against its manifest while they are reassembled,
+/// so gaps, overlaps and truncated chunks surface as a `ChunkError` instead of corrupt output.
+///
+/// Chunks may be recorded in any order, e.g. as parallel downloads complete; `finish` then
+/// checks that together they cover the whole result.
+#[derive(Debug, Clone)]
+pub struct ChunkValidator {
+    statement_id: String,
+    manifest_chunks: HashMap<i32, (i64, i64)>,
+    total_chunk_count: i32,
+    total_row_count: i64,
+    // Keyed by (row offset, chunk index) so empty chunks sharing an offset are kept apart.
+    received: BTreeMap<(i64, i32), i64>,
+}
+
+impl ChunkValidator {
+    /// Creates a validator for the result described by `manifest`.
+    pub fn new(statement_id: &str, manifest: &Manifest) -> Self {
+        ChunkValidator {
+            statement_id: statement_id.to_string(),
+            manifest_chunks: manifest
+                .chunks
+                .iter()
+                .map(|chunk| (chunk.chunk_index, (chunk.row_offset, chunk.row_count)))
+                .collect(),
+            total_chunk_count: manifest.total_chunk_count,
+            total_row_count: manifest.total_row_count,
+            received: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a validator for the result of a finished statement, or `None` if the response
+    /// has no manifest to validate against.
+    pub fn for_response(response: &SqlStatementResponse) -> Option<Self> {
+        let manifest: &Manifest = response.manifest.as_ref()?;
+        Some(Self::new(
+            response.statement_id.as_deref().unwrap_or_default(),
+            manifest,
+        ))
+    }
+
+    /// Records a chunk by its declared position in the result.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the chunk matches the manifest and does not overlap a chunk recorded
+    ///   before, or a `ChunkError` describing the conflict.
+    pub fn record(
+        &mut self,
+        chunk_index: i32,
+        row_offset: i64,
+        row_count: i64,
+    ) -> Result<(), ChunkError> {
+        if let Some(&(manifest_offset, manifest_count)) = self.manifest_chunks.get(&chunk_index) {
+            if (manifest_offset, manifest_count) != (row_offset, row_count) {
+                return Err(self.error(
+                    Some(chunk_index),
+                    ChunkErrorKind::ManifestMismatch {
+                        manifest_offset,
+                        manifest_count,
+                        actual_offset: row_offset,
+                        actual_count: row_count,
+                    },
+                ));
+            }
+        }
+        if self.received.keys().any(|&(_, index)| index == chunk_index) {
+            return Err(self.error(Some(chunk_index), ChunkErrorKind::Duplicate));
+        }
+
+        let end: i64 = row_offset + row_count;
+        let before = self.received.range(..(row_offset, chunk_index)).next_back();
+        let after = self.received.range((row_offset, chunk_index)..).next();
+        let overlap: Option<(i64, i32)> = match (before, after) {
+            (Some((&(offset, index), &count)), _) if offset + count > row_offset => {
+                Some((row_offset, index))
+            }
+            (_, Some((&(offset, index), &count))) if offset < end && count > 0 && row_count > 0 => {
+                Some((offset, index))
+            }
+            _ => None,
+        };
+        if let Some((offset, other_chunk_index)) = overlap {
+            return Err(self.error(
+                Some(chunk_index),
+                ChunkErrorKind::Overlap {
+                    offset,
+                    other_chunk_index,
+                },
+            ));
+        }
+
+        self.received.insert((row_offset, chunk_index), row_count);
+        Ok(())
+    }
+
+    /// Records an inline result chunk, also checking that it holds as many rows as it declares.
+    ///
+    /// Returns:
+    /// - Same as `record`.
+    pub fn record_data(&mut self, data: &ResultData) -> Result<(), ChunkError> {
+        // Results without chunking (e.g. empty ones) carry no position to check.
+        let Some(chunk_index) = data.chunk_index else {
+            return Ok(());
+        };
+        let rows: Option<i64> = data.data_array.as_ref().map(|rows| rows.len() as i64);
+        let row_count: i64 = match (data.row_count, rows) {
+            (Some(expected), Some(actual)) if expected != actual => {
+                return Err(self.error(
+                    Some(chunk_index),
+                    ChunkErrorKind::RowCountMismatch { expected, actual },
+                ))
+            }
+            (Some(count), _) | (None, Some(count)) => count,
+            (None, None) => 0,
+        };
+        self.record(chunk_index, data.row_offset.unwrap_or_default(), row_count)
+    }
+
+    /// Records the chunk behind an external link.
+    ///
+    /// Returns:
+    /// - Same as `record`.
+    pub fn record_link(&mut self, link: &ExternalLink) -> Result<(), ChunkError> {
+        self.record(link.chunk_index, link.row_offset, link.row_count)
+    }
+
+    /// Checks that a downloaded chunk has the size its external link declares.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the sizes match or the link declares no size, or a `ChunkError`.
+    pub fn check_download(
+        &self,
+        link: &ExternalLink,
+        bytes_received: i64,
+    ) -> Result<(), ChunkError> {
+        if link.byte_count > 0 && link.byte_count != bytes_received {
+            return Err(self.error(
+                Some(link.chunk_index),
+                ChunkErrorKind::ByteCountMismatch {
+                    expected: link.byte_count,
+                    actual: bytes_received,
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    /// Checks that the recorded chunks cover every row of the result without gaps.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the result is complete, or a `ChunkError` for the first gap or the
+    ///   missing chunks.
+    pub fn finish(&self) -> Result<(), ChunkError> {
+        let mut expected_offset: i64 = 0;
+        for (&(offset, index), &count) in &self.received {
+            if offset > expected_offset {
+                return Err(self.error(
+                    Some(index),
+                    ChunkErrorKind::Gap {
+                        expected_offset,
+                        actual_offset: offset,
+                    },
+                ));
+            }
+            expected_offset = expected_offset.max(offset + count);
+        }
+        if expected_offset < self.total_row_count {
+            return Err(self.error(
+                None,
+                ChunkErrorKind::Gap {
+                    expected_offset,
+                    actual_offset: self.total_row_count,
+                },
+            ));
+        }
+        if (self.received.len() as i32) < self.total_chunk_count {
+            return Err(self.error(
+                None,
+                ChunkErrorKind::MissingChunks {
+                    expected: self.total_chunk_count,
+                    received: self.received.len() as i32,
+                },
+            ));
+        }
+        Ok(())
+    }
+
+    fn error(&self, chunk_index: Option<i32>, kind: ChunkErrorKind) -> ChunkError {
+        ChunkError {
+            statement_id: self.statement_id.clone(),
+            chunk_index,
+            kind,
+        }
+    }
+}
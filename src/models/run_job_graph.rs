@@ -0,0 +1,249 @@
+use crate::models::{JobParameterDefinition, JobSettings, JobTask, RunJobTask, TaskDependency};
+use regex::Regex;
+use std::collections::{HashMap, HashSet};
+
+/// One job triggered by a `RunJobGraph`, with the job parameters it is run with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunJobStep {
+    pub task_key: String,
+    pub job_id: i64,
+    pub depends_on: Vec<String>,
+    /// Job parameters passed to the triggered job, in the order they were added. Values may be
+    /// dynamic references such as `{{job.parameters.date}}` or `{{tasks.ingest.values.rows}}`.
+    pub parameters: Vec<(String, String)>,
+}
+
+impl RunJobStep {
+    /// Creates a step triggering `job_id` as the task `task_key`.
+    pub fn new(task_key: &str, job_id: i64) -> Self {
+        RunJobStep {
+            task_key: task_key.to_string(),
+            job_id,
+            ..Default::default()
+        }
+    }
+
+    /// Runs the step after the step `task_key` has finished.
+    pub fn depends_on(mut self, task_key: &str) -> Self {
+        if !self.depends_on.iter().any(|key| key == task_key) {
+            self.depends_on.push(task_key.to_string());
+        }
+        self
+    }
+
+    /// Passes a fixed value as the job parameter `name`.
+    pub fn with_parameter(mut self, name: &str, value: &str) -> Self {
+        self.parameters.push((name.to_string(), value.to_string()));
+        self
+    }
+
+    /// Passes the graph's own job parameter `name` on under the same name.
+    pub fn forward_parameter(self, name: &str) -> Self {
+        let value: String = format!("{{{{job.parameters.{}}}}}", name);
+        self.with_parameter(name, &value)
+    }
+
+    /// Passes the task value `value_key` that the upstream step `task_key` set (with
+    /// `dbutils.jobs.taskValues.set`) as the job parameter `name`, and runs the step after it.
+    pub fn with_task_value(self, name: &str, task_key: &str, value_key: &str) -> Self {
+        let value: String = format!("{{{{tasks.{}.values.{}}}}}", task_key, value_key);
+        self.with_parameter(name, &value).depends_on(task_key)
+    }
+}
+
+/// A job of jobs: a graph of `run_job_task`s, with parameters passed from the graph's own job
+/// parameters and from upstream steps to the jobs it triggers.
+///
+/// `to_job_settings` validates the graph and produces settings for `create_job` or `apply_job`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RunJobGraph {
+    pub name: String,
+    /// The graph's job parameters and their defaults.
+    pub parameters: Vec<(String, String)>,
+    pub steps: Vec<RunJobStep>,
+}
+
+impl RunJobGraph {
+    /// Creates an empty graph for a job named `name`.
+    pub fn new(name: &str) -> Self {
+        RunJobGraph {
+            name: name.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Adds a job parameter of the graph itself, with its default value.
+    pub fn with_parameter(mut self, name: &str, default: &str) -> Self {
+        self.parameters
+            .push((name.to_string(), default.to_string()));
+        self
+    }
+
+    /// Adds a step.
+    pub fn with_step(mut self, step: RunJobStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// Checks that task keys and parameter names are unique, that dependencies and references
+    /// name existing steps and parameters, that task values are only read from upstream steps,
+    /// and that the steps do not depend on each other in a cycle.
+    pub fn validate(&self) -> Result<(), String> {
+        let mut graph_parameters: HashSet<&str> = HashSet::new();
+        for (name, _) in &self.parameters {
+            if !graph_parameters.insert(name) {
+                return Err(format!("Job parameter {} is defined more than once", name));
+            }
+        }
+
+        let mut dependencies: HashMap<&str, &[String]> = HashMap::new();
+        for step in &self.steps {
+            if step.task_key.is_empty() {
+                return Err(format!(
+                    "The step running job {} has no task key",
+                    step.job_id
+                ));
+            }
+            if dependencies
+                .insert(&step.task_key, &step.depends_on)
+                .is_some()
+            {
+                return Err(format!("Task key {} is used more than once", step.task_key));
+            }
+        }
+
+        let job_reference: Regex =
+            Regex::new(r"\{\{\s*job\.parameters\.([^\s}]+)\s*\}\}").expect("valid regex");
+        let task_reference: Regex =
+            Regex::new(r"\{\{\s*tasks\.([^\s.}]+)\.values\.[^\s}]+\s*\}\}").expect("valid regex");
+        for step in &self.steps {
+            for upstream in &step.depends_on {
+                if !dependencies.contains_key(upstream.as_str()) {
+                    return Err(format!(
+                        "Task {} depends on {}, which is not a step of the graph",
+                        step.task_key, upstream
+                    ));
+                }
+            }
+
+            let upstream: HashSet<&str> = ancestors(&step.task_key, &dependencies);
+            let mut names: HashSet<&str> = HashSet::new();
+            for (name, value) in &step.parameters {
+                if !names.insert(name) {
+                    return Err(format!(
+                        "Parameter {} of task {} is set more than once",
+                        name, step.task_key
+                    ));
+                }
+                for reference in job_reference.captures_iter(value) {
+                    if !graph_parameters.contains(&reference[1]) {
+                        return Err(format!(
+                            "Parameter {} of task {} refers to job parameter {}, which the graph does not define",
+                            name, step.task_key, &reference[1]
+                        ));
+                    }
+                }
+                for reference in task_reference.captures_iter(value) {
+                    if !upstream.contains(&reference[1]) {
+                        return Err(format!(
+                            "Parameter {} of task {} reads a task value of {}, which is not upstream of it",
+                            name, step.task_key, &reference[1]
+                        ));
+                    }
+                }
+            }
+        }
+
+        if let Some(task_key) = cycle_member(&dependencies) {
+            return Err(format!(
+                "Task {} depends on itself through a cycle",
+                task_key
+            ));
+        }
+        Ok(())
+    }
+
+    /// Validates the graph and converts it into the settings of a job with one `run_job_task`
+    /// per step.
+    pub fn to_job_settings(&self) -> Result<JobSettings, String> {
+        self.validate()?;
+
+        let mut settings: JobSettings = JobSettings::new(&self.name);
+        settings.parameters = self
+            .parameters
+            .iter()
+            .map(|(name, default)| JobParameterDefinition {
+                name: name.clone(),
+                default: default.clone(),
+            })
+            .collect();
+        settings.tasks = self
+            .steps
+            .iter()
+            .map(|step| JobTask {
+                task_key: step.task_key.clone(),
+                depends_on: step
+                    .depends_on
+                    .iter()
+                    .map(|task_key| TaskDependency {
+                        task_key: task_key.clone(),
+                    })
+                    .collect(),
+                run_job_task: Some(RunJobTask {
+                    job_id: step.job_id,
+                    job_parameters: step.parameters.iter().cloned().collect(),
+                }),
+                ..Default::default()
+            })
+            .collect();
+        Ok(settings)
+    }
+}
+
+/// The steps `task_key` transitively depends on.
+fn ancestors<'a>(
+    task_key: &str,
+    dependencies: &HashMap<&'a str, &'a [String]>,
+) -> HashSet<&'a str> {
+    let mut found: HashSet<&'a str> = HashSet::new();
+    let mut pending: Vec<&'a str> = dependencies
+        .get(task_key)
+        .map(|upstream| upstream.iter().map(String::as_str).collect())
+        .unwrap_or_default();
+    while let Some(upstream) = pending.pop() {
+        if found.insert(upstream) {
+            if let Some(next) = dependencies.get(upstream) {
+                pending.extend(next.iter().map(String::as_str));
+            }
+        }
+    }
+    found
+}
+
+/// A step that is part of a dependency cycle, if there is one.
+fn cycle_member<'a>(dependencies: &HashMap<&'a str, &'a [String]>) -> Option<&'a str> {
+    let mut remaining: HashMap<&'a str, usize> = dependencies
+        .iter()
+        .map(|(task_key, upstream)| (*task_key, upstream.len()))
+        .collect();
+    loop {
+        let ready: Vec<&'a str> = remaining
+            .iter()
+            .filter(|(_, count)| **count == 0)
+            .map(|(task_key, _)| *task_key)
+            .collect();
+        if ready.is_empty() {
+            return remaining.keys().min().copied();
+        }
+        for task_key in ready {
+            remaining.remove(task_key);
+            for (downstream, upstream) in dependencies {
+                if upstream.iter().any(|key| key == task_key) {
+                    if let Some(count) = remaining.get_mut(downstream) {
+                        *count -= 1;
+                    }
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use crate::models::{Run, RunLifeCycleState, RunResultState};
+use std::{fmt, sync::Arc, time::Duration};
+
+/// How a failed job run is retried.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunRetryStrategy {
+    /// Repairs the run, re-running only its failed tasks and their dependents.
+    Repair,
+    /// Starts a new run of the job.
+    Resubmit,
+}
+
+/// When and how often `run_job_with_retries` and `submit_run_with_retries` retry a failed run.
+///
+/// By default a run is retried if it failed, timed out or hit an internal error, but not if it
+/// was canceled or skipped.
+#[derive(Clone)]
+pub struct RunRetryPolicy {
+    pub max_retries: u32,
+    pub strategy: RunRetryStrategy,
+    /// The delay before the first retry; it doubles for each further retry, up to `max_backoff`.
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+    pub poll_interval: Duration,
+    retry_if: Arc<dyn Fn(&Run) -> bool + Send + Sync>,
+}
+
+impl RunRetryPolicy {
+    /// A policy repairing a failed run up to `max_retries` times, waiting 30 seconds before the
+    /// first retry, at most 10 minutes between retries, and polling every 15 seconds.
+    pub fn new(max_retries: u32) -> Self {
+        RunRetryPolicy {
+            max_retries,
+            strategy: RunRetryStrategy::Repair,
+            initial_backoff: Duration::from_secs(30),
+            max_backoff: Duration::from_secs(10 * 60),
+            poll_interval: Duration::from_secs(15),
+            retry_if: Arc::new(Self::is_retryable_failure),
+        }
+    }
+
+    /// Sets whether failed runs are repaired or resubmitted.
+    pub fn with_strategy(mut self, strategy: RunRetryStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// Sets the delay before the first retry and the maximum delay between retries.
+    pub fn with_backoff(mut self, initial_backoff: Duration, max_backoff: Duration) -> Self {
+        self.initial_backoff = initial_backoff;
+        self.max_backoff = max_backoff;
+        self
+    }
+
+    /// Sets the time between polls of a running attempt.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    /// Replaces the check deciding whether a finished, unsuccessful run is retried, e.g. to retry
+    /// only runs whose `state_message` mentions a spot instance loss.
+    pub fn retry_if(mut self, predicate: impl Fn(&Run) -> bool + Send + Sync + 'static) -> Self {
+        self.retry_if = Arc::new(predicate);
+        self
+    }
+
+    /// The default retry check: the run failed, timed out or hit an internal error.
+    pub fn is_retryable_failure(run: &Run) -> bool {
+        matches!(
+            run.result_state(),
+            Some(RunResultState::Failed) | Some(RunResultState::Timedout)
+        ) || run.life_cycle_state() == Some(RunLifeCycleState::InternalError)
+    }
+
+    /// Whether `run`, which has finished, should be retried.
+    pub(crate) fn should_retry(&self, run: &Run) -> bool {
+        !run.result_state().is_some_and(|state| state.is_success()) && (self.retry_if)(run)
+    }
+
+    /// The delay before retry number `retry` (starting at 1).
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        self.initial_backoff
+            .saturating_mul(2u32.saturating_pow(retry.saturating_sub(1)))
+            .min(self.max_backoff)
+    }
+}
+
+impl fmt::Debug for RunRetryPolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RunRetryPolicy")
+            .field("max_retries", &self.max_retries)
+            .field("strategy", &self.strategy)
+            .field("initial_backoff", &self.initial_backoff)
+            .field("max_backoff", &self.max_backoff)
+            .field("poll_interval", &self.poll_interval)
+            .finish_non_exhaustive()
+    }
+}
+
+/// One attempt of a retried run.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RunAttempt {
+    /// 0 for the first attempt, then 1 for the first retry, and so on.
+    pub attempt: u32,
+    pub run_id: i64,
+    /// The repair that made this attempt, for attempts made with `RunRetryStrategy::Repair`.
+    pub repair_id: Option<i64>,
+    pub result_state: Option<RunResultState>,
+    pub state_message: Option<String>,
+}
+
+/// The outcome of a run retried with a `RunRetryPolicy`.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct RunRetryOutcome {
+    /// Every attempt, in order; the last one is the final state.
+    pub attempts: Vec<RunAttempt>,
+    /// The run as it was when the last attempt finished.
+    pub run: Run,
+}
+
+impl RunRetryOutcome {
+    /// Returns `true` if the last attempt succeeded.
+    pub fn succeeded(&self) -> bool {
+        self.run
+            .result_state()
+            .is_some_and(|state| state.is_success())
+    }
+}
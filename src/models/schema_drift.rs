@@ -0,0 +1,168 @@
+use crate::models::ColumnInfo;
+use serde::{Deserialize, Serialize};
+
+/// A column the table is expected to have.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpectedColumn {
+    pub name: String,
+    pub type_text: String, // e.g. "bigint", "decimal(10,2)", "array<string>"
+    pub nullable: Option<bool>,
+}
+
+/// The columns a table is expected to have, compared against its actual columns by
+/// `check_schema_drift`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExpectedSchema {
+    pub columns: Vec<ExpectedColumn>,
+    /// Whether columns that are not expected count as drift.
+    pub allow_extra_columns: bool,
+}
+
+impl ExpectedSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expects a column of the given SQL type; its nullability is not checked.
+    pub fn with_column(mut self, name: &str, type_text: &str) -> Self {
+        self.columns.push(ExpectedColumn {
+            name: name.to_string(),
+            type_text: type_text.to_string(),
+            nullable: None,
+        });
+        self
+    }
+
+    /// Expects a column of the given SQL type and nullability.
+    pub fn with_column_nullable(mut self, name: &str, type_text: &str, nullable: bool) -> Self {
+        self.columns.push(ExpectedColumn {
+            name: name.to_string(),
+            type_text: type_text.to_string(),
+            nullable: Some(nullable),
+        });
+        self
+    }
+
+    /// Tolerates columns that are present in the table but not expected, e.g. columns added by
+    /// schema evolution.
+    pub fn allow_extra_columns(mut self, allow: bool) -> Self {
+        self.allow_extra_columns = allow;
+        self
+    }
+
+    /// Compares the expected columns against a table's actual columns. Names are compared
+    /// case-insensitively and types ignoring case, whitespace and aliases such as `long`/`bigint`.
+    pub fn drift(&self, table_full_name: &str, actual: &[ColumnInfo]) -> SchemaDrift {
+        let mut report: SchemaDrift = SchemaDrift {
+            table_full_name: table_full_name.to_string(),
+            ..Default::default()
+        };
+
+        for expected in &self.columns {
+            let Some(column) = actual
+                .iter()
+                .find(|column| column.name.eq_ignore_ascii_case(&expected.name))
+            else {
+                report.missing_columns.push(expected.clone());
+                continue;
+            };
+            let actual_type: String = column.type_text.clone().unwrap_or_default();
+            if normalize_type(&actual_type) != normalize_type(&expected.type_text) {
+                report.type_mismatches.push(ColumnTypeMismatch {
+                    name: column.name.clone(),
+                    expected: expected.type_text.clone(),
+                    actual: actual_type,
+                });
+            }
+            if let Some(nullable) = expected.nullable {
+                let actual_nullable: bool = column.nullable.unwrap_or(true);
+                if actual_nullable != nullable {
+                    report
+                        .nullability_mismatches
+                        .push(ColumnNullabilityMismatch {
+                            name: column.name.clone(),
+                            expected: nullable,
+                            actual: actual_nullable,
+                        });
+                }
+            }
+        }
+
+        if !self.allow_extra_columns {
+            report.unexpected_columns = actual
+                .iter()
+                .filter(|column| {
+                    !self
+                        .columns
+                        .iter()
+                        .any(|expected| expected.name.eq_ignore_ascii_case(&column.name))
+                })
+                .cloned()
+                .collect();
+        }
+        report
+    }
+}
+
+/// Implemented by row types that describe the table they are loaded into, so the table can be
+/// checked with `check_schema_drift_for::<T>`.
+pub trait ExpectedTableSchema {
+    fn expected_schema() -> ExpectedSchema;
+}
+
+/// A column whose type differs from the expected one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnTypeMismatch {
+    pub name: String,
+    pub expected: String,
+    pub actual: String,
+}
+
+/// A column whose nullability differs from the expected one.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ColumnNullabilityMismatch {
+    pub name: String,
+    pub expected: bool,
+    pub actual: bool,
+}
+
+/// The differences between a table's actual columns and its `ExpectedSchema`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SchemaDrift {
+    pub table_full_name: String,
+    pub missing_columns: Vec<ExpectedColumn>,
+    pub unexpected_columns: Vec<ColumnInfo>,
+    pub type_mismatches: Vec<ColumnTypeMismatch>,
+    pub nullability_mismatches: Vec<ColumnNullabilityMismatch>,
+}
+
+impl SchemaDrift {
+    /// Whether the table matches its expected schema.
+    pub fn is_empty(&self) -> bool {
+        self.missing_columns.is_empty()
+            && self.unexpected_columns.is_empty()
+            && self.type_mismatches.is_empty()
+            && self.nullability_mismatches.is_empty()
+    }
+}
+
+/// Lowercases SQL type text, drops whitespace and maps type aliases onto their canonical names.
+fn normalize_type(type_text: &str) -> String {
+    let compact: String = type_text
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .collect::<String>()
+        .to_ascii_lowercase();
+    let split: usize = compact.find(['(', '<']).unwrap_or(compact.len());
+    let (base, rest) = compact.split_at(split);
+    let base: &str = match base {
+        "long" => "bigint",
+        "integer" => "int",
+        "short" => "smallint",
+        "byte" => "tinyint",
+        "real" => "float",
+        "dec" | "numeric" => "decimal",
+        other => other,
+    };
+    format!("{}{}", base, rest)
+}
@@ -0,0 +1,297 @@
+use crate::models::AiGatewayConfig;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WorkloadSize {
+    Small,
+    Medium,
+    Large,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WorkloadType {
+    Cpu,
+    GpuSmall,
+    GpuMedium,
+    GpuLarge,
+    MultigpuMedium,
+}
+
+/// A model (or other entity) to serve behind an endpoint.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ServedEntityInput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>, // Defaults to "<entity_name>-<entity_version>"
+    pub entity_name: String, // e.g. a UC model "main.ml.churn"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub entity_version: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workload_size: Option<WorkloadSize>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub workload_type: Option<WorkloadType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scale_to_zero_enabled: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_provisioned_throughput: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_provisioned_throughput: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub environment_vars: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance_profile_arn: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_model: Option<serde_json::Value>,
+}
+
+impl ServedEntityInput {
+    /// Serves `version` of the registered model `entity_name` on a small, scale-to-zero CPU workload.
+    pub fn model(entity_name: &str, version: &str) -> Self {
+        ServedEntityInput {
+            entity_name: entity_name.to_string(),
+            entity_version: Some(version.to_string()),
+            workload_size: Some(WorkloadSize::Small),
+            scale_to_zero_enabled: Some(true),
+            ..Default::default()
+        }
+    }
+
+    /// Serves a feature spec (a Unity Catalog function, e.g. `main.features.user_spec`) behind a
+    /// feature serving endpoint on a small, scale-to-zero CPU workload.
+    ///
+    /// Feature specs are defined with the Feature Engineering Python client; the REST API only
+    /// serves them.
+    pub fn feature_spec(feature_spec_name: &str) -> Self {
+        ServedEntityInput {
+            entity_name: feature_spec_name.to_string(),
+            workload_size: Some(WorkloadSize::Small),
+            scale_to_zero_enabled: Some(true),
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrafficRoute {
+    pub served_model_name: String, // Name of the served entity
+    pub traffic_percentage: i32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct TrafficConfig {
+    #[serde(default)]
+    pub routes: Vec<TrafficRoute>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AutoCaptureConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub catalog_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table_name_prefix: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct EndpointCoreConfigInput {
+    #[serde(default)]
+    pub served_entities: Vec<ServedEntityInput>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub traffic_config: Option<TrafficConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub auto_capture_config: Option<AutoCaptureConfig>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EndpointTag {
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateServingEndpointRequest {
+    pub name: String,
+    pub config: EndpointCoreConfigInput,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<EndpointTag>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub route_optimized: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ai_gateway: Option<AiGatewayConfig>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EndpointReadyState {
+    Ready,
+    NotReady,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum EndpointConfigUpdateState {
+    NotUpdating,
+    InProgress,
+    UpdateFailed,
+    UpdateCanceled,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct EndpointState {
+    pub ready: Option<EndpointReadyState>,
+    pub config_update: Option<EndpointConfigUpdateState>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ServedEntityState {
+    pub deployment: Option<String>, // "DEPLOYMENT_READY", "DEPLOYMENT_CREATING", ...
+    pub deployment_state_message: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ServedEntityOutput {
+    pub name: String,
+    pub entity_name: Option<String>,
+    pub entity_version: Option<String>,
+    pub workload_size: Option<WorkloadSize>,
+    pub workload_type: Option<WorkloadType>,
+    pub scale_to_zero_enabled: Option<bool>,
+    pub min_provisioned_throughput: Option<i64>,
+    pub max_provisioned_throughput: Option<i64>,
+    pub environment_vars: Option<HashMap<String, String>>,
+    pub state: Option<ServedEntityState>,
+    pub creator: Option<String>,
+    pub creation_timestamp: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl From<&ServedEntityOutput> for ServedEntityInput {
+    /// The input that serves the entity again with the same settings, e.g. to keep it in a new config.
+    fn from(entity: &ServedEntityOutput) -> Self {
+        ServedEntityInput {
+            name: Some(entity.name.clone()),
+            entity_name: entity.entity_name.clone().unwrap_or_default(),
+            entity_version: entity.entity_version.clone(),
+            workload_size: entity.workload_size,
+            workload_type: entity.workload_type,
+            scale_to_zero_enabled: entity.scale_to_zero_enabled,
+            min_provisioned_throughput: entity.min_provisioned_throughput,
+            max_provisioned_throughput: entity.max_provisioned_throughput,
+            environment_vars: entity.environment_vars.clone(),
+            instance_profile_arn: entity
+                .extra
+                .get("instance_profile_arn")
+                .and_then(Value::as_str)
+                .map(str::to_string),
+            external_model: entity.extra.get("external_model").cloned(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct EndpointCoreConfigOutput {
+    #[serde(default)]
+    pub served_entities: Vec<ServedEntityOutput>,
+    pub traffic_config: Option<TrafficConfig>,
+    pub auto_capture_config: Option<AutoCaptureConfig>,
+    pub config_version: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl From<&EndpointCoreConfigOutput> for EndpointCoreConfigInput {
+    /// The input that reapplies the configuration as it is, e.g. to roll back a failed update.
+    fn from(config: &EndpointCoreConfigOutput) -> Self {
+        EndpointCoreConfigInput {
+            served_entities: config
+                .served_entities
+                .iter()
+                .map(ServedEntityInput::from)
+                .collect(),
+            traffic_config: config.traffic_config.clone(),
+            auto_capture_config: config.auto_capture_config.clone(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ServingEndpointDetailed {
+    pub name: String,
+    pub id: Option<String>,
+    pub creator: Option<String>,
+    pub creation_timestamp: Option<i64>,
+    pub last_updated_timestamp: Option<i64>,
+    pub state: Option<EndpointState>,
+    pub config: Option<EndpointCoreConfigOutput>,
+    pub pending_config: Option<EndpointCoreConfigOutput>,
+    #[serde(default)]
+    pub tags: Vec<EndpointTag>,
+    pub task: Option<String>, // e.g. "llm/v1/chat"
+    pub permission_level: Option<String>,
+    pub route_optimized: Option<bool>,
+    pub ai_gateway: Option<AiGatewayConfig>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl ServingEndpointDetailed {
+    /// Returns `true` once the endpoint is ready and no configuration update is in flight.
+    pub fn is_ready(&self) -> bool {
+        matches!(
+            &self.state,
+            Some(EndpointState {
+                ready: Some(EndpointReadyState::Ready),
+                config_update: None | Some(EndpointConfigUpdateState::NotUpdating),
+                ..
+            })
+        )
+    }
+
+    /// Returns `true` while a configuration update is in progress.
+    pub fn is_updating(&self) -> bool {
+        matches!(
+            self.state.as_ref().and_then(|state| state.config_update),
+            Some(EndpointConfigUpdateState::InProgress)
+        )
+    }
+
+    /// Returns `true` if the most recent configuration update failed or was canceled.
+    pub fn update_failed(&self) -> bool {
+        matches!(
+            self.state.as_ref().and_then(|state| state.config_update),
+            Some(EndpointConfigUpdateState::UpdateFailed)
+                | Some(EndpointConfigUpdateState::UpdateCanceled)
+        )
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListServingEndpointsResponse {
+    #[serde(default)]
+    pub endpoints: Vec<ServingEndpointDetailed>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
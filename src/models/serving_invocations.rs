@@ -0,0 +1,85 @@
+use crate::models::{HasTokenUsage, TokenUsage};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// The pandas-style `split` orientation: column names plus row-major values.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DataframeSplit {
+    pub columns: Vec<String>,
+    pub data: Vec<Vec<Value>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub index: Option<Vec<Value>>,
+}
+
+/// The input payload of a serving endpoint query, in one of the supported input formats.
+///
+/// Serialized as a single top-level key, e.g. `{"dataframe_split": {...}}`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ServingInput {
+    DataframeSplit(DataframeSplit),
+    DataframeRecords(Vec<Map<String, Value>>),
+    Instances(Vec<Value>),
+    Inputs(Value),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryServingEndpointRequest {
+    #[serde(flatten)]
+    pub input: ServingInput,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub params: Option<Map<String, Value>>,
+}
+
+impl QueryServingEndpointRequest {
+    pub fn new(input: ServingInput) -> Self {
+        QueryServingEndpointRequest {
+            input,
+            params: None,
+        }
+    }
+
+    /// Builds a `dataframe_records` request from any serializable records (e.g. a slice of structs).
+    pub fn from_records<R: Serialize>(records: &[R]) -> Result<Self, serde_json::Error> {
+        let records: Vec<Map<String, Value>> = records
+            .iter()
+            .map(|record| match serde_json::to_value(record)? {
+                Value::Object(map) => Ok(map),
+                other => Err(serde::ser::Error::custom(format!(
+                    "expected a record to serialize as an object, got {}",
+                    other
+                ))),
+            })
+            .collect::<Result<_, serde_json::Error>>()?;
+        Ok(Self::new(ServingInput::DataframeRecords(records)))
+    }
+}
+
+/// The response of a serving endpoint query.
+///
+/// Custom models return `predictions`; other fields (e.g. `outputs` or LLM `choices`) are kept in
+/// `extra`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct QueryServingEndpointResponse {
+    pub predictions: Option<Value>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl QueryServingEndpointResponse {
+    /// Deserializes `predictions` into a typed value (e.g. `Vec<f64>` or `Vec<MyPrediction>`).
+    pub fn predictions_as<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        T::deserialize(self.predictions.clone().unwrap_or(Value::Null))
+    }
+}
+
+impl HasTokenUsage for QueryServingEndpointResponse {
+    /// The `usage` field LLM and external model endpoints include in their responses.
+    fn token_usage(&self) -> Option<TokenUsage> {
+        self.extra
+            .get("usage")
+            .and_then(|usage| TokenUsage::deserialize(usage).ok())
+    }
+}
@@ -0,0 +1,70 @@
+use crate::models::ServingEndpointDetailed;
+use std::time::Duration;
+
+/// A gradual shift of a serving endpoint's traffic to a new version of its served model.
+///
+/// At each step the new version receives the given percentage of traffic and the model currently
+/// taking the most traffic gives up the difference; other routes are left as they are. After each
+/// step the endpoint must become ready within `step_timeout` and stay healthy for `soak_time`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrafficRollout {
+    pub new_version: String,
+    pub steps: Vec<i32>, // Traffic percentages for the new version, e.g. [10, 50, 100]
+    pub step_timeout: Duration,
+    pub soak_time: Duration,
+}
+
+impl TrafficRollout {
+    /// A rollout of `new_version` through the given traffic percentages, allowing 20 minutes per
+    /// step and soaking for 1 minute between steps.
+    pub fn new(new_version: &str, steps: Vec<i32>) -> Self {
+        TrafficRollout {
+            new_version: new_version.to_string(),
+            steps,
+            step_timeout: Duration::from_secs(20 * 60),
+            soak_time: Duration::from_secs(60),
+        }
+    }
+
+    /// Sets how long each step may take to become ready.
+    pub fn with_step_timeout(mut self, step_timeout: Duration) -> Self {
+        self.step_timeout = step_timeout;
+        self
+    }
+
+    /// Sets how long the endpoint must stay healthy after a step before the next one starts.
+    pub fn with_soak_time(mut self, soak_time: Duration) -> Self {
+        self.soak_time = soak_time;
+        self
+    }
+
+    /// Checks that the steps are strictly increasing percentages between 1 and 100.
+    pub(crate) fn validate(&self) -> Result<(), String> {
+        if self.steps.is_empty() {
+            return Err("A traffic rollout needs at least one step".to_string());
+        }
+        let mut previous: i32 = 0;
+        for &step in &self.steps {
+            if !(1..=100).contains(&step) || step <= previous {
+                return Err(format!(
+                    "Traffic rollout steps must be increasing percentages between 1 and 100, got {:?}",
+                    self.steps
+                ));
+            }
+            previous = step;
+        }
+        Ok(())
+    }
+}
+
+/// The result of a completed traffic rollout.
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct TrafficRolloutOutcome {
+    /// The name of the served entity running the new version.
+    pub served_entity_name: String,
+    /// The name of the served entity traffic was shifted away from.
+    pub previous_served_entity_name: String,
+    pub completed_steps: Vec<i32>,
+    pub endpoint: ServingEndpointDetailed,
+}
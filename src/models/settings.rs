@@ -0,0 +1,158 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+/// A workspace setting served by `/api/2.0/settings/types/{type}/names/default`.
+///
+/// Every setting carries an `etag` that must be echoed back on update and delete; a stale etag is
+/// rejected with a conflict, which the client resolves by re-reading the setting.
+pub(crate) trait WorkspaceSetting: Serialize + DeserializeOwned + Default {
+    const SETTING_TYPE: &'static str;
+    const FIELD_MASK: &'static str;
+
+    fn etag(&self) -> Option<&str>;
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StringMessage {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+}
+
+/// The default catalog used for unqualified table names in SQL and notebooks.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DefaultNamespaceSetting {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setting_name: Option<String>,
+    #[serde(default)]
+    pub namespace: StringMessage,
+}
+
+impl WorkspaceSetting for DefaultNamespaceSetting {
+    const SETTING_TYPE: &'static str = "default_namespace_ws";
+    const FIELD_MASK: &'static str = "namespace.value";
+
+    fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RestrictWorkspaceAdminsStatus {
+    #[default]
+    AllowAll,
+    RestrictTokensAndJobRunAs,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RestrictWorkspaceAdminsMessage {
+    pub status: RestrictWorkspaceAdminsStatus,
+}
+
+/// Whether workspace admins may create tokens on behalf of others and change job run-as identities.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct RestrictWorkspaceAdminsSetting {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setting_name: Option<String>,
+    #[serde(default)]
+    pub restrict_workspace_admins: RestrictWorkspaceAdminsMessage,
+}
+
+impl WorkspaceSetting for RestrictWorkspaceAdminsSetting {
+    const SETTING_TYPE: &'static str = "restrict_workspace_admins";
+    const FIELD_MASK: &'static str = "restrict_workspace_admins.status";
+
+    fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WindowStartTime {
+    pub hours: u8,
+    pub minutes: u8,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct WeekDayBasedSchedule {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub day_of_week: Option<String>, // "MONDAY", ...
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub frequency: Option<String>, // "EVERY_WEEK", "FIRST_OF_MONTH", ...
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub window_start_time: Option<WindowStartTime>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MaintenanceWindow {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub week_day_based_schedule: Option<WeekDayBasedSchedule>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AutomaticClusterUpdateMessage {
+    pub enabled: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub can_toggle: Option<bool>, // Output only
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintenance_window: Option<MaintenanceWindow>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restart_even_if_no_updates_available: Option<bool>,
+}
+
+/// Whether clusters are automatically restarted during a maintenance window to pick up updates.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AutomaticClusterUpdateSetting {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setting_name: Option<String>,
+    #[serde(default)]
+    pub automatic_cluster_update_workspace: AutomaticClusterUpdateMessage,
+}
+
+impl WorkspaceSetting for AutomaticClusterUpdateSetting {
+    const SETTING_TYPE: &'static str = "automatic_cluster_update";
+    const FIELD_MASK: &'static str = "automatic_cluster_update_workspace";
+
+    fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ComplianceSecurityProfileMessage {
+    pub is_enabled: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub compliance_standards: Vec<String>, // e.g. "HIPAA", "PCI_DSS"
+}
+
+/// Whether the compliance security profile (hardened images, enforced encryption) is enabled.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ComplianceSecurityProfileSetting {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub setting_name: Option<String>,
+    #[serde(default)]
+    pub compliance_security_profile_workspace: ComplianceSecurityProfileMessage,
+}
+
+impl WorkspaceSetting for ComplianceSecurityProfileSetting {
+    const SETTING_TYPE: &'static str = "shield_csp_enablement_ws_db";
+    const FIELD_MASK: &'static str = "compliance_security_profile_workspace";
+
+    fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct UpdateSettingRequest<'a, T> {
+    pub allow_missing: bool,
+    pub field_mask: &'static str,
+    pub setting: &'a T,
+}
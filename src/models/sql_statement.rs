@@ -1,22 +1,296 @@
+use crate::utils::quote_literal;
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::{Map, Number, Value};
+use std::{fmt, io, time::Duration};
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SqlStatementRequest {
     pub statement: String,
     pub warehouse_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub catalog: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub schema: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub parameters: Option<Vec<SqlParameter>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub row_limit: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub byte_limit: Option<i64>,
-    pub disposition: String, // "INLINE" or "EXTERNAL_LINKS"
-    pub format: String,      // "JSON_ARRAY", "ARROW_STREAM", or "CSV"
-    pub wait_timeout: Option<String>,
-    pub on_wait_timeout: Option<String>, // "CONTINUE" or "CANCEL"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub disposition: Option<String>, // "INLINE" or "EXTERNAL_LINKS"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>, // "JSON_ARRAY", "ARROW_STREAM", or "CSV"
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "serialize_wait_timeout",
+        deserialize_with = "deserialize_wait_timeout"
+    )]
+    pub wait_timeout: Option<Duration>, // 0 or 5 to 50 whole seconds
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub on_wait_timeout: Option<OnWaitTimeout>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+/// What happens to a statement that is still running when `wait_timeout` elapses.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum OnWaitTimeout {
+    /// Keep running asynchronously; poll the statement for its result.
+    #[default]
+    Continue,
+    /// Cancel the statement.
+    Cancel,
+}
+
+impl Default for SqlStatementRequest {
+    /// An empty statement waiting up to 30 seconds before continuing asynchronously. The result
+    /// disposition and format are left to the session's `SqlSessionSettings`, and otherwise to
+    /// the API, which returns inline JSON results.
+    fn default() -> Self {
+        SqlStatementRequest {
+            statement: String::new(),
+            warehouse_id: String::new(),
+            catalog: None,
+            schema: None,
+            parameters: None,
+            row_limit: None,
+            byte_limit: None,
+            disposition: None,
+            format: None,
+            wait_timeout: Some(Duration::from_secs(30)),
+            on_wait_timeout: Some(OnWaitTimeout::Continue),
+        }
+    }
+}
+
+impl SqlStatementRequest {
+    /// Creates a request for `statement` on `warehouse_id` with the defaults of `Default`.
+    ///
+    /// Optional settings are chained on, e.g.
+    /// `SqlStatementRequest::new(warehouse_id, "SELECT 1").with_catalog("main").with_row_limit(10)`.
+    pub fn new(warehouse_id: &str, statement: &str) -> Self {
+        SqlStatementRequest {
+            statement: statement.to_string(),
+            warehouse_id: warehouse_id.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Sets the default catalog for unqualified names.
+    pub fn with_catalog(mut self, catalog: &str) -> Self {
+        self.catalog = Some(catalog.to_string());
+        self
+    }
+
+    /// Sets the default schema for unqualified names.
+    pub fn with_schema(mut self, schema: &str) -> Self {
+        self.schema = Some(schema.to_string());
+        self
+    }
+
+    /// Adds a named parameter, referenced as `:name` in the statement.
+    pub fn with_parameter(
+        mut self,
+        name: &str,
+        value: Option<&str>,
+        sql_type: Option<&str>,
+    ) -> Self {
+        self.parameters
+            .get_or_insert_with(Vec::new)
+            .push(SqlParameter {
+                name: name.to_string(),
+                value: value.map(str::to_string),
+                sql_type: sql_type.map(str::to_string),
+            });
+        self
+    }
+
+    /// Limits the number of rows returned.
+    pub fn with_row_limit(mut self, row_limit: i64) -> Self {
+        self.row_limit = Some(row_limit);
+        self
+    }
+
+    /// Limits the number of bytes returned.
+    pub fn with_byte_limit(mut self, byte_limit: i64) -> Self {
+        self.byte_limit = Some(byte_limit);
+        self
+    }
+
+    /// Sets the result disposition, `"INLINE"` or `"EXTERNAL_LINKS"`, overriding the session's
+    /// default.
+    pub fn with_disposition(mut self, disposition: &str) -> Self {
+        self.disposition = Some(disposition.to_string());
+        self
+    }
+
+    /// Sets the result format, `"JSON_ARRAY"`, `"ARROW_STREAM"` or `"CSV"`, overriding the
+    /// session's default.
+    pub fn with_format(mut self, format: &str) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+
+    /// Tags the statement with a leading SQL comment, so its query-history entry can be found
+    /// with `find_tagged_query` even if the statement ID never reached the client.
+    ///
+    /// Use a tag that is unique per request, such as a client-side request or trace ID.
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.statement = format!("{}\n{}", statement_tag_comment(tag), self.statement);
+        self
+    }
+
+    /// Sets how long the request waits for the result, and what happens to the statement when
+    /// that time elapses.
+    ///
+    /// The API accepts `Duration::ZERO` (return immediately) or 5 to 50 whole seconds; other
+    /// values are rejected by `execute_sql_statement` before the request is sent.
+    pub fn with_wait_timeout(
+        mut self,
+        wait_timeout: Duration,
+        on_wait_timeout: OnWaitTimeout,
+    ) -> Self {
+        self.wait_timeout = Some(wait_timeout);
+        self.on_wait_timeout = Some(on_wait_timeout);
+        self
+    }
+
+    /// Checks that `wait_timeout` is within the bounds accepted by the API.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the timeout is unset, zero or 5 to 50 whole seconds, or a message
+    ///   describing the problem otherwise.
+    pub fn validate_wait_timeout(&self) -> Result<(), String> {
+        match self.wait_timeout {
+            None => Ok(()),
+            Some(timeout) if timeout.subsec_nanos() != 0 => Err(format!(
+                "wait_timeout must be a whole number of seconds, got {:?}",
+                timeout
+            )),
+            Some(timeout) if timeout.is_zero() || (5..=50).contains(&timeout.as_secs()) => Ok(()),
+            Some(timeout) => Err(format!(
+                "wait_timeout must be 0 or between 5 and 50 seconds, got {}s",
+                timeout.as_secs()
+            )),
+        }
+    }
+}
+
+/// Settings applied to every statement a session executes, configured once with
+/// `DatabricksSession::with_sql_session_settings`.
+///
+/// The catalog, schema, result disposition and result format are sent as request fields and only
+/// fill in values the request leaves unset. The Statement Execution API has no fields for the
+/// other settings, so they are applied by wrapping the statement in a SQL script
+/// (`BEGIN SET ...; <statement>; END`), which requires a warehouse with SQL scripting support.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SqlSessionSettings {
+    pub catalog: Option<String>,
+    pub schema: Option<String>,
+    pub disposition: Option<String>, // "INLINE" or "EXTERNAL_LINKS"
+    pub format: Option<String>,      // "JSON_ARRAY", "ARROW_STREAM", or "CSV"
+    pub time_zone: Option<String>,   // e.g. "UTC" or "America/Los_Angeles"
+    pub ansi_mode: Option<bool>,
+    pub configuration: Vec<(String, String)>, // Further `SET key = value` pairs, sent verbatim
+}
+
+impl SqlSessionSettings {
+    /// Sets the default catalog for unqualified names.
+    pub fn with_catalog(mut self, catalog: &str) -> Self {
+        self.catalog = Some(catalog.to_string());
+        self
+    }
+
+    /// Sets the default schema for unqualified names.
+    pub fn with_schema(mut self, schema: &str) -> Self {
+        self.schema = Some(schema.to_string());
+        self
+    }
+
+    /// Sets the default result disposition, e.g. `"EXTERNAL_LINKS"`.
+    pub fn with_disposition(mut self, disposition: &str) -> Self {
+        self.disposition = Some(disposition.to_string());
+        self
+    }
+
+    /// Sets the default result format, e.g. `"ARROW_STREAM"`.
+    pub fn with_format(mut self, format: &str) -> Self {
+        self.format = Some(format.to_string());
+        self
+    }
+
+    /// Sets the session time zone used for timestamp conversions.
+    pub fn with_time_zone(mut self, time_zone: &str) -> Self {
+        self.time_zone = Some(time_zone.to_string());
+        self
+    }
+
+    /// Enables or disables ANSI SQL semantics (e.g. errors instead of `NULL` on invalid casts).
+    pub fn with_ansi_mode(mut self, ansi_mode: bool) -> Self {
+        self.ansi_mode = Some(ansi_mode);
+        self
+    }
+
+    /// Adds a configuration parameter, set as `SET key = value`.
+    pub fn with_configuration(mut self, key: &str, value: &str) -> Self {
+        self.configuration
+            .push((key.to_string(), value.to_string()));
+        self
+    }
+
+    /// The `SET` statements for the settings that have no request field.
+    pub fn set_statements(&self) -> Vec<String> {
+        let mut statements: Vec<String> = Vec::new();
+        if let Some(time_zone) = &self.time_zone {
+            statements.push(format!("SET TIME ZONE {}", quote_literal(time_zone)));
+        }
+        if let Some(ansi_mode) = self.ansi_mode {
+            statements.push(format!("SET ANSI_MODE = {}", ansi_mode));
+        }
+        for (key, value) in &self.configuration {
+            statements.push(format!("SET {} = {}", key, value));
+        }
+        statements
+    }
+
+    /// Applies the settings to a request, leaving values the request sets itself untouched.
+    pub fn apply(&self, mut request: SqlStatementRequest) -> SqlStatementRequest {
+        if request.catalog.is_none() {
+            request.catalog = self.catalog.clone();
+        }
+        if request.schema.is_none() {
+            request.schema = self.schema.clone();
+        }
+        if request.disposition.is_none() {
+            request.disposition = self.disposition.clone();
+        }
+        if request.format.is_none() {
+            request.format = self.format.clone();
+        }
+
+        let set_statements: Vec<String> = self.set_statements();
+        if !set_statements.is_empty() {
+            // The statement's terminator goes on its own line, so a trailing `--` comment in the
+            // statement cannot swallow it and the closing `END`.
+            request.statement = format!(
+                "BEGIN\n{};\n{}\n;\nEND",
+                set_statements.join(";\n"),
+                request.statement.trim().trim_end_matches(';').trim_end()
+            );
+        }
+        request
+    }
+}
+
+/// The comment `with_tag` prepends to a statement. `*/` in the tag is broken up so the tag
+/// cannot end the comment early.
+pub(crate) fn statement_tag_comment(tag: &str) -> String {
+    format!("/* rustbricks-tag: {} */", tag.replace("*/", "* /"))
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct SqlParameter {
     pub name: String,
     pub value: Option<String>,
@@ -24,7 +298,11 @@ pub struct SqlParameter {
     pub sql_type: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+// No flattened `extra` map here or on `ResultData`: it would make serde buffer the whole result
+// before deserializing it.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct SqlStatementResponse {
     pub statement_id: Option<String>,
     pub status: Option<StatementStatus>,
@@ -34,13 +312,116 @@ pub struct SqlStatementResponse {
     pub error: Option<String>,                     // Optional field to capture error messages
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl SqlStatementResponse {
+    /// The state of the statement, or `None` if the response has no status.
+    pub fn state(&self) -> Option<StatementState> {
+        self.status.as_ref().map(|status| status.state)
+    }
+
+    /// The total number of rows in the result, across all chunks.
+    pub fn row_count(&self) -> Option<i64> {
+        self.manifest
+            .as_ref()
+            .map(|manifest| manifest.total_row_count)
+    }
+
+    /// The total number of chunks in the result.
+    pub fn chunk_count(&self) -> Option<i32> {
+        self.manifest
+            .as_ref()
+            .map(|manifest| manifest.total_chunk_count)
+    }
+
+    /// The total size of the result in bytes; not reported for `INLINE` results.
+    pub fn total_bytes(&self) -> Option<i64> {
+        self.manifest
+            .as_ref()
+            .and_then(|manifest| manifest.total_byte_count)
+    }
+
+    /// Whether the result was cut short by the request's `row_limit` or `byte_limit`, or by the
+    /// 25 MiB limit on `INLINE` results.
+    pub fn is_truncated(&self) -> bool {
+        self.manifest
+            .as_ref()
+            .is_some_and(|manifest| manifest.truncated)
+    }
+}
+
+/// The execution state of a SQL statement.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum StatementState {
+    #[default]
+    Pending,
+    Running,
+    Succeeded,
+    Failed,
+    Canceled,
+    Closed, // Succeeded, but the result has been closed or has expired
+    /// A state this crate does not know yet; treated as terminal, so that waiting for the
+    /// statement does not poll forever. The state as received is logged when it is parsed.
+    Unknown,
+}
+
+impl<'de> Deserialize<'de> for StatementState {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let state: String = String::deserialize(deserializer)?;
+        Ok(match state.as_str() {
+            "PENDING" => StatementState::Pending,
+            "RUNNING" => StatementState::Running,
+            "SUCCEEDED" => StatementState::Succeeded,
+            "FAILED" => StatementState::Failed,
+            "CANCELED" => StatementState::Canceled,
+            "CLOSED" => StatementState::Closed,
+            other => {
+                log::warn!("Unknown statement state {}, treating it as terminal", other);
+                StatementState::Unknown
+            }
+        })
+    }
+}
+
+impl StatementState {
+    /// Whether the statement has finished and its state will not change again.
+    pub fn is_terminal(&self) -> bool {
+        !matches!(self, StatementState::Pending | StatementState::Running)
+    }
+
+    /// Whether the statement finished successfully and its result can be fetched.
+    pub fn is_success(&self) -> bool {
+        *self == StatementState::Succeeded
+    }
+}
+
+impl fmt::Display for StatementState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name: &str = match self {
+            StatementState::Pending => "PENDING",
+            StatementState::Running => "RUNNING",
+            StatementState::Succeeded => "SUCCEEDED",
+            StatementState::Failed => "FAILED",
+            StatementState::Canceled => "CANCELED",
+            StatementState::Closed => "CLOSED",
+            StatementState::Unknown => "UNKNOWN",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct StatementStatus {
-    pub state: String,
+    pub state: StatementState,
     pub error: Option<ErrorResponse>, // Changed from Option<String> to Option<ErrorResponse>
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct Manifest {
     pub format: String,         // "JSON_ARRAY", "ARROW_STREAM", "CSV"
     pub schema: Option<Schema>, // Schema is already optional
@@ -50,23 +431,82 @@ pub struct Manifest {
     pub total_row_count: i64,
     pub total_byte_count: Option<i64>, // Not available for INLINE disposition
     pub truncated: bool,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl Manifest {
+    /// The chunks holding any of the `limit` rows starting at row `offset`, in order.
+    pub fn chunks_for_rows(&self, offset: i64, limit: i64) -> impl Iterator<Item = &ChunkMetadata> {
+        let end: i64 = offset.saturating_add(limit);
+        self.chunks.iter().filter(move |chunk| {
+            chunk.row_offset < end && chunk.row_offset + chunk.row_count > offset
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct Schema {
     #[serde(default)]
     pub columns: Vec<ColumnDescription>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct ColumnDescription {
     pub name: String,
     #[serde(rename = "type_name")]
     data_type: String,
     position: i32,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Schema {
+    /// Converts JSON_ARRAY rows into JSON objects keyed by column name, with cells converted to
+    /// the JSON type matching the column type (numbers, booleans, and nested arrays and objects).
+    ///
+    /// Parameters:
+    /// - `rows`: The rows of one or more result chunks.
+    ///
+    /// Returns:
+    /// - One `Map` per row; `DECIMAL` cells and cells of unknown or unparseable types are kept as
+    ///   strings so that no precision is lost.
+    pub fn to_json_rows(&self, rows: &[Vec<Option<String>>]) -> Vec<Map<String, Value>> {
+        rows.iter()
+            .map(|row| {
+                self.columns
+                    .iter()
+                    .zip(row.iter())
+                    .map(|(column, cell)| {
+                        (column.name.clone(), typed_value(column, cell.as_deref()))
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl ColumnDescription {
+    /// The column's type name as reported by the API, e.g. `"INT"`, `"STRING"`, `"DECIMAL"`.
+    pub fn type_name(&self) -> &str {
+        &self.data_type
+    }
+
+    /// The zero-based position of the column in the result set.
+    pub fn position(&self) -> i32 {
+        self.position
+    }
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct ChunkMetadata {
     pub chunk_index: i32,
     pub row_offset: i64,
@@ -74,18 +514,64 @@ pub struct ChunkMetadata {
     pub byte_count: Option<i64>, // Not available for INLINE disposition
     pub next_chunk_index: Option<i32>,
     pub next_chunk_internal_link: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct ResultData {
     // Removed the fields that are not directly under `result` when `external_links` is used
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub chunk_index: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row_offset: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub row_count: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_chunk_index: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next_chunk_internal_link: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub data_array: Option<Vec<Vec<Option<String>>>>, // For INLINE, JSON_ARRAY format
     #[serde(skip_serializing_if = "Option::is_none")]
     pub external_links: Option<Vec<ExternalLink>>, // For EXTERNAL_LINKS disposition
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+impl ResultData {
+    /// Converts the inline rows of this chunk into JSON objects keyed by column name.
+    ///
+    /// Parameters:
+    /// - `schema`: The result schema from the statement's manifest.
+    ///
+    /// Returns:
+    /// - Same as `Schema::to_json_rows`; empty for `EXTERNAL_LINKS` results.
+    pub fn to_json_rows(&self, schema: &Schema) -> Vec<Map<String, Value>> {
+        schema.to_json_rows(self.data_array.as_deref().unwrap_or_default())
+    }
+
+    /// Writes the inline rows of this chunk as newline-delimited JSON, one object per line.
+    ///
+    /// Parameters:
+    /// - `schema`: The result schema from the statement's manifest.
+    /// - `writer`: Where to write, e.g. a file, a socket or a `Vec<u8>` request body.
+    ///
+    /// Returns:
+    /// - A `Result` containing the number of rows written, or the `io::Error` of the writer.
+    pub fn write_ndjson<W: io::Write>(&self, schema: &Schema, mut writer: W) -> io::Result<usize> {
+        let rows: Vec<Map<String, Value>> = self.to_json_rows(schema);
+        for row in &rows {
+            serde_json::to_writer(&mut writer, row)?;
+            writer.write_all(b"\n")?;
+        }
+        Ok(rows.len())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct ExternalLink {
     pub chunk_index: i32,
     pub row_offset: i64,
@@ -98,12 +584,77 @@ pub struct ExternalLink {
     pub external_link: String,
     #[serde(deserialize_with = "deserialize_datetime")]
     pub expiration: Option<DateTime<Utc>>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
 }
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
 pub struct ErrorResponse {
     pub error_code: Option<String>,
     pub message: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Deserializes JSON_ARRAY rows into `T`, converting the string cells to JSON values of the type
+/// declared in the schema so that numeric, boolean and complex fields deserialize naturally.
+pub(crate) fn rows_as<T: DeserializeOwned>(
+    schema: &Schema,
+    rows: &[Vec<Option<String>>],
+) -> Result<Vec<T>, serde_json::Error> {
+    schema
+        .to_json_rows(rows)
+        .into_iter()
+        .map(|object| serde_json::from_value(Value::Object(object)))
+        .collect()
+}
+
+/// Converts a single JSON_ARRAY cell (always a string) into a typed JSON value.
+fn typed_value(column: &ColumnDescription, cell: Option<&str>) -> Value {
+    let Some(cell) = cell else {
+        return Value::Null;
+    };
+    let typed: Option<Value> = match column.type_name() {
+        "BOOLEAN" => cell.parse::<bool>().ok().map(Value::Bool),
+        "BYTE" | "SHORT" | "INT" | "LONG" => {
+            cell.parse::<i64>().ok().map(|n| Value::Number(n.into()))
+        }
+        // DECIMAL stays a string: converting it to f64 would silently round exact values.
+        "FLOAT" | "DOUBLE" => cell
+            .parse::<f64>()
+            .ok()
+            .and_then(Number::from_f64)
+            .map(Value::Number),
+        "ARRAY" | "MAP" | "STRUCT" => serde_json::from_str(cell).ok(),
+        _ => None,
+    };
+    typed.unwrap_or_else(|| Value::String(cell.to_string()))
+}
+
+fn serialize_wait_timeout<S>(timeout: &Option<Duration>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match timeout {
+        Some(timeout) => serializer.serialize_str(&format!("{}s", timeout.as_secs())),
+        None => serializer.serialize_none(),
+    }
+}
+
+fn deserialize_wait_timeout<'de, D>(deserializer: D) -> Result<Option<Duration>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let s: Option<String> = Option::deserialize(deserializer)?;
+    s.map(|s| {
+        s.trim_end_matches('s')
+            .parse::<u64>()
+            .map(Duration::from_secs)
+            .map_err(serde::de::Error::custom)
+    })
+    .transpose()
 }
 
 fn deserialize_datetime<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
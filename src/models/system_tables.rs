@@ -0,0 +1,240 @@
+use crate::utils::quote_literal;
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Common filters applied when querying `system.*` tables.
+///
+/// Time bounds are applied to the table's natural time column (e.g. `usage_start_time` or
+/// `event_time`); `equals` adds exact-match conditions on arbitrary columns.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SystemTableFilter {
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+    pub workspace_id: Option<i64>,
+    pub equals: Vec<(String, String)>,
+    pub limit: Option<u32>,
+}
+
+impl SystemTableFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn since(mut self, since: DateTime<Utc>) -> Self {
+        self.since = Some(since);
+        self
+    }
+
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.until = Some(until);
+        self
+    }
+
+    pub fn workspace_id(mut self, workspace_id: i64) -> Self {
+        self.workspace_id = Some(workspace_id);
+        self
+    }
+
+    /// Adds an exact-match condition; `column` must be a plain (possibly dotted) column reference.
+    pub fn equals(mut self, column: &str, value: &str) -> Self {
+        self.equals.push((column.to_string(), value.to_string()));
+        self
+    }
+
+    pub fn limit(mut self, limit: u32) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Builds a `SELECT` over `table`, ordered by `time_column` (newest first).
+    pub(crate) fn to_sql(&self, table: &str, columns: &[&str], time_column: &str) -> String {
+        let mut conditions: Vec<String> = Vec::new();
+        if let Some(since) = self.since {
+            conditions.push(format!(
+                "{} >= {}",
+                time_column,
+                quote_literal(&since.to_rfc3339())
+            ));
+        }
+        if let Some(until) = self.until {
+            conditions.push(format!(
+                "{} < {}",
+                time_column,
+                quote_literal(&until.to_rfc3339())
+            ));
+        }
+        if let Some(workspace_id) = self.workspace_id {
+            conditions.push(format!(
+                "workspace_id = {}",
+                quote_literal(&workspace_id.to_string())
+            ));
+        }
+        for (column, value) in &self.equals {
+            let column: String = column
+                .chars()
+                .filter(|c| c.is_ascii_alphanumeric() || *c == '_' || *c == '.')
+                .collect();
+            conditions.push(format!("{} = {}", column, quote_literal(value)));
+        }
+
+        let mut statement: String = format!("SELECT {} FROM {}", columns.join(", "), table);
+        if !conditions.is_empty() {
+            statement.push_str(&format!(" WHERE {}", conditions.join(" AND ")));
+        }
+        statement.push_str(&format!(" ORDER BY {} DESC", time_column));
+        if let Some(limit) = self.limit {
+            statement.push_str(&format!(" LIMIT {}", limit));
+        }
+        statement
+    }
+}
+
+/// A row of `system.billing.usage`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BillingUsageRecord {
+    pub record_id: String,
+    pub account_id: String,
+    pub workspace_id: String,
+    pub sku_name: String,
+    pub cloud: String,
+    pub usage_start_time: DateTime<Utc>,
+    pub usage_end_time: DateTime<Utc>,
+    pub usage_date: NaiveDate,
+    pub usage_unit: String,
+    pub usage_quantity: f64,
+    #[serde(default)]
+    pub custom_tags: Option<HashMap<String, String>>,
+    pub billing_origin_product: Option<String>,
+    pub usage_metadata: Option<serde_json::Value>,
+}
+
+impl BillingUsageRecord {
+    pub(crate) const COLUMNS: &'static [&'static str] = &[
+        "record_id",
+        "account_id",
+        "workspace_id",
+        "sku_name",
+        "cloud",
+        "usage_start_time",
+        "usage_end_time",
+        "usage_date",
+        "usage_unit",
+        // DECIMAL columns are returned as strings; cast so the value fits `usage_quantity: f64`.
+        "CAST(usage_quantity AS DOUBLE) AS usage_quantity",
+        "custom_tags",
+        "billing_origin_product",
+        "usage_metadata",
+    ];
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditUserIdentity {
+    pub email: Option<String>,
+    #[serde(alias = "subjectName")]
+    pub subject_name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditResponse {
+    #[serde(alias = "statusCode")]
+    pub status_code: Option<i64>,
+    #[serde(alias = "errorMessage")]
+    pub error_message: Option<String>,
+    pub result: Option<String>,
+}
+
+/// A row of `system.access.audit`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AuditLogRecord {
+    pub account_id: String,
+    pub workspace_id: Option<String>,
+    pub version: Option<String>,
+    pub event_time: DateTime<Utc>,
+    pub event_date: NaiveDate,
+    pub source_ip_address: Option<String>,
+    pub user_agent: Option<String>,
+    pub session_id: Option<String>,
+    pub user_identity: Option<AuditUserIdentity>,
+    pub service_name: String,
+    pub action_name: String,
+    pub request_id: Option<String>,
+    #[serde(default)]
+    pub request_params: Option<HashMap<String, String>>,
+    pub response: Option<AuditResponse>,
+    pub audit_level: Option<String>,
+}
+
+impl AuditLogRecord {
+    pub(crate) const COLUMNS: &'static [&'static str] = &[
+        "account_id",
+        "workspace_id",
+        "version",
+        "event_time",
+        "event_date",
+        "source_ip_address",
+        "user_agent",
+        "session_id",
+        "user_identity",
+        "service_name",
+        "action_name",
+        "request_id",
+        "request_params",
+        "response",
+        "audit_level",
+    ];
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryCompute {
+    #[serde(rename = "type")]
+    pub compute_type: Option<String>, // "WAREHOUSE" or "SERVERLESS_COMPUTE"
+    pub warehouse_id: Option<String>,
+    pub cluster_id: Option<String>,
+}
+
+/// A row of `system.query.history`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryHistoryRecord {
+    pub account_id: String,
+    pub workspace_id: String,
+    pub statement_id: String,
+    pub session_id: Option<String>,
+    pub executed_by: Option<String>,
+    pub statement_text: Option<String>,
+    pub statement_type: Option<String>,
+    pub execution_status: Option<String>, // "FINISHED", "FAILED", "CANCELED"
+    pub compute: Option<QueryCompute>,
+    pub error_message: Option<String>,
+    pub client_application: Option<String>,
+    pub start_time: DateTime<Utc>,
+    pub end_time: Option<DateTime<Utc>>,
+    pub total_duration_ms: Option<i64>,
+    pub execution_duration_ms: Option<i64>,
+    pub read_rows: Option<i64>,
+    pub read_bytes: Option<i64>,
+    pub produced_rows: Option<i64>,
+}
+
+impl QueryHistoryRecord {
+    pub(crate) const COLUMNS: &'static [&'static str] = &[
+        "account_id",
+        "workspace_id",
+        "statement_id",
+        "session_id",
+        "executed_by",
+        "statement_text",
+        "statement_type",
+        "execution_status",
+        "compute",
+        "error_message",
+        "client_application",
+        "start_time",
+        "end_time",
+        "total_duration_ms",
+        "execution_duration_ms",
+        "read_rows",
+        "read_bytes",
+        "produced_rows",
+    ];
+}
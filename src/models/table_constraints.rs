@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrimaryKeyConstraint {
+    pub name: String,
+    pub child_columns: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub timeseries_columns: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ForeignKeyConstraint {
+    pub name: String,
+    pub child_columns: Vec<String>,
+    pub parent_table: String, // Full name of the referenced table
+    pub parent_columns: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NamedTableConstraint {
+    pub name: String,
+}
+
+/// A Unity Catalog table constraint. Constraints are informational and not enforced.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TableConstraint {
+    PrimaryKeyConstraint(PrimaryKeyConstraint),
+    ForeignKeyConstraint(ForeignKeyConstraint),
+    NamedTableConstraint(NamedTableConstraint),
+}
+
+impl TableConstraint {
+    pub fn name(&self) -> &str {
+        match self {
+            TableConstraint::PrimaryKeyConstraint(constraint) => &constraint.name,
+            TableConstraint::ForeignKeyConstraint(constraint) => &constraint.name,
+            TableConstraint::NamedTableConstraint(constraint) => &constraint.name,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateTableConstraintRequest {
+    pub full_name_arg: String, // Full name of the table the constraint is added to
+    pub constraint: TableConstraint,
+}
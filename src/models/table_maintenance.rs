@@ -0,0 +1,203 @@
+use crate::utils::{quote_identifier, quote_qualified_name};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// An `OPTIMIZE` of a Delta table, optionally restricted to some partitions and Z-ordered.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OptimizeCommand {
+    pub table: String,
+    pub predicate: Option<String>,
+    pub zorder_by: Vec<String>,
+}
+
+impl OptimizeCommand {
+    /// Compacts the whole table; `table` is its (possibly qualified) name, e.g. `main.sales.orders`.
+    pub fn new(table: &str) -> Self {
+        OptimizeCommand {
+            table: table.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Restricts the operation to the partitions matching `predicate`, e.g. `date >= '2024-01-01'`.
+    ///
+    /// The predicate is SQL text and is inserted as given; it may only reference partition columns.
+    pub fn with_predicate(mut self, predicate: &str) -> Self {
+        self.predicate = Some(predicate.to_string());
+        self
+    }
+
+    /// Co-locates rows by the given columns (`ZORDER BY`).
+    pub fn with_zorder_by(mut self, columns: &[&str]) -> Self {
+        self.zorder_by = columns.iter().map(|column| column.to_string()).collect();
+        self
+    }
+
+    /// The `OPTIMIZE` statement, with the table and Z-order columns quoted.
+    pub fn to_sql(&self) -> String {
+        let mut statement: String = format!("OPTIMIZE {}", quote_qualified_name(&self.table));
+        if let Some(predicate) = &self.predicate {
+            statement.push_str(&format!(" WHERE {}", predicate));
+        }
+        if !self.zorder_by.is_empty() {
+            let columns: Vec<String> = self
+                .zorder_by
+                .iter()
+                .map(|column| quote_identifier(column))
+                .collect();
+            statement.push_str(&format!(" ZORDER BY ({})", columns.join(", ")));
+        }
+        statement
+    }
+}
+
+/// A `VACUUM` of a Delta table, removing data files no longer referenced by versions within the
+/// retention period.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VacuumCommand {
+    pub table: String,
+    pub retain_hours: Option<u32>,
+    pub dry_run: bool,
+}
+
+impl VacuumCommand {
+    /// Vacuums the table with its configured retention (7 days unless the table overrides it).
+    pub fn new(table: &str) -> Self {
+        VacuumCommand {
+            table: table.to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// Keeps files needed by versions of the last `hours` hours. Values below 168 are rejected by
+    /// Databricks unless the retention duration check is disabled.
+    pub fn with_retain_hours(mut self, hours: u32) -> Self {
+        self.retain_hours = Some(hours);
+        self
+    }
+
+    /// Lists the files that would be deleted instead of deleting them.
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// The `VACUUM` statement, with the table quoted.
+    pub fn to_sql(&self) -> String {
+        let mut statement: String = format!("VACUUM {}", quote_qualified_name(&self.table));
+        if let Some(hours) = self.retain_hours {
+            statement.push_str(&format!(" RETAIN {} HOURS", hours));
+        }
+        if self.dry_run {
+            statement.push_str(" DRY RUN");
+        }
+        statement
+    }
+}
+
+/// Which statistics `ANALYZE TABLE` computes.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum AnalyzeScope {
+    /// Table size and row count.
+    #[default]
+    Table,
+    /// Table size only, without scanning the data.
+    NoScan,
+    /// Table statistics and column statistics for every column.
+    AllColumns,
+    /// Table statistics and column statistics for the given columns.
+    Columns(Vec<String>),
+}
+
+/// An `ANALYZE TABLE ... COMPUTE STATISTICS`, collecting statistics for the query optimizer.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AnalyzeCommand {
+    pub table: String,
+    pub scope: AnalyzeScope,
+}
+
+impl AnalyzeCommand {
+    /// Computes the statistics of `scope` for `table`.
+    pub fn new(table: &str, scope: AnalyzeScope) -> Self {
+        AnalyzeCommand {
+            table: table.to_string(),
+            scope,
+        }
+    }
+
+    /// The `ANALYZE TABLE` statement, with the table and columns quoted.
+    pub fn to_sql(&self) -> String {
+        let statement: String = format!(
+            "ANALYZE TABLE {} COMPUTE STATISTICS",
+            quote_qualified_name(&self.table)
+        );
+        match &self.scope {
+            AnalyzeScope::Table => statement,
+            AnalyzeScope::NoScan => format!("{} NOSCAN", statement),
+            AnalyzeScope::AllColumns => format!("{} FOR ALL COLUMNS", statement),
+            AnalyzeScope::Columns(columns) => {
+                let columns: Vec<String> = columns
+                    .iter()
+                    .map(|column| quote_identifier(column))
+                    .collect();
+                format!("{} FOR COLUMNS {}", statement, columns.join(", "))
+            }
+        }
+    }
+}
+
+/// Size statistics of the files added or removed by `OPTIMIZE`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default, rename_all = "camelCase")]
+pub struct FileSizeStats {
+    pub min: Option<i64>,
+    pub max: Option<i64>,
+    pub avg: Option<f64>,
+    pub total_files: Option<i64>,
+    pub total_size: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The metrics reported by `OPTIMIZE`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default, rename_all = "camelCase")]
+pub struct OptimizeMetrics {
+    pub num_files_added: Option<i64>,
+    pub num_files_removed: Option<i64>,
+    pub files_added: Option<FileSizeStats>,
+    pub files_removed: Option<FileSizeStats>,
+    pub partitions_optimized: Option<i64>,
+    pub num_batches: Option<i64>,
+    pub total_considered_files: Option<i64>,
+    pub total_files_skipped: Option<i64>,
+    pub z_order_stats: Option<Value>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The row returned by `OPTIMIZE`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct OptimizeResultRow {
+    pub path: Option<String>,
+    pub metrics: OptimizeMetrics,
+}
+
+/// A row returned by `VACUUM`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub(crate) struct VacuumResultRow {
+    pub path: Option<String>,
+}
+
+/// The outcome of `VACUUM`.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct VacuumOutcome {
+    /// Whether this was a dry run, in which case `paths` lists the files that would be deleted.
+    pub dry_run: bool,
+    /// The files to delete for a dry run, or the table location otherwise.
+    pub paths: Vec<String>,
+}
@@ -0,0 +1,31 @@
+use crate::errors::HttpError;
+
+/// Progress of `DatabricksSession::for_each_table`, reported after each table completes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableProgress {
+    /// The full name of the table that just completed.
+    pub table: String,
+    /// Whether the operation succeeded on it.
+    pub succeeded: bool,
+    /// The number of tables completed so far, including this one.
+    pub completed: usize,
+    /// The number of those that failed.
+    pub failed: usize,
+    /// The number of tables the operation runs on.
+    pub total: usize,
+}
+
+/// The outcome of `DatabricksSession::for_each_table`: the result of every table, keyed by its
+/// full name, in completion order.
+#[derive(Debug, Default)]
+pub struct TableOperationReport<T> {
+    pub succeeded: Vec<(String, T)>,
+    pub failed: Vec<(String, HttpError)>,
+}
+
+impl<T> TableOperationReport<T> {
+    /// Whether the operation succeeded on every table.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum TableOperation {
+    Read,
+    ReadWrite,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct GenerateTemporaryTableCredentialRequest {
+    pub table_id: String,
+    pub operation: TableOperation,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct AwsTemporaryCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: String,
+    pub access_point: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct AzureUserDelegationSas {
+    pub sas_token: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct AzureActiveDirectoryToken {
+    pub aad_token: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct GcpOauthToken {
+    pub oauth_token: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct R2TemporaryCredentials {
+    pub access_key_id: String,
+    pub secret_access_key: String,
+    pub session_token: String,
+}
+
+/// Short-lived, down-scoped cloud credentials for reading (or writing) a table's files directly
+/// from object storage. Exactly one of the cloud-specific fields is set.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct TemporaryTableCredentials {
+    pub aws_temp_credentials: Option<AwsTemporaryCredentials>,
+    pub azure_user_delegation_sas: Option<AzureUserDelegationSas>,
+    pub azure_aad: Option<AzureActiveDirectoryToken>,
+    pub gcp_oauth_token: Option<GcpOauthToken>,
+    pub r2_temp_credentials: Option<R2TemporaryCredentials>,
+    pub expiration_time: Option<i64>, // Milliseconds since the epoch
+    pub url: Option<String>,          // Storage location of the table
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl TemporaryTableCredentials {
+    /// Returns `true` if the credentials expire within `margin_ms` milliseconds of `now_ms`.
+    pub fn expires_within(&self, now_ms: i64, margin_ms: i64) -> bool {
+        match self.expiration_time {
+            Some(expiration_time) => expiration_time - now_ms <= margin_ms,
+            None => false,
+        }
+    }
+}
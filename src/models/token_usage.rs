@@ -0,0 +1,74 @@
+use crate::models::{HasTokenUsage, TokenUsage};
+use std::{
+    collections::BTreeMap,
+    sync::{Arc, Mutex},
+};
+
+/// The tokens consumed by one tenant, summed over its requests.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MeteredUsage {
+    /// The number of responses that reported usage.
+    pub requests: u64,
+    pub usage: TokenUsage,
+}
+
+/// Accumulates the token usage of LLM endpoint calls per tenant, e.g. for metering or billing.
+///
+/// Clones share the same totals, so one meter can be handed to every task serving requests.
+#[derive(Debug, Clone, Default)]
+pub struct TokenUsageMeter {
+    totals: Arc<Mutex<BTreeMap<String, MeteredUsage>>>,
+}
+
+impl TokenUsageMeter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds `usage` to the totals of `tenant`.
+    pub fn record(&self, tenant: &str, usage: TokenUsage) {
+        let mut totals = self.totals.lock().unwrap_or_else(|err| err.into_inner());
+        let metered: &mut MeteredUsage = totals.entry(tenant.to_string()).or_default();
+        metered.requests += 1;
+        metered.usage += usage;
+    }
+
+    /// Adds the usage reported by `response` to the totals of `tenant`, and passes the usage on.
+    ///
+    /// Responses without usage, such as stream chunks before the last one, are not counted.
+    pub fn record_response(
+        &self,
+        tenant: &str,
+        response: &impl HasTokenUsage,
+    ) -> Option<TokenUsage> {
+        let usage: Option<TokenUsage> = response.token_usage();
+        if let Some(usage) = usage {
+            self.record(tenant, usage);
+        }
+        usage
+    }
+
+    /// The totals of `tenant` so far.
+    pub fn usage(&self, tenant: &str) -> MeteredUsage {
+        self.totals
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .get(tenant)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// The totals of every tenant so far.
+    pub fn snapshot(&self) -> BTreeMap<String, MeteredUsage> {
+        self.totals
+            .lock()
+            .unwrap_or_else(|err| err.into_inner())
+            .clone()
+    }
+
+    /// Returns the totals of every tenant and starts counting from zero, e.g. at the end of a
+    /// reporting period.
+    pub fn take(&self) -> BTreeMap<String, MeteredUsage> {
+        std::mem::take(&mut *self.totals.lock().unwrap_or_else(|err| err.into_inner()))
+    }
+}
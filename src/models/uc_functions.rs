@@ -0,0 +1,277 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// Whether the function body is SQL or an external language such as Python.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RoutineBody {
+    Sql,
+    External,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum SqlDataAccess {
+    ContainsSql,
+    ReadsSqlData,
+    NoSql,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FunctionParameterMode {
+    In,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum FunctionParameterType {
+    Param,
+    Column,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FunctionParameterInfo {
+    pub name: String,
+    pub type_text: String, // e.g. "int", "array<string>"
+    pub type_name: String, // e.g. "INT", "ARRAY", "STRING"
+    pub position: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_json: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_precision: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_scale: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub type_interval_type: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter_mode: Option<FunctionParameterMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter_type: Option<FunctionParameterType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub parameter_default: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FunctionParameterInfos {
+    #[serde(default)]
+    pub parameters: Vec<FunctionParameterInfo>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FunctionDependency {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub table: Option<TableDependency>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub function: Option<FunctionNameDependency>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TableDependency {
+    pub table_full_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FunctionNameDependency {
+    pub function_full_name: String,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DependencyList {
+    #[serde(default)]
+    pub dependencies: Vec<FunctionDependency>,
+}
+
+/// The definition of a Unity Catalog function to create.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateFunction {
+    pub name: String,
+    pub catalog_name: String,
+    pub schema_name: String,
+    pub input_params: FunctionParameterInfos,
+    pub data_type: String,      // Return type name, e.g. "INT" or "TABLE_TYPE"
+    pub full_data_type: String, // Full return type, e.g. "int" or "array<string>"
+    pub routine_body: RoutineBody,
+    pub routine_definition: String,
+    pub parameter_style: String, // Always "S"
+    pub is_deterministic: bool,
+    pub sql_data_access: SqlDataAccess,
+    pub is_null_call: bool,
+    pub security_type: String, // Always "DEFINER"
+    pub specific_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub return_params: Option<FunctionParameterInfos>, // For table functions
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub routine_dependencies: Option<DependencyList>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_language: Option<String>, // e.g. "Python"
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sql_path: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<String>, // JSON-serialized key-value pairs
+}
+
+impl CreateFunction {
+    /// Creates a deterministic SQL scalar function definition with sensible defaults.
+    ///
+    /// Parameters:
+    /// - `catalog_name`, `schema_name`, `name`: Where to create the function.
+    /// - `parameters`: The input parameters of the function.
+    /// - `return_type`: The return type as SQL text, e.g. `"int"`.
+    /// - `definition`: The SQL expression forming the function body.
+    pub fn sql(
+        catalog_name: &str,
+        schema_name: &str,
+        name: &str,
+        parameters: Vec<FunctionParameterInfo>,
+        return_type: &str,
+        definition: &str,
+    ) -> Self {
+        CreateFunction {
+            name: name.to_string(),
+            catalog_name: catalog_name.to_string(),
+            schema_name: schema_name.to_string(),
+            input_params: FunctionParameterInfos { parameters },
+            data_type: base_type_name(return_type),
+            full_data_type: return_type.to_string(),
+            routine_body: RoutineBody::Sql,
+            routine_definition: definition.to_string(),
+            parameter_style: "S".to_string(),
+            is_deterministic: true,
+            sql_data_access: SqlDataAccess::ContainsSql,
+            is_null_call: false,
+            security_type: "DEFINER".to_string(),
+            specific_name: name.to_string(),
+            return_params: None,
+            routine_dependencies: None,
+            external_language: None,
+            external_name: None,
+            sql_path: None,
+            comment: None,
+            properties: None,
+        }
+    }
+
+    /// Creates a Python scalar function definition (a Python UDF).
+    ///
+    /// `definition` is the Python function body; its return value becomes the function result.
+    pub fn python(
+        catalog_name: &str,
+        schema_name: &str,
+        name: &str,
+        parameters: Vec<FunctionParameterInfo>,
+        return_type: &str,
+        definition: &str,
+    ) -> Self {
+        CreateFunction {
+            routine_body: RoutineBody::External,
+            external_language: Some("Python".to_string()),
+            sql_data_access: SqlDataAccess::NoSql,
+            ..Self::sql(
+                catalog_name,
+                schema_name,
+                name,
+                parameters,
+                return_type,
+                definition,
+            )
+        }
+    }
+}
+
+impl FunctionParameterInfo {
+    /// Creates an input parameter at `position` with the given SQL type text (e.g. `"string"`).
+    pub fn new(name: &str, type_text: &str, position: i32) -> Self {
+        FunctionParameterInfo {
+            name: name.to_string(),
+            type_text: type_text.to_string(),
+            type_name: base_type_name(type_text),
+            position,
+            parameter_mode: Some(FunctionParameterMode::In),
+            parameter_type: Some(FunctionParameterType::Param),
+            ..Default::default()
+        }
+    }
+}
+
+/// Maps SQL type text such as `decimal(10,2)` or `array<int>` onto the API type name (`DECIMAL`, `ARRAY`).
+fn base_type_name(type_text: &str) -> String {
+    let base: &str = type_text
+        .split(['(', '<'])
+        .next()
+        .unwrap_or(type_text)
+        .trim();
+    match base.to_ascii_uppercase().as_str() {
+        "BIGINT" => "LONG".to_string(),
+        "SMALLINT" => "SHORT".to_string(),
+        "TINYINT" => "BYTE".to_string(),
+        "INTEGER" => "INT".to_string(),
+        "REAL" => "FLOAT".to_string(),
+        other => other.to_string(),
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateFunctionRequest {
+    pub function_info: CreateFunction,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateFunctionRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct FunctionInfo {
+    pub name: String,
+    pub catalog_name: String,
+    pub schema_name: String,
+    pub full_name: Option<String>,
+    pub function_id: Option<String>,
+    pub owner: Option<String>,
+    pub comment: Option<String>,
+    pub input_params: Option<FunctionParameterInfos>,
+    pub return_params: Option<FunctionParameterInfos>,
+    pub data_type: Option<String>,
+    pub full_data_type: Option<String>,
+    pub routine_body: Option<RoutineBody>,
+    pub routine_definition: Option<String>,
+    pub routine_dependencies: Option<DependencyList>,
+    pub parameter_style: Option<String>,
+    pub is_deterministic: Option<bool>,
+    pub sql_data_access: Option<SqlDataAccess>,
+    pub is_null_call: Option<bool>,
+    pub security_type: Option<String>,
+    pub specific_name: Option<String>,
+    pub external_language: Option<String>,
+    pub external_name: Option<String>,
+    pub sql_path: Option<String>,
+    pub properties: Option<String>,
+    pub metastore_id: Option<String>,
+    pub created_at: Option<i64>,
+    pub created_by: Option<String>,
+    pub updated_at: Option<i64>,
+    pub updated_by: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListFunctionsResponse {
+    #[serde(default)]
+    pub functions: Vec<FunctionInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,198 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+
+/// Whether a catalog is accessible from every workspace attached to the metastore (`Open`) or
+/// only from the workspaces it is explicitly bound to (`Isolated`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IsolationMode {
+    #[serde(rename = "ISOLATION_MODE_OPEN", alias = "OPEN")]
+    Open,
+    #[serde(rename = "ISOLATION_MODE_ISOLATED", alias = "ISOLATED")]
+    Isolated,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateCatalogRequest {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_root: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub provider_name: Option<String>, // For Delta Sharing catalogs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub share_name: Option<String>, // For Delta Sharing catalogs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub connection_name: Option<String>, // For foreign catalogs
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub options: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateCatalogRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub isolation_mode: Option<IsolationMode>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct CatalogInfo {
+    pub name: String,
+    pub full_name: Option<String>,
+    pub owner: Option<String>,
+    pub comment: Option<String>,
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+    pub storage_root: Option<String>,
+    pub storage_location: Option<String>,
+    pub isolation_mode: Option<IsolationMode>,
+    pub catalog_type: Option<String>, // "MANAGED_CATALOG", "DELTASHARING_CATALOG", ...
+    pub provider_name: Option<String>,
+    pub share_name: Option<String>,
+    pub connection_name: Option<String>,
+    pub metastore_id: Option<String>,
+    pub created_at: Option<i64>,
+    pub created_by: Option<String>,
+    pub updated_at: Option<i64>,
+    pub updated_by: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListCatalogsResponse {
+    #[serde(default)]
+    pub catalogs: Vec<CatalogInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CreateSchemaRequest {
+    pub name: String,
+    pub catalog_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<HashMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub storage_root: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct UpdateSchemaRequest {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub comment: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub properties: Option<HashMap<String, String>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct SchemaInfo {
+    pub name: String,
+    pub catalog_name: String,
+    pub full_name: Option<String>,
+    pub owner: Option<String>,
+    pub comment: Option<String>,
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+    pub storage_root: Option<String>,
+    pub storage_location: Option<String>,
+    pub catalog_type: Option<String>,
+    pub metastore_id: Option<String>,
+    pub created_at: Option<i64>,
+    pub created_by: Option<String>,
+    pub updated_at: Option<i64>,
+    pub updated_by: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListSchemasResponse {
+    #[serde(default)]
+    pub schemas: Vec<SchemaInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ColumnInfo {
+    pub name: String,
+    pub type_text: Option<String>, // e.g. "decimal(10,2)"
+    pub type_name: Option<String>, // e.g. "DECIMAL"
+    pub type_json: Option<String>,
+    pub type_precision: Option<i32>,
+    pub type_scale: Option<i32>,
+    pub position: Option<i32>,
+    pub nullable: Option<bool>,
+    pub comment: Option<String>,
+    pub partition_index: Option<i32>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct TableInfo {
+    pub name: String,
+    pub catalog_name: String,
+    pub schema_name: String,
+    pub full_name: Option<String>,
+    pub table_id: Option<String>,
+    pub table_type: Option<String>, // "MANAGED", "EXTERNAL", "VIEW", ...
+    pub data_source_format: Option<String>, // "DELTA", "PARQUET", ...
+    #[serde(default)]
+    pub columns: Vec<ColumnInfo>,
+    pub storage_location: Option<String>,
+    pub view_definition: Option<String>,
+    pub owner: Option<String>,
+    pub comment: Option<String>,
+    #[serde(default)]
+    pub properties: HashMap<String, String>,
+    pub metastore_id: Option<String>,
+    pub created_at: Option<i64>,
+    pub created_by: Option<String>,
+    pub updated_at: Option<i64>,
+    pub updated_by: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListTablesResponse {
+    #[serde(default)]
+    pub tables: Vec<TableInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
@@ -0,0 +1,445 @@
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VectorSearchEndpointType {
+    Standard,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateVectorSearchEndpointRequest {
+    pub name: String,
+    pub endpoint_type: VectorSearchEndpointType,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct VectorSearchEndpointStatus {
+    pub state: Option<String>, // "PROVISIONING", "ONLINE", "OFFLINE"
+    pub message: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct VectorSearchEndpointInfo {
+    pub name: String,
+    pub id: Option<String>,
+    pub endpoint_type: Option<VectorSearchEndpointType>,
+    pub endpoint_status: Option<VectorSearchEndpointStatus>,
+    pub num_indexes: Option<i64>,
+    pub creator: Option<String>,
+    pub creation_timestamp: Option<i64>,
+    pub last_updated_user: Option<String>,
+    pub last_updated_timestamp: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl VectorSearchEndpointInfo {
+    /// Whether the endpoint is online and can serve queries.
+    pub fn is_online(&self) -> bool {
+        self.endpoint_status
+            .as_ref()
+            .and_then(|status| status.state.as_deref())
+            == Some("ONLINE")
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListVectorSearchEndpointsResponse {
+    #[serde(default)]
+    pub endpoints: Vec<VectorSearchEndpointInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VectorIndexType {
+    DeltaSync,    // Kept in sync with a source Delta table
+    DirectAccess, // Written to directly through upsert/delete calls
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VectorIndexPipelineType {
+    Triggered,
+    Continuous,
+}
+
+/// A text column from which embeddings are computed by a model serving endpoint.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingSourceColumn {
+    pub name: String,
+    pub embedding_model_endpoint_name: String,
+}
+
+/// A column holding precomputed embedding vectors.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EmbeddingVectorColumn {
+    pub name: String,
+    pub embedding_dimension: u32,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeltaSyncVectorIndexSpec {
+    pub source_table: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pipeline_type: Option<VectorIndexPipelineType>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub embedding_source_columns: Vec<EmbeddingSourceColumn>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub embedding_vector_columns: Vec<EmbeddingVectorColumn>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub columns_to_sync: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub embedding_writeback_table: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pipeline_id: Option<String>, // Output only
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct DirectAccessVectorIndexSpec {
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub embedding_source_columns: Vec<EmbeddingSourceColumn>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub embedding_vector_columns: Vec<EmbeddingVectorColumn>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub schema_json: Option<String>, // JSON object mapping column names to types
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CreateVectorIndexRequest {
+    pub name: String, // Full Unity Catalog name, e.g. "main.rag.docs_index"
+    pub endpoint_name: String,
+    pub primary_key: String,
+    pub index_type: VectorIndexType,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delta_sync_index_spec: Option<DeltaSyncVectorIndexSpec>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub direct_access_index_spec: Option<DirectAccessVectorIndexSpec>,
+}
+
+impl CreateVectorIndexRequest {
+    /// Creates a Delta Sync index request.
+    pub fn delta_sync(
+        name: &str,
+        endpoint_name: &str,
+        primary_key: &str,
+        spec: DeltaSyncVectorIndexSpec,
+    ) -> Self {
+        CreateVectorIndexRequest {
+            name: name.to_string(),
+            endpoint_name: endpoint_name.to_string(),
+            primary_key: primary_key.to_string(),
+            index_type: VectorIndexType::DeltaSync,
+            delta_sync_index_spec: Some(spec),
+            direct_access_index_spec: None,
+        }
+    }
+
+    /// Creates a Direct Vector Access index request.
+    pub fn direct_access(
+        name: &str,
+        endpoint_name: &str,
+        primary_key: &str,
+        spec: DirectAccessVectorIndexSpec,
+    ) -> Self {
+        CreateVectorIndexRequest {
+            name: name.to_string(),
+            endpoint_name: endpoint_name.to_string(),
+            primary_key: primary_key.to_string(),
+            index_type: VectorIndexType::DirectAccess,
+            delta_sync_index_spec: None,
+            direct_access_index_spec: Some(spec),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct VectorIndexStatus {
+    pub ready: Option<bool>,
+    pub message: Option<String>,
+    pub indexed_row_count: Option<i64>,
+    pub index_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct VectorIndexInfo {
+    pub name: String,
+    pub endpoint_name: Option<String>,
+    pub primary_key: Option<String>,
+    pub index_type: Option<VectorIndexType>,
+    pub delta_sync_index_spec: Option<DeltaSyncVectorIndexSpec>,
+    pub direct_access_index_spec: Option<DirectAccessVectorIndexSpec>,
+    pub status: Option<VectorIndexStatus>,
+    pub creator: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl VectorIndexInfo {
+    /// Whether the index has finished its initial build and can serve queries.
+    pub fn is_ready(&self) -> bool {
+        self.status
+            .as_ref()
+            .and_then(|status| status.ready)
+            .unwrap_or(false)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListVectorIndexesResponse {
+    #[serde(default)]
+    pub vector_indexes: Vec<VectorIndexInfo>,
+    pub next_page_token: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A filter over index columns, serialized to the `filters_json` format understood by the query API.
+///
+/// Conditions are ANDed together; e.g. `VectorSearchFilter::new().eq("lang", "en").lt("price", 100)`
+/// becomes `{"lang": "en", "price <": 100}`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VectorSearchFilter {
+    conditions: Map<String, Value>,
+}
+
+impl VectorSearchFilter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn condition(mut self, key: String, value: impl Into<Value>) -> Self {
+        self.conditions.insert(key, value.into());
+        self
+    }
+
+    /// Matches rows where `column` equals `value`.
+    pub fn eq(self, column: &str, value: impl Into<Value>) -> Self {
+        self.condition(column.to_string(), value)
+    }
+
+    /// Matches rows where `column` does not equal `value`.
+    pub fn ne(self, column: &str, value: impl Into<Value>) -> Self {
+        self.condition(format!("{} NOT", column), value)
+    }
+
+    /// Matches rows where `column` equals any of `values`.
+    pub fn is_in<V: Into<Value>>(self, column: &str, values: Vec<V>) -> Self {
+        let values: Vec<Value> = values.into_iter().map(Into::into).collect();
+        self.condition(column.to_string(), values)
+    }
+
+    pub fn lt(self, column: &str, value: impl Into<Value>) -> Self {
+        self.condition(format!("{} <", column), value)
+    }
+
+    pub fn le(self, column: &str, value: impl Into<Value>) -> Self {
+        self.condition(format!("{} <=", column), value)
+    }
+
+    pub fn gt(self, column: &str, value: impl Into<Value>) -> Self {
+        self.condition(format!("{} >", column), value)
+    }
+
+    pub fn ge(self, column: &str, value: impl Into<Value>) -> Self {
+        self.condition(format!("{} >=", column), value)
+    }
+
+    /// Matches rows where `column` contains the token `value`.
+    pub fn like(self, column: &str, value: &str) -> Self {
+        self.condition(format!("{} LIKE", column), value)
+    }
+
+    /// Matches rows where any of `columns` equals any of `values`.
+    pub fn any_of<V: Into<Value>>(self, columns: &[&str], values: Vec<V>) -> Self {
+        let values: Vec<Value> = values.into_iter().map(Into::into).collect();
+        self.condition(columns.join(" OR "), values)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.conditions.is_empty()
+    }
+
+    /// Serializes the filter to the JSON string expected in `filters_json`.
+    pub fn to_json(&self) -> String {
+        Value::Object(self.conditions.clone()).to_string()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VectorQueryType {
+    Ann,
+    Hybrid,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct QueryVectorIndexRequest {
+    pub columns: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_text: Option<String>, // For indexes with embedding source columns
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_vector: Option<Vec<f32>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub num_results: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filters_json: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_type: Option<VectorQueryType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub score_threshold: Option<f64>,
+}
+
+impl QueryVectorIndexRequest {
+    /// Creates a similarity search by text, returning `columns` for the top `num_results` matches.
+    pub fn text(query_text: &str, columns: &[&str], num_results: u32) -> Self {
+        QueryVectorIndexRequest {
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+            query_text: Some(query_text.to_string()),
+            num_results: Some(num_results),
+            ..Default::default()
+        }
+    }
+
+    /// Creates a similarity search by embedding vector.
+    pub fn vector(query_vector: Vec<f32>, columns: &[&str], num_results: u32) -> Self {
+        QueryVectorIndexRequest {
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+            query_vector: Some(query_vector),
+            num_results: Some(num_results),
+            ..Default::default()
+        }
+    }
+
+    /// Restricts results to rows matching `filter`.
+    pub fn with_filter(mut self, filter: &VectorSearchFilter) -> Self {
+        self.filters_json = (!filter.is_empty()).then(|| filter.to_json());
+        self
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct VectorResultColumn {
+    pub name: String,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct VectorResultManifest {
+    pub column_count: Option<i64>,
+    #[serde(default)]
+    pub columns: Vec<VectorResultColumn>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct VectorResultData {
+    pub row_count: Option<i64>,
+    #[serde(default)]
+    pub data_array: Vec<Vec<Value>>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct QueryVectorIndexResponse {
+    #[serde(default)]
+    pub manifest: VectorResultManifest,
+    #[serde(default)]
+    pub result: VectorResultData,
+    pub next_page_token: Option<String>,
+}
+
+impl QueryVectorIndexResponse {
+    /// Returns each result row as a JSON object keyed by column name. The similarity score is
+    /// included under the `score` column.
+    pub fn records(&self) -> Vec<Map<String, Value>> {
+        self.result
+            .data_array
+            .iter()
+            .map(|row| {
+                self.manifest
+                    .columns
+                    .iter()
+                    .zip(row)
+                    .map(|(column, value)| (column.name.clone(), value.clone()))
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Deserializes each result row into `T`, matching fields by column name.
+    pub fn records_as<T: DeserializeOwned>(&self) -> Result<Vec<T>, serde_json::Error> {
+        self.records()
+            .into_iter()
+            .map(|record| serde_json::from_value(Value::Object(record)))
+            .collect()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct UpsertVectorIndexDataRequest {
+    pub inputs_json: String, // JSON array of row objects
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct DeleteVectorIndexDataRequest {
+    pub primary_keys: Vec<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum VectorIndexWriteStatus {
+    Success,
+    PartialSuccess,
+    Failure,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct VectorIndexWriteResult {
+    pub success_row_count: Option<i64>,
+    #[serde(default)]
+    pub failed_primary_keys: Vec<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct VectorIndexWriteResponse {
+    pub status: Option<VectorIndexWriteStatus>,
+    #[serde(default)]
+    pub result: VectorIndexWriteResult,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
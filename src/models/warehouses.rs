@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::{fmt, time::Duration};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum WarehouseState {
+    Starting,
+    Running,
+    Stopping,
+    Stopped,
+    Deleting,
+    Deleted,
+}
+
+impl fmt::Display for WarehouseState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name: &str = match self {
+            WarehouseState::Starting => "STARTING",
+            WarehouseState::Running => "RUNNING",
+            WarehouseState::Stopping => "STOPPING",
+            WarehouseState::Stopped => "STOPPED",
+            WarehouseState::Deleting => "DELETING",
+            WarehouseState::Deleted => "DELETED",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct WarehouseInfo {
+    pub id: String,
+    pub name: Option<String>,
+    pub state: Option<WarehouseState>,
+    pub cluster_size: Option<String>, // "2X-Small", "Small", ...
+    pub min_num_clusters: Option<i32>,
+    pub max_num_clusters: Option<i32>,
+    pub num_clusters: Option<i32>,
+    pub auto_stop_mins: Option<i32>,
+    pub warehouse_type: Option<String>, // "PRO" or "CLASSIC"
+    pub enable_serverless_compute: Option<bool>,
+    pub budget_policy_id: Option<String>,
+    pub creator_name: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListWarehousesResponse {
+    #[serde(default)]
+    pub warehouses: Vec<WarehouseInfo>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// Progress of a warehouse that a statement is waiting for, reported by
+/// `execute_sql_statement_with_warehouse_start` on every poll until the warehouse runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WarehouseStartProgress {
+    pub warehouse_id: String,
+    pub state: WarehouseState,
+    pub elapsed: Duration,
+}
@@ -0,0 +1,99 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+/// A Model Registry event that can trigger a registry webhook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum RegistryWebhookEvent {
+    ModelVersionCreated,
+    ModelVersionTransitionedStage,
+    ModelVersionTransitionedToStaging,
+    ModelVersionTransitionedToProduction,
+    ModelVersionTransitionedToArchived,
+    ModelVersionTagSet,
+    TransitionRequestCreated,
+    TransitionRequestToStagingCreated,
+    TransitionRequestToProductionCreated,
+    TransitionRequestToArchivedCreated,
+    CommentCreated,
+    RegisteredModelCreated,
+    /// An event type this crate does not know yet.
+    #[serde(other)]
+    Other,
+}
+
+/// The body of a request sent by a Model Registry webhook.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct RegistryWebhookPayload {
+    pub event: RegistryWebhookEvent,
+    #[serde(default)]
+    pub webhook_id: Option<String>,
+    #[serde(default)]
+    pub event_timestamp: Option<i64>, // Milliseconds since the epoch
+    #[serde(default)]
+    pub model_name: Option<String>,
+    #[serde(default)]
+    pub version: Option<String>,
+    #[serde(default)]
+    pub from_stage: Option<String>,
+    #[serde(default)]
+    pub to_stage: Option<String>,
+    #[serde(default)]
+    pub text: Option<String>, // Comment text, for COMMENT_CREATED
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// A job lifecycle event delivered to a webhook notification destination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JobWebhookEventType {
+    #[serde(rename = "jobs.on_start")]
+    OnStart,
+    #[serde(rename = "jobs.on_success")]
+    OnSuccess,
+    #[serde(rename = "jobs.on_failure")]
+    OnFailure,
+    #[serde(rename = "jobs.on_duration_warning_threshold_exceeded")]
+    OnDurationWarningThresholdExceeded,
+    #[serde(rename = "jobs.on_streaming_backlog_exceeded")]
+    OnStreamingBacklogExceeded,
+    /// An event type this crate does not know yet.
+    #[serde(other)]
+    Other,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct JobWebhookJob {
+    pub job_id: i64,
+    pub name: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct JobWebhookRun {
+    pub run_id: i64,
+    pub parent_run_id: Option<i64>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+/// The body of a request sent to a webhook notification destination for a job run.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct JobWebhookPayload {
+    pub event_type: JobWebhookEventType,
+    #[serde(default)]
+    pub workspace_id: Option<i64>,
+    #[serde(default)]
+    pub job: Option<JobWebhookJob>,
+    #[serde(default)]
+    pub run: Option<JobWebhookRun>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
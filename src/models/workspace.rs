@@ -0,0 +1,158 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ObjectType {
+    Notebook,
+    Directory,
+    Library,
+    File,
+    Repo,
+    Dashboard,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum NotebookLanguage {
+    Python,
+    Scala,
+    Sql,
+    R,
+}
+
+impl NotebookLanguage {
+    /// The file extension used for notebooks exported in `SOURCE` format.
+    pub fn source_extension(&self) -> &'static str {
+        match self {
+            NotebookLanguage::Python => "py",
+            NotebookLanguage::Scala => "scala",
+            NotebookLanguage::Sql => "sql",
+            NotebookLanguage::R => "r",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ExportFormat {
+    #[default]
+    Source,
+    Html,
+    Jupyter,
+    Dbc,
+    RMarkdown,
+    Auto, // On import, detected from the extension and header; on export, the native format
+    Raw,  // Workspace files (non-notebooks), transferred as-is
+}
+
+impl ExportFormat {
+    /// The file extension for a notebook in this format, or `None` for `SOURCE`/`AUTO`, where it
+    /// depends on the notebook language.
+    pub fn extension(&self) -> Option<&'static str> {
+        match self {
+            ExportFormat::Html => Some("html"),
+            ExportFormat::Jupyter => Some("ipynb"),
+            ExportFormat::Dbc => Some("dbc"),
+            ExportFormat::RMarkdown => Some("Rmd"),
+            ExportFormat::Source | ExportFormat::Auto | ExportFormat::Raw => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ObjectInfo {
+    pub path: String,
+    pub object_type: Option<ObjectType>,
+    pub object_id: Option<i64>,
+    pub language: Option<NotebookLanguage>,
+    pub size: Option<i64>,
+    pub created_at: Option<i64>,
+    pub modified_at: Option<i64>,
+    pub resource_id: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ListWorkspaceResponse {
+    #[serde(default)]
+    pub objects: Vec<ObjectInfo>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[non_exhaustive]
+#[serde(default)]
+pub struct ExportWorkspaceResponse {
+    pub content: String, // Base64-encoded
+    pub file_type: Option<String>,
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ImportWorkspaceRequest {
+    pub path: String,
+    pub format: ExportFormat,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub language: Option<NotebookLanguage>, // Required for SOURCE format
+    pub content: String, // Base64-encoded
+    pub overwrite: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct WorkspacePathRequest {
+    pub path: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub(crate) struct DeleteWorkspaceObjectRequest {
+    pub path: String,
+    pub recursive: bool,
+}
+
+/// Options for recursive workspace export and import.
+#[derive(Debug, Clone, PartialEq)]
+pub struct WorkspaceSyncOptions {
+    /// The notebook format; `SOURCE` writes `.py`/`.scala`/`.sql`/`.r` files.
+    pub format: ExportFormat,
+    /// Whether existing files (on export) or objects (on import) are replaced. Otherwise they are
+    /// skipped.
+    pub overwrite: bool,
+    /// Whether workspace files, i.e. objects other than notebooks such as YAML configs, are left
+    /// out. Otherwise they are transferred as-is under their own name.
+    pub skip_files: bool,
+    /// The number of objects exported or imported at the same time.
+    pub concurrency: usize,
+    /// How often a request is retried after a `REQUEST_LIMIT_EXCEEDED` response, with exponential
+    /// backoff starting at one second.
+    pub max_rate_limit_retries: u32,
+}
+
+impl Default for WorkspaceSyncOptions {
+    /// `SOURCE` format without overwriting, transferring 8 objects at a time.
+    fn default() -> Self {
+        WorkspaceSyncOptions {
+            format: ExportFormat::default(),
+            overwrite: false,
+            skip_files: false,
+            concurrency: 8,
+            max_rate_limit_retries: 5,
+        }
+    }
+}
+
+/// The outcome of a recursive export or import.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct WorkspaceSyncReport {
+    /// Workspace paths that were exported or imported.
+    pub transferred: Vec<String>,
+    /// Workspace paths that were skipped, because they already existed or are of an unsupported type.
+    pub skipped: Vec<String>,
+}
@@ -0,0 +1,113 @@
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt};
+
+/// Well-known workspace configuration keys accepted by `/api/2.0/workspace-conf`.
+///
+/// Keys that are not modelled explicitly can still be addressed through `Other`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WorkspaceConfKey {
+    MaxTokenLifetimeDays,
+    EnableTokensConfig,
+    EnableResultsDownloading,
+    EnableWebTerminal,
+    EnableExportNotebook,
+    EnableNotebookTableClipboard,
+    EnableIpAccessLists,
+    EnableDbfsFileBrowser,
+    Other(String),
+}
+
+impl WorkspaceConfKey {
+    pub fn as_str(&self) -> &str {
+        match self {
+            WorkspaceConfKey::MaxTokenLifetimeDays => "maxTokenLifetimeDays",
+            WorkspaceConfKey::EnableTokensConfig => "enableTokensConfig",
+            WorkspaceConfKey::EnableResultsDownloading => "enableResultsDownloading",
+            WorkspaceConfKey::EnableWebTerminal => "enableWebTerminal",
+            WorkspaceConfKey::EnableExportNotebook => "enableExportNotebook",
+            WorkspaceConfKey::EnableNotebookTableClipboard => "enableNotebookTableClipboard",
+            WorkspaceConfKey::EnableIpAccessLists => "enableIpAccessLists",
+            WorkspaceConfKey::EnableDbfsFileBrowser => "enableDbfsFileBrowser",
+            WorkspaceConfKey::Other(key) => key,
+        }
+    }
+}
+
+impl fmt::Display for WorkspaceConfKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A set of workspace configuration values, keyed by their API name.
+///
+/// The API represents every value as a string (e.g. `"true"`, `"90"`), and reports keys that have
+/// never been set as `null`.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct WorkspaceConf {
+    pub settings: HashMap<String, Option<String>>,
+}
+
+impl WorkspaceConf {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a key to the given value, returning `self` for chaining.
+    pub fn set(mut self, key: WorkspaceConfKey, value: impl ToString) -> Self {
+        self.settings
+            .insert(key.as_str().to_string(), Some(value.to_string()));
+        self
+    }
+
+    /// Returns the raw string value of a key, if present and set.
+    pub fn get(&self, key: &WorkspaceConfKey) -> Option<&str> {
+        self.settings
+            .get(key.as_str())
+            .and_then(|value| value.as_deref())
+    }
+
+    /// Returns the value of a key parsed as a boolean.
+    pub fn get_bool(&self, key: &WorkspaceConfKey) -> Option<bool> {
+        self.get(key).and_then(|value| value.parse().ok())
+    }
+
+    /// Returns the value of a key parsed as an integer.
+    pub fn get_i64(&self, key: &WorkspaceConfKey) -> Option<i64> {
+        self.get(key).and_then(|value| value.parse().ok())
+    }
+
+    /// Compares this (actual) configuration against a desired one.
+    ///
+    /// Only keys present in `desired` are checked; a drift entry is reported for every key whose
+    /// actual value differs from the desired value.
+    pub fn drift(&self, desired: &WorkspaceConf) -> Vec<WorkspaceConfDrift> {
+        let mut drifts: Vec<WorkspaceConfDrift> = desired
+            .settings
+            .iter()
+            .filter_map(|(key, expected)| {
+                let actual: Option<String> = self.settings.get(key).cloned().flatten();
+                if &actual != expected {
+                    Some(WorkspaceConfDrift {
+                        key: key.clone(),
+                        expected: expected.clone(),
+                        actual,
+                    })
+                } else {
+                    None
+                }
+            })
+            .collect();
+        drifts.sort_by(|a, b| a.key.cmp(&b.key));
+        drifts
+    }
+}
+
+/// A single workspace setting whose actual value differs from the desired one.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WorkspaceConfDrift {
+    pub key: String,
+    pub expected: Option<String>,
+    pub actual: Option<String>,
+}
@@ -0,0 +1,31 @@
+use crate::errors::HttpError;
+
+/// Progress of `WorkspaceRegistry::for_each_workspace`, reported after each workspace completes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WorkspaceProgress {
+    /// The name of the workspace that just completed.
+    pub workspace: String,
+    /// Whether the operation succeeded on it.
+    pub succeeded: bool,
+    /// The number of workspaces completed so far, including this one.
+    pub completed: usize,
+    /// The number of those that failed.
+    pub failed: usize,
+    /// The number of workspaces the operation runs on.
+    pub total: usize,
+}
+
+/// The outcome of `WorkspaceRegistry::for_each_workspace`: the result of every workspace, keyed
+/// by its registry name, in completion order.
+#[derive(Debug, Default)]
+pub struct WorkspaceOperationReport<T> {
+    pub succeeded: Vec<(String, T)>,
+    pub failed: Vec<(String, HttpError)>,
+}
+
+impl<T> WorkspaceOperationReport<T> {
+    /// Whether the operation succeeded on every workspace.
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
@@ -0,0 +1,210 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        CreateNetworkRequest, CreateVpcEndpointRequest, Network, PrivateAccessSettings,
+        PrivateAccessSettingsRequest, VpcEndpoint,
+    },
+    services::{databricks_session::EmptyResponse, AccountSession},
+};
+use reqwest::Method;
+
+impl AccountSession {
+    /// Registers a customer-managed VPC as a network configuration.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateNetworkRequest` with the VPC, subnets, security groups and
+    ///   optional PrivateLink endpoints.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `Network` if successful, or an `HttpError` if the request fails.
+    pub async fn create_network(
+        &self,
+        request_body: CreateNetworkRequest,
+    ) -> Result<Network, HttpError> {
+        self.send_account_request(Method::POST, "networks", Some(request_body))
+            .await
+    }
+
+    /// Retrieves a network configuration, including its validation status.
+    ///
+    /// Parameters:
+    /// - `network_id`: The id of the network configuration.
+    ///
+    /// Returns:
+    /// - Same as `create_network`.
+    pub async fn get_network(&self, network_id: &str) -> Result<Network, HttpError> {
+        self.send_account_request(Method::GET, &format!("networks/{}", network_id), None::<()>)
+            .await
+    }
+
+    /// Lists all network configurations of the account.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `Network`, or an `HttpError` if the request fails.
+    pub async fn list_networks(&self) -> Result<Vec<Network>, HttpError> {
+        self.send_account_request(Method::GET, "networks", None::<()>)
+            .await
+    }
+
+    /// Deletes a network configuration that is not attached to a workspace.
+    ///
+    /// Parameters:
+    /// - `network_id`: The id of the network configuration.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_network(&self, network_id: &str) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!("networks/{}", network_id),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Registers an AWS VPC endpoint (or GCP Private Service Connect endpoint) with the account.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateVpcEndpointRequest` naming the cloud endpoint.
+    ///
+    /// Returns:
+    /// - A `Result` containing the registered `VpcEndpoint` if successful, or an `HttpError` if the request fails.
+    pub async fn create_vpc_endpoint(
+        &self,
+        request_body: CreateVpcEndpointRequest,
+    ) -> Result<VpcEndpoint, HttpError> {
+        self.send_account_request(Method::POST, "vpc-endpoints", Some(request_body))
+            .await
+    }
+
+    /// Retrieves a VPC endpoint registration.
+    ///
+    /// Parameters:
+    /// - `vpc_endpoint_id`: The Databricks id of the VPC endpoint registration.
+    ///
+    /// Returns:
+    /// - Same as `create_vpc_endpoint`.
+    pub async fn get_vpc_endpoint(&self, vpc_endpoint_id: &str) -> Result<VpcEndpoint, HttpError> {
+        self.send_account_request(
+            Method::GET,
+            &format!("vpc-endpoints/{}", vpc_endpoint_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Lists all VPC endpoint registrations of the account.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `VpcEndpoint`, or an `HttpError` if the request fails.
+    pub async fn list_vpc_endpoints(&self) -> Result<Vec<VpcEndpoint>, HttpError> {
+        self.send_account_request(Method::GET, "vpc-endpoints", None::<()>)
+            .await
+    }
+
+    /// Deletes a VPC endpoint registration. The cloud endpoint itself is not affected.
+    ///
+    /// Parameters:
+    /// - `vpc_endpoint_id`: The Databricks id of the VPC endpoint registration.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_vpc_endpoint(&self, vpc_endpoint_id: &str) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!("vpc-endpoints/{}", vpc_endpoint_id),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Creates a private access settings object controlling PrivateLink access to workspaces.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `PrivateAccessSettingsRequest`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `PrivateAccessSettings` if successful, or an `HttpError`
+    ///   if the request fails.
+    pub async fn create_private_access_settings(
+        &self,
+        request_body: PrivateAccessSettingsRequest,
+    ) -> Result<PrivateAccessSettings, HttpError> {
+        self.send_account_request(Method::POST, "private-access-settings", Some(request_body))
+            .await
+    }
+
+    /// Retrieves a private access settings object.
+    ///
+    /// Parameters:
+    /// - `private_access_settings_id`: The id of the private access settings object.
+    ///
+    /// Returns:
+    /// - Same as `create_private_access_settings`.
+    pub async fn get_private_access_settings(
+        &self,
+        private_access_settings_id: &str,
+    ) -> Result<PrivateAccessSettings, HttpError> {
+        self.send_account_request(
+            Method::GET,
+            &format!("private-access-settings/{}", private_access_settings_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Lists all private access settings objects of the account.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `PrivateAccessSettings`, or an `HttpError` if the request fails.
+    pub async fn list_private_access_settings(
+        &self,
+    ) -> Result<Vec<PrivateAccessSettings>, HttpError> {
+        self.send_account_request(Method::GET, "private-access-settings", None::<()>)
+            .await
+    }
+
+    /// Replaces a private access settings object. Every field is overwritten.
+    ///
+    /// Parameters:
+    /// - `private_access_settings_id`: The id of the private access settings object.
+    /// - `request_body`: The complete new `PrivateAccessSettingsRequest`.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn replace_private_access_settings(
+        &self,
+        private_access_settings_id: &str,
+        request_body: PrivateAccessSettingsRequest,
+    ) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::PUT,
+            &format!("private-access-settings/{}", private_access_settings_id),
+            Some(request_body),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Deletes a private access settings object that is not attached to a workspace.
+    ///
+    /// Parameters:
+    /// - `private_access_settings_id`: The id of the private access settings object.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_private_access_settings(
+        &self,
+        private_access_settings_id: &str,
+    ) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!("private-access-settings/{}", private_access_settings_id),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+}
@@ -0,0 +1,169 @@
+use crate::{
+    config::AccountConfig,
+    errors::HttpError,
+    models::PlannedRequest,
+    services::databricks_session::{
+        decode_json, handle_response, is_mutating, record_planned_request,
+    },
+};
+use reqwest::{
+    header::{HeaderMap, HeaderValue, AUTHORIZATION},
+    Client, Method,
+};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use std::sync::{Arc, Mutex};
+
+/// A session against the account-level API, used for resources that live above individual
+/// workspaces (workspaces themselves, networking, log delivery, OAuth integrations).
+pub struct AccountSession {
+    pub(crate) client: Arc<Client>,
+    pub(crate) config: AccountConfig,
+    pub(crate) dry_run: Option<Arc<Mutex<Vec<PlannedRequest>>>>,
+}
+
+impl AccountSession {
+    /// Creates a new `AccountSession` with the specified configuration.
+    ///
+    /// Parameters:
+    /// - `config`: An `AccountConfig` containing the accounts host, the account id and the
+    ///   authentication token.
+    ///
+    /// Returns:
+    /// - A `Result` containing the new `AccountSession` if successful, or a `reqwest::Error` if
+    ///   the HTTP client could not be initialized.
+    pub fn new(config: AccountConfig) -> Result<Self, reqwest::Error> {
+        let client: Client = Client::builder().pool_max_idle_per_host(12).build()?;
+
+        Ok(AccountSession {
+            client: Arc::new(client),
+            config,
+            dry_run: None,
+        })
+    }
+
+    /// Turns dry-run mode on or off, like `DatabricksSession::with_dry_run`: mutating requests
+    /// (e.g. creating or deleting workspaces, networks or log delivery configurations) are
+    /// logged and recorded instead of sent, and return a simulated success.
+    ///
+    /// Parameters:
+    /// - `enabled`: Whether to record mutating requests instead of sending them.
+    ///
+    /// Returns:
+    /// - The session, for chaining.
+    pub fn with_dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = enabled.then(|| Arc::new(Mutex::new(Vec::new())));
+        self
+    }
+
+    /// Whether the session is in dry-run mode.
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run.is_some()
+    }
+
+    /// The mutating requests recorded in dry-run mode so far, in the order they were made.
+    pub fn planned_requests(&self) -> Vec<PlannedRequest> {
+        self.dry_run
+            .as_ref()
+            .map(|planned| {
+                planned
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner())
+                    .clone()
+            })
+            .unwrap_or_default()
+    }
+
+    /// The id of the account this session operates on.
+    pub fn account_id(&self) -> &str {
+        &self.config.account_id
+    }
+
+    /// Sends a request to an account-scoped endpoint.
+    ///
+    /// `endpoint` is relative to `api/2.0/accounts/{account_id}/`, e.g. `"workspaces"`.
+    ///
+    /// Parameters:
+    /// - `method`: The HTTP method to use for the request.
+    /// - `endpoint`: The account-relative API endpoint.
+    /// - `body`: An optional request body to serialize and include with the request.
+    ///
+    /// Returns:
+    /// - A `Result` containing the deserialized response body if successful, or an `HttpError` if the request fails.
+    pub(crate) async fn send_account_request<T: DeserializeOwned, B: Serialize>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<B>,
+    ) -> Result<T, HttpError> {
+        self.send_versioned_account_request("2.0", method, endpoint, body)
+            .await
+    }
+
+    /// Sends a request to an account-scoped endpoint of a specific API version, for services
+    /// that are not served under `api/2.0`.
+    ///
+    /// Parameters:
+    /// - `api_version`: The API version, e.g. `"2.1"`.
+    /// - `method`, `endpoint`, `body`: Same as `send_account_request`.
+    ///
+    /// Returns:
+    /// - Same as `send_account_request`.
+    pub(crate) async fn send_versioned_account_request<T: DeserializeOwned, B: Serialize>(
+        &self,
+        api_version: &str,
+        method: Method,
+        endpoint: &str,
+        body: Option<B>,
+    ) -> Result<T, HttpError> {
+        let path: String = format!(
+            "api/{}/accounts/{}/{}",
+            api_version, self.config.account_id, endpoint
+        );
+        if let Some(planned) = self.dry_run.as_ref().filter(|_| is_mutating(&method)) {
+            let body: Option<Value> = body
+                .map(|body| serde_json::to_value(body))
+                .transpose()
+                .map_err(|err| HttpError::InternalServerError(err.to_string()))?;
+            record_planned_request(planned, &method, &path, body);
+            return decode_json(&path, b"{}");
+        }
+        let url: String = format!(
+            "{}/{}",
+            self.config.account_host.trim_end_matches('/'),
+            path
+        );
+
+        let mut authorization: HeaderValue = HeaderValue::from_str(&format!(
+            "Bearer {}",
+            self.config.databricks_token
+        ))
+        .map_err(|_| {
+            HttpError::Unauthorized(
+                "The access token contains characters that are not allowed in a header".to_string(),
+            )
+        })?;
+        authorization.set_sensitive(true);
+        let mut headers: HeaderMap = HeaderMap::new();
+        headers.insert(AUTHORIZATION, authorization);
+
+        let request_builder: reqwest::RequestBuilder =
+            self.client.request(method, &url).headers(headers);
+
+        let request_builder: reqwest::RequestBuilder = if let Some(body) = body {
+            request_builder.json(&body)
+        } else {
+            request_builder
+        };
+
+        let response: reqwest::Response = request_builder.send().await.map_err(|err| {
+            if err.is_timeout() {
+                HttpError::TemporarilyUnavailable(err.to_string())
+            } else {
+                HttpError::InternalServerError(err.to_string())
+            }
+        })?;
+
+        handle_response(response, None).await
+    }
+}
@@ -0,0 +1,205 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        CreateWorkspaceRequest, PermissionAssignment, PermissionAssignments,
+        UpdateWorkspaceAssignmentRequest, UpdateWorkspaceRequest, Workspace, WorkspacePermission,
+        WorkspaceStatus,
+    },
+    services::{databricks_session::EmptyResponse, AccountSession},
+};
+use reqwest::Method;
+use std::time::{Duration, Instant};
+
+impl AccountSession {
+    /// Creates a new workspace. Provisioning continues in the background.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateWorkspaceRequest` referencing the credential, storage and network
+    ///   configurations to use.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `Workspace` if successful, or an `HttpError` if the request fails.
+    pub async fn create_workspace(
+        &self,
+        request_body: CreateWorkspaceRequest,
+    ) -> Result<Workspace, HttpError> {
+        self.send_account_request(Method::POST, "workspaces", Some(request_body))
+            .await
+    }
+
+    /// Retrieves a workspace by id.
+    ///
+    /// Parameters:
+    /// - `workspace_id`: The id of the workspace.
+    ///
+    /// Returns:
+    /// - Same as `create_workspace`.
+    pub async fn get_workspace(&self, workspace_id: i64) -> Result<Workspace, HttpError> {
+        self.send_account_request(
+            Method::GET,
+            &format!("workspaces/{}", workspace_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Lists all workspaces in the account.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `Workspace`, or an `HttpError` if the request fails.
+    pub async fn list_workspaces(&self) -> Result<Vec<Workspace>, HttpError> {
+        self.send_account_request(Method::GET, "workspaces", None::<()>)
+            .await
+    }
+
+    /// Updates the configurations of a workspace, e.g. to move it to a new network configuration.
+    ///
+    /// Parameters:
+    /// - `workspace_id`: The id of the workspace.
+    /// - `request_body`: An `UpdateWorkspaceRequest` containing the fields to change.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the update was accepted, or an `HttpError` if the request fails.
+    pub async fn update_workspace(
+        &self,
+        workspace_id: i64,
+        request_body: UpdateWorkspaceRequest,
+    ) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::PATCH,
+            &format!("workspaces/{}", workspace_id),
+            Some(request_body),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Deletes a workspace.
+    ///
+    /// Parameters:
+    /// - `workspace_id`: The id of the workspace.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_workspace(&self, workspace_id: i64) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!("workspaces/{}", workspace_id),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Polls a workspace until it leaves the `PROVISIONING` state.
+    ///
+    /// Parameters:
+    /// - `workspace_id`: The id of the workspace.
+    /// - `timeout`: The maximum time to wait.
+    ///
+    /// Returns:
+    /// - A `Result` containing the running `Workspace`, or an `HttpError` if polling fails, the
+    ///   workspace fails to provision, or the timeout elapses.
+    pub async fn wait_until_workspace_running(
+        &self,
+        workspace_id: i64,
+        timeout: Duration,
+    ) -> Result<Workspace, HttpError> {
+        let started: Instant = Instant::now();
+
+        loop {
+            let workspace: Workspace = self.get_workspace(workspace_id).await?;
+            match workspace.workspace_status {
+                Some(WorkspaceStatus::Running) => return Ok(workspace),
+                Some(WorkspaceStatus::Provisioning) | None => {}
+                Some(status) => {
+                    return Err(HttpError::BadRequest(format!(
+                        "Workspace {} is in state {:?}: {}",
+                        workspace_id,
+                        status,
+                        workspace.workspace_status_message.unwrap_or_default()
+                    )))
+                }
+            }
+            if started.elapsed() >= timeout {
+                return Err(HttpError::TemporarilyUnavailable(format!(
+                    "Workspace {} was not running after {:?}",
+                    workspace_id, timeout
+                )));
+            }
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        }
+    }
+
+    /// Lists the principals assigned to a workspace and their permissions.
+    ///
+    /// Parameters:
+    /// - `workspace_id`: The id of the workspace.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `PermissionAssignment`, or an `HttpError` if the request fails.
+    pub async fn list_workspace_assignments(
+        &self,
+        workspace_id: i64,
+    ) -> Result<Vec<PermissionAssignment>, HttpError> {
+        let response: PermissionAssignments = self
+            .send_account_request(
+                Method::GET,
+                &format!("workspaces/{}/permissionassignments", workspace_id),
+                None::<()>,
+            )
+            .await?;
+        Ok(response.permission_assignments)
+    }
+
+    /// Assigns a user, group or service principal to a workspace, replacing any existing permissions.
+    ///
+    /// Parameters:
+    /// - `workspace_id`: The id of the workspace.
+    /// - `principal_id`: The account-level id of the principal.
+    /// - `permissions`: The permissions to grant, e.g. `[WorkspacePermission::User]`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the resulting `PermissionAssignment`, or an `HttpError` if the request fails.
+    pub async fn update_workspace_assignment(
+        &self,
+        workspace_id: i64,
+        principal_id: i64,
+        permissions: Vec<WorkspacePermission>,
+    ) -> Result<PermissionAssignment, HttpError> {
+        self.send_account_request(
+            Method::PUT,
+            &format!(
+                "workspaces/{}/permissionassignments/principals/{}",
+                workspace_id, principal_id
+            ),
+            Some(UpdateWorkspaceAssignmentRequest { permissions }),
+        )
+        .await
+    }
+
+    /// Removes a principal's assignment to a workspace.
+    ///
+    /// Parameters:
+    /// - `workspace_id`: The id of the workspace.
+    /// - `principal_id`: The account-level id of the principal.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_workspace_assignment(
+        &self,
+        workspace_id: i64,
+        principal_id: i64,
+    ) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!(
+                "workspaces/{}/permissionassignments/principals/{}",
+                workspace_id, principal_id
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+}
@@ -0,0 +1,45 @@
+use serde_json::Value;
+
+/// Turns a request body into the JSON bytes sent to the API.
+///
+/// Some endpoints treat an explicit `null` differently from an omitted field (e.g. as "clear this
+/// setting" rather than "leave it unchanged"). A serializer set with
+/// `DatabricksSession::with_body_serializer`, or passed to
+/// `DatabricksSession::send_databricks_request_with_serializer` for a single request, decides how
+/// such fields are sent.
+pub trait BodySerializer: Send + Sync {
+    /// Serializes `body`, which is the request body converted to a JSON value.
+    fn serialize(&self, body: Value) -> Result<Vec<u8>, serde_json::Error>;
+}
+
+/// Sends the body as the request models serialize it, including explicit `null`s.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SendNulls;
+
+impl BodySerializer for SendNulls {
+    fn serialize(&self, body: Value) -> Result<Vec<u8>, serde_json::Error> {
+        serde_json::to_vec(&body)
+    }
+}
+
+/// Removes every object field whose value is `null`, at any depth, before sending the body.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OmitNulls;
+
+impl BodySerializer for OmitNulls {
+    fn serialize(&self, mut body: Value) -> Result<Vec<u8>, serde_json::Error> {
+        remove_nulls(&mut body);
+        serde_json::to_vec(&body)
+    }
+}
+
+fn remove_nulls(value: &mut Value) {
+    match value {
+        Value::Object(map) => {
+            map.retain(|_, value| !value.is_null());
+            map.values_mut().for_each(remove_nulls);
+        }
+        Value::Array(items) => items.iter_mut().for_each(remove_nulls),
+        _ => {}
+    }
+}
@@ -0,0 +1,148 @@
+use crate::{
+    config::ApiService,
+    errors::HttpError,
+    models::{
+        BudgetPolicy, BudgetPolicyAssignment, CreateBudgetPolicyRequest,
+        ListBudgetPoliciesResponse, NewBudgetPolicy, UpdateJobBudgetPolicyRequest,
+    },
+    services::{databricks_session::EmptyResponse, AccountSession, DatabricksSession},
+    utils::encode_query_value,
+};
+use reqwest::Method;
+
+impl AccountSession {
+    /// Creates a serverless budget policy.
+    ///
+    /// Parameters:
+    /// - `policy`: A `NewBudgetPolicy` with the policy name and its custom tags.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `BudgetPolicy` if successful, or an `HttpError` if the request fails.
+    pub async fn create_budget_policy(
+        &self,
+        policy: NewBudgetPolicy,
+    ) -> Result<BudgetPolicy, HttpError> {
+        self.send_versioned_account_request(
+            "2.1",
+            Method::POST,
+            "budget-policies",
+            Some(CreateBudgetPolicyRequest { policy }),
+        )
+        .await
+    }
+
+    /// Retrieves a budget policy.
+    ///
+    /// Parameters:
+    /// - `policy_id`: The id of the budget policy.
+    ///
+    /// Returns:
+    /// - Same as `create_budget_policy`.
+    pub async fn get_budget_policy(&self, policy_id: &str) -> Result<BudgetPolicy, HttpError> {
+        self.send_versioned_account_request(
+            "2.1",
+            Method::GET,
+            &format!("budget-policies/{}", policy_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Lists all budget policies of the account, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `BudgetPolicy`, or an `HttpError` if any page request fails.
+    pub async fn list_budget_policies(&self) -> Result<Vec<BudgetPolicy>, HttpError> {
+        let mut policies: Vec<BudgetPolicy> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => format!("budget-policies?page_token={}", encode_query_value(token)),
+                None => "budget-policies".to_string(),
+            };
+            let page: ListBudgetPoliciesResponse = self
+                .send_versioned_account_request("2.1", Method::GET, &endpoint, None::<()>)
+                .await?;
+            policies.extend(page.policies);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(policies),
+            }
+        }
+    }
+
+    /// Deletes a budget policy. Resources attached to it fall back to the default policy.
+    ///
+    /// Parameters:
+    /// - `policy_id`: The id of the budget policy.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_budget_policy(&self, policy_id: &str) -> Result<(), HttpError> {
+        self.send_versioned_account_request::<EmptyResponse, _>(
+            "2.1",
+            Method::DELETE,
+            &format!("budget-policies/{}", policy_id),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+}
+
+impl DatabricksSession {
+    /// Attaches a budget policy to a job, so usage of its serverless tasks is attributed to the
+    /// policy. Other job settings are left unchanged.
+    ///
+    /// Parameters:
+    /// - `job_id`: The ID of the job.
+    /// - `policy_id`: The id of the budget policy.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn assign_job_budget_policy(
+        &self,
+        job_id: i64,
+        policy_id: &str,
+    ) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            &self.api_endpoint(ApiService::Jobs, "jobs/update"),
+            Some(UpdateJobBudgetPolicyRequest {
+                job_id,
+                new_settings: BudgetPolicyAssignment {
+                    budget_policy_id: policy_id.to_string(),
+                },
+            }),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Attaches a budget policy to a serverless SQL warehouse. Other warehouse settings are left
+    /// unchanged.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The ID of the warehouse.
+    /// - `policy_id`: The id of the budget policy.
+    ///
+    /// Returns:
+    /// - Same as `assign_job_budget_policy`.
+    pub async fn assign_warehouse_budget_policy(
+        &self,
+        warehouse_id: &str,
+        policy_id: &str,
+    ) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            &format!("api/2.0/sql/warehouses/{}/edit", warehouse_id),
+            Some(BudgetPolicyAssignment {
+                budget_policy_id: policy_id.to_string(),
+            }),
+        )
+        .await
+        .map(|_| ())
+    }
+}
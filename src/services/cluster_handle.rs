@@ -0,0 +1,109 @@
+use crate::{
+    errors::HttpError,
+    models::{ClusterEvent, ClusterInfo},
+    services::DatabricksSession,
+};
+use futures::Stream;
+use std::time::Duration;
+
+impl DatabricksSession {
+    /// Returns a handle to a cluster for calling its lifecycle methods.
+    ///
+    /// Parameters:
+    /// - `cluster_id`: The ID of the cluster.
+    pub fn cluster(&self, cluster_id: &str) -> ClusterHandle<'_> {
+        ClusterHandle {
+            session: self,
+            cluster_id: cluster_id.to_string(),
+            info: None,
+        }
+    }
+}
+
+/// A cluster, returned by `DatabricksSession::cluster`.
+///
+/// The handle caches the `ClusterInfo` last retrieved by `refresh` or `wait_running`.
+pub struct ClusterHandle<'a> {
+    session: &'a DatabricksSession,
+    cluster_id: String,
+    info: Option<ClusterInfo>,
+}
+
+impl<'a> ClusterHandle<'a> {
+    /// The ID of the cluster.
+    pub fn cluster_id(&self) -> &str {
+        &self.cluster_id
+    }
+
+    /// The cluster information retrieved last, if any.
+    pub fn info(&self) -> Option<&ClusterInfo> {
+        self.info.as_ref()
+    }
+
+    /// Retrieves the current cluster information and caches it.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `ClusterInfo`, or an `HttpError` if the request fails.
+    pub async fn refresh(&mut self) -> Result<&ClusterInfo, HttpError> {
+        let info: ClusterInfo = self.session.get_cluster_info(&self.cluster_id).await?;
+        Ok(self.info.insert(info))
+    }
+
+    /// Starts the cluster if it is terminated.
+    ///
+    /// Returns:
+    /// - Same as `DatabricksSession::start_cluster`.
+    pub async fn start(&self) -> Result<(), HttpError> {
+        self.session.start_cluster(&self.cluster_id).await
+    }
+
+    /// Terminates the cluster.
+    ///
+    /// Returns:
+    /// - Same as `DatabricksSession::terminate_cluster`.
+    pub async fn terminate(&self) -> Result<(), HttpError> {
+        self.session.terminate_cluster(&self.cluster_id).await
+    }
+
+    /// Polls the cluster every `poll_interval` until it is `RUNNING`.
+    ///
+    /// The cluster is not started; call `start` first if it may be terminated.
+    ///
+    /// Parameters:
+    /// - `poll_interval`: The time between polls.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `ClusterInfo` of the running cluster, or an `HttpError` if a
+    ///   poll fails or the cluster terminates or errors while waiting.
+    pub async fn wait_running(
+        &mut self,
+        poll_interval: Duration,
+    ) -> Result<&ClusterInfo, HttpError> {
+        loop {
+            let info: ClusterInfo = self.session.get_cluster_info(&self.cluster_id).await?;
+            match info.state.as_str() {
+                "RUNNING" => return Ok(self.info.insert(info)),
+                "TERMINATING" | "TERMINATED" | "ERROR" | "UNKNOWN" => {
+                    let error: HttpError = HttpError::InternalServerError(format!(
+                        "Cluster {} is {} while waiting for it to run: {}",
+                        self.cluster_id, info.state, info.state_message
+                    ));
+                    self.info = Some(info);
+                    return Err(error);
+                }
+                _ => {
+                    self.info = Some(info);
+                    tokio::time::sleep(poll_interval).await;
+                }
+            }
+        }
+    }
+
+    /// Streams the lifecycle events of the cluster, newest first.
+    ///
+    /// Returns:
+    /// - Same as `DatabricksSession::list_cluster_events`.
+    pub fn events(&self) -> impl Stream<Item = Result<ClusterEvent, HttpError>> + 'a {
+        self.session.list_cluster_events(&self.cluster_id)
+    }
+}
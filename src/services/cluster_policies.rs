@@ -0,0 +1,61 @@
+use crate::{
+    errors::HttpError,
+    models::{ClusterPolicy, ListClusterPoliciesResponse, PolicyViolation},
+    services::DatabricksSession,
+    utils::encode_query_value,
+};
+use reqwest::Method;
+use serde::Serialize;
+
+impl DatabricksSession {
+    /// Retrieves a cluster policy, including its definition.
+    ///
+    /// Parameters:
+    /// - `policy_id`: The ID of the policy.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `ClusterPolicy`, or an `HttpError` if the request fails.
+    pub async fn get_cluster_policy(&self, policy_id: &str) -> Result<ClusterPolicy, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &format!(
+                "api/2.0/policies/clusters/get?policy_id={}",
+                encode_query_value(policy_id)
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Lists the cluster policies the caller can use.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `ClusterPolicy`, or an `HttpError` if the request fails.
+    pub async fn list_cluster_policies(&self) -> Result<Vec<ClusterPolicy>, HttpError> {
+        let response: ListClusterPoliciesResponse = self
+            .send_databricks_request(Method::GET, "api/2.0/policies/clusters/list", None::<()>)
+            .await?;
+        Ok(response.policies)
+    }
+
+    /// Fetches a cluster policy and checks a cluster create or edit request against it, so
+    /// violations can be reported before the request is sent.
+    ///
+    /// Parameters:
+    /// - `policy_id`: The ID of the policy the cluster will use.
+    /// - `spec`: The cluster spec, e.g. a `ClusterSpec` or a JSON value.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `PolicyViolation`s (empty if the spec complies), or an
+    ///   `HttpError` if the policy cannot be retrieved or its definition cannot be parsed.
+    pub async fn validate_cluster_spec<S: Serialize>(
+        &self,
+        policy_id: &str,
+        spec: &S,
+    ) -> Result<Vec<PolicyViolation>, HttpError> {
+        let policy: ClusterPolicy = self.get_cluster_policy(policy_id).await?;
+        policy
+            .validate(spec)
+            .map_err(|err| HttpError::InternalError(Box::new(err)))
+    }
+}
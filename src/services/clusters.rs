@@ -0,0 +1,142 @@
+use crate::{
+    config::ApiService,
+    errors::HttpError,
+    models::{
+        ClusterEvent, ClusterEventsRequest, ClusterEventsResponse, ClusterIdRequest,
+        ClusterSummary, ListClustersResponse,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+    utils::encode_query_value,
+};
+use futures::{Stream, TryStreamExt};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Lists all clusters in the workspace, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing a `ClusterSummary` for every cluster, or an `HttpError` if any page
+    ///   request fails.
+    pub async fn list_clusters(&self) -> Result<Vec<ClusterSummary>, HttpError> {
+        let mut clusters: Vec<ClusterSummary> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => self.api_endpoint(
+                    ApiService::Clusters,
+                    &format!("clusters/list?page_token={}", encode_query_value(token)),
+                ),
+                None => self.api_endpoint(ApiService::Clusters, "clusters/list"),
+            };
+            let page: ListClustersResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            clusters.extend(page.clusters);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(clusters),
+            }
+        }
+    }
+
+    /// Starts a terminated cluster. The cluster transitions through `PENDING` to `RUNNING` in the
+    /// background.
+    ///
+    /// Parameters:
+    /// - `cluster_id`: The ID of the cluster to start.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the start was accepted, or an `HttpError` if the request fails.
+    pub async fn start_cluster(&self, cluster_id: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            &self.api_endpoint(ApiService::Clusters, "clusters/start"),
+            Some(ClusterIdRequest {
+                cluster_id: cluster_id.to_string(),
+            }),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Terminates a cluster. Its configuration is kept, so it can be started again with
+    /// `start_cluster`.
+    ///
+    /// Parameters:
+    /// - `cluster_id`: The ID of the cluster to terminate.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the termination was accepted, or an `HttpError` if the request fails.
+    pub async fn terminate_cluster(&self, cluster_id: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            &self.api_endpoint(ApiService::Clusters, "clusters/delete"),
+            Some(ClusterIdRequest {
+                cluster_id: cluster_id.to_string(),
+            }),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Permanently deletes a cluster. Unlike `terminate_cluster`, its configuration is removed and
+    /// it can no longer be started.
+    ///
+    /// Parameters:
+    /// - `cluster_id`: The ID of the cluster to delete.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the deletion was accepted, or an `HttpError` if the request fails.
+    pub async fn permanent_delete_cluster(&self, cluster_id: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            &self.api_endpoint(ApiService::Clusters, "clusters/permanent-delete"),
+            Some(ClusterIdRequest {
+                cluster_id: cluster_id.to_string(),
+            }),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Streams the lifecycle events of a cluster, newest first.
+    ///
+    /// Pages are fetched lazily as the stream is consumed.
+    ///
+    /// Parameters:
+    /// - `cluster_id`: The ID of the cluster.
+    ///
+    /// Returns:
+    /// - A stream of `ClusterEvent`s, yielding an `HttpError` if a page request fails.
+    pub fn list_cluster_events<'a>(
+        &'a self,
+        cluster_id: &str,
+    ) -> impl Stream<Item = Result<ClusterEvent, HttpError>> + 'a {
+        let first_page: ClusterEventsRequest = ClusterEventsRequest {
+            cluster_id: cluster_id.to_string(),
+            order: Some("DESC".to_string()),
+            ..Default::default()
+        };
+
+        futures::stream::try_unfold(
+            Some(first_page),
+            move |page: Option<ClusterEventsRequest>| async move {
+                let Some(page) = page else {
+                    return Ok(None);
+                };
+                let response: ClusterEventsResponse = self
+                    .send_databricks_request(
+                        Method::POST,
+                        &self.api_endpoint(ApiService::Clusters, "clusters/events"),
+                        Some(page),
+                    )
+                    .await?;
+                Ok(Some((response.events, response.next_page)))
+            },
+        )
+        .map_ok(|events: Vec<ClusterEvent>| futures::stream::iter(events.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{
+    errors::HttpError,
+    models::{rows_as, CopyIntoCommand, CopyIntoMetrics, SqlStatementResponse},
+    services::DatabricksSession,
+};
+
+impl DatabricksSession {
+    /// Bulk loads files into a Delta table with `COPY INTO` and returns the load metrics.
+    ///
+    /// Files that were already loaded into the table are skipped unless the `force` copy option
+    /// is set, so the same command can be rerun safely.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the statement on.
+    /// - `command`: The `CopyIntoCommand` describing the target table, source files and options.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `CopyIntoMetrics`, or an `HttpError` if the statement fails or
+    ///   its result cannot be parsed.
+    pub async fn copy_into(
+        &self,
+        warehouse_id: &str,
+        command: &CopyIntoCommand,
+    ) -> Result<CopyIntoMetrics, HttpError> {
+        let response: SqlStatementResponse = self
+            .execute_sql_statement_and_wait(
+                command
+                    .to_request(warehouse_id)
+                    .with_disposition("INLINE")
+                    .with_format("JSON_ARRAY"),
+            )
+            .await?;
+        let Some(schema) = response
+            .manifest
+            .as_ref()
+            .and_then(|manifest| manifest.schema.as_ref())
+        else {
+            return Ok(CopyIntoMetrics::default());
+        };
+        let rows: &[Vec<Option<String>>] = response
+            .result
+            .as_ref()
+            .and_then(|result| result.data_array.as_deref())
+            .unwrap_or_default();
+        let metrics: Vec<CopyIntoMetrics> =
+            rows_as(schema, rows).map_err(|err| HttpError::InternalServerError(err.to_string()))?;
+        Ok(metrics.into_iter().next().unwrap_or_default())
+    }
+}
@@ -0,0 +1,15 @@
+/// Supplies the bearer token sent with each request, in place of the configuration's token.
+///
+/// A provider set with `DatabricksSession::with_credentials` is asked for the token every time a
+/// request is built, so it can hand out refreshed or per-tenant tokens. Closures returning a
+/// `String` are providers too.
+pub trait CredentialsProvider: Send + Sync {
+    /// The token to send with the next request.
+    fn token(&self) -> String;
+}
+
+impl<F: Fn() -> String + Send + Sync> CredentialsProvider for F {
+    fn token(&self) -> String {
+        self()
+    }
+}
@@ -1,21 +1,53 @@
 use crate::{
-    config::Config,
-    errors::{ErrorResponse, HttpError},
+    config::{ApiService, ApiVersion, ApiVersions, Config},
+    errors::{DecodeError, ErrorResponse, HttpError},
     models::{
-        ClusterInfo, JobRunRequest, JobRunResponse, ResultData, SqlStatementRequest,
-        SqlStatementResponse,
+        redact_secrets, rows_as, ChunkValidator, ClusterInfo, JobRunRequest, JobRunResponse,
+        Manifest, PlannedRequest, ResultData, SqlSessionSettings, SqlStatementRequest,
+        SqlStatementResponse, StatementState,
+    },
+    services::{
+        sse::sse_events, token_file::TokenFile, BodySerializer, CredentialsProvider, OnBehalfOf,
+        ResponseMeta, SseEvent,
     },
 };
+use bytes::{Bytes, BytesMut};
+use futures::{Stream, StreamExt, TryStreamExt};
 use reqwest::{
-    header::{HeaderMap, AUTHORIZATION},
+    header::{
+        HeaderMap, HeaderName, HeaderValue, ACCEPT, AUTHORIZATION, CONTENT_TYPE, RETRY_AFTER,
+    },
     Client, Method, StatusCode,
 };
-use serde::{de::DeserializeOwned, Serialize};
-use std::sync::Arc;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::Value;
+use std::{
+    future::Future,
+    io,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc, Mutex,
+    },
+    time::Duration,
+};
+use tokio::time::Instant;
+use tokio_util::io::{StreamReader, SyncIoBridge};
+
+/// Placeholder for endpoints that return an empty JSON object (or no body at all) on success.
+#[derive(Deserialize)]
+pub(crate) struct EmptyResponse {}
 
 pub struct DatabricksSession {
-    client: Arc<Client>,
-    config: Config,
+    pub(crate) client: Arc<Client>,
+    pub(crate) config: Config,
+    pub(crate) api_versions: ApiVersions,
+    pub(crate) max_response_bytes: Option<u64>,
+    pub(crate) sql_settings: SqlSessionSettings,
+    pub(crate) body_serializer: Option<Arc<dyn BodySerializer>>,
+    pub(crate) operation_timeout: Option<Duration>,
+    pub(crate) dry_run: Option<Arc<Mutex<Vec<PlannedRequest>>>>,
+    pub(crate) credentials: Option<Arc<dyn CredentialsProvider>>,
+    pub(crate) extra_headers: HeaderMap,
 }
 
 impl DatabricksSession {
@@ -57,7 +89,15 @@ impl DatabricksSession {
 
         Ok(DatabricksSession {
             client: Arc::new(client),
+            credentials: token_file(&config),
             config,
+            api_versions: ApiVersions::default(),
+            max_response_bytes: None,
+            sql_settings: SqlSessionSettings::default(),
+            body_serializer: None,
+            operation_timeout: None,
+            dry_run: None,
+            extra_headers: HeaderMap::new(),
         })
     }
 
@@ -79,26 +119,383 @@ impl DatabricksSession {
 
         Ok(DatabricksSession {
             client: Arc::new(client),
+            credentials: token_file(&config),
             config,
+            api_versions: ApiVersions::default(),
+            max_response_bytes: None,
+            sql_settings: SqlSessionSettings::default(),
+            body_serializer: None,
+            operation_timeout: None,
+            dry_run: None,
+            extra_headers: HeaderMap::new(),
         })
     }
 
+    /// Selects the API version used for one group of endpoints, e.g. Jobs API 2.2.
+    ///
+    /// Parameters:
+    /// - `service`: The group of endpoints.
+    /// - `version`: The version to call them with.
+    ///
+    /// Returns:
+    /// - The session, for chaining.
+    pub fn with_api_version(mut self, service: ApiService, version: ApiVersion) -> Self {
+        self.api_versions.overrides.insert(service, version);
+        self
+    }
+
+    /// Selects the API version used for every `ApiService` without a per-service override.
+    ///
+    /// Services that are not published in `version` (see `ApiService::supports`), e.g. Clusters
+    /// and Unity Catalog for 2.2, keep their default version.
+    ///
+    /// Parameters:
+    /// - `version`: The version to call the endpoints with.
+    ///
+    /// Returns:
+    /// - The session, for chaining.
+    pub fn with_default_api_version(mut self, version: ApiVersion) -> Self {
+        self.api_versions.default = Some(version);
+        self
+    }
+
+    /// Limits the size of response bodies that are read into memory, so that an unexpectedly large
+    /// payload (e.g. an INLINE query over a wide table) fails with `HttpError::ResponseTooLarge`
+    /// instead of exhausting memory. Bodies read through `request_stream` are not limited.
+    ///
+    /// Parameters:
+    /// - `limit`: The maximum body size in bytes.
+    ///
+    /// Returns:
+    /// - The session, for chaining.
+    pub fn with_max_response_bytes(mut self, limit: u64) -> Self {
+        self.max_response_bytes = Some(limit);
+        self
+    }
+
+    /// Applies session settings such as the default catalog, result format, time zone or ANSI
+    /// mode to every SQL statement the session executes.
+    ///
+    /// Parameters:
+    /// - `settings`: The `SqlSessionSettings` to apply.
+    ///
+    /// Returns:
+    /// - The session, for chaining.
+    pub fn with_sql_session_settings(mut self, settings: SqlSessionSettings) -> Self {
+        self.sql_settings = settings;
+        self
+    }
+
+    /// Serializes every request body with `serializer`, e.g. `OmitNulls`, instead of directly from
+    /// the request models.
+    ///
+    /// Parameters:
+    /// - `serializer`: The `BodySerializer` to use.
+    ///
+    /// Returns:
+    /// - The session, for chaining.
+    pub fn with_body_serializer(mut self, serializer: impl BodySerializer + 'static) -> Self {
+        self.body_serializer = Some(Arc::new(serializer));
+        self
+    }
+
+    /// Limits the total time of composite operations, across all the requests, polls and retries
+    /// they are made of, so an SLA can be enforced per logical operation rather than per request.
+    ///
+    /// Covered are `execute_sql_statement_and_wait` (and the methods built on it, such as
+    /// `execute_sql` and `execute_count`), `query_rows`, `get_rows`, the Parquet export methods,
+    /// and a `StatementHandle`'s `wait` and `stream_rows`, measured from `submit_sql_statement`. An operation that runs out
+    /// of time fails with `HttpError::OperationTimedOut`; a statement it was waiting for is canceled.
+    ///
+    /// Parameters:
+    /// - `timeout`: The maximum duration of one operation.
+    ///
+    /// Returns:
+    /// - The session, for chaining.
+    pub fn with_operation_timeout(mut self, timeout: Duration) -> Self {
+        self.operation_timeout = Some(timeout);
+        self
+    }
+
+    /// Turns dry-run mode on or off. In dry-run mode, mutating requests (any method other than
+    /// `GET` and `HEAD`) are logged and recorded instead of sent, and return a simulated success:
+    /// the response type's defaults, as if the API had replied with an empty object. This covers
+    /// the typed methods as well as `request_raw` and `send_databricks_request_bytes` (which
+    /// return `{}`), `request_stream`, `request_sse` and the streaming model invocations (which
+    /// return empty streams), file and artifact uploads, and the cancellation sent when a
+    /// `StatementHandle` is dropped. Read requests are still sent, so a deployment tool can
+    /// compute and show its plan against the live workspace.
+    ///
+    /// SQL statements are submitted with a `POST` and are therefore not executed either; composite
+    /// operations that depend on what a mutating call returns, such as waiting for a statement or a
+    /// created cluster, are not meaningful in this mode.
+    ///
+    /// Parameters:
+    /// - `enabled`: Whether to record mutating requests instead of sending them.
+    ///
+    /// Returns:
+    /// - The session, for chaining.
+    pub fn with_dry_run(mut self, enabled: bool) -> Self {
+        self.dry_run = enabled.then(|| Arc::new(Mutex::new(Vec::new())));
+        self
+    }
+
+    /// Returns a copy of the session that authenticates with `token` instead, e.g. to act as a
+    /// different service principal for one tenant's requests.
+    ///
+    /// Unlike the other `with_` methods, this borrows the session: the copy is cheap, sharing the
+    /// connection pool, settings and dry-run record of the original, which keeps its own
+    /// credentials.
+    ///
+    /// Parameters:
+    /// - `token`: The token the copy sends.
+    ///
+    /// Returns:
+    /// - The new session.
+    pub fn with_token(&self, token: &str) -> Self {
+        let mut session: DatabricksSession = self.with_shared_client();
+        session.config.databricks_token = token.to_string();
+        session.config.databricks_token_file = None;
+        session.credentials = None;
+        session
+    }
+
+    /// Returns a copy of the session that asks `provider` for the token of each request, like
+    /// `with_token` but for tokens that are refreshed or looked up per call.
+    ///
+    /// Parameters:
+    /// - `provider`: The `CredentialsProvider`, e.g. a closure returning the token.
+    ///
+    /// Returns:
+    /// - The new session.
+    pub fn with_credentials(&self, provider: impl CredentialsProvider + 'static) -> Self {
+        let mut session: DatabricksSession = self.with_shared_client();
+        session.credentials = Some(Arc::new(provider));
+        session
+    }
+
+    /// Returns a copy of the session that sends requests on behalf of an end user, e.g. with the
+    /// user's forwarded access token, for gateway services that pass a user's calls through.
+    /// Like `with_token`, the copy is cheap and meant to be created per call.
+    ///
+    /// Parameters:
+    /// - `context`: The `OnBehalfOf` describing the user's token and headers.
+    ///
+    /// Returns:
+    /// - A `Result` containing the new session, or an `HttpError::BadRequest` if a header name or
+    ///   value is invalid.
+    pub fn on_behalf_of(&self, context: &OnBehalfOf) -> Result<Self, HttpError> {
+        let mut session: DatabricksSession = match &context.access_token {
+            Some(token) => self.with_token(token),
+            None => self.with_shared_client(),
+        };
+        for (name, value) in &context.headers {
+            let name: HeaderName = name
+                .parse()
+                .map_err(|_| HttpError::BadRequest(format!("Invalid header name {}", name)))?;
+            let value: HeaderValue = value
+                .parse()
+                .map_err(|_| HttpError::BadRequest(format!("Invalid value for header {}", name)))?;
+            session.extra_headers.insert(name, value);
+        }
+        Ok(session)
+    }
+
+    /// A copy of the session sharing its client, settings and credentials.
+    pub(crate) fn with_shared_client(&self) -> Self {
+        DatabricksSession {
+            client: Arc::clone(&self.client),
+            credentials: self.credentials.clone(),
+            extra_headers: self.extra_headers.clone(),
+            config: self.config.clone(),
+            api_versions: self.api_versions.clone(),
+            max_response_bytes: self.max_response_bytes,
+            sql_settings: self.sql_settings.clone(),
+            body_serializer: self.body_serializer.clone(),
+            operation_timeout: self.operation_timeout,
+            dry_run: self.dry_run.clone(),
+        }
+    }
+
+    /// Whether the session is in dry-run mode.
+    pub fn is_dry_run(&self) -> bool {
+        self.dry_run.is_some()
+    }
+
+    /// The mutating requests recorded in dry-run mode so far, in the order they were made.
+    pub fn planned_requests(&self) -> Vec<PlannedRequest> {
+        self.dry_run
+            .as_ref()
+            .map(|planned| {
+                planned
+                    .lock()
+                    .unwrap_or_else(|err| err.into_inner())
+                    .clone()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Returns the recorded mutating requests and clears the record, e.g. between plan steps.
+    pub fn take_planned_requests(&self) -> Vec<PlannedRequest> {
+        self.dry_run
+            .as_ref()
+            .map(|planned| {
+                std::mem::take(&mut *planned.lock().unwrap_or_else(|err| err.into_inner()))
+            })
+            .unwrap_or_default()
+    }
+
+    /// In dry-run mode, records a mutating request instead of sending it and returns its simulated
+    /// response; returns `None` if the request should be sent.
+    fn simulate_request<T: DeserializeOwned, B: Serialize>(
+        &self,
+        method: &Method,
+        endpoint: &str,
+        body: Option<&B>,
+        serializer: Option<&dyn BodySerializer>,
+    ) -> Option<Result<T, HttpError>> {
+        if !self.is_planned(method) {
+            return None;
+        }
+        let body: Result<Option<Value>, serde_json::Error> = body
+            .map(|body| {
+                let value: Value = serde_json::to_value(body)?;
+                match serializer {
+                    Some(serializer) => serde_json::from_slice(&serializer.serialize(value)?),
+                    None => Ok(value),
+                }
+            })
+            .transpose();
+        match body {
+            Ok(body) => {
+                self.plan_request(method, endpoint, body);
+                Some(decode_json(endpoint, b"{}"))
+            }
+            Err(err) => Some(Err(HttpError::InternalServerError(err.to_string()))),
+        }
+    }
+
+    /// In dry-run mode, records a mutating request whose response is returned raw or streamed
+    /// instead of deserialized, so the caller can return an empty result.
+    ///
+    /// Returns:
+    /// - A `Result` containing whether the request was recorded instead of to be sent, or an
+    ///   `HttpError` if its body cannot be serialized.
+    pub(crate) fn plan_unread_request<B: Serialize>(
+        &self,
+        method: &Method,
+        endpoint: &str,
+        body: Option<&B>,
+    ) -> Result<bool, HttpError> {
+        match self.simulate_request::<Value, B>(
+            method,
+            endpoint,
+            body,
+            self.body_serializer.as_deref(),
+        ) {
+            Some(simulated) => simulated.map(|_| true),
+            None => Ok(false),
+        }
+    }
+
+    /// Whether a request with `method` is recorded instead of sent, i.e. the session is in
+    /// dry-run mode and the method is mutating.
+    pub(crate) fn is_planned(&self, method: &Method) -> bool {
+        self.dry_run.is_some() && is_mutating(method)
+    }
+
+    /// Logs and records a request that is not sent because of dry-run mode, with credentials in
+    /// its body redacted.
+    pub(crate) fn plan_request(&self, method: &Method, endpoint: &str, body: Option<Value>) {
+        if let Some(planned) = &self.dry_run {
+            record_planned_request(planned, method, endpoint, body);
+        }
+    }
+
+    /// The API version the session uses for `service`.
+    pub fn api_version(&self, service: ApiService) -> ApiVersion {
+        self.api_versions.version(service)
+    }
+
+    /// The point in time by which an operation starting now must finish, if the session has an
+    /// `operation_timeout`.
+    pub(crate) fn operation_deadline(&self) -> Option<Instant> {
+        self.operation_timeout
+            .map(|timeout| Instant::now() + timeout)
+    }
+
+    /// Runs one step of an operation, failing with `HttpError::OperationTimedOut` if `deadline`
+    /// passes first.
+    pub(crate) async fn before_deadline<T>(
+        &self,
+        deadline: Option<Instant>,
+        operation: &str,
+        step: impl Future<Output = Result<T, HttpError>>,
+    ) -> Result<T, HttpError> {
+        let Some(deadline) = deadline else {
+            return step.await;
+        };
+        tokio::time::timeout_at(deadline, step)
+            .await
+            .unwrap_or_else(|_| {
+                Err(HttpError::OperationTimedOut {
+                    operation: operation.to_string(),
+                    timeout: self.operation_timeout.unwrap_or_default(),
+                })
+            })
+    }
+
+    /// Builds the endpoint for `path` (e.g. `jobs/runs/get?run_id=1`) under the API version
+    /// selected for `service`.
+    pub(crate) fn api_endpoint(&self, service: ApiService, path: &str) -> String {
+        format!("api/{}/{}", self.api_version(service), path)
+    }
+
     /// Executes a SQL statement on Databricks and returns the response.
     ///
     /// This method submits a SQL statement for execution and provides the initial response,
     /// which includes details such as the statement ID for subsequent status checks or result retrieval.
+    /// The session's `SqlSessionSettings` are applied to the request first. To get a
+    /// `StatementHandle` that owns the statement instead of its raw ID, use `submit_sql_statement`.
     ///
     /// Parameters:
     /// - `request_body`: A `SqlStatementRequest` struct containing the SQL statement to be executed.
     ///
     /// Returns:
     /// - A `Result` containing the `SqlStatementResponse` if successful, or an `HttpError` if the request fails.
+    ///   A `wait_timeout` outside the accepted bounds is rejected with `HttpError::BadRequest`
+    ///   without sending the request.
     pub async fn execute_sql_statement(
         &self,
         request_body: SqlStatementRequest,
     ) -> Result<SqlStatementResponse, HttpError> {
-        self.send_databricks_request(Method::POST, "api/2.0/sql/statements", Some(request_body))
+        self.execute_sql_statement_with_meta(request_body)
             .await
+            .map(ResponseMeta::into_inner)
+    }
+
+    /// Executes a SQL statement like `execute_sql_statement`, additionally returning the request
+    /// ID and rate-limit headers of the response, e.g. to throttle a submission loop adaptively.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `SqlStatementRequest` struct containing the SQL statement to be executed.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `SqlStatementResponse` wrapped in a `ResponseMeta`, or an
+    ///   `HttpError` under the same conditions as `execute_sql_statement`.
+    pub async fn execute_sql_statement_with_meta(
+        &self,
+        request_body: SqlStatementRequest,
+    ) -> Result<ResponseMeta<SqlStatementResponse>, HttpError> {
+        request_body
+            .validate_wait_timeout()
+            .map_err(HttpError::BadRequest)?;
+        self.send_databricks_request_incremental_with_meta(
+            Method::POST,
+            "api/2.0/sql/statements",
+            Some(self.sql_settings.apply(request_body)),
+        )
+        .await
     }
 
     /// Retrieves the status of a previously executed SQL statement.
@@ -115,7 +512,24 @@ impl DatabricksSession {
         &self,
         statement_id: &str,
     ) -> Result<SqlStatementResponse, HttpError> {
-        self.send_databricks_request(
+        self.get_sql_statement_status_with_meta(statement_id)
+            .await
+            .map(ResponseMeta::into_inner)
+    }
+
+    /// Retrieves the status of a SQL statement like `get_sql_statement_status`, additionally
+    /// returning the request ID and rate-limit headers, e.g. to stretch the polling interval.
+    ///
+    /// Parameters:
+    /// - `statement_id`: The ID of the SQL statement execution to check.
+    ///
+    /// Returns:
+    /// - Same as `execute_sql_statement_with_meta`.
+    pub async fn get_sql_statement_status_with_meta(
+        &self,
+        statement_id: &str,
+    ) -> Result<ResponseMeta<SqlStatementResponse>, HttpError> {
+        self.send_databricks_request_incremental_with_meta(
             Method::GET,
             &format!("api/2.0/sql/statements/{}", statement_id),
             None::<()>,
@@ -131,6 +545,7 @@ impl DatabricksSession {
     /// Parameters:
     /// - `statement_id`: The ID of the SQL statement execution.
     /// - `chunk_index`: The index of the result chunk to retrieve.
+    ///
     /// Returns:
     /// - A `Result` containing the `ResultData` for the specified chunk, or an `HttpError` if the request fails.
     pub async fn get_sql_statement_result_chunk(
@@ -138,7 +553,7 @@ impl DatabricksSession {
         statement_id: &str,
         chunk_index: i32,
     ) -> Result<ResultData, HttpError> {
-        self.send_databricks_request(
+        self.send_databricks_request_incremental(
             Method::GET,
             &format!(
                 "api/2.0/sql/statements/{}/result/chunks/{}",
@@ -149,6 +564,348 @@ impl DatabricksSession {
         .await
     }
 
+    /// Requests cancellation of a running SQL statement. Cancellation is asynchronous; poll the
+    /// statement's status to observe the `CANCELED` state.
+    ///
+    /// Parameters:
+    /// - `statement_id`: The ID of the SQL statement execution to cancel.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn cancel_sql_statement(&self, statement_id: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            &format!("api/2.0/sql/statements/{}/cancel", statement_id),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Fetches a range of rows of a succeeded statement's result, downloading only the chunks
+    /// that hold them.
+    ///
+    /// The chunk layout is read from the statement's manifest; the first chunk, which the status
+    /// response already includes, is reused rather than fetched again.
+    ///
+    /// Parameters:
+    /// - `statement_id`: The ID of the SQL statement execution.
+    /// - `offset`: The index of the first row to return.
+    /// - `limit`: The maximum number of rows to return.
+    ///
+    /// Returns:
+    /// - A `Result` containing the rows (fewer than `limit` at the end of the result), or an
+    ///   `HttpError` if a request fails or the statement has not succeeded.
+    pub async fn get_rows(
+        &self,
+        statement_id: &str,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<Vec<Option<String>>>, HttpError> {
+        self.before_deadline(
+            self.operation_deadline(),
+            "get_rows",
+            self.read_rows(statement_id, offset, limit),
+        )
+        .await
+    }
+
+    async fn read_rows(
+        &self,
+        statement_id: &str,
+        offset: i64,
+        limit: i64,
+    ) -> Result<Vec<Vec<Option<String>>>, HttpError> {
+        let mut response: SqlStatementResponse =
+            self.get_sql_statement_status(statement_id).await?;
+        let state: StatementState = response.state().unwrap_or_default();
+        if !state.is_success() {
+            return Err(HttpError::BadRequest(format!(
+                "Statement {} has no result in state {}",
+                statement_id, state
+            )));
+        }
+        let manifest: Manifest = response.manifest.take().ok_or_else(|| {
+            HttpError::InternalServerError("Statement result has no manifest".to_string())
+        })?;
+        let mut first_chunk: Option<ResultData> = response.result.take();
+        let mut validator: ChunkValidator = ChunkValidator::new(statement_id, &manifest);
+
+        let mut rows: Vec<Vec<Option<String>>> = Vec::new();
+        for chunk in manifest.chunks_for_rows(offset, limit) {
+            let data: ResultData = match first_chunk.take() {
+                Some(data) if data.chunk_index.unwrap_or_default() == chunk.chunk_index => data,
+                _ => {
+                    self.get_sql_statement_result_chunk(statement_id, chunk.chunk_index)
+                        .await?
+                }
+            };
+            validator
+                .record_data(&data)
+                .map_err(HttpError::InvalidChunk)?;
+            let start: usize = (offset - chunk.row_offset).max(0) as usize;
+            let end: usize =
+                (offset.saturating_add(limit) - chunk.row_offset).min(chunk.row_count) as usize;
+            rows.extend(
+                data.data_array
+                    .unwrap_or_default()
+                    .into_iter()
+                    .take(end)
+                    .skip(start),
+            );
+        }
+        Ok(rows)
+    }
+
+    /// Executes a SQL statement and polls its status until it reaches a terminal state.
+    ///
+    /// The statement is submitted with the given request; if it has not finished within the
+    /// request's `wait_timeout`, the status is polled every second until it succeeds, fails or is
+    /// canceled. The request should use `OnWaitTimeout::Continue` for polling to be possible.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `SqlStatementRequest` struct containing the SQL statement to be executed.
+    ///
+    /// A truncated result (see `SqlStatementResponse::is_truncated`) is logged as a warning.
+    ///
+    /// Returns:
+    /// - A `Result` containing the final `SqlStatementResponse` if the statement succeeded, or an
+    ///   `HttpError` if the request fails or the statement ends in a failed or canceled state.
+    pub async fn execute_sql_statement_and_wait(
+        &self,
+        request_body: SqlStatementRequest,
+    ) -> Result<SqlStatementResponse, HttpError> {
+        self.execute_sql_statement_and_wait_until(
+            request_body,
+            self.operation_deadline(),
+            "execute_sql_statement_and_wait",
+        )
+        .await
+    }
+
+    /// Executes a statement and waits for it like `execute_sql_statement_and_wait`, failing with
+    /// `HttpError::OperationTimedOut` and canceling the statement if `deadline` passes first.
+    pub(crate) async fn execute_sql_statement_and_wait_until(
+        &self,
+        request_body: SqlStatementRequest,
+        deadline: Option<Instant>,
+        operation: &str,
+    ) -> Result<SqlStatementResponse, HttpError> {
+        let mut response: SqlStatementResponse = self
+            .before_deadline(
+                deadline,
+                operation,
+                self.execute_sql_statement(request_body),
+            )
+            .await?;
+        let statement_id: Option<String> = response.statement_id.clone();
+
+        let result: Result<SqlStatementResponse, HttpError> = self
+            .before_deadline(deadline, operation, self.wait_for_statement(&mut response))
+            .await
+            .map(|()| response);
+        if let (Err(HttpError::OperationTimedOut { .. }), Some(statement_id)) =
+            (&result, statement_id)
+        {
+            if let Err(err) = self.cancel_sql_statement(&statement_id).await {
+                log::warn!(
+                    "Could not cancel timed out statement {}: {}",
+                    statement_id,
+                    err
+                );
+            }
+        }
+        result
+    }
+
+    /// Polls a submitted statement every second until it reaches a terminal state.
+    ///
+    /// Parameters:
+    /// - `response`: The latest response for the statement, e.g. from `execute_sql_statement`.
+    ///   It is replaced by each polled response, so it holds the last known state even if
+    ///   waiting fails or is abandoned.
+    ///
+    /// Returns:
+    /// - An empty `Result` once the statement succeeded, or an `HttpError` if it failed or a
+    ///   request fails.
+    pub(crate) async fn wait_for_statement(
+        &self,
+        response: &mut SqlStatementResponse,
+    ) -> Result<(), HttpError> {
+        loop {
+            let state: StatementState = response
+                .status
+                .as_ref()
+                .map(|status| status.state)
+                .unwrap_or_default();
+
+            if state.is_success() {
+                if response.is_truncated() {
+                    log::warn!(
+                        "Result of statement {} is truncated to {} rows",
+                        response.statement_id.as_deref().unwrap_or_default(),
+                        response.row_count().unwrap_or_default()
+                    );
+                }
+                return Ok(());
+            }
+            if state.is_terminal() {
+                return Err(statement_error(response, state));
+            }
+
+            let statement_id: &str = response.statement_id.as_deref().ok_or_else(|| {
+                HttpError::InternalServerError("Statement response has no statement_id".to_string())
+            })?;
+            tokio::time::sleep(Duration::from_secs(1)).await;
+            *response = self.get_sql_statement_status(statement_id).await?;
+        }
+    }
+
+    /// Executes a single SQL statement on a warehouse and waits for it to complete.
+    ///
+    /// Results are always requested inline as JSON arrays, regardless of the session's
+    /// `SqlSessionSettings` disposition and format; use `execute_sql_statement_and_wait` for
+    /// external links or Arrow results.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the statement on.
+    /// - `statement`: The SQL text.
+    ///
+    /// Returns:
+    /// - Same as `execute_sql_statement_and_wait`.
+    pub async fn execute_sql(
+        &self,
+        warehouse_id: &str,
+        statement: &str,
+    ) -> Result<SqlStatementResponse, HttpError> {
+        self.execute_sql_statement_and_wait(
+            SqlStatementRequest::new(warehouse_id, statement)
+                .with_disposition("INLINE")
+                .with_format("JSON_ARRAY"),
+        )
+        .await
+    }
+
+    /// Executes a query and deserializes every row of its result into `T`.
+    ///
+    /// Results are always requested inline as JSON arrays, regardless of the session's
+    /// `SqlSessionSettings`; additional result chunks are fetched as needed.
+    /// Cells are converted according to the result schema, so `T` can use numeric, boolean and
+    /// nested fields, with struct field names matching the column names.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the query on.
+    /// - `statement`: The SQL query text.
+    ///
+    /// Returns:
+    /// - A `Result` containing the deserialized rows, or an `HttpError` if the query fails or a row
+    ///   cannot be deserialized into `T`.
+    pub async fn query_rows<T: DeserializeOwned>(
+        &self,
+        warehouse_id: &str,
+        statement: &str,
+    ) -> Result<Vec<T>, HttpError> {
+        let deadline: Option<Instant> = self.operation_deadline();
+        let mut response: SqlStatementResponse = self
+            .execute_sql_statement_and_wait_until(
+                SqlStatementRequest::new(warehouse_id, statement)
+                    .with_disposition("INLINE")
+                    .with_format("JSON_ARRAY"),
+                deadline,
+                "query_rows",
+            )
+            .await?;
+
+        let mut validator: Option<ChunkValidator> = ChunkValidator::for_response(&response);
+        let mut rows: Vec<Vec<Option<String>>> = Vec::new();
+        let mut next_chunk_index: Option<i32> = None;
+        if let Some(result) = response.result.take() {
+            if let Some(validator) = &mut validator {
+                validator
+                    .record_data(&result)
+                    .map_err(HttpError::InvalidChunk)?;
+            }
+            rows.extend(result.data_array.unwrap_or_default());
+            next_chunk_index = result.next_chunk_index;
+        }
+
+        while let Some(chunk_index) = next_chunk_index {
+            let statement_id: &str = response.statement_id.as_deref().unwrap_or_default();
+            let chunk: ResultData = self
+                .before_deadline(
+                    deadline,
+                    "query_rows",
+                    self.get_sql_statement_result_chunk(statement_id, chunk_index),
+                )
+                .await?;
+            if let Some(validator) = &mut validator {
+                validator
+                    .record_data(&chunk)
+                    .map_err(HttpError::InvalidChunk)?;
+            }
+            rows.extend(chunk.data_array.unwrap_or_default());
+            next_chunk_index = chunk.next_chunk_index;
+        }
+        if let Some(validator) = &validator {
+            validator.finish().map_err(HttpError::InvalidChunk)?;
+        }
+
+        let schema = response
+            .manifest
+            .as_ref()
+            .and_then(|manifest| manifest.schema.as_ref())
+            .ok_or_else(|| {
+                HttpError::InternalServerError("Statement result has no schema".to_string())
+            })?;
+
+        rows_as(schema, &rows).map_err(|err| HttpError::InternalServerError(err.to_string()))
+    }
+
+    /// Validates a query and counts its rows without transferring them, by running it wrapped in
+    /// `SELECT COUNT(*) FROM (...)`.
+    ///
+    /// The statement must be a query (`SELECT`, `WITH`, `VALUES`, ...); the request's row and
+    /// byte limits are dropped, and the result is always fetched inline.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `SqlStatementRequest` for the query to count.
+    ///
+    /// Returns:
+    /// - A `Result` containing the number of rows the query returns, or an `HttpError` if the query
+    ///   is invalid or fails.
+    pub async fn execute_count(&self, request_body: SqlStatementRequest) -> Result<i64, HttpError> {
+        // The closing parenthesis goes on its own line, so a trailing `--` comment in the
+        // statement cannot swallow it.
+        let statement: String = format!(
+            "SELECT COUNT(*) FROM (\n{}\n) AS counted",
+            request_body
+                .statement
+                .trim()
+                .trim_end_matches(';')
+                .trim_end()
+        );
+        let request_body: SqlStatementRequest = SqlStatementRequest {
+            statement,
+            row_limit: None,
+            byte_limit: None,
+            ..request_body
+        }
+        .with_disposition("INLINE")
+        .with_format("JSON_ARRAY");
+
+        let response: SqlStatementResponse =
+            self.execute_sql_statement_and_wait(request_body).await?;
+        response
+            .result
+            .and_then(|result| result.data_array)
+            .and_then(|rows| rows.into_iter().next())
+            .and_then(|row| row.into_iter().next().flatten())
+            .and_then(|count| count.parse().ok())
+            .ok_or_else(|| {
+                HttpError::InternalServerError("Count query returned no count".to_string())
+            })
+    }
+
     /// Retrieves information about a specific cluster.
     ///
     /// This method fetches detailed information about a Databricks cluster, identified by the cluster ID.
@@ -161,7 +918,10 @@ impl DatabricksSession {
     pub async fn get_cluster_info(&self, cluster_id: &str) -> Result<ClusterInfo, HttpError> {
         self.send_databricks_request(
             Method::GET,
-            &format!("api/2.0/clusters/get?cluster_id={}", cluster_id),
+            &self.api_endpoint(
+                ApiService::Clusters,
+                &format!("clusters/get?cluster_id={}", cluster_id),
+            ),
             None::<()>, // No body for GET request
         )
         .await
@@ -180,74 +940,332 @@ impl DatabricksSession {
     ///
     /// Returns:
     /// - A `Result` containing the deserialized response body if successful, or an `HttpError` if the request fails.
-    async fn send_databricks_request<T: DeserializeOwned, B: Serialize>(
+    pub(crate) async fn send_databricks_request<T: DeserializeOwned, B: Serialize>(
         &self,
         method: Method,
         endpoint: &str,
         body: Option<B>,
     ) -> Result<T, HttpError> {
-        let url: String = format!("{}/{}", self.config.databricks_host, endpoint);
+        if let Some(simulated) = self.simulate_request(
+            &method,
+            endpoint,
+            body.as_ref(),
+            self.body_serializer.as_deref(),
+        ) {
+            return simulated;
+        }
+        let response: reqwest::Response = self.send_request(method, endpoint, body).await?;
 
-        let mut headers: HeaderMap = HeaderMap::new();
-        headers.insert(
-            AUTHORIZATION,
-            format!("Bearer {}", self.config.databricks_token)
-                .parse()
-                .unwrap(),
-        );
+        handle_response(response, self.max_response_bytes).await
+    }
 
+    /// Sends a request like the typed methods do, but serializes its body with `serializer`
+    /// instead of the session's serializer, e.g. to omit `null` fields for one endpoint only.
+    ///
+    /// Parameters:
+    /// - `method`: The HTTP method to use for the request.
+    /// - `endpoint`: The API endpoint relative to the workspace host, e.g. `api/2.0/sql/warehouses/123/edit`.
+    /// - `body`: An optional request body.
+    /// - `serializer`: The `BodySerializer` that turns the body into JSON.
+    ///
+    /// Returns:
+    /// - A `Result` containing the deserialized response body if successful, or an `HttpError` if
+    ///   the request fails.
+    pub async fn send_databricks_request_with_serializer<T: DeserializeOwned, B: Serialize>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<B>,
+        serializer: &dyn BodySerializer,
+    ) -> Result<T, HttpError> {
+        if let Some(simulated) =
+            self.simulate_request(&method, endpoint, body.as_ref(), Some(serializer))
+        {
+            return simulated;
+        }
         let request_builder: reqwest::RequestBuilder =
-            self.client.request(method, &url).headers(headers);
+            self.json_request(method, endpoint, body, Some(serializer))?;
+        let response: reqwest::Response = send(request_builder).await?;
 
-        let request_builder: reqwest::RequestBuilder = if let Some(body) = body {
-            request_builder.json(&body)
-        } else {
-            request_builder
-        };
+        handle_response(response, self.max_response_bytes).await
+    }
 
-        let response = request_builder.send().await.map_err(|err| {
-            if err.is_timeout() {
-                HttpError::TemporarilyUnavailable(err.to_string())
-            } else {
-                HttpError::InternalServerError(err.to_string())
-            }
-        })?;
+    /// Sends a request to the Databricks API and returns the successful response body as raw
+    /// bytes, without deserializing it, e.g. to persist a payload exactly as received.
+    ///
+    /// Parameters:
+    /// - `method`: The HTTP method to use for the request.
+    /// - `endpoint`: The API endpoint relative to the workspace host, e.g. `api/2.1/jobs/get?job_id=1`.
+    /// - `body`: An optional request body to serialize as JSON.
+    ///
+    /// Returns:
+    /// - A `Result` containing the response body as `Bytes`, or an `HttpError` if the request fails.
+    pub async fn send_databricks_request_bytes<B: Serialize>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<B>,
+    ) -> Result<Bytes, HttpError> {
+        if self.plan_unread_request(&method, endpoint, body.as_ref())? {
+            return Ok(Bytes::from_static(b"{}"));
+        }
+        let response: reqwest::Response = self
+            .send_databricks_request_streaming(method, endpoint, body)
+            .await?;
+        read_body(response, self.max_response_bytes).await
+    }
 
-        self.handle_response(response).await
+    /// Sends a request to any workspace API endpoint, including ones this crate does not model,
+    /// reusing the session's credentials and connection pool.
+    ///
+    /// Unlike the typed methods, error statuses are not converted into an `HttpError`; the
+    /// status and body are returned as received so the caller can interpret them.
+    ///
+    /// Parameters:
+    /// - `method`: The HTTP method to use for the request.
+    /// - `endpoint`: The API endpoint relative to the workspace host, e.g. `api/2.0/preview/scim/v2/Me`.
+    /// - `body`: An optional request body to serialize as JSON.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `StatusCode` and body `Bytes`, or an `HttpError` if the
+    ///   request could not be sent or its body could not be read.
+    pub async fn request_raw<B: Serialize>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<B>,
+    ) -> Result<(StatusCode, Bytes), HttpError> {
+        if self.plan_unread_request(&method, endpoint, body.as_ref())? {
+            return Ok((StatusCode::OK, Bytes::from_static(b"{}")));
+        }
+        let response: reqwest::Response = self.send_request(method, endpoint, body).await?;
+        let status: StatusCode = response.status();
+        let body: Bytes = read_body(response, self.max_response_bytes).await?;
+        Ok((status, body))
     }
 
-    /// Handles the HTTP response, deserializing the JSON body or converting errors.
+    /// Sends a request to any workspace API endpoint and returns the successful response body as a
+    /// stream of byte chunks, e.g. to write a payload too large for `max_response_bytes` to disk.
+    ///
+    /// Parameters:
+    /// - `method`: The HTTP method to use for the request.
+    /// - `endpoint`: The API endpoint relative to the workspace host, e.g. `api/2.0/sql/statements/123`.
+    /// - `body`: An optional request body to serialize as JSON.
+    ///
+    /// Returns:
+    /// - A `Result` containing the stream of body chunks, or an `HttpError` if the request fails.
+    pub async fn request_stream<B: Serialize>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<B>,
+    ) -> Result<impl Stream<Item = Result<Bytes, HttpError>>, HttpError> {
+        if self.plan_unread_request(&method, endpoint, body.as_ref())? {
+            return Ok(futures::stream::empty().left_stream());
+        }
+        let response: reqwest::Response = self
+            .send_databricks_request_streaming(method, endpoint, body)
+            .await?;
+        Ok(response
+            .bytes_stream()
+            .map_err(|err| HttpError::InternalServerError(err.to_string()))
+            .right_stream())
+    }
+
+    /// Sends a request to any workspace API endpoint that streams its response as server-sent
+    /// events (`text/event-stream`), and yields each event as it arrives.
+    ///
+    /// Parameters:
+    /// - `method`: The HTTP method to use for the request.
+    /// - `endpoint`: The API endpoint relative to the workspace host.
+    /// - `body`: An optional request body to serialize as JSON.
     ///
-    /// This internal method processes the HTTP response from the Databricks API, attempting to deserialize
-    /// the response body into the expected type or converting HTTP errors into `HttpError` instances.
+    /// Returns:
+    /// - A `Result` containing the stream of `SseEvent`s, or an `HttpError` if the request fails.
+    ///   The stream ends when the server closes the response.
+    pub async fn request_sse<B: Serialize>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<B>,
+    ) -> Result<impl Stream<Item = Result<SseEvent, HttpError>>, HttpError> {
+        if self.plan_unread_request(&method, endpoint, body.as_ref())? {
+            return Ok(futures::stream::empty().left_stream());
+        }
+        let request_builder: reqwest::RequestBuilder = self
+            .json_request(method, endpoint, body, self.body_serializer.as_deref())?
+            .header(ACCEPT, "text/event-stream");
+
+        let response: reqwest::Response = self.send_raw_request(request_builder).await?;
+        Ok(sse_events(response).right_stream())
+    }
+
+    /// Like `send_databricks_request`, but deserializes the response body while it is being
+    /// received instead of buffering it first, roughly halving peak memory for large payloads such
+    /// as inline statement results.
     ///
     /// Parameters:
-    /// - `response`: The `reqwest::Response` object to process.
+    /// - Same as `send_databricks_request`.
     ///
     /// Returns:
-    /// - A `Result` containing the deserialized response body if the request was successful, or an `HttpError`
-    ///   if there was an error with the request or response processing.
-    async fn handle_response<T: DeserializeOwned>(
+    /// - Same as `send_databricks_request`.
+    pub(crate) async fn send_databricks_request_incremental<
+        T: DeserializeOwned + Send + 'static,
+        B: Serialize,
+    >(
         &self,
-        response: reqwest::Response,
+        method: Method,
+        endpoint: &str,
+        body: Option<B>,
     ) -> Result<T, HttpError> {
-        let status: StatusCode = response.status();
-        let body_text: String = response
-            .text()
+        self.send_databricks_request_incremental_with_meta(method, endpoint, body)
             .await
-            .unwrap_or_else(|_| "Failed to get response text".to_string());
-
-        match status {
-            reqwest::StatusCode::OK => serde_json::from_str::<T>(&body_text)
-                .map_err(|err| HttpError::InternalServerError(err.to_string())),
-            _ => {
-                let error: ErrorResponse =
-                    serde_json::from_str(&body_text).unwrap_or(ErrorResponse {
-                        error_code: "UNKNOWN".to_string(),
-                        message: format!("Unknown error with status code: {}", status),
-                    });
-                Err(HttpError::from_error_response(error))
+            .map(ResponseMeta::into_inner)
+    }
+
+    /// Like `send_databricks_request_incremental`, but also returns the request ID and rate-limit
+    /// headers of the response.
+    ///
+    /// Parameters:
+    /// - Same as `send_databricks_request`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the deserialized body wrapped in a `ResponseMeta`, or an
+    ///   `HttpError` if the request fails.
+    pub(crate) async fn send_databricks_request_incremental_with_meta<
+        T: DeserializeOwned + Send + 'static,
+        B: Serialize,
+    >(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<B>,
+    ) -> Result<ResponseMeta<T>, HttpError> {
+        if let Some(simulated) = self.simulate_request::<T, B>(
+            &method,
+            endpoint,
+            body.as_ref(),
+            self.body_serializer.as_deref(),
+        ) {
+            return simulated.map(|value| ResponseMeta::new(value, &HeaderMap::new()));
+        }
+        let response: reqwest::Response = self.send_request(method, endpoint, body).await?;
+        let headers: HeaderMap = response.headers().clone();
+
+        let value: T = handle_response_incremental(response, self.max_response_bytes).await?;
+        Ok(ResponseMeta::new(value, &headers))
+    }
+
+    /// Sends a request to the Databricks API and returns the successful response unread, so that
+    /// its body can be consumed incrementally (e.g. as a stream of server-sent events).
+    ///
+    /// Parameters:
+    /// - Same as `send_databricks_request`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `reqwest::Response` if the status indicates success, or an
+    ///   `HttpError` built from the error body otherwise.
+    pub(crate) async fn send_databricks_request_streaming<B: Serialize>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<B>,
+    ) -> Result<reqwest::Response, HttpError> {
+        let request_builder: reqwest::RequestBuilder =
+            self.json_request(method, endpoint, body, self.body_serializer.as_deref())?;
+
+        self.send_raw_request(request_builder).await
+    }
+
+    /// Builds the authenticated request, serializes the body and sends it.
+    async fn send_request<B: Serialize>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<B>,
+    ) -> Result<reqwest::Response, HttpError> {
+        let request_builder: reqwest::RequestBuilder =
+            self.json_request(method, endpoint, body, self.body_serializer.as_deref())?;
+
+        send(request_builder).await
+    }
+
+    /// Builds the authenticated request with a JSON body, serialized by `serializer` if given and
+    /// directly from the model otherwise.
+    fn json_request<B: Serialize>(
+        &self,
+        method: Method,
+        endpoint: &str,
+        body: Option<B>,
+        serializer: Option<&dyn BodySerializer>,
+    ) -> Result<reqwest::RequestBuilder, HttpError> {
+        let request_builder: reqwest::RequestBuilder = self.authorized_request(method, endpoint)?;
+
+        match (body, serializer) {
+            (Some(body), Some(serializer)) => {
+                let bytes: Vec<u8> = serde_json::to_value(&body)
+                    .and_then(|value| serializer.serialize(value))
+                    .map_err(|err| HttpError::InternalServerError(err.to_string()))?;
+                Ok(request_builder
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(bytes))
             }
+            (Some(body), None) => Ok(request_builder.json(&body)),
+            (None, _) => Ok(request_builder),
+        }
+    }
+
+    /// Builds a request to a workspace API endpoint carrying the session's credentials, for
+    /// callers that send a non-JSON body (e.g. file uploads) or read the response as raw bytes.
+    ///
+    /// Fails with `HttpError::Unauthorized` if the token (e.g. one read from a token file or
+    /// returned by a `CredentialsProvider`) cannot be sent as a header value.
+    pub(crate) fn authorized_request(
+        &self,
+        method: Method,
+        endpoint: &str,
+    ) -> Result<reqwest::RequestBuilder, HttpError> {
+        let url: String = format!("{}/{}", self.config.databricks_host, endpoint);
+        let token: String = match &self.credentials {
+            Some(credentials) => credentials.token(),
+            None => self.config.databricks_token.clone(),
+        };
+
+        let mut authorization: HeaderValue = HeaderValue::from_str(&format!("Bearer {}", token))
+            .map_err(|_| {
+                HttpError::Unauthorized(
+                    "The access token contains characters that are not allowed in a header"
+                        .to_string(),
+                )
+            })?;
+        authorization.set_sensitive(true);
+        let mut headers: HeaderMap = self.extra_headers.clone();
+        headers.insert(AUTHORIZATION, authorization);
+
+        Ok(self.client.request(method, &url).headers(headers))
+    }
+
+    /// Sends a request built with `authorized_request` and returns the response unread if the
+    /// status indicates success.
+    ///
+    /// Parameters:
+    /// - `request_builder`: The request to send.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `reqwest::Response`, or an `HttpError` built from the error body.
+    pub(crate) async fn send_raw_request(
+        &self,
+        request_builder: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, HttpError> {
+        let response: reqwest::Response = send(request_builder).await?;
+        let status: StatusCode = response.status();
+
+        if status.is_success() {
+            Ok(response)
+        } else {
+            let retry_after: Option<Duration> = retry_after(response.headers());
+            let body: Bytes = response.bytes().await.unwrap_or_default();
+            Err(error_from_body(status, retry_after, &body))
         }
     }
 
@@ -255,7 +1273,7 @@ impl DatabricksSession {
     ///
     /// This asynchronous method sends a request to the Databricks API to trigger
     /// a job run based on the provided job configuration. The method constructs a POST
-    /// request to the `/api/2.1/jobs/run-now` endpoint with a `JobRunRequest` body,
+    /// request to the `jobs/run-now` endpoint with a `JobRunRequest` body,
     /// which includes various optional parameters to customize the job run.
     ///
     /// Parameters:
@@ -272,7 +1290,316 @@ impl DatabricksSession {
         &self,
         request_body: JobRunRequest,
     ) -> Result<JobRunResponse, HttpError> {
-        self.send_databricks_request(Method::POST, "api/2.1/jobs/run-now", Some(request_body))
+        self.send_databricks_request(
+            Method::POST,
+            &self.api_endpoint(ApiService::Jobs, "jobs/run-now"),
+            Some(request_body),
+        )
+        .await
+    }
+}
+
+/// The `TokenFile` a session reads its token from, if the configuration names one.
+fn token_file(config: &Config) -> Option<Arc<dyn CredentialsProvider>> {
+    config.databricks_token_file.as_ref().map(|path| {
+        Arc::new(TokenFile::new(
+            path.clone(),
+            config.databricks_token.clone(),
+        )) as Arc<dyn CredentialsProvider>
+    })
+}
+
+/// Handles the HTTP response, deserializing the JSON body or converting errors.
+///
+/// This internal method processes the HTTP response from the Databricks API, attempting to deserialize
+/// the response body into the expected type or converting HTTP errors into `HttpError` instances.
+///
+/// Parameters:
+/// - `response`: The `reqwest::Response` object to process.
+///
+/// Returns:
+/// - A `Result` containing the deserialized response body if the request was successful, or an `HttpError`
+///   if there was an error with the request or response processing.
+pub(crate) async fn handle_response<T: DeserializeOwned>(
+    response: reqwest::Response,
+    max_bytes: Option<u64>,
+) -> Result<T, HttpError> {
+    let status: StatusCode = response.status();
+    let endpoint: String = response.url().path().to_string();
+    if !status.is_success() {
+        let retry_after: Option<Duration> = retry_after(response.headers());
+        let body: Bytes = response.bytes().await.unwrap_or_default();
+        return Err(error_from_body(status, retry_after, &body));
+    }
+
+    let body: Bytes = read_body(response, max_bytes).await?;
+    // Some endpoints (e.g. PATCH/DELETE) reply with an empty body on success.
+    if body.trim_ascii().is_empty() {
+        decode_json(&endpoint, b"{}")
+    } else {
+        decode_json(&endpoint, &body)
+    }
+}
+
+/// Handles the HTTP response like `handle_response`, but parses a successful body directly from
+/// the network stream on a blocking thread, so the raw JSON text is never held in memory.
+pub(crate) async fn handle_response_incremental<T: DeserializeOwned + Send + 'static>(
+    response: reqwest::Response,
+    max_bytes: Option<u64>,
+) -> Result<T, HttpError> {
+    let status: StatusCode = response.status();
+    if !status.is_success() {
+        return handle_response(response, max_bytes).await;
+    }
+
+    let endpoint: String = response.url().path().to_string();
+    check_content_length(&response, &endpoint, max_bytes)?;
+
+    let exceeded: Arc<AtomicBool> = Arc::new(AtomicBool::new(false));
+    let mut received: u64 = 0;
+    let stream = response.bytes_stream().map({
+        let exceeded: Arc<AtomicBool> = Arc::clone(&exceeded);
+        move |chunk| {
+            let chunk: Bytes = chunk.map_err(io::Error::other)?;
+            received += chunk.len() as u64;
+            if max_bytes.is_some_and(|limit| received > limit) {
+                exceeded.store(true, Ordering::Relaxed);
+                return Err(io::Error::other("response exceeds the maximum size"));
+            }
+            Ok(chunk)
+        }
+    });
+    let reader = SyncIoBridge::new(StreamReader::new(stream));
+    let result: Result<T, DecodeError> = tokio::task::spawn_blocking({
+        let endpoint: String = endpoint.clone();
+        move || {
+            let mut deserializer =
+                serde_json::Deserializer::from_reader(io::BufReader::new(reader));
+            serde_path_to_error::deserialize(&mut deserializer)
+                .map_err(|err| decode_error(&endpoint, err, None))
+        }
+    })
+    .await
+    .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+
+    match (result, max_bytes) {
+        (Err(_), Some(limit)) if exceeded.load(Ordering::Relaxed) => {
+            Err(HttpError::ResponseTooLarge { endpoint, limit })
+        }
+        (result, _) => result.map_err(HttpError::DecodeError),
+    }
+}
+
+/// Reads a response body into memory, failing with `HttpError::ResponseTooLarge` as soon as it is
+/// known to exceed `max_bytes`.
+async fn read_body(
+    response: reqwest::Response,
+    max_bytes: Option<u64>,
+) -> Result<Bytes, HttpError> {
+    let Some(limit) = max_bytes else {
+        return response
+            .bytes()
             .await
+            .map_err(|err| HttpError::InternalServerError(err.to_string()));
+    };
+
+    let endpoint: String = response.url().path().to_string();
+    check_content_length(&response, &endpoint, max_bytes)?;
+
+    let mut body: BytesMut = BytesMut::new();
+    let mut stream = response.bytes_stream();
+    while let Some(chunk) = stream.next().await {
+        let chunk: Bytes = chunk.map_err(|err| HttpError::InternalServerError(err.to_string()))?;
+        if (body.len() + chunk.len()) as u64 > limit {
+            return Err(HttpError::ResponseTooLarge { endpoint, limit });
+        }
+        body.extend_from_slice(&chunk);
+    }
+    Ok(body.freeze())
+}
+
+/// Rejects a response up front if its declared `Content-Length` exceeds `max_bytes`.
+fn check_content_length(
+    response: &reqwest::Response,
+    endpoint: &str,
+    max_bytes: Option<u64>,
+) -> Result<(), HttpError> {
+    match (response.content_length(), max_bytes) {
+        (Some(length), Some(limit)) if length > limit => Err(HttpError::ResponseTooLarge {
+            endpoint: endpoint.to_string(),
+            limit,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Deserializes a JSON body, reporting failures as `HttpError::DecodeError` with the path of the
+/// offending field and the surrounding text.
+pub(crate) fn decode_json<T: DeserializeOwned>(
+    endpoint: &str,
+    body: &[u8],
+) -> Result<T, HttpError> {
+    let mut deserializer = serde_json::Deserializer::from_slice(body);
+    serde_path_to_error::deserialize(&mut deserializer)
+        .map_err(|err| HttpError::DecodeError(decode_error(endpoint, err, Some(body))))
+}
+
+fn decode_error(
+    endpoint: &str,
+    err: serde_path_to_error::Error<serde_json::Error>,
+    body: Option<&[u8]>,
+) -> DecodeError {
+    let path: String = err.path().to_string();
+    let inner: serde_json::Error = err.into_inner();
+    let snippet: String = body
+        .map(|body| snippet_at(body, inner.line(), inner.column()))
+        .unwrap_or_default();
+
+    DecodeError {
+        endpoint: endpoint.to_string(),
+        path,
+        snippet,
+        message: inner.to_string(),
+    }
+}
+
+/// Returns up to 40 bytes of `body` on either side of a 1-based line and column.
+fn snippet_at(body: &[u8], line: usize, column: usize) -> String {
+    const CONTEXT: usize = 40;
+
+    let line_start: usize = body
+        .split_inclusive(|byte| *byte == b'\n')
+        .take(line.saturating_sub(1))
+        .map(<[u8]>::len)
+        .sum();
+    let offset: usize = (line_start + column.saturating_sub(1)).min(body.len());
+    let start: usize = offset.saturating_sub(CONTEXT);
+    let end: usize = (offset + CONTEXT).min(body.len());
+    String::from_utf8_lossy(&body[start..end]).into_owned()
+}
+
+/// Runs `operation`, retrying it while it fails with `HttpError::RequestLimitExceeded`, at most
+/// `max_retries` times. Each retry waits as long as the server's `Retry-After` header asked for,
+/// or otherwise backs off exponentially (1s, 2s, 4s, ...).
+pub(crate) async fn retry_rate_limited<T, F, Fut>(
+    max_retries: u32,
+    mut operation: F,
+) -> Result<T, HttpError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, HttpError>>,
+{
+    let mut delay: Duration = Duration::from_secs(1);
+    let mut retries: u32 = 0;
+    loop {
+        match operation().await {
+            Err(HttpError::RequestLimitExceeded {
+                message,
+                retry_after,
+            }) if retries < max_retries => {
+                let wait: Duration = retry_after.unwrap_or(delay);
+                log::debug!("Rate limited ({}), retrying in {:?}", message, wait);
+                tokio::time::sleep(wait).await;
+                delay *= 2;
+                retries += 1;
+            }
+            result => return result,
+        }
     }
 }
+
+/// Whether dry-run mode records requests with `method` instead of sending them.
+pub(crate) fn is_mutating(method: &Method) -> bool {
+    method != Method::GET && method != Method::HEAD
+}
+
+/// Logs and records a request that is not sent because of dry-run mode, with credentials in its
+/// body redacted.
+pub(crate) fn record_planned_request(
+    planned: &Mutex<Vec<PlannedRequest>>,
+    method: &Method,
+    endpoint: &str,
+    mut body: Option<Value>,
+) {
+    if let Some(body) = &mut body {
+        redact_secrets(body);
+    }
+    log::info!(
+        "Dry run: not sending {} {} {}",
+        method,
+        endpoint,
+        body.as_ref().map(Value::to_string).unwrap_or_default()
+    );
+    planned
+        .lock()
+        .unwrap_or_else(|err| err.into_inner())
+        .push(PlannedRequest {
+            method: method.to_string(),
+            endpoint: endpoint.to_string(),
+            body,
+        });
+}
+
+/// Sends a request, mapping transport failures onto `HttpError`.
+async fn send(request_builder: reqwest::RequestBuilder) -> Result<reqwest::Response, HttpError> {
+    request_builder.send().await.map_err(|err| {
+        if err.is_timeout() {
+            HttpError::TemporarilyUnavailable(err.to_string())
+        } else {
+            HttpError::InternalServerError(err.to_string())
+        }
+    })
+}
+
+/// Converts a non-success response body into an `HttpError`, falling back to the status code when
+/// the body is not a Databricks error payload or carries an unknown `error_code` (e.g. an empty
+/// 429 or 404 from a gateway, or any response to a HEAD request).
+pub(crate) fn error_from_body(
+    status: StatusCode,
+    retry_after: Option<Duration>,
+    body: &[u8],
+) -> HttpError {
+    let error: HttpError = match serde_json::from_slice::<ErrorResponse>(body) {
+        Ok(error) => HttpError::from_error_code(&error.error_code, error.message)
+            .unwrap_or_else(|message| HttpError::from_status(status.as_u16(), message)),
+        Err(_) => HttpError::from_status(
+            status.as_u16(),
+            format!("Unknown error with status code: {}", status),
+        ),
+    };
+    match error {
+        HttpError::RequestLimitExceeded { message, .. } => HttpError::RequestLimitExceeded {
+            message,
+            retry_after,
+        },
+        error => error,
+    }
+}
+
+/// The delay a `Retry-After` header asks for, if it is given in seconds.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    headers
+        .get(RETRY_AFTER)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.trim().parse().ok())
+        .map(Duration::from_secs)
+}
+
+/// Converts a statement that ended in a non-successful state into an `HttpError`.
+fn statement_error(response: &SqlStatementResponse, state: StatementState) -> HttpError {
+    let error = response
+        .status
+        .as_ref()
+        .and_then(|status| status.error.as_ref());
+    let error_code: String = error
+        .and_then(|error| error.error_code.clone())
+        .unwrap_or("UNKNOWN".to_string());
+    let message: String = error
+        .and_then(|error| error.message.clone())
+        .unwrap_or(format!("Statement finished in state {}", state));
+
+    HttpError::from_error_response(ErrorResponse {
+        error_code,
+        message,
+    })
+}
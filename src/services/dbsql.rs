@@ -0,0 +1,258 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        AlertInfo, DashboardInfo, ListAlertsResponse, ListDashboardsResponse, ListQueriesResponse,
+        QueryInfo, QueryTemplate, SqlObjectType, SqlStatementRequest, SqlStatementResponse,
+        TransferOwnershipRequest, TransferOwnershipResponse, TransferredSqlObject,
+    },
+    services::DatabricksSession,
+    utils::encode_query_value,
+};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Lists all saved Databricks SQL queries the caller can access, following pagination until
+    /// exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `QueryInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_queries(&self) -> Result<Vec<QueryInfo>, HttpError> {
+        let mut queries: Vec<QueryInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let page: ListQueriesResponse = self
+                .send_databricks_request(
+                    Method::GET,
+                    &page_endpoint("api/2.0/sql/queries", page_token.as_deref()),
+                    None::<()>,
+                )
+                .await?;
+            queries.extend(page.results);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(queries),
+            }
+        }
+    }
+
+    /// Retrieves a saved Databricks SQL query, including its query text.
+    ///
+    /// Parameters:
+    /// - `query_id`: The ID of the query.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `QueryInfo`, or an `HttpError` if the request fails.
+    pub async fn get_query(&self, query_id: &str) -> Result<QueryInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &format!("api/2.0/sql/queries/{}", query_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Executes a templated query with its bound values and waits for the result.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the query on.
+    /// - `template`: The `QueryTemplate`, e.g. parsed from a saved query's `query_text`, with a
+    ///   value bound to every parameter.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `SqlStatementResponse`, or an `HttpError::BadRequest` if a
+    ///   parameter is unbound, or an `HttpError` if the statement fails.
+    pub async fn execute_query_template(
+        &self,
+        warehouse_id: &str,
+        template: &QueryTemplate,
+    ) -> Result<SqlStatementResponse, HttpError> {
+        let request: SqlStatementRequest = template
+            .to_request(warehouse_id)
+            .map_err(HttpError::BadRequest)?;
+        self.execute_sql_statement_and_wait(request).await
+    }
+
+    /// Lists all Databricks SQL alerts the caller can access, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `AlertInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_alerts(&self) -> Result<Vec<AlertInfo>, HttpError> {
+        let mut alerts: Vec<AlertInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let page: ListAlertsResponse = self
+                .send_databricks_request(
+                    Method::GET,
+                    &page_endpoint("api/2.0/sql/alerts", page_token.as_deref()),
+                    None::<()>,
+                )
+                .await?;
+            alerts.extend(page.results);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(alerts),
+            }
+        }
+    }
+
+    /// Lists all AI/BI dashboards the caller can access, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `DashboardInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_dashboards(&self) -> Result<Vec<DashboardInfo>, HttpError> {
+        let mut dashboards: Vec<DashboardInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let page: ListDashboardsResponse = self
+                .send_databricks_request(
+                    Method::GET,
+                    &page_endpoint("api/2.0/lakeview/dashboards", page_token.as_deref()),
+                    None::<()>,
+                )
+                .await?;
+            dashboards.extend(page.dashboards);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(dashboards),
+            }
+        }
+    }
+
+    /// Transfers ownership of a query, alert or legacy dashboard to another user.
+    ///
+    /// Parameters:
+    /// - `object_type`: The kind of object.
+    /// - `object_id`: The ID of the object.
+    /// - `new_owner`: The user name (email) of the new owner.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `TransferOwnershipResponse` if successful, or an `HttpError` if
+    ///   the request fails.
+    pub async fn transfer_sql_object_ownership(
+        &self,
+        object_type: SqlObjectType,
+        object_id: &str,
+        new_owner: &str,
+    ) -> Result<TransferOwnershipResponse, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &format!(
+                "api/2.0/preview/sql/permissions/{}/{}/transfer",
+                object_type.as_path_segment(),
+                object_id
+            ),
+            Some(TransferOwnershipRequest {
+                new_owner: new_owner.to_string(),
+            }),
+        )
+        .await
+    }
+
+    /// Transfers ownership of a query to another user.
+    ///
+    /// Parameters:
+    /// - `query_id`: The ID of the query.
+    /// - `new_owner`: The user name (email) of the new owner.
+    ///
+    /// Returns:
+    /// - Same as `transfer_sql_object_ownership`.
+    pub async fn transfer_query_ownership(
+        &self,
+        query_id: &str,
+        new_owner: &str,
+    ) -> Result<TransferOwnershipResponse, HttpError> {
+        self.transfer_sql_object_ownership(SqlObjectType::Queries, query_id, new_owner)
+            .await
+    }
+
+    /// Transfers ownership of an alert to another user.
+    ///
+    /// Parameters:
+    /// - `alert_id`: The ID of the alert.
+    /// - `new_owner`: The user name (email) of the new owner.
+    ///
+    /// Returns:
+    /// - Same as `transfer_sql_object_ownership`.
+    pub async fn transfer_alert_ownership(
+        &self,
+        alert_id: &str,
+        new_owner: &str,
+    ) -> Result<TransferOwnershipResponse, HttpError> {
+        self.transfer_sql_object_ownership(SqlObjectType::Alerts, alert_id, new_owner)
+            .await
+    }
+
+    /// Transfers ownership of a legacy Databricks SQL dashboard to another user.
+    ///
+    /// Parameters:
+    /// - `dashboard_id`: The ID of the dashboard.
+    /// - `new_owner`: The user name (email) of the new owner.
+    ///
+    /// Returns:
+    /// - Same as `transfer_sql_object_ownership`.
+    pub async fn transfer_dashboard_ownership(
+        &self,
+        dashboard_id: &str,
+        new_owner: &str,
+    ) -> Result<TransferOwnershipResponse, HttpError> {
+        self.transfer_sql_object_ownership(SqlObjectType::Dashboards, dashboard_id, new_owner)
+            .await
+    }
+
+    /// Hands every query and alert owned by one user over to another, e.g. when offboarding.
+    ///
+    /// Objects are transferred one by one; if a transfer fails, the objects transferred before it
+    /// keep their new owner.
+    ///
+    /// Parameters:
+    /// - `previous_owner`: The user name (email) of the current owner.
+    /// - `new_owner`: The user name (email) of the new owner.
+    ///
+    /// Returns:
+    /// - A `Result` containing the transferred objects, or an `HttpError` if listing or any
+    ///   transfer fails.
+    pub async fn transfer_sql_objects_owned_by(
+        &self,
+        previous_owner: &str,
+        new_owner: &str,
+    ) -> Result<Vec<TransferredSqlObject>, HttpError> {
+        let mut owned: Vec<TransferredSqlObject> = Vec::new();
+        for query in self.list_queries().await? {
+            if query.owner_user_name.as_deref() == Some(previous_owner) {
+                owned.push(TransferredSqlObject {
+                    object_type: SqlObjectType::Queries,
+                    object_id: query.id,
+                    display_name: query.display_name,
+                });
+            }
+        }
+        for alert in self.list_alerts().await? {
+            if alert.owner_user_name.as_deref() == Some(previous_owner) {
+                owned.push(TransferredSqlObject {
+                    object_type: SqlObjectType::Alerts,
+                    object_id: alert.id,
+                    display_name: alert.display_name,
+                });
+            }
+        }
+
+        for object in &owned {
+            self.transfer_sql_object_ownership(object.object_type, &object.object_id, new_owner)
+                .await?;
+        }
+        Ok(owned)
+    }
+}
+
+fn page_endpoint(endpoint: &str, page_token: Option<&str>) -> String {
+    match page_token {
+        Some(token) => format!("{}?page_token={}", endpoint, encode_query_value(token)),
+        None => endpoint.to_string(),
+    }
+}
@@ -0,0 +1,58 @@
+use crate::{
+    errors::HttpError,
+    models::{TableDetail, TableHistoryEntry},
+    services::DatabricksSession,
+    utils::quote_qualified_name,
+};
+
+impl DatabricksSession {
+    /// Lists the commits of a Delta table by running `DESCRIBE HISTORY`, newest first.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the statement on.
+    /// - `table_full_name`: The full name of the table, e.g. `main.sales.orders`.
+    /// - `limit`: The maximum number of commits to return, or `None` for the whole retained history.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `TableHistoryEntry` rows, or an `HttpError` if the statement
+    ///   fails or its result cannot be parsed.
+    pub async fn describe_history(
+        &self,
+        warehouse_id: &str,
+        table_full_name: &str,
+        limit: Option<u32>,
+    ) -> Result<Vec<TableHistoryEntry>, HttpError> {
+        let mut statement: String =
+            format!("DESCRIBE HISTORY {}", quote_qualified_name(table_full_name));
+        if let Some(limit) = limit {
+            statement.push_str(&format!(" LIMIT {}", limit));
+        }
+        self.query_rows(warehouse_id, &statement).await
+    }
+
+    /// Retrieves the current snapshot of a Delta table (location, size, file count, properties)
+    /// by running `DESCRIBE DETAIL`.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the statement on.
+    /// - `table_full_name`: The full name of the table.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `TableDetail`, or an `HttpError` if the statement fails or
+    ///   returns no row.
+    pub async fn describe_detail(
+        &self,
+        warehouse_id: &str,
+        table_full_name: &str,
+    ) -> Result<TableDetail, HttpError> {
+        let statement: String =
+            format!("DESCRIBE DETAIL {}", quote_qualified_name(table_full_name));
+        let rows: Vec<TableDetail> = self.query_rows(warehouse_id, &statement).await?;
+        rows.into_iter().next().ok_or_else(|| {
+            HttpError::InternalServerError(format!(
+                "DESCRIBE DETAIL returned no row for {}",
+                table_full_name
+            ))
+        })
+    }
+}
@@ -0,0 +1,515 @@
+use crate::{
+    config::ApiService,
+    errors::HttpError,
+    models::{
+        ActivationUrlInfo, CreateProviderRequest, CreateRecipientRequest, CreateShareRequest,
+        ListProviderSharesResponse, ListProvidersResponse, ListRecipientsResponse,
+        ListSharesResponse, ProviderInfo, ProviderShare, RecipientInfo,
+        RotateRecipientTokenRequest, ShareInfo, SharedDataObject, SharedDataObjectUpdate,
+        SharedDataObjectUpdateAction, UpdateProviderRequest, UpdateRecipientRequest,
+        UpdateShareRequest,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+    utils::encode_query_value,
+};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Creates an empty share. Data objects are added afterwards with `add_share_objects`.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateShareRequest` naming the share.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `ShareInfo` if successful, or an `HttpError` if the request fails.
+    pub async fn create_share(
+        &self,
+        request_body: CreateShareRequest,
+    ) -> Result<ShareInfo, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/shares"),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Retrieves a share, including the data objects it contains.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the share.
+    ///
+    /// Returns:
+    /// - Same as `create_share`.
+    pub async fn get_share(&self, name: &str) -> Result<ShareInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/shares/{}?include_shared_data=true",
+                    encode_query_value(name)
+                ),
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Updates a share's name, owner, comment and/or data objects.
+    ///
+    /// Parameters:
+    /// - `name`: The current name of the share.
+    /// - `request_body`: An `UpdateShareRequest` containing the changes.
+    ///
+    /// Returns:
+    /// - Same as `create_share`.
+    pub async fn update_share(
+        &self,
+        name: &str,
+        request_body: UpdateShareRequest,
+    ) -> Result<ShareInfo, HttpError> {
+        self.send_databricks_request(
+            Method::PATCH,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/shares/{}", encode_query_value(name)),
+            ),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Adds data objects (tables, schemas, volumes, ...) to a share.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the share.
+    /// - `objects`: The data objects to add.
+    ///
+    /// Returns:
+    /// - Same as `create_share`.
+    pub async fn add_share_objects(
+        &self,
+        name: &str,
+        objects: Vec<SharedDataObject>,
+    ) -> Result<ShareInfo, HttpError> {
+        self.update_share_objects(name, SharedDataObjectUpdateAction::Add, objects)
+            .await
+    }
+
+    /// Removes data objects from a share.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the share.
+    /// - `objects`: The data objects to remove; only `name` needs to be set.
+    ///
+    /// Returns:
+    /// - Same as `create_share`.
+    pub async fn remove_share_objects(
+        &self,
+        name: &str,
+        objects: Vec<SharedDataObject>,
+    ) -> Result<ShareInfo, HttpError> {
+        self.update_share_objects(name, SharedDataObjectUpdateAction::Remove, objects)
+            .await
+    }
+
+    async fn update_share_objects(
+        &self,
+        name: &str,
+        action: SharedDataObjectUpdateAction,
+        objects: Vec<SharedDataObject>,
+    ) -> Result<ShareInfo, HttpError> {
+        let updates: Vec<SharedDataObjectUpdate> = objects
+            .into_iter()
+            .map(|data_object| SharedDataObjectUpdate {
+                action,
+                data_object,
+            })
+            .collect();
+        self.update_share(
+            name,
+            UpdateShareRequest {
+                updates,
+                ..Default::default()
+            },
+        )
+        .await
+    }
+
+    /// Deletes a share.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the share.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_share(&self, name: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/shares/{}", encode_query_value(name)),
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Lists all shares, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `ShareInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_shares(&self) -> Result<Vec<ShareInfo>, HttpError> {
+        let mut shares: Vec<ShareInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => self.api_endpoint(
+                    ApiService::UnityCatalog,
+                    &format!(
+                        "unity-catalog/shares?page_token={}",
+                        encode_query_value(token)
+                    ),
+                ),
+                None => self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/shares"),
+            };
+            let page: ListSharesResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            shares.extend(page.shares);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(shares),
+            }
+        }
+    }
+
+    /// Creates a recipient that shares can be granted to.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateRecipientRequest` describing the recipient.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `RecipientInfo` if successful, or an `HttpError` if the request fails.
+    pub async fn create_recipient(
+        &self,
+        request_body: CreateRecipientRequest,
+    ) -> Result<RecipientInfo, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/recipients"),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Retrieves a recipient by name.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the recipient.
+    ///
+    /// Returns:
+    /// - Same as `create_recipient`.
+    pub async fn get_recipient(&self, name: &str) -> Result<RecipientInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/recipients/{}", encode_query_value(name)),
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Updates a recipient.
+    ///
+    /// Parameters:
+    /// - `name`: The current name of the recipient.
+    /// - `request_body`: An `UpdateRecipientRequest` containing the fields to change.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn update_recipient(
+        &self,
+        name: &str,
+        request_body: UpdateRecipientRequest,
+    ) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::PATCH,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/recipients/{}", encode_query_value(name)),
+            ),
+            Some(request_body),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Deletes a recipient, revoking its access to all shares.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the recipient.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_recipient(&self, name: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/recipients/{}", encode_query_value(name)),
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Lists all recipients, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `RecipientInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_recipients(&self) -> Result<Vec<RecipientInfo>, HttpError> {
+        let mut recipients: Vec<RecipientInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => self.api_endpoint(
+                    ApiService::UnityCatalog,
+                    &format!(
+                        "unity-catalog/recipients?page_token={}",
+                        encode_query_value(token)
+                    ),
+                ),
+                None => self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/recipients"),
+            };
+            let page: ListRecipientsResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            recipients.extend(page.recipients);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(recipients),
+            }
+        }
+    }
+
+    /// Rotates the bearer token of a token-authenticated recipient.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the recipient.
+    /// - `existing_token_expire_in_seconds`: How long the current token stays valid; `0` revokes it
+    ///   immediately.
+    ///
+    /// Returns:
+    /// - A `Result` containing the updated `RecipientInfo` with the new token's activation URL, or an
+    ///   `HttpError` if the request fails.
+    pub async fn rotate_recipient_token(
+        &self,
+        name: &str,
+        existing_token_expire_in_seconds: i64,
+    ) -> Result<RecipientInfo, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/recipients/{}/rotate-token",
+                    encode_query_value(name)
+                ),
+            ),
+            Some(RotateRecipientTokenRequest {
+                existing_token_expire_in_seconds,
+            }),
+        )
+        .await
+    }
+
+    /// Retrieves the activation URL that a token-authenticated recipient uses to download its
+    /// credential file.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the recipient.
+    ///
+    /// Returns:
+    /// - A `Result` containing the activation URL, `None` if the recipient has no pending activation,
+    ///   or an `HttpError` if the request fails.
+    pub async fn get_recipient_activation_url(
+        &self,
+        name: &str,
+    ) -> Result<Option<String>, HttpError> {
+        let recipient: RecipientInfo = self.get_recipient(name).await?;
+        Ok(recipient.latest_activation_url().map(str::to_string))
+    }
+
+    /// Retrieves the public information behind an activation URL.
+    ///
+    /// Parameters:
+    /// - `activation_url`: The one-time activation URL (or its trailing code).
+    ///
+    /// Returns:
+    /// - A `Result` containing the `ActivationUrlInfo`, or an `HttpError` if the request fails.
+    pub async fn get_activation_url_info(
+        &self,
+        activation_url: &str,
+    ) -> Result<ActivationUrlInfo, HttpError> {
+        let code: &str = activation_url.rsplit('/').next().unwrap_or(activation_url);
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/public/data_sharing_activation_info/{}",
+                    encode_query_value(code)
+                ),
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Creates a provider from a credential file received from a data provider.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateProviderRequest` describing the provider.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `ProviderInfo` if successful, or an `HttpError` if the request fails.
+    pub async fn create_provider(
+        &self,
+        request_body: CreateProviderRequest,
+    ) -> Result<ProviderInfo, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/providers"),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Retrieves a provider by name.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the provider.
+    ///
+    /// Returns:
+    /// - Same as `create_provider`.
+    pub async fn get_provider(&self, name: &str) -> Result<ProviderInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/providers/{}", encode_query_value(name)),
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Updates a provider.
+    ///
+    /// Parameters:
+    /// - `name`: The current name of the provider.
+    /// - `request_body`: An `UpdateProviderRequest` containing the fields to change.
+    ///
+    /// Returns:
+    /// - Same as `create_provider`.
+    pub async fn update_provider(
+        &self,
+        name: &str,
+        request_body: UpdateProviderRequest,
+    ) -> Result<ProviderInfo, HttpError> {
+        self.send_databricks_request(
+            Method::PATCH,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/providers/{}", encode_query_value(name)),
+            ),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Deletes a provider.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the provider.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_provider(&self, name: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/providers/{}", encode_query_value(name)),
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Lists all providers, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `ProviderInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_providers(&self) -> Result<Vec<ProviderInfo>, HttpError> {
+        let mut providers: Vec<ProviderInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => self.api_endpoint(
+                    ApiService::UnityCatalog,
+                    &format!(
+                        "unity-catalog/providers?page_token={}",
+                        encode_query_value(token)
+                    ),
+                ),
+                None => self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/providers"),
+            };
+            let page: ListProvidersResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            providers.extend(page.providers);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(providers),
+            }
+        }
+    }
+
+    /// Lists the shares a provider has made available to this metastore.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the provider.
+    ///
+    /// Returns:
+    /// - A `Result` containing the provider's shares, or an `HttpError` if the request fails.
+    pub async fn list_provider_shares(&self, name: &str) -> Result<Vec<ProviderShare>, HttpError> {
+        let response: ListProviderSharesResponse = self
+            .send_databricks_request(
+                Method::GET,
+                &self.api_endpoint(
+                    ApiService::UnityCatalog,
+                    &format!(
+                        "unity-catalog/providers/{}/shares",
+                        encode_query_value(name)
+                    ),
+                ),
+                None::<()>,
+            )
+            .await?;
+        Ok(response.shares)
+    }
+}
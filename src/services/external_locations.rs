@@ -0,0 +1,304 @@
+use crate::{
+    config::ApiService,
+    errors::HttpError,
+    models::{
+        CreateExternalLocationRequest, CreateStorageCredentialRequest, ExternalLocationInfo,
+        ListExternalLocationsResponse, ListStorageCredentialsResponse, StorageCredentialInfo,
+        UpdateExternalLocationRequest, UpdateStorageCredentialRequest,
+        ValidateStorageCredentialRequest, ValidateStorageCredentialResponse,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+    utils::encode_query_value,
+};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Creates a storage credential backed by a cloud identity (IAM role, managed identity, ...).
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateStorageCredentialRequest` describing the credential.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `StorageCredentialInfo` if successful, or an `HttpError` if the request fails.
+    pub async fn create_storage_credential(
+        &self,
+        request_body: CreateStorageCredentialRequest,
+    ) -> Result<StorageCredentialInfo, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                "unity-catalog/storage-credentials",
+            ),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Retrieves a storage credential by name.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the storage credential.
+    ///
+    /// Returns:
+    /// - Same as `create_storage_credential`.
+    pub async fn get_storage_credential(
+        &self,
+        name: &str,
+    ) -> Result<StorageCredentialInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/storage-credentials/{}",
+                    encode_query_value(name)
+                ),
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Updates a storage credential.
+    ///
+    /// Parameters:
+    /// - `name`: The current name of the storage credential.
+    /// - `request_body`: An `UpdateStorageCredentialRequest` containing the fields to change.
+    ///
+    /// Returns:
+    /// - Same as `create_storage_credential`.
+    pub async fn update_storage_credential(
+        &self,
+        name: &str,
+        request_body: UpdateStorageCredentialRequest,
+    ) -> Result<StorageCredentialInfo, HttpError> {
+        self.send_databricks_request(
+            Method::PATCH,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/storage-credentials/{}",
+                    encode_query_value(name)
+                ),
+            ),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Deletes a storage credential.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the storage credential.
+    /// - `force`: Whether to delete the credential even if it has dependent external locations.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_storage_credential(
+        &self,
+        name: &str,
+        force: bool,
+    ) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/storage-credentials/{}?force={}",
+                    encode_query_value(name),
+                    force
+                ),
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Lists all storage credentials, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `StorageCredentialInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_storage_credentials(&self) -> Result<Vec<StorageCredentialInfo>, HttpError> {
+        let mut credentials: Vec<StorageCredentialInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => self.api_endpoint(
+                    ApiService::UnityCatalog,
+                    &format!(
+                        "unity-catalog/storage-credentials?page_token={}",
+                        encode_query_value(token)
+                    ),
+                ),
+                None => self.api_endpoint(
+                    ApiService::UnityCatalog,
+                    "unity-catalog/storage-credentials",
+                ),
+            };
+            let page: ListStorageCredentialsResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            credentials.extend(page.storage_credentials);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(credentials),
+            }
+        }
+    }
+
+    /// Validates that a storage credential can access the given external location or URL.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `ValidateStorageCredentialRequest` naming the credential and target.
+    ///
+    /// Returns:
+    /// - A `Result` containing the per-operation `ValidateStorageCredentialResponse`, or an `HttpError`
+    ///   if the request fails.
+    pub async fn validate_storage_credential(
+        &self,
+        request_body: ValidateStorageCredentialRequest,
+    ) -> Result<ValidateStorageCredentialResponse, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                "unity-catalog/validate-storage-credentials",
+            ),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Creates an external location, binding a cloud storage path to a storage credential.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateExternalLocationRequest` describing the location.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `ExternalLocationInfo` if successful, or an `HttpError` if the request fails.
+    pub async fn create_external_location(
+        &self,
+        request_body: CreateExternalLocationRequest,
+    ) -> Result<ExternalLocationInfo, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/external-locations"),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Retrieves an external location by name.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the external location.
+    ///
+    /// Returns:
+    /// - Same as `create_external_location`.
+    pub async fn get_external_location(
+        &self,
+        name: &str,
+    ) -> Result<ExternalLocationInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/external-locations/{}",
+                    encode_query_value(name)
+                ),
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Updates an external location.
+    ///
+    /// Parameters:
+    /// - `name`: The current name of the external location.
+    /// - `request_body`: An `UpdateExternalLocationRequest` containing the fields to change.
+    ///
+    /// Returns:
+    /// - Same as `create_external_location`.
+    pub async fn update_external_location(
+        &self,
+        name: &str,
+        request_body: UpdateExternalLocationRequest,
+    ) -> Result<ExternalLocationInfo, HttpError> {
+        self.send_databricks_request(
+            Method::PATCH,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/external-locations/{}",
+                    encode_query_value(name)
+                ),
+            ),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Deletes an external location.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the external location.
+    /// - `force`: Whether to delete the location even if it has dependent tables or volumes.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_external_location(&self, name: &str, force: bool) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/external-locations/{}?force={}",
+                    encode_query_value(name),
+                    force
+                ),
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Lists all external locations, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `ExternalLocationInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_external_locations(&self) -> Result<Vec<ExternalLocationInfo>, HttpError> {
+        let mut locations: Vec<ExternalLocationInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => self.api_endpoint(
+                    ApiService::UnityCatalog,
+                    &format!(
+                        "unity-catalog/external-locations?page_token={}",
+                        encode_query_value(token)
+                    ),
+                ),
+                None => {
+                    self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/external-locations")
+                }
+            };
+            let page: ListExternalLocationsResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            locations.extend(page.external_locations);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(locations),
+            }
+        }
+    }
+}
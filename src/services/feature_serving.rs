@@ -0,0 +1,162 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        CreateOnlineTableRequest, CreateServingEndpointRequest, EndpointCoreConfigInput,
+        FeatureValues, OnlineTable, OnlineTableSpec, QueryServingEndpointRequest,
+        ServedEntityInput, ServingEndpointDetailed,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+};
+use reqwest::Method;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+impl DatabricksSession {
+    /// Creates an online table: a low-latency, read-only copy of a Delta table used for feature lookups.
+    ///
+    /// Parameters:
+    /// - `name`: The full name of the online table, e.g. `main.features.users_online`.
+    /// - `spec`: An `OnlineTableSpec` naming the source table, its primary key and the sync mode.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `OnlineTable` if successful, or an `HttpError` if the request fails.
+    pub async fn create_online_table(
+        &self,
+        name: &str,
+        spec: OnlineTableSpec,
+    ) -> Result<OnlineTable, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            "api/2.0/online-tables",
+            Some(CreateOnlineTableRequest {
+                name: name.to_string(),
+                spec,
+            }),
+        )
+        .await
+    }
+
+    /// Retrieves an online table, including its sync status.
+    ///
+    /// Parameters:
+    /// - `name`: The full name of the online table.
+    ///
+    /// Returns:
+    /// - Same as `create_online_table`.
+    pub async fn get_online_table(&self, name: &str) -> Result<OnlineTable, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &format!("api/2.0/online-tables/{}", name),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Deletes an online table. The source table is not affected.
+    ///
+    /// Parameters:
+    /// - `name`: The full name of the online table.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_online_table(&self, name: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!("api/2.0/online-tables/{}", name),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Polls an online table until it is online.
+    ///
+    /// Parameters:
+    /// - `name`: The full name of the online table.
+    /// - `timeout`: The maximum time to wait.
+    ///
+    /// Returns:
+    /// - A `Result` containing the online `OnlineTable`, or an `HttpError` if polling fails,
+    ///   provisioning fails, or the timeout elapses.
+    pub async fn wait_until_online_table_ready(
+        &self,
+        name: &str,
+        timeout: Duration,
+    ) -> Result<OnlineTable, HttpError> {
+        let started: Instant = Instant::now();
+
+        loop {
+            let table: OnlineTable = self.get_online_table(name).await?;
+            if table.is_online() {
+                return Ok(table);
+            }
+            if table.has_failed() {
+                let message: String = table
+                    .status
+                    .and_then(|status| status.message)
+                    .unwrap_or_default();
+                return Err(HttpError::BadRequest(format!(
+                    "Online table {} failed: {}",
+                    name, message
+                )));
+            }
+            if started.elapsed() >= timeout {
+                return Err(HttpError::TemporarilyUnavailable(format!(
+                    "Online table {} was not online after {:?}",
+                    name, timeout
+                )));
+            }
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        }
+    }
+
+    /// Creates a feature serving endpoint for a feature spec.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the serving endpoint.
+    /// - `feature_spec_name`: The full name of the feature spec, e.g. `main.features.user_spec`.
+    ///
+    /// Returns:
+    /// - Same as `create_serving_endpoint`.
+    pub async fn create_feature_serving_endpoint(
+        &self,
+        name: &str,
+        feature_spec_name: &str,
+    ) -> Result<ServingEndpointDetailed, HttpError> {
+        self.create_serving_endpoint(CreateServingEndpointRequest {
+            name: name.to_string(),
+            config: EndpointCoreConfigInput {
+                served_entities: vec![ServedEntityInput::feature_spec(feature_spec_name)],
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await
+    }
+
+    /// Looks up feature values from a feature serving endpoint.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the feature serving endpoint.
+    /// - `lookup_keys`: One record per lookup, holding the primary key columns (and any
+    ///   on-demand feature inputs), e.g. `[{"user_id": 42}]` or a slice of structs.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `FeatureValues`, one output per lookup key, or an `HttpError`
+    ///   if the keys cannot be serialized as records or the request fails.
+    pub async fn lookup_features<K: Serialize>(
+        &self,
+        name: &str,
+        lookup_keys: &[K],
+    ) -> Result<FeatureValues, HttpError> {
+        let request_body: QueryServingEndpointRequest =
+            QueryServingEndpointRequest::from_records(lookup_keys)
+                .map_err(|err| HttpError::BadRequest(err.to_string()))?;
+        self.send_databricks_request(
+            Method::POST,
+            &format!("serving-endpoints/{}/invocations", name),
+            Some(request_body),
+        )
+        .await
+    }
+}
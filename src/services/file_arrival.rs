@@ -0,0 +1,109 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        ExternalLocationInfo, FileArrivalCheck, FileArrivalTrigger,
+        ValidateStorageCredentialRequest, ValidateStorageCredentialResponse,
+        ValidationResultStatus,
+    },
+    services::{
+        databricks_session::error_from_body, files::directories_endpoint, DatabricksSession,
+    },
+};
+use reqwest::{Method, StatusCode};
+
+impl DatabricksSession {
+    /// Tests whether a file-arrival trigger can monitor its URL, without deploying the job.
+    ///
+    /// Volume paths must name an existing directory. Other URLs must lie within an external
+    /// location, whose storage credential is then validated against the URL for read access; this
+    /// needs the same privileges as `validate_storage_credential`.
+    ///
+    /// Parameters:
+    /// - `trigger`: The trigger to test.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `FileArrivalCheck`, or an `HttpError` if the trigger settings
+    ///   are out of range (`HttpError::BadRequest`) or a request fails.
+    pub async fn check_file_arrival_trigger(
+        &self,
+        trigger: &FileArrivalTrigger,
+    ) -> Result<FileArrivalCheck, HttpError> {
+        trigger.validate().map_err(HttpError::BadRequest)?;
+        let mut check: FileArrivalCheck = FileArrivalCheck {
+            url: trigger.url.clone(),
+            ..Default::default()
+        };
+
+        if trigger.is_volume() {
+            // HEAD responses carry no error body, so the status is checked directly.
+            let (status, _): (StatusCode, _) = self
+                .request_raw(
+                    Method::HEAD,
+                    &directories_endpoint(&trigger.url),
+                    None::<()>,
+                )
+                .await?;
+            if status == StatusCode::NOT_FOUND {
+                check
+                    .problems
+                    .push(format!("Directory {} does not exist", trigger.url));
+            } else if !status.is_success() {
+                return Err(error_from_body(status, None, &[]));
+            }
+            return Ok(check);
+        }
+
+        let locations: Vec<ExternalLocationInfo> = self.list_external_locations().await?;
+        let Some(location) = containing_location(&trigger.url, &locations) else {
+            check.problems.push(format!(
+                "No external location contains {}; file arrival triggers can only monitor external locations and volumes",
+                trigger.url
+            ));
+            return Ok(check);
+        };
+        check.external_location = Some(location.name.clone());
+
+        let validation: ValidateStorageCredentialResponse = self
+            .validate_storage_credential(ValidateStorageCredentialRequest {
+                storage_credential_name: Some(location.credential_name.clone()),
+                url: Some(trigger.url.clone()),
+                read_only: Some(true),
+                ..Default::default()
+            })
+            .await?;
+        for result in &validation.results {
+            if result.result == Some(ValidationResultStatus::Fail) {
+                check.problems.push(format!(
+                    "{} check failed: {}",
+                    result.operation.as_deref().unwrap_or("Storage"),
+                    result.message.as_deref().unwrap_or("no details")
+                ));
+            }
+        }
+        if validation.is_dir == Some(false) {
+            check
+                .problems
+                .push(format!("{} is not a directory", trigger.url));
+        }
+        check.results = validation.results;
+        Ok(check)
+    }
+}
+
+/// The most specific external location whose URL is `url` or one of its parent directories.
+fn containing_location<'a>(
+    url: &str,
+    locations: &'a [ExternalLocationInfo],
+) -> Option<&'a ExternalLocationInfo> {
+    let url: &str = url.trim_end_matches('/');
+    locations
+        .iter()
+        .filter(|location| {
+            let root: &str = location.url.trim_end_matches('/');
+            url == root
+                || url
+                    .strip_prefix(root)
+                    .is_some_and(|rest| rest.starts_with('/'))
+        })
+        .max_by_key(|location| location.url.trim_end_matches('/').len())
+}
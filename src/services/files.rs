@@ -0,0 +1,300 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        DbfsAddBlockRequest, DbfsCreateRequest, DbfsFileInfo, DbfsHandle, DbfsReadResponse,
+        FileMetadata,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+    utils::encode_query_value,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use reqwest::{
+    header::{HeaderMap, CONTENT_LENGTH, CONTENT_TYPE, LAST_MODIFIED},
+    Body, Method, Response,
+};
+use tokio::io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt};
+use tokio_util::io::ReaderStream;
+
+/// The maximum block size accepted by the DBFS read and add-block endpoints.
+const DBFS_BLOCK_SIZE: usize = 1024 * 1024;
+
+impl DatabricksSession {
+    /// Retrieves the size and type of a file through the Files API.
+    ///
+    /// Parameters:
+    /// - `path`: The absolute path, e.g. `/Volumes/main/default/landing/data.csv`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `FileMetadata`, or an `HttpError` if the file does not exist or
+    ///   the request fails.
+    pub async fn get_file_metadata(&self, path: &str) -> Result<FileMetadata, HttpError> {
+        let response: Response = self
+            .send_raw_request(self.authorized_request(Method::HEAD, &files_endpoint(path))?)
+            .await?;
+        let headers: &HeaderMap = response.headers();
+        let header = |name| {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::to_string)
+        };
+
+        Ok(FileMetadata {
+            content_length: header(CONTENT_LENGTH).and_then(|value| value.parse().ok()),
+            content_type: header(CONTENT_TYPE),
+            last_modified: header(LAST_MODIFIED),
+        })
+    }
+
+    /// Streams a file from a volume (or the workspace) into `writer` without buffering it in memory.
+    ///
+    /// Parameters:
+    /// - `path`: The absolute path of the file.
+    /// - `writer`: The destination, e.g. a `tokio::fs::File`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the number of bytes written, or an `HttpError` if the download fails.
+    pub async fn download_file<W: AsyncWrite + Unpin>(
+        &self,
+        path: &str,
+        writer: &mut W,
+    ) -> Result<u64, HttpError> {
+        let mut response: Response = self
+            .send_raw_request(self.authorized_request(Method::GET, &files_endpoint(path))?)
+            .await?;
+
+        let mut written: u64 = 0;
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|err| HttpError::InternalServerError(err.to_string()))?
+        {
+            writer
+                .write_all(&chunk)
+                .await
+                .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+            written += chunk.len() as u64;
+        }
+        writer
+            .flush()
+            .await
+            .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+        Ok(written)
+    }
+
+    /// Streams `reader` into a file in a volume (or the workspace).
+    ///
+    /// Parameters:
+    /// - `path`: The absolute path of the file.
+    /// - `reader`: The source, e.g. a `tokio::fs::File`.
+    /// - `content_length`: The size of the payload, if known.
+    /// - `overwrite`: Whether to replace an existing file.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the upload fails.
+    pub async fn upload_file<R: AsyncRead + Send + Sync + 'static>(
+        &self,
+        path: &str,
+        reader: R,
+        content_length: Option<u64>,
+        overwrite: bool,
+    ) -> Result<(), HttpError> {
+        let endpoint: String = format!("{}?overwrite={}", files_endpoint(path), overwrite);
+        if self.is_planned(&Method::PUT) {
+            self.plan_request(&Method::PUT, &endpoint, None);
+            return Ok(());
+        }
+        let mut request = self
+            .authorized_request(Method::PUT, &endpoint)?
+            .header(CONTENT_TYPE, "application/octet-stream");
+        if let Some(content_length) = content_length {
+            request = request.header(CONTENT_LENGTH, content_length);
+        }
+
+        self.send_raw_request(request.body(Body::wrap_stream(ReaderStream::new(reader))))
+            .await
+            .map(|_| ())
+    }
+
+    /// Deletes a file through the Files API.
+    ///
+    /// Parameters:
+    /// - `path`: The absolute path of the file.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_file(&self, path: &str) -> Result<(), HttpError> {
+        if self.is_planned(&Method::DELETE) {
+            self.plan_request(&Method::DELETE, &files_endpoint(path), None);
+            return Ok(());
+        }
+        self.send_raw_request(self.authorized_request(Method::DELETE, &files_endpoint(path))?)
+            .await
+            .map(|_| ())
+    }
+
+    /// Retrieves the status of a DBFS file or directory.
+    ///
+    /// Parameters:
+    /// - `path`: The DBFS path, without the `dbfs:` scheme, e.g. `/tmp/data.csv`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `DbfsFileInfo`, or an `HttpError` if the request fails.
+    pub async fn get_dbfs_status(&self, path: &str) -> Result<DbfsFileInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &format!("api/2.0/dbfs/get-status?path={}", encode_query_value(path)),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Streams a DBFS file into `writer`, reading it in 1 MB blocks.
+    ///
+    /// Parameters:
+    /// - `path`: The DBFS path, without the `dbfs:` scheme.
+    /// - `writer`: The destination, e.g. a `tokio::fs::File`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the number of bytes written, or an `HttpError` if the download fails.
+    pub async fn download_dbfs_file<W: AsyncWrite + Unpin>(
+        &self,
+        path: &str,
+        writer: &mut W,
+    ) -> Result<u64, HttpError> {
+        let mut offset: u64 = 0;
+
+        loop {
+            let block: DbfsReadResponse = self
+                .send_databricks_request(
+                    Method::GET,
+                    &format!(
+                        "api/2.0/dbfs/read?path={}&offset={}&length={}",
+                        encode_query_value(path),
+                        offset,
+                        DBFS_BLOCK_SIZE
+                    ),
+                    None::<()>,
+                )
+                .await?;
+            if block.bytes_read == 0 {
+                break;
+            }
+
+            let data: Vec<u8> = STANDARD
+                .decode(block.data.as_bytes())
+                .map_err(|err| HttpError::InternalServerError(err.to_string()))?;
+            writer
+                .write_all(&data)
+                .await
+                .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+            offset += block.bytes_read;
+        }
+
+        writer
+            .flush()
+            .await
+            .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+        Ok(offset)
+    }
+
+    /// Uploads `reader` to a DBFS file through a streaming handle, in 1 MB blocks.
+    ///
+    /// Parameters:
+    /// - `path`: The DBFS path, without the `dbfs:` scheme.
+    /// - `reader`: The source, e.g. a `tokio::fs::File`.
+    /// - `overwrite`: Whether to replace an existing file.
+    ///
+    /// Returns:
+    /// - A `Result` containing the number of bytes uploaded, or an `HttpError` if the upload fails.
+    pub async fn upload_dbfs_file<R: AsyncRead + Unpin>(
+        &self,
+        path: &str,
+        reader: &mut R,
+        overwrite: bool,
+    ) -> Result<u64, HttpError> {
+        let handle: DbfsHandle = self
+            .send_databricks_request(
+                Method::POST,
+                "api/2.0/dbfs/create",
+                Some(DbfsCreateRequest {
+                    path: path.to_string(),
+                    overwrite,
+                }),
+            )
+            .await?;
+
+        let result: Result<u64, HttpError> = self.add_dbfs_blocks(handle.handle, reader).await;
+
+        // The handle is closed even if a block failed, so that it does not linger until it expires.
+        let closed: Result<EmptyResponse, HttpError> = self
+            .send_databricks_request(Method::POST, "api/2.0/dbfs/close", Some(handle))
+            .await;
+        let uploaded: u64 = result?;
+        closed.map(|_| uploaded)
+    }
+
+    async fn add_dbfs_blocks<R: AsyncRead + Unpin>(
+        &self,
+        handle: i64,
+        reader: &mut R,
+    ) -> Result<u64, HttpError> {
+        let mut buffer: Vec<u8> = vec![0; DBFS_BLOCK_SIZE];
+        let mut uploaded: u64 = 0;
+
+        loop {
+            let filled: usize = read_block(reader, &mut buffer)
+                .await
+                .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+            if filled == 0 {
+                return Ok(uploaded);
+            }
+
+            self.send_databricks_request::<EmptyResponse, _>(
+                Method::POST,
+                "api/2.0/dbfs/add-block",
+                Some(DbfsAddBlockRequest {
+                    handle,
+                    data: STANDARD.encode(&buffer[..filled]),
+                }),
+            )
+            .await?;
+            uploaded += filled as u64;
+        }
+    }
+}
+
+/// Builds the Files API endpoint for an absolute path, percent-encoding each path segment.
+fn files_endpoint(path: &str) -> String {
+    format!("api/2.0/fs/files/{}", encode_path(path))
+}
+
+/// The Files API endpoint of the directory `path`.
+pub(crate) fn directories_endpoint(path: &str) -> String {
+    format!("api/2.0/fs/directories/{}", encode_path(path))
+}
+
+fn encode_path(path: &str) -> String {
+    let segments: Vec<String> = path
+        .trim_start_matches('/')
+        .split('/')
+        .map(encode_query_value)
+        .collect();
+    segments.join("/")
+}
+
+/// Fills `buffer` as far as possible, returning fewer bytes only at the end of the input.
+async fn read_block<R: AsyncRead + Unpin>(
+    reader: &mut R,
+    buffer: &mut [u8],
+) -> std::io::Result<usize> {
+    let mut filled: usize = 0;
+    while filled < buffer.len() {
+        let read: usize = reader.read(&mut buffer[filled..]).await?;
+        if read == 0 {
+            break;
+        }
+        filled += read;
+    }
+    Ok(filled)
+}
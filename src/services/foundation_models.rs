@@ -0,0 +1,109 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        ChatCompletionChunk, ChatCompletionRequest, ChatCompletionResponse, CompletionRequest,
+        CompletionResponse, EmbeddingsRequest, EmbeddingsResponse,
+    },
+    services::{databricks_session::decode_json, sse::llm_sse_events, DatabricksSession},
+};
+use futures::{Stream, StreamExt};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Sends a chat completion request to a Foundation Model API (or external model) endpoint.
+    ///
+    /// Parameters:
+    /// - `endpoint`: The serving endpoint name, e.g. `databricks-meta-llama-3-1-70b-instruct`.
+    /// - `request_body`: A `ChatCompletionRequest` with the conversation so far.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `ChatCompletionResponse` including token usage, or an `HttpError`
+    ///   if the request fails.
+    pub async fn chat_completion(
+        &self,
+        endpoint: &str,
+        mut request_body: ChatCompletionRequest,
+    ) -> Result<ChatCompletionResponse, HttpError> {
+        request_body.stream = None;
+        self.send_databricks_request(
+            Method::POST,
+            &format!("serving-endpoints/{}/invocations", endpoint),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Sends a chat completion request and streams the response as server-sent events.
+    ///
+    /// Parameters:
+    /// - `endpoint`: The serving endpoint name.
+    /// - `request_body`: A `ChatCompletionRequest`; `stream` is set automatically.
+    ///
+    /// Returns:
+    /// - A `Result` containing a stream of `ChatCompletionChunk`s, or an `HttpError` if the request
+    ///   is rejected.
+    pub async fn chat_completion_stream(
+        &self,
+        endpoint: &str,
+        mut request_body: ChatCompletionRequest,
+    ) -> Result<impl Stream<Item = Result<ChatCompletionChunk, HttpError>>, HttpError> {
+        request_body.stream = Some(true);
+        let invocations: String = format!("serving-endpoints/{}/invocations", endpoint);
+        if self.plan_unread_request(&Method::POST, &invocations, Some(&request_body))? {
+            return Ok(futures::stream::empty().left_stream());
+        }
+        let response: reqwest::Response = self
+            .send_databricks_request_streaming(Method::POST, &invocations, Some(request_body))
+            .await?;
+
+        let path: String = response.url().path().to_string();
+        Ok(llm_sse_events(response)
+            .map(move |event| {
+                let event = event?;
+                decode_json::<ChatCompletionChunk>(&path, event.data.as_bytes())
+            })
+            .right_stream())
+    }
+
+    /// Sends a text completion request to a completions endpoint.
+    ///
+    /// Parameters:
+    /// - `endpoint`: The serving endpoint name.
+    /// - `request_body`: A `CompletionRequest` with the prompt.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `CompletionResponse`, or an `HttpError` if the request fails.
+    pub async fn completion(
+        &self,
+        endpoint: &str,
+        request_body: CompletionRequest,
+    ) -> Result<CompletionResponse, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &format!("serving-endpoints/{}/invocations", endpoint),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Computes embeddings for a batch of texts.
+    ///
+    /// Parameters:
+    /// - `endpoint`: The serving endpoint name, e.g. `databricks-bge-large-en`.
+    /// - `input`: The texts to embed.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `EmbeddingsResponse`, or an `HttpError` if the request fails.
+    pub async fn embeddings(
+        &self,
+        endpoint: &str,
+        input: Vec<String>,
+    ) -> Result<EmbeddingsResponse, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &format!("serving-endpoints/{}/invocations", endpoint),
+            Some(EmbeddingsRequest { input }),
+        )
+        .await
+    }
+}
@@ -0,0 +1,173 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        GenieCreateMessageRequest, GenieMessage, GenieMessageStatus, GenieQueryResultResponse,
+        GenieStartConversationResponse, SqlStatementResponse,
+    },
+    services::DatabricksSession,
+};
+use reqwest::Method;
+use std::time::{Duration, Instant};
+
+impl DatabricksSession {
+    /// Starts a new conversation in a Genie space by asking the first question.
+    ///
+    /// Genie answers asynchronously; use `wait_for_genie_message` with the returned ids to get the reply.
+    ///
+    /// Parameters:
+    /// - `space_id`: The id of the Genie space.
+    /// - `content`: The user's question.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `GenieStartConversationResponse` with the conversation and message ids,
+    ///   or an `HttpError` if the request fails.
+    pub async fn start_genie_conversation(
+        &self,
+        space_id: &str,
+        content: &str,
+    ) -> Result<GenieStartConversationResponse, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &format!("api/2.0/genie/spaces/{}/start-conversation", space_id),
+            Some(GenieCreateMessageRequest {
+                content: content.to_string(),
+            }),
+        )
+        .await
+    }
+
+    /// Asks a follow-up question in an existing Genie conversation.
+    ///
+    /// Parameters:
+    /// - `space_id`: The id of the Genie space.
+    /// - `conversation_id`: The id of the conversation.
+    /// - `content`: The user's question.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `GenieMessage`, or an `HttpError` if the request fails.
+    pub async fn create_genie_message(
+        &self,
+        space_id: &str,
+        conversation_id: &str,
+        content: &str,
+    ) -> Result<GenieMessage, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &format!(
+                "api/2.0/genie/spaces/{}/conversations/{}/messages",
+                space_id, conversation_id
+            ),
+            Some(GenieCreateMessageRequest {
+                content: content.to_string(),
+            }),
+        )
+        .await
+    }
+
+    /// Retrieves a Genie message, including its status and any attachments generated so far.
+    ///
+    /// Parameters:
+    /// - `space_id`: The id of the Genie space.
+    /// - `conversation_id`: The id of the conversation.
+    /// - `message_id`: The id of the message.
+    ///
+    /// Returns:
+    /// - Same as `create_genie_message`.
+    pub async fn get_genie_message(
+        &self,
+        space_id: &str,
+        conversation_id: &str,
+        message_id: &str,
+    ) -> Result<GenieMessage, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &format!(
+                "api/2.0/genie/spaces/{}/conversations/{}/messages/{}",
+                space_id, conversation_id, message_id
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Polls a Genie message until Genie has finished answering it.
+    ///
+    /// Parameters:
+    /// - `space_id`: The id of the Genie space.
+    /// - `conversation_id`: The id of the conversation.
+    /// - `message_id`: The id of the message.
+    /// - `timeout`: The maximum time to wait.
+    ///
+    /// Returns:
+    /// - A `Result` containing the completed `GenieMessage`, or an `HttpError` if polling fails,
+    ///   the message fails or is cancelled, or the timeout elapses.
+    pub async fn wait_for_genie_message(
+        &self,
+        space_id: &str,
+        conversation_id: &str,
+        message_id: &str,
+        timeout: Duration,
+    ) -> Result<GenieMessage, HttpError> {
+        let started: Instant = Instant::now();
+
+        loop {
+            let message: GenieMessage = self
+                .get_genie_message(space_id, conversation_id, message_id)
+                .await?;
+            match message.status {
+                Some(status) if status.is_terminal() => {
+                    if status == GenieMessageStatus::Completed {
+                        return Ok(message);
+                    }
+                    let reason: String = message
+                        .error
+                        .and_then(|error| error.error)
+                        .unwrap_or_else(|| format!("{:?}", status));
+                    return Err(HttpError::BadRequest(format!(
+                        "Genie message {} did not complete: {}",
+                        message_id, reason
+                    )));
+                }
+                _ => {}
+            }
+            if started.elapsed() >= timeout {
+                return Err(HttpError::TemporarilyUnavailable(format!(
+                    "Genie message {} did not complete after {:?}",
+                    message_id, timeout
+                )));
+            }
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        }
+    }
+
+    /// Fetches the result of a query generated by Genie.
+    ///
+    /// Parameters:
+    /// - `space_id`: The id of the Genie space.
+    /// - `conversation_id`: The id of the conversation.
+    /// - `message_id`: The id of the message.
+    /// - `attachment_id`: The id of the query attachment, see `GenieMessage::query_attachment`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `SqlStatementResponse` with the query result, or an `HttpError` if
+    ///   the request fails.
+    pub async fn get_genie_query_result(
+        &self,
+        space_id: &str,
+        conversation_id: &str,
+        message_id: &str,
+        attachment_id: &str,
+    ) -> Result<SqlStatementResponse, HttpError> {
+        let response: GenieQueryResultResponse = self
+            .send_databricks_request(
+                Method::GET,
+                &format!(
+                    "api/2.0/genie/spaces/{}/conversations/{}/messages/{}/attachments/{}/query-result",
+                    space_id, conversation_id, message_id, attachment_id
+                ),
+                None::<()>,
+            )
+            .await?;
+        Ok(response.statement_response)
+    }
+}
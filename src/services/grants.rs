@@ -0,0 +1,104 @@
+use crate::{
+    config::ApiService,
+    errors::HttpError,
+    models::{
+        EffectivePermissionsList, PermissionsChange, PermissionsList, SecurableType,
+        UpdatePermissionsRequest,
+    },
+    services::DatabricksSession,
+    utils::encode_query_value,
+};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Retrieves the privileges granted directly on a Unity Catalog securable.
+    ///
+    /// Parameters:
+    /// - `securable_type`: The kind of object (catalog, schema, table, ...).
+    /// - `full_name`: The full name of the object, e.g. `main.sales.orders`.
+    /// - `principal`: If set, only the grants of this principal are returned.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `PermissionsList` if successful, or an `HttpError` if the request fails.
+    pub async fn get_grants(
+        &self,
+        securable_type: SecurableType,
+        full_name: &str,
+        principal: Option<&str>,
+    ) -> Result<PermissionsList, HttpError> {
+        let mut endpoint: String = self.api_endpoint(
+            ApiService::UnityCatalog,
+            &format!(
+                "unity-catalog/permissions/{}/{}",
+                securable_type.as_path_segment(),
+                encode_query_value(full_name)
+            ),
+        );
+        if let Some(principal) = principal {
+            endpoint.push_str(&format!("?principal={}", encode_query_value(principal)));
+        }
+        self.send_databricks_request(Method::GET, &endpoint, None::<()>)
+            .await
+    }
+
+    /// Adds and/or removes privileges on a Unity Catalog securable.
+    ///
+    /// Parameters:
+    /// - `securable_type`: The kind of object (catalog, schema, table, ...).
+    /// - `full_name`: The full name of the object.
+    /// - `changes`: The per-principal privilege changes to apply.
+    ///
+    /// Returns:
+    /// - A `Result` containing the updated `PermissionsList`, or an `HttpError` if the request fails.
+    pub async fn update_grants(
+        &self,
+        securable_type: SecurableType,
+        full_name: &str,
+        changes: Vec<PermissionsChange>,
+    ) -> Result<PermissionsList, HttpError> {
+        self.send_databricks_request(
+            Method::PATCH,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/permissions/{}/{}",
+                    securable_type.as_path_segment(),
+                    encode_query_value(full_name)
+                ),
+            ),
+            Some(UpdatePermissionsRequest { changes }),
+        )
+        .await
+    }
+
+    /// Retrieves the effective privileges on a securable, including those inherited from parent
+    /// objects (e.g. a `SELECT` granted on the enclosing catalog).
+    ///
+    /// Parameters:
+    /// - `securable_type`: The kind of object (catalog, schema, table, ...).
+    /// - `full_name`: The full name of the object.
+    /// - `principal`: If set, only the privileges of this principal are returned.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `EffectivePermissionsList`, or an `HttpError` if the request fails.
+    pub async fn get_effective_grants(
+        &self,
+        securable_type: SecurableType,
+        full_name: &str,
+        principal: Option<&str>,
+    ) -> Result<EffectivePermissionsList, HttpError> {
+        let mut endpoint: String = self.api_endpoint(
+            ApiService::UnityCatalog,
+            &format!(
+                "unity-catalog/effective-permissions/{}/{}",
+                securable_type.as_path_segment(),
+                encode_query_value(full_name)
+            ),
+        );
+        if let Some(principal) = principal {
+            endpoint.push_str(&format!("?principal={}", encode_query_value(principal)));
+        }
+        self.send_databricks_request(Method::GET, &endpoint, None::<()>)
+            .await
+    }
+}
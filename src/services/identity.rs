@@ -0,0 +1,33 @@
+use crate::{
+    errors::HttpError,
+    models::{CurrentUser, PingResponse},
+    services::DatabricksSession,
+};
+use reqwest::Method;
+use std::time::Instant;
+
+impl DatabricksSession {
+    /// Retrieves the user or service principal the session authenticates as.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `CurrentUser`, or an `HttpError` if the request fails.
+    pub async fn current_user(&self) -> Result<CurrentUser, HttpError> {
+        self.send_databricks_request(Method::GET, "api/2.0/preview/scim/v2/Me", None::<()>)
+            .await
+    }
+
+    /// Checks that the workspace is reachable and the token is valid with a cheap authenticated
+    /// call, e.g. for readiness probes.
+    ///
+    /// Returns:
+    /// - A `Result` containing the latency and identity in a `PingResponse`, or an `HttpError` if
+    ///   the workspace is unreachable or rejects the token.
+    pub async fn ping(&self) -> Result<PingResponse, HttpError> {
+        let started: Instant = Instant::now();
+        let user: CurrentUser = self.current_user().await?;
+        Ok(PingResponse {
+            latency: started.elapsed(),
+            user,
+        })
+    }
+}
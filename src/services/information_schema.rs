@@ -0,0 +1,119 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        InformationSchemaColumn, InformationSchemaFilter, InformationSchemaTable,
+        InformationSchemaTablePrivilege, InformationSchemaView,
+    },
+    services::DatabricksSession,
+};
+
+impl DatabricksSession {
+    /// Queries `catalog.information_schema.tables` and returns the tables and views visible to
+    /// the caller, ordered by schema and name.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the query on.
+    /// - `catalog`: The catalog to browse; `system` covers every catalog of the metastore.
+    /// - `filter`: An `InformationSchemaFilter` narrowing the schema or table.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `InformationSchemaTable`s, or an `HttpError` if the query fails.
+    pub async fn query_information_schema_tables(
+        &self,
+        warehouse_id: &str,
+        catalog: &str,
+        filter: &InformationSchemaFilter,
+    ) -> Result<Vec<InformationSchemaTable>, HttpError> {
+        let statement: String = filter.to_sql(
+            catalog,
+            "tables",
+            InformationSchemaTable::COLUMNS,
+            &["table_catalog", "table_schema", "table_name"],
+        );
+        self.query_rows(warehouse_id, &statement).await
+    }
+
+    /// Queries `catalog.information_schema.columns`, ordered by table and column position.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the query on.
+    /// - `catalog`: The catalog to browse.
+    /// - `filter`: An `InformationSchemaFilter` narrowing the schema or table.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `InformationSchemaColumn`s, or an `HttpError` if the query fails.
+    pub async fn query_information_schema_columns(
+        &self,
+        warehouse_id: &str,
+        catalog: &str,
+        filter: &InformationSchemaFilter,
+    ) -> Result<Vec<InformationSchemaColumn>, HttpError> {
+        let statement: String = filter.to_sql(
+            catalog,
+            "columns",
+            InformationSchemaColumn::COLUMNS,
+            &[
+                "table_catalog",
+                "table_schema",
+                "table_name",
+                "ordinal_position",
+            ],
+        );
+        self.query_rows(warehouse_id, &statement).await
+    }
+
+    /// Queries `catalog.information_schema.views`, including each view's definition.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the query on.
+    /// - `catalog`: The catalog to browse.
+    /// - `filter`: An `InformationSchemaFilter` narrowing the schema or view.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `InformationSchemaView`s, or an `HttpError` if the query fails.
+    pub async fn query_information_schema_views(
+        &self,
+        warehouse_id: &str,
+        catalog: &str,
+        filter: &InformationSchemaFilter,
+    ) -> Result<Vec<InformationSchemaView>, HttpError> {
+        let statement: String = filter.to_sql(
+            catalog,
+            "views",
+            InformationSchemaView::COLUMNS,
+            &["table_catalog", "table_schema", "table_name"],
+        );
+        self.query_rows(warehouse_id, &statement).await
+    }
+
+    /// Queries `catalog.information_schema.table_privileges`, the grants on tables and views.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the query on.
+    /// - `catalog`: The catalog to browse.
+    /// - `filter`: An `InformationSchemaFilter` narrowing the schema or table.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `InformationSchemaTablePrivilege`s, or an `HttpError` if the
+    ///   query fails.
+    pub async fn query_information_schema_table_privileges(
+        &self,
+        warehouse_id: &str,
+        catalog: &str,
+        filter: &InformationSchemaFilter,
+    ) -> Result<Vec<InformationSchemaTablePrivilege>, HttpError> {
+        let statement: String = filter.to_sql(
+            catalog,
+            "table_privileges",
+            InformationSchemaTablePrivilege::COLUMNS,
+            &[
+                "table_catalog",
+                "table_schema",
+                "table_name",
+                "grantee",
+                "privilege_type",
+            ],
+        );
+        self.query_rows(warehouse_id, &statement).await
+    }
+}
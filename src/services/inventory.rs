@@ -0,0 +1,67 @@
+use crate::{
+    errors::HttpError,
+    models::{InventoryOptions, WorkspaceInventory},
+    services::{databricks_session::retry_rate_limited, DatabricksSession},
+};
+use std::future::Future;
+use tokio::sync::Semaphore;
+
+impl DatabricksSession {
+    /// Lists the clusters, jobs, SQL warehouses, repos, queries, alerts and dashboards of the
+    /// workspace into a single snapshot.
+    ///
+    /// The listings run concurrently, at most `options.concurrency` at a time, and each is retried
+    /// with backoff when the workspace rate-limits it.
+    ///
+    /// Parameters:
+    /// - `options`: The concurrency and retry settings.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `WorkspaceInventory`, or an `HttpError` if any listing fails.
+    pub async fn collect_inventory(
+        &self,
+        options: InventoryOptions,
+    ) -> Result<WorkspaceInventory, HttpError> {
+        let permits: Semaphore = Semaphore::new(options.concurrency.max(1));
+        let retries: u32 = options.max_rate_limit_retries;
+
+        let (clusters, jobs, warehouses, repos, queries, alerts, dashboards) = futures::try_join!(
+            limited(&permits, retries, || self.list_clusters()),
+            limited(&permits, retries, || self.list_jobs()),
+            limited(&permits, retries, || self.list_warehouses()),
+            limited(&permits, retries, || self.list_repos()),
+            limited(&permits, retries, || self.list_queries()),
+            limited(&permits, retries, || self.list_alerts()),
+            limited(&permits, retries, || self.list_dashboards()),
+        )?;
+
+        Ok(WorkspaceInventory {
+            host: self.config.databricks_host.clone(),
+            collected_at: chrono::Utc::now(),
+            clusters,
+            jobs,
+            warehouses,
+            repos,
+            queries,
+            alerts,
+            dashboards,
+        })
+    }
+}
+
+/// Runs a listing once a permit is free, retrying it while it is rate-limited.
+async fn limited<T, F, Fut>(
+    permits: &Semaphore,
+    max_retries: u32,
+    listing: F,
+) -> Result<T, HttpError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = Result<T, HttpError>>,
+{
+    let _permit = permits
+        .acquire()
+        .await
+        .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+    retry_rate_limited(max_retries, listing).await
+}
@@ -0,0 +1,243 @@
+use crate::{
+    config::ApiService,
+    errors::HttpError,
+    models::{
+        BaseJob, CleanupCandidate, CleanupResourceKind, ClusterSummary, JanitorOptions,
+        JanitorReport, ListRunsResponse, WarehouseInfo, WarehouseState,
+    },
+    services::{databricks_session::retry_rate_limited, DatabricksSession},
+};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use reqwest::Method;
+use serde_json::Value;
+
+impl DatabricksSession {
+    /// Finds clusters terminated for a long time, jobs without recent runs and stopped ephemeral
+    /// warehouses, and deletes them if `delete` is set.
+    ///
+    /// Run it with `delete` unset first: the report then lists the candidates without touching
+    /// them. Clusters are deleted permanently. A failed deletion, like a job whose runs could not
+    /// be listed, is recorded in the report and does not stop the others.
+    ///
+    /// Parameters:
+    /// - `options`: The `JanitorOptions` deciding what counts as unused.
+    /// - `delete`: Whether to delete the candidates.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `JanitorReport`, or an `HttpError` if a listing fails.
+    pub async fn run_janitor(
+        &self,
+        options: &JanitorOptions,
+        delete: bool,
+    ) -> Result<JanitorReport, HttpError> {
+        let now: DateTime<Utc> = Utc::now();
+        let retries: u32 = options.max_rate_limit_retries;
+
+        let (clusters, jobs, warehouses) = futures::try_join!(
+            retry_rate_limited(retries, || self.list_clusters()),
+            retry_rate_limited(retries, || self.list_jobs()),
+            retry_rate_limited(retries, || self.list_warehouses()),
+        )?;
+
+        let mut candidates: Vec<CleanupCandidate> = Vec::new();
+        candidates.extend(
+            clusters
+                .iter()
+                .filter_map(|cluster| stale_cluster(cluster, options, now)),
+        );
+        let (inactive_jobs, unchecked) = self.inactive_jobs(jobs, options, now).await;
+        candidates.extend(inactive_jobs);
+        candidates.extend(
+            warehouses
+                .iter()
+                .filter_map(|warehouse| ephemeral_warehouse(warehouse, options)),
+        );
+
+        let mut report: JanitorReport = JanitorReport {
+            collected_at: now,
+            delete,
+            candidates,
+            unchecked,
+            ..Default::default()
+        };
+        if !delete {
+            return Ok(report);
+        }
+
+        for candidate in report.candidates.clone() {
+            let deleted: Result<(), HttpError> = match candidate.kind {
+                CleanupResourceKind::Cluster => self.permanent_delete_cluster(&candidate.id).await,
+                CleanupResourceKind::Job => match candidate.id.parse::<i64>() {
+                    Ok(job_id) => self.delete_job(job_id).await,
+                    Err(err) => Err(HttpError::InternalError(Box::new(err))),
+                },
+                CleanupResourceKind::Warehouse => self.delete_warehouse(&candidate.id).await,
+            };
+            match deleted {
+                Ok(()) => {
+                    log::info!("Deleted {} {}", candidate.kind, candidate.id);
+                    report.deleted.push(candidate);
+                }
+                Err(err) => {
+                    log::warn!(
+                        "Could not delete {} {}: {}",
+                        candidate.kind,
+                        candidate.id,
+                        err
+                    );
+                    report.failed.push((candidate, err.to_string()));
+                }
+            }
+        }
+        Ok(report)
+    }
+
+    /// Looks up the latest run of each job and returns the jobs inactive for too long, along with
+    /// the jobs whose runs could not be listed. Jobs without a creation time are skipped.
+    async fn inactive_jobs(
+        &self,
+        jobs: Vec<BaseJob>,
+        options: &JanitorOptions,
+        now: DateTime<Utc>,
+    ) -> (
+        Vec<CleanupCandidate>,
+        Vec<(CleanupResourceKind, String, String)>,
+    ) {
+        let cutoff: i64 = cutoff_millis(now, options.job_inactivity);
+        let retries: u32 = options.max_rate_limit_retries;
+
+        let lookups: Vec<Result<Option<CleanupCandidate>, (String, HttpError)>> =
+            futures::stream::iter(jobs)
+                .filter_map(|job| {
+                    futures::future::ready(match job.created_time {
+                        Some(created) if created < cutoff => Some((job, created)),
+                        _ => None,
+                    })
+                })
+                .map(|(job, created)| async move {
+                    let endpoint: String = self.api_endpoint(
+                        ApiService::Jobs,
+                        &format!("jobs/runs/list?job_id={}&limit=1", job.job_id),
+                    );
+                    let latest: ListRunsResponse = retry_rate_limited(retries, || {
+                        self.send_databricks_request(Method::GET, &endpoint, None::<()>)
+                    })
+                    .await
+                    .map_err(|err| (job.job_id.to_string(), err))?;
+                    let last_run: Option<i64> = latest.runs.first().and_then(|run| run.start_time);
+
+                    let (last_activity, reason): (i64, String) = match last_run {
+                        Some(start) if start >= cutoff => return Ok(None),
+                        Some(start) => (start, format!("last run {}", days_ago(now, start))),
+                        None => (
+                            created,
+                            format!("never run, created {}", days_ago(now, created)),
+                        ),
+                    };
+                    Ok(Some(CleanupCandidate {
+                        kind: CleanupResourceKind::Job,
+                        id: job.job_id.to_string(),
+                        name: job
+                            .settings
+                            .as_ref()
+                            .and_then(|settings| settings.name.clone()),
+                        reason,
+                        last_activity: DateTime::from_timestamp_millis(last_activity),
+                        creator: job.creator_user_name.clone(),
+                    }))
+                })
+                .buffer_unordered(options.concurrency.max(1))
+                .collect()
+                .await;
+
+        let mut candidates: Vec<CleanupCandidate> = Vec::new();
+        let mut unchecked: Vec<(CleanupResourceKind, String, String)> = Vec::new();
+        for lookup in lookups {
+            match lookup {
+                Ok(candidate) => candidates.extend(candidate),
+                Err((job_id, err)) => {
+                    log::warn!("Could not list the runs of job {}: {}", job_id, err);
+                    unchecked.push((CleanupResourceKind::Job, job_id, err.to_string()));
+                }
+            }
+        }
+        candidates.sort_by(|a, b| a.id.cmp(&b.id));
+        unchecked.sort();
+        (candidates, unchecked)
+    }
+}
+
+fn stale_cluster(
+    cluster: &ClusterSummary,
+    options: &JanitorOptions,
+    now: DateTime<Utc>,
+) -> Option<CleanupCandidate> {
+    let terminated: i64 = cluster.terminated_time?;
+    let pinned: bool = cluster
+        .extra
+        .get("pinned_by_user_name")
+        .is_some_and(|user| !user.is_null());
+    if cluster.state.as_deref() != Some("TERMINATED")
+        || cluster.cluster_source.as_deref() == Some("JOB")
+        || pinned
+        || terminated >= cutoff_millis(now, options.terminated_cluster_age)
+    {
+        return None;
+    }
+
+    Some(CleanupCandidate {
+        kind: CleanupResourceKind::Cluster,
+        id: cluster.cluster_id.clone(),
+        name: cluster.cluster_name.clone(),
+        reason: format!("terminated {}", days_ago(now, terminated)),
+        last_activity: DateTime::from_timestamp_millis(terminated),
+        creator: cluster.creator_user_name.clone(),
+    })
+}
+
+fn ephemeral_warehouse(
+    warehouse: &WarehouseInfo,
+    options: &JanitorOptions,
+) -> Option<CleanupCandidate> {
+    let name: &str = warehouse.name.as_deref().unwrap_or_default();
+    let by_prefix: bool = options
+        .ephemeral_warehouse_prefix
+        .as_deref()
+        .is_some_and(|prefix| name.starts_with(prefix));
+    let by_tag: bool = options
+        .ephemeral_warehouse_tag
+        .as_deref()
+        .is_some_and(|key| {
+            warehouse
+                .extra
+                .get("tags")
+                .and_then(|tags| tags.get("custom_tags"))
+                .and_then(Value::as_array)
+                .is_some_and(|tags| tags.iter().any(|tag| tag.get("key") == Some(&key.into())))
+        });
+    if warehouse.state != Some(WarehouseState::Stopped) || !(by_prefix || by_tag) {
+        return None;
+    }
+
+    Some(CleanupCandidate {
+        kind: CleanupResourceKind::Warehouse,
+        id: warehouse.id.clone(),
+        name: warehouse.name.clone(),
+        reason: "stopped ephemeral warehouse".to_string(),
+        last_activity: None,
+        creator: warehouse.creator_name.clone(),
+    })
+}
+
+/// The epoch milliseconds `age` before `now`.
+fn cutoff_millis(now: DateTime<Utc>, age: std::time::Duration) -> i64 {
+    now.timestamp_millis() - i64::try_from(age.as_millis()).unwrap_or(i64::MAX)
+}
+
+fn days_ago(now: DateTime<Utc>, millis: i64) -> String {
+    format!(
+        "{} days ago",
+        (now.timestamp_millis() - millis) / 86_400_000
+    )
+}
@@ -0,0 +1,99 @@
+use crate::{
+    errors::HttpError,
+    models::{BaseJob, JobApplyAction, JobApplyOutcome, JobSettings, JobSettingsDiff},
+    services::DatabricksSession,
+};
+
+impl DatabricksSession {
+    /// Compares a job's current settings with the desired ones.
+    ///
+    /// Parameters:
+    /// - `job_id`: The ID of the job.
+    /// - `desired`: The desired `JobSettings`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `JobSettingsDiff`, or an `HttpError` if the job cannot be
+    ///   retrieved.
+    pub async fn diff_job(
+        &self,
+        job_id: i64,
+        desired: &JobSettings,
+    ) -> Result<JobSettingsDiff, HttpError> {
+        let job: BaseJob = self.get_job(job_id).await?;
+        Ok(JobSettingsDiff::between(
+            &job.settings.unwrap_or_default(),
+            desired,
+        ))
+    }
+
+    /// Brings a job in line with a desired spec, identifying the job by its name: creates it if no
+    /// job has that name, resets its settings if they differ, and leaves it alone otherwise, so
+    /// the same spec can be deployed repeatedly.
+    ///
+    /// Parameters:
+    /// - `desired`: The desired `JobSettings`; its `name` must be set and unique in the workspace.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `JobApplyOutcome`, or an `HttpError::BadRequest` if the spec
+    ///   has no name or several jobs share it, or an `HttpError` if a request fails.
+    pub async fn apply_job(&self, desired: JobSettings) -> Result<JobApplyOutcome, HttpError> {
+        let name: String = desired
+            .name
+            .clone()
+            .ok_or_else(|| HttpError::BadRequest("Job settings have no name".to_string()))?;
+        let matching: Vec<BaseJob> = self
+            .list_jobs()
+            .await?
+            .into_iter()
+            .filter(|job| {
+                job.settings
+                    .as_ref()
+                    .and_then(|settings| settings.name.as_deref())
+                    == Some(name.as_str())
+            })
+            .collect();
+
+        match matching.as_slice() {
+            [] => Ok(JobApplyOutcome {
+                job_id: self.create_job(desired).await?,
+                action: JobApplyAction::Created,
+                diff: JobSettingsDiff::default(),
+            }),
+            [job] => self.apply_job_by_id(job.job_id, desired).await,
+            _ => Err(HttpError::BadRequest(format!(
+                "{} jobs are named {}",
+                matching.len(),
+                name
+            ))),
+        }
+    }
+
+    /// Resets an existing job's settings to the desired ones if they differ.
+    ///
+    /// Parameters:
+    /// - `job_id`: The ID of the job.
+    /// - `desired`: The desired `JobSettings`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `JobApplyOutcome`, or an `HttpError` if a request fails.
+    pub async fn apply_job_by_id(
+        &self,
+        job_id: i64,
+        desired: JobSettings,
+    ) -> Result<JobApplyOutcome, HttpError> {
+        let diff: JobSettingsDiff = self.diff_job(job_id, &desired).await?;
+        if diff.is_empty() {
+            return Ok(JobApplyOutcome {
+                job_id,
+                action: JobApplyAction::Unchanged,
+                diff,
+            });
+        }
+        self.reset_job(job_id, desired).await?;
+        Ok(JobApplyOutcome {
+            job_id,
+            action: JobApplyAction::Updated,
+            diff,
+        })
+    }
+}
@@ -0,0 +1,416 @@
+use crate::{
+    config::ApiService,
+    errors::HttpError,
+    models::{
+        BaseJob, CreateJobResponse, DbtOutput, JobIdRequest, JobQueueSnapshot, JobSettings,
+        ListJobsResponse, ListRunsResponse, ResetJobRequest, Run, RunIdRequest, RunLifeCycleState,
+        RunOutput, RunOutputEvent,
+    },
+    services::{
+        databricks_session::EmptyResponse, mlflow_artifacts::send_presigned, DatabricksSession,
+        OmitNulls,
+    },
+    utils::encode_query_value,
+};
+use futures::Stream;
+use reqwest::{Method, RequestBuilder, Response};
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    time::Duration,
+};
+use tokio::io::{AsyncWrite, AsyncWriteExt};
+
+impl DatabricksSession {
+    /// Lists all jobs in the workspace, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `BaseJob`, or an `HttpError` if any page request fails.
+    pub async fn list_jobs(&self) -> Result<Vec<BaseJob>, HttpError> {
+        let mut jobs: Vec<BaseJob> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => self.api_endpoint(
+                    ApiService::Jobs,
+                    &format!("jobs/list?page_token={}", encode_query_value(token)),
+                ),
+                None => self.api_endpoint(ApiService::Jobs, "jobs/list"),
+            };
+            let page: ListJobsResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            jobs.extend(page.jobs);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(jobs),
+            }
+        }
+    }
+
+    /// Retrieves a job and its current settings.
+    ///
+    /// Parameters:
+    /// - `job_id`: The ID of the job.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `BaseJob` if successful, or an `HttpError` if the request fails.
+    pub async fn get_job(&self, job_id: i64) -> Result<BaseJob, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(ApiService::Jobs, &format!("jobs/get?job_id={}", job_id)),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Creates a job. Unset settings are omitted from the request, so the server applies its
+    /// defaults.
+    ///
+    /// Parameters:
+    /// - `settings`: The `JobSettings` of the new job.
+    ///
+    /// Returns:
+    /// - A `Result` containing the ID of the created job, or an `HttpError` if the request fails.
+    pub async fn create_job(&self, settings: JobSettings) -> Result<i64, HttpError> {
+        self.send_databricks_request_with_serializer::<CreateJobResponse, _>(
+            Method::POST,
+            &self.api_endpoint(ApiService::Jobs, "jobs/create"),
+            Some(settings),
+            &OmitNulls,
+        )
+        .await
+        .map(|response| response.job_id)
+    }
+
+    /// Replaces all settings of a job. Settings left unset are reset to their defaults.
+    ///
+    /// Parameters:
+    /// - `job_id`: The ID of the job.
+    /// - `settings`: The new `JobSettings`.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn reset_job(&self, job_id: i64, settings: JobSettings) -> Result<(), HttpError> {
+        self.send_databricks_request_with_serializer::<EmptyResponse, _>(
+            Method::POST,
+            &self.api_endpoint(ApiService::Jobs, "jobs/reset"),
+            Some(ResetJobRequest {
+                job_id,
+                new_settings: settings,
+            }),
+            &OmitNulls,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Deletes a job. Its active runs are canceled.
+    ///
+    /// Parameters:
+    /// - `job_id`: The ID of the job.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_job(&self, job_id: i64) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            &self.api_endpoint(ApiService::Jobs, "jobs/delete"),
+            Some(JobIdRequest { job_id }),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Retrieves the metadata and current state of a job run, including the state of its tasks.
+    ///
+    /// Parameters:
+    /// - `run_id`: The ID of the run.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `Run` if successful, or an `HttpError` if the request fails.
+    pub async fn get_run(&self, run_id: i64) -> Result<Run, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(
+                ApiService::Jobs,
+                &format!("jobs/runs/get?run_id={}", run_id),
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Lists the runs of a job that have not finished yet, queued runs included, following
+    /// pagination until exhausted.
+    ///
+    /// Parameters:
+    /// - `job_id`: The ID of the job.
+    ///
+    /// Returns:
+    /// - A `Result` containing the active `Run`s, newest first, or an `HttpError` if any page request fails.
+    pub async fn active_runs(&self, job_id: i64) -> Result<Vec<Run>, HttpError> {
+        let mut runs: Vec<Run> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut path: String = format!("jobs/runs/list?job_id={}&active_only=true", job_id);
+            if let Some(token) = &page_token {
+                path.push_str(&format!("&page_token={}", encode_query_value(token)));
+            }
+            let page: ListRunsResponse = self
+                .send_databricks_request(
+                    Method::GET,
+                    &self.api_endpoint(ApiService::Jobs, &path),
+                    None::<()>,
+                )
+                .await?;
+            runs.extend(page.runs);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(runs),
+            }
+        }
+    }
+
+    /// Retrieves a job's concurrency limit and queue setting together with its active runs, so a
+    /// scheduler can decide whether to trigger another run.
+    ///
+    /// Parameters:
+    /// - `job_id`: The ID of the job.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `JobQueueSnapshot`, or an `HttpError` if a request fails.
+    pub async fn job_queue_snapshot(&self, job_id: i64) -> Result<JobQueueSnapshot, HttpError> {
+        let (job, runs) = futures::try_join!(self.get_job(job_id), self.active_runs(job_id))?;
+        let settings: JobSettings = job.settings.unwrap_or_default();
+
+        Ok(JobQueueSnapshot::new(
+            job_id,
+            settings.max_concurrent_runs,
+            settings.queue.is_some_and(|queue| queue.enabled),
+            runs,
+        ))
+    }
+
+    /// Requests cancellation of a job run and its active tasks. Cancellation is asynchronous; the
+    /// run reaches the `TERMINATED` state once its tasks have stopped.
+    ///
+    /// Parameters:
+    /// - `run_id`: The ID of the run.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the cancellation was accepted, or an `HttpError` if the request fails.
+    pub async fn cancel_run(&self, run_id: i64) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            &self.api_endpoint(ApiService::Jobs, "jobs/runs/cancel"),
+            Some(RunIdRequest { run_id }),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Retrieves the output of a task run: its logs, notebook exit value and error, if any.
+    ///
+    /// Parameters:
+    /// - `run_id`: The ID of the task run (not of a multi-task job run).
+    ///
+    /// Returns:
+    /// - A `Result` containing the `RunOutput` if successful, or an `HttpError` if the request fails.
+    pub async fn get_run_output(&self, run_id: i64) -> Result<RunOutput, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(
+                ApiService::Jobs,
+                &format!("jobs/runs/get-output?run_id={}", run_id),
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Downloads the artifacts of a dbt task run, a `.tar.gz` archive of the project's `target/`
+    /// directory and logs, streaming it into `writer`.
+    ///
+    /// Parameters:
+    /// - `run_id`: The ID of the dbt task run (not of a multi-task job run).
+    /// - `writer`: The destination, e.g. a `tokio::fs::File`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the number of bytes written, or an `HttpError` if the run has no
+    ///   dbt artifacts (`HttpError::NotFound`) or the download fails.
+    pub async fn download_dbt_artifacts<W: AsyncWrite + Unpin>(
+        &self,
+        run_id: i64,
+        writer: &mut W,
+    ) -> Result<u64, HttpError> {
+        let output: DbtOutput = self
+            .get_run_output(run_id)
+            .await?
+            .dbt_output
+            .unwrap_or_default();
+        let Some(artifacts_link) = output.artifacts_link else {
+            return Err(HttpError::NotFound(format!(
+                "Run {} has no dbt artifacts",
+                run_id
+            )));
+        };
+
+        let request: RequestBuilder = output.artifacts_headers.iter().fold(
+            self.client.get(&artifacts_link),
+            |request, (name, value)| request.header(name, value),
+        );
+        let mut response: Response = send_presigned(request).await?;
+
+        let mut written: u64 = 0;
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|err| HttpError::InternalServerError(err.to_string()))?
+        {
+            writer
+                .write_all(&chunk)
+                .await
+                .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+            written += chunk.len() as u64;
+        }
+        writer
+            .flush()
+            .await
+            .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+        Ok(written)
+    }
+
+    /// Follows a run until it terminates, yielding log output as it is produced and each task's
+    /// notebook exit value or error as it finishes.
+    ///
+    /// The run and the output of its active tasks are polled every `poll_interval`. Logs are only
+    /// available for tasks that write to stdout (Python, wheel and JAR tasks); notebook tasks
+    /// report their `dbutils.notebook.exit()` value once they finish.
+    ///
+    /// Parameters:
+    /// - `run_id`: The ID of the job run.
+    /// - `poll_interval`: The time between polls.
+    ///
+    /// Returns:
+    /// - A stream of `RunOutputEvent`s ending with `RunOutputEvent::Finished`, yielding an
+    ///   `HttpError` if a poll fails.
+    pub fn stream_run_output(
+        &self,
+        run_id: i64,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<RunOutputEvent, HttpError>> + '_ {
+        futures::stream::try_unfold(
+            RunOutputFollower::default(),
+            move |mut follower: RunOutputFollower| async move {
+                loop {
+                    if let Some(event) = follower.pending.pop_front() {
+                        return Ok(Some((event, follower)));
+                    }
+                    if follower.done {
+                        return Ok(None);
+                    }
+                    if follower.polled {
+                        tokio::time::sleep(poll_interval).await;
+                    }
+                    follower.polled = true;
+                    self.poll_run_output(run_id, &mut follower).await?;
+                }
+            },
+        )
+    }
+
+    async fn poll_run_output(
+        &self,
+        run_id: i64,
+        follower: &mut RunOutputFollower,
+    ) -> Result<(), HttpError> {
+        let run: Run = self.get_run(run_id).await?;
+
+        // Output is fetched per task run; a run without tasks is its own single task.
+        let tasks: Vec<(Option<String>, i64, Option<RunLifeCycleState>)> = if run.tasks.is_empty() {
+            vec![(None, run.run_id, run.life_cycle_state())]
+        } else {
+            run.tasks
+                .iter()
+                .filter_map(|task| {
+                    Some((
+                        Some(task.task_key.clone()),
+                        task.run_id?,
+                        task.state.as_ref().and_then(|state| state.life_cycle_state),
+                    ))
+                })
+                .collect()
+        };
+
+        for (task_key, task_run_id, state) in tasks {
+            let started: bool = state.is_some_and(|state| state.has_started());
+            if !started || follower.finished.contains(&task_run_id) {
+                continue;
+            }
+
+            let output: RunOutput = self.get_run_output(task_run_id).await?;
+            follower.push_logs(&task_key, task_run_id, output.logs.unwrap_or_default());
+
+            if state.is_some_and(|state| state.is_terminal()) {
+                follower.finished.insert(task_run_id);
+                if let Some(notebook_output) = output.notebook_output {
+                    if let Some(result) = notebook_output.result {
+                        follower.pending.push_back(RunOutputEvent::NotebookResult {
+                            task_key: task_key.clone(),
+                            result,
+                            truncated: notebook_output.truncated.unwrap_or(false),
+                        });
+                    }
+                }
+                if let Some(message) = output.error {
+                    follower.pending.push_back(RunOutputEvent::Error {
+                        task_key,
+                        message,
+                        trace: output.error_trace,
+                    });
+                }
+            }
+        }
+
+        if run
+            .life_cycle_state()
+            .is_some_and(|state| state.is_terminal())
+        {
+            follower
+                .pending
+                .push_back(RunOutputEvent::Finished(Box::new(run)));
+            follower.done = true;
+        }
+        Ok(())
+    }
+}
+
+/// What `stream_run_output` has already reported, carried between polls.
+#[derive(Default)]
+struct RunOutputFollower {
+    pending: VecDeque<RunOutputEvent>,
+    logs: HashMap<i64, String>,
+    finished: HashSet<i64>,
+    polled: bool,
+    done: bool,
+}
+
+impl RunOutputFollower {
+    /// Queues the part of `logs` not seen in the previous poll of the same task run. The API only
+    /// keeps the tail of long logs, so if the window has moved the whole tail is queued again.
+    fn push_logs(&mut self, task_key: &Option<String>, task_run_id: i64, logs: String) {
+        let previous: &mut String = self.logs.entry(task_run_id).or_default();
+        let text: &str = logs.strip_prefix(previous.as_str()).unwrap_or(&logs);
+        if !text.is_empty() {
+            self.pending.push_back(RunOutputEvent::Log {
+                task_key: task_key.clone(),
+                text: text.to_string(),
+            });
+        }
+        *previous = logs;
+    }
+}
@@ -0,0 +1,65 @@
+use crate::{errors::HttpError, services::DatabricksSession};
+use std::time::Duration;
+use tokio::task::JoinHandle;
+
+impl DatabricksSession {
+    /// Opens a connection to the workspace ahead of the first real request, so DNS resolution,
+    /// the TLS handshake and credential checks are not paid for by the first query.
+    ///
+    /// The connection is returned to the session's pool for the next request to reuse.
+    ///
+    /// Returns:
+    /// - A `Result` containing the round-trip time of the warm-up request, or an `HttpError` if
+    ///   the workspace cannot be reached or rejects the session's credentials.
+    pub async fn warm_up(&self) -> Result<Duration, HttpError> {
+        self.ping().await.map(|ping| ping.latency)
+    }
+
+    /// Starts pinging the workspace in the background so pooled connections do not go idle
+    /// and get closed between sporadic requests.
+    ///
+    /// Pings run until the returned handle is stopped or dropped; failed pings are logged and
+    /// retried on the next tick. Each ping is authorized with the session's current token, so
+    /// rotated credentials are picked up. Choose an interval shorter than the idle timeouts of any
+    /// proxies or load balancers in between; the connection pool itself drops connections idle for
+    /// 90 seconds.
+    ///
+    /// Parameters:
+    /// - `interval`: The time between pings.
+    ///
+    /// Returns:
+    /// - A `KeepAliveHandle` that stops the pings when dropped. Must be called within a Tokio
+    ///   runtime.
+    pub fn start_keep_alive(&self, interval: Duration) -> KeepAliveHandle {
+        let session: DatabricksSession = self.with_shared_client();
+        let task: JoinHandle<()> = tokio::spawn(async move {
+            let mut ticker: tokio::time::Interval = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                ticker.tick().await;
+                if let Err(err) = session.ping().await {
+                    log::warn!("Keep-alive ping failed: {}", err);
+                }
+            }
+        });
+        KeepAliveHandle { task }
+    }
+}
+
+/// The background pings started by `DatabricksSession::start_keep_alive`.
+///
+/// Dropping the handle stops the pings.
+pub struct KeepAliveHandle {
+    task: JoinHandle<()>,
+}
+
+impl KeepAliveHandle {
+    /// Stops the pings.
+    pub fn stop(self) {}
+}
+
+impl Drop for KeepAliveHandle {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
@@ -0,0 +1,59 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        ColumnLineageRequest, ColumnLineageResponse, TableLineageRequest, TableLineageResponse,
+    },
+    services::DatabricksSession,
+};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Retrieves the upstream and downstream lineage of a table.
+    ///
+    /// Parameters:
+    /// - `table_name`: The full name of the table, e.g. `main.sales.orders`.
+    /// - `include_entity_lineage`: Whether to include notebooks, jobs, queries and other entities
+    ///   that read from or write to the table.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `TableLineageResponse` if successful, or an `HttpError` if the request fails.
+    pub async fn get_table_lineage(
+        &self,
+        table_name: &str,
+        include_entity_lineage: bool,
+    ) -> Result<TableLineageResponse, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            "api/2.0/lineage-tracking/table-lineage",
+            Some(TableLineageRequest {
+                table_name: table_name.to_string(),
+                include_entity_lineage: Some(include_entity_lineage),
+            }),
+        )
+        .await
+    }
+
+    /// Retrieves the upstream and downstream lineage of a single column.
+    ///
+    /// Parameters:
+    /// - `table_name`: The full name of the table containing the column.
+    /// - `column_name`: The name of the column.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `ColumnLineageResponse` if successful, or an `HttpError` if the request fails.
+    pub async fn get_column_lineage(
+        &self,
+        table_name: &str,
+        column_name: &str,
+    ) -> Result<ColumnLineageResponse, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            "api/2.0/lineage-tracking/column-lineage",
+            Some(ColumnLineageRequest {
+                table_name: table_name.to_string(),
+                column_name: column_name.to_string(),
+            }),
+        )
+        .await
+    }
+}
@@ -0,0 +1,101 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        CreateLogDeliveryConfiguration, CreateLogDeliveryRequest, LogDeliveryConfigStatus,
+        LogDeliveryConfiguration, UpdateLogDeliveryStatusRequest, WrappedLogDeliveryConfiguration,
+        WrappedLogDeliveryConfigurations,
+    },
+    services::{databricks_session::EmptyResponse, AccountSession},
+};
+use reqwest::Method;
+
+impl AccountSession {
+    /// Creates a log delivery configuration, delivering audit logs or billable usage to cloud storage.
+    ///
+    /// Parameters:
+    /// - `configuration`: A `CreateLogDeliveryConfiguration` referencing a credential and storage configuration.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `LogDeliveryConfiguration` if successful, or an `HttpError`
+    ///   if the request fails.
+    pub async fn create_log_delivery(
+        &self,
+        configuration: CreateLogDeliveryConfiguration,
+    ) -> Result<LogDeliveryConfiguration, HttpError> {
+        let response: WrappedLogDeliveryConfiguration = self
+            .send_account_request(
+                Method::POST,
+                "log-delivery",
+                Some(CreateLogDeliveryRequest {
+                    log_delivery_configuration: configuration,
+                }),
+            )
+            .await?;
+        Ok(response.log_delivery_configuration)
+    }
+
+    /// Retrieves a log delivery configuration, including the status of its most recent delivery.
+    ///
+    /// Parameters:
+    /// - `config_id`: The id of the log delivery configuration.
+    ///
+    /// Returns:
+    /// - Same as `create_log_delivery`.
+    pub async fn get_log_delivery(
+        &self,
+        config_id: &str,
+    ) -> Result<LogDeliveryConfiguration, HttpError> {
+        let response: WrappedLogDeliveryConfiguration = self
+            .send_account_request(
+                Method::GET,
+                &format!("log-delivery/{}", config_id),
+                None::<()>,
+            )
+            .await?;
+        Ok(response.log_delivery_configuration)
+    }
+
+    /// Lists the log delivery configurations of the account.
+    ///
+    /// Parameters:
+    /// - `status`: Optionally return only enabled or only disabled configurations.
+    ///
+    /// Returns:
+    /// - A `Result` containing every matching `LogDeliveryConfiguration`, or an `HttpError` if the request fails.
+    pub async fn list_log_deliveries(
+        &self,
+        status: Option<LogDeliveryConfigStatus>,
+    ) -> Result<Vec<LogDeliveryConfiguration>, HttpError> {
+        let endpoint: String = match status {
+            Some(LogDeliveryConfigStatus::Enabled) => "log-delivery?status=ENABLED".to_string(),
+            Some(LogDeliveryConfigStatus::Disabled) => "log-delivery?status=DISABLED".to_string(),
+            None => "log-delivery".to_string(),
+        };
+        let response: WrappedLogDeliveryConfigurations = self
+            .send_account_request(Method::GET, &endpoint, None::<()>)
+            .await?;
+        Ok(response.log_delivery_configurations)
+    }
+
+    /// Enables or disables a log delivery configuration. Configurations cannot be deleted, only disabled.
+    ///
+    /// Parameters:
+    /// - `config_id`: The id of the log delivery configuration.
+    /// - `status`: The new status.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn patch_log_delivery_status(
+        &self,
+        config_id: &str,
+        status: LogDeliveryConfigStatus,
+    ) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::PATCH,
+            &format!("log-delivery/{}", config_id),
+            Some(UpdateLogDeliveryStatusRequest { status }),
+        )
+        .await
+        .map(|_| ())
+    }
+}
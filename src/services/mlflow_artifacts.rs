@@ -0,0 +1,292 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        ArtifactCredentialInfo, ArtifactCredentialType, ArtifactCredentialsResponse,
+        ArtifactFileInfo, ListArtifactsResponse,
+    },
+    services::DatabricksSession,
+    utils::encode_query_value,
+};
+use reqwest::{Body, Method, RequestBuilder, Response};
+use std::path::Path;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio_util::io::ReaderStream;
+
+impl DatabricksSession {
+    /// Lists the artifacts of an MLflow run under the given directory, following pagination.
+    ///
+    /// Parameters:
+    /// - `run_id`: The ID of the run.
+    /// - `path`: The directory relative to the run's artifact root, or `None` for the root.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `ArtifactFileInfo` entries, or an `HttpError` if the request fails.
+    pub async fn list_artifacts(
+        &self,
+        run_id: &str,
+        path: Option<&str>,
+    ) -> Result<Vec<ArtifactFileInfo>, HttpError> {
+        let mut files: Vec<ArtifactFileInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut endpoint: String = format!(
+                "api/2.0/mlflow/artifacts/list?run_id={}",
+                encode_query_value(run_id)
+            );
+            if let Some(path) = path {
+                endpoint.push_str(&format!("&path={}", encode_query_value(path)));
+            }
+            if let Some(token) = &page_token {
+                endpoint.push_str(&format!("&page_token={}", encode_query_value(token)));
+            }
+            let page: ListArtifactsResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            files.extend(page.files);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(files),
+            }
+        }
+    }
+
+    /// Retrieves presigned URIs for reading the given artifacts directly from cloud storage.
+    ///
+    /// Parameters:
+    /// - `run_id`: The ID of the run.
+    /// - `paths`: Artifact paths relative to the run's artifact root.
+    ///
+    /// Returns:
+    /// - A `Result` containing one `ArtifactCredentialInfo` per path, or an `HttpError` if the request fails.
+    pub async fn get_artifact_read_credentials(
+        &self,
+        run_id: &str,
+        paths: &[&str],
+    ) -> Result<Vec<ArtifactCredentialInfo>, HttpError> {
+        self.get_artifact_credentials("credentials-for-read", run_id, paths)
+            .await
+    }
+
+    /// Retrieves presigned URIs for writing the given artifacts directly to cloud storage.
+    ///
+    /// Parameters:
+    /// - `run_id`: The ID of the run.
+    /// - `paths`: Artifact paths relative to the run's artifact root.
+    ///
+    /// Returns:
+    /// - Same as `get_artifact_read_credentials`.
+    pub async fn get_artifact_write_credentials(
+        &self,
+        run_id: &str,
+        paths: &[&str],
+    ) -> Result<Vec<ArtifactCredentialInfo>, HttpError> {
+        self.get_artifact_credentials("credentials-for-write", run_id, paths)
+            .await
+    }
+
+    async fn get_artifact_credentials(
+        &self,
+        kind: &str,
+        run_id: &str,
+        paths: &[&str],
+    ) -> Result<Vec<ArtifactCredentialInfo>, HttpError> {
+        let mut credentials: Vec<ArtifactCredentialInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut endpoint: String = format!(
+                "api/2.0/mlflow/artifacts/{}?run_id={}",
+                kind,
+                encode_query_value(run_id)
+            );
+            for path in paths {
+                endpoint.push_str(&format!("&path={}", encode_query_value(path)));
+            }
+            if let Some(token) = &page_token {
+                endpoint.push_str(&format!("&page_token={}", encode_query_value(token)));
+            }
+            let page: ArtifactCredentialsResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            credentials.extend(page.credential_infos);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(credentials),
+            }
+        }
+    }
+
+    /// Streams an artifact from cloud storage into `writer` without buffering it in memory.
+    ///
+    /// Parameters:
+    /// - `run_id`: The ID of the run.
+    /// - `path`: The artifact path relative to the run's artifact root.
+    /// - `writer`: The destination, e.g. a `tokio::fs::File`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the number of bytes written, or an `HttpError` if the download fails.
+    pub async fn download_artifact<W: AsyncWrite + Unpin>(
+        &self,
+        run_id: &str,
+        path: &str,
+        writer: &mut W,
+    ) -> Result<u64, HttpError> {
+        let credential: ArtifactCredentialInfo = self
+            .get_artifact_read_credentials(run_id, &[path])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| HttpError::NotFound(format!("No read credentials for {}", path)))?;
+
+        let request: RequestBuilder = presigned_request(
+            self.client.request(Method::GET, &credential.signed_uri),
+            &credential,
+        );
+        let mut response: Response = send_presigned(request).await?;
+
+        let mut written: u64 = 0;
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|err| HttpError::InternalServerError(err.to_string()))?
+        {
+            writer
+                .write_all(&chunk)
+                .await
+                .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+            written += chunk.len() as u64;
+        }
+        writer
+            .flush()
+            .await
+            .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+        Ok(written)
+    }
+
+    /// Streams `reader` to cloud storage as the artifact at `path`. In dry-run mode the write
+    /// credentials are still fetched, but nothing is uploaded.
+    ///
+    /// Parameters:
+    /// - `run_id`: The ID of the run.
+    /// - `path`: The artifact path relative to the run's artifact root.
+    /// - `reader`: The source, e.g. a `tokio::fs::File`.
+    /// - `content_length`: The size of the payload, if known. Some stores (e.g. S3 presigned URLs)
+    ///   reject chunked uploads, so it should be provided whenever possible.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the upload fails.
+    pub async fn upload_artifact<R: AsyncRead + Send + Sync + 'static>(
+        &self,
+        run_id: &str,
+        path: &str,
+        reader: R,
+        content_length: Option<u64>,
+    ) -> Result<(), HttpError> {
+        let credential: ArtifactCredentialInfo = self
+            .get_artifact_write_credentials(run_id, &[path])
+            .await?
+            .into_iter()
+            .next()
+            .ok_or_else(|| HttpError::NotFound(format!("No write credentials for {}", path)))?;
+        if self.is_planned(&Method::PUT) {
+            // The signature in the query string is a credential, so only the target is recorded.
+            let target: &str = credential.signed_uri.split('?').next().unwrap_or_default();
+            self.plan_request(&Method::PUT, target, None);
+            return Ok(());
+        }
+
+        let mut request: RequestBuilder = presigned_request(
+            self.client.request(Method::PUT, &credential.signed_uri),
+            &credential,
+        );
+        if matches!(
+            credential.credential_type,
+            Some(ArtifactCredentialType::AzureSasUri)
+        ) && !credential
+            .headers
+            .iter()
+            .any(|header| header.name.eq_ignore_ascii_case("x-ms-blob-type"))
+        {
+            request = request.header("x-ms-blob-type", "BlockBlob");
+        }
+        if let Some(content_length) = content_length {
+            request = request.header(reqwest::header::CONTENT_LENGTH, content_length);
+        }
+
+        send_presigned(request.body(Body::wrap_stream(ReaderStream::new(reader))))
+            .await
+            .map(|_| ())
+    }
+
+    /// Downloads an artifact into a local file, creating or truncating it.
+    ///
+    /// Returns:
+    /// - Same as `download_artifact`.
+    pub async fn download_artifact_to_file(
+        &self,
+        run_id: &str,
+        path: &str,
+        local_path: &Path,
+    ) -> Result<u64, HttpError> {
+        let mut file: tokio::fs::File = tokio::fs::File::create(local_path)
+            .await
+            .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+        self.download_artifact(run_id, path, &mut file).await
+    }
+
+    /// Uploads a local file as the artifact at `path`.
+    ///
+    /// Returns:
+    /// - Same as `upload_artifact`.
+    pub async fn upload_artifact_from_file(
+        &self,
+        run_id: &str,
+        path: &str,
+        local_path: &Path,
+    ) -> Result<(), HttpError> {
+        let file: tokio::fs::File = tokio::fs::File::open(local_path)
+            .await
+            .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+        let content_length: Option<u64> = file.metadata().await.ok().map(|meta| meta.len());
+        self.upload_artifact(run_id, path, file, content_length)
+            .await
+    }
+}
+
+/// Applies the headers required by a presigned URI. No Databricks credentials are attached.
+fn presigned_request(
+    mut request: RequestBuilder,
+    credential: &ArtifactCredentialInfo,
+) -> RequestBuilder {
+    for header in &credential.headers {
+        request = request.header(&header.name, &header.value);
+    }
+    request
+}
+
+pub(crate) async fn send_presigned(request: RequestBuilder) -> Result<Response, HttpError> {
+    let response: Response = request.send().await.map_err(|err| {
+        if err.is_timeout() {
+            HttpError::TemporarilyUnavailable(err.to_string())
+        } else {
+            HttpError::InternalServerError(err.to_string())
+        }
+    })?;
+
+    let status = response.status();
+    if status.is_success() {
+        Ok(response)
+    } else {
+        let body: String = response.text().await.unwrap_or_default();
+        Err(HttpError::from_status(
+            status.as_u16(),
+            format!(
+                "Cloud storage request failed with status {}: {}",
+                status, body
+            ),
+        ))
+    }
+}
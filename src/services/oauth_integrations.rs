@@ -0,0 +1,379 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        CreateCustomAppIntegrationRequest, CreateCustomAppIntegrationResponse,
+        CreatePublishedAppIntegrationRequest, CreatePublishedAppIntegrationResponse,
+        CreateServicePrincipalSecretRequest, CreateServicePrincipalSecretResponse,
+        CustomAppIntegration, ListCustomAppIntegrationsResponse,
+        ListPublishedAppIntegrationsResponse, ListServicePrincipalSecretsResponse,
+        PublishedAppIntegration, SecretInfo, UpdateCustomAppIntegrationRequest,
+        UpdatePublishedAppIntegrationRequest,
+    },
+    services::{databricks_session::EmptyResponse, AccountSession},
+    utils::encode_query_value,
+};
+use reqwest::Method;
+use std::time::Duration;
+
+impl AccountSession {
+    /// Creates a custom OAuth app integration.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateCustomAppIntegrationRequest` with the redirect URLs and scopes.
+    ///
+    /// Returns:
+    /// - A `Result` containing the client id and, for confidential apps, the client secret, or an
+    ///   `HttpError` if the request fails.
+    pub async fn create_custom_app_integration(
+        &self,
+        request_body: CreateCustomAppIntegrationRequest,
+    ) -> Result<CreateCustomAppIntegrationResponse, HttpError> {
+        self.send_account_request(
+            Method::POST,
+            "oauth2/custom-app-integrations",
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Retrieves a custom OAuth app integration.
+    ///
+    /// Parameters:
+    /// - `integration_id`: The id of the integration.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `CustomAppIntegration`, or an `HttpError` if the request fails.
+    pub async fn get_custom_app_integration(
+        &self,
+        integration_id: &str,
+    ) -> Result<CustomAppIntegration, HttpError> {
+        self.send_account_request(
+            Method::GET,
+            &format!(
+                "oauth2/custom-app-integrations/{}",
+                encode_query_value(integration_id)
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Lists all custom OAuth app integrations, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `CustomAppIntegration`, or an `HttpError` if any page request fails.
+    pub async fn list_custom_app_integrations(
+        &self,
+    ) -> Result<Vec<CustomAppIntegration>, HttpError> {
+        let mut apps: Vec<CustomAppIntegration> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => format!(
+                    "oauth2/custom-app-integrations?page_token={}",
+                    encode_query_value(token)
+                ),
+                None => "oauth2/custom-app-integrations".to_string(),
+            };
+            let page: ListCustomAppIntegrationsResponse = self
+                .send_account_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            apps.extend(page.apps);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(apps),
+            }
+        }
+    }
+
+    /// Updates the redirect URLs, scopes or token policy of a custom OAuth app integration.
+    ///
+    /// Parameters:
+    /// - `integration_id`: The id of the integration.
+    /// - `request_body`: An `UpdateCustomAppIntegrationRequest` containing the fields to change.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn update_custom_app_integration(
+        &self,
+        integration_id: &str,
+        request_body: UpdateCustomAppIntegrationRequest,
+    ) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::PATCH,
+            &format!(
+                "oauth2/custom-app-integrations/{}",
+                encode_query_value(integration_id)
+            ),
+            Some(request_body),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Deletes a custom OAuth app integration.
+    ///
+    /// Parameters:
+    /// - `integration_id`: The id of the integration.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_custom_app_integration(
+        &self,
+        integration_id: &str,
+    ) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!(
+                "oauth2/custom-app-integrations/{}",
+                encode_query_value(integration_id)
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Enables a published (Databricks-provided) OAuth app integration such as Power BI or Tableau.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreatePublishedAppIntegrationRequest` naming the app.
+    ///
+    /// Returns:
+    /// - A `Result` containing the integration id, or an `HttpError` if the request fails.
+    pub async fn create_published_app_integration(
+        &self,
+        request_body: CreatePublishedAppIntegrationRequest,
+    ) -> Result<CreatePublishedAppIntegrationResponse, HttpError> {
+        self.send_account_request(
+            Method::POST,
+            "oauth2/published-app-integrations",
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Retrieves a published OAuth app integration.
+    ///
+    /// Parameters:
+    /// - `integration_id`: The id of the integration.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `PublishedAppIntegration`, or an `HttpError` if the request fails.
+    pub async fn get_published_app_integration(
+        &self,
+        integration_id: &str,
+    ) -> Result<PublishedAppIntegration, HttpError> {
+        self.send_account_request(
+            Method::GET,
+            &format!(
+                "oauth2/published-app-integrations/{}",
+                encode_query_value(integration_id)
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Lists all enabled published OAuth app integrations, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `PublishedAppIntegration`, or an `HttpError` if any page request fails.
+    pub async fn list_published_app_integrations(
+        &self,
+    ) -> Result<Vec<PublishedAppIntegration>, HttpError> {
+        let mut apps: Vec<PublishedAppIntegration> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => format!(
+                    "oauth2/published-app-integrations?page_token={}",
+                    encode_query_value(token)
+                ),
+                None => "oauth2/published-app-integrations".to_string(),
+            };
+            let page: ListPublishedAppIntegrationsResponse = self
+                .send_account_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            apps.extend(page.apps);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(apps),
+            }
+        }
+    }
+
+    /// Updates the token policy of a published OAuth app integration.
+    ///
+    /// Parameters:
+    /// - `integration_id`: The id of the integration.
+    /// - `request_body`: An `UpdatePublishedAppIntegrationRequest`.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn update_published_app_integration(
+        &self,
+        integration_id: &str,
+        request_body: UpdatePublishedAppIntegrationRequest,
+    ) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::PATCH,
+            &format!(
+                "oauth2/published-app-integrations/{}",
+                encode_query_value(integration_id)
+            ),
+            Some(request_body),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Disables a published OAuth app integration.
+    ///
+    /// Parameters:
+    /// - `integration_id`: The id of the integration.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_published_app_integration(
+        &self,
+        integration_id: &str,
+    ) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!(
+                "oauth2/published-app-integrations/{}",
+                encode_query_value(integration_id)
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Creates an OAuth secret for a service principal, for use with the M2M client credentials flow.
+    ///
+    /// Parameters:
+    /// - `service_principal_id`: The account-level id of the service principal.
+    /// - `lifetime`: How long the secret stays valid; the account default applies if `None`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the new secret, which is only returned once, or an `HttpError` if the
+    ///   request fails.
+    pub async fn create_service_principal_secret(
+        &self,
+        service_principal_id: i64,
+        lifetime: Option<Duration>,
+    ) -> Result<CreateServicePrincipalSecretResponse, HttpError> {
+        self.send_account_request(
+            Method::POST,
+            &format!(
+                "servicePrincipals/{}/credentials/secrets",
+                service_principal_id
+            ),
+            Some(CreateServicePrincipalSecretRequest {
+                lifetime: lifetime.map(|lifetime| format!("{}s", lifetime.as_secs())),
+            }),
+        )
+        .await
+    }
+
+    /// Lists the OAuth secrets of a service principal, following pagination until exhausted.
+    ///
+    /// Parameters:
+    /// - `service_principal_id`: The account-level id of the service principal.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `SecretInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_service_principal_secrets(
+        &self,
+        service_principal_id: i64,
+    ) -> Result<Vec<SecretInfo>, HttpError> {
+        let mut secrets: Vec<SecretInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut endpoint: String = format!(
+                "servicePrincipals/{}/credentials/secrets",
+                service_principal_id
+            );
+            if let Some(token) = &page_token {
+                endpoint.push_str(&format!("?page_token={}", encode_query_value(token)));
+            }
+            let page: ListServicePrincipalSecretsResponse = self
+                .send_account_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            secrets.extend(page.secrets);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(secrets),
+            }
+        }
+    }
+
+    /// Deletes an OAuth secret of a service principal.
+    ///
+    /// Parameters:
+    /// - `service_principal_id`: The account-level id of the service principal.
+    /// - `secret_id`: The id of the secret.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_service_principal_secret(
+        &self,
+        service_principal_id: i64,
+        secret_id: &str,
+    ) -> Result<(), HttpError> {
+        self.send_account_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!(
+                "servicePrincipals/{}/credentials/secrets/{}",
+                service_principal_id,
+                encode_query_value(secret_id)
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Rotates the OAuth secret of a service principal: creates a new secret, then deletes every
+    /// other secret of the principal.
+    ///
+    /// Consumers still using an old secret will fail to obtain tokens once this returns, so the new
+    /// secret should be distributed before old tokens expire.
+    ///
+    /// Parameters:
+    /// - `service_principal_id`: The account-level id of the service principal.
+    /// - `lifetime`: Same as `create_service_principal_secret`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the new secret, or an `HttpError` if creating the secret or deleting
+    ///   an old one fails. If deletion fails, the new secret has already been created.
+    pub async fn rotate_service_principal_secret(
+        &self,
+        service_principal_id: i64,
+        lifetime: Option<Duration>,
+    ) -> Result<CreateServicePrincipalSecretResponse, HttpError> {
+        let created: CreateServicePrincipalSecretResponse = self
+            .create_service_principal_secret(service_principal_id, lifetime)
+            .await?;
+
+        for secret in self
+            .list_service_principal_secrets(service_principal_id)
+            .await?
+        {
+            if secret.id != created.id {
+                self.delete_service_principal_secret(service_principal_id, &secret.id)
+                    .await?;
+            }
+        }
+
+        Ok(created)
+    }
+}
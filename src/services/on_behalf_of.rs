@@ -0,0 +1,43 @@
+use reqwest::header::HeaderMap;
+
+/// The header in which Databricks Apps forward the signed-in user's access token.
+pub const FORWARDED_ACCESS_TOKEN_HEADER: &str = "x-forwarded-access-token";
+
+/// The end user a gateway service acts for, applied to a session with
+/// `DatabricksSession::on_behalf_of`.
+///
+/// With the user's own access token, requests run with the user's permissions rather than the
+/// service's. Further headers, such as `X-Databricks-Org-Id` for workspace routing, are sent
+/// verbatim with every request.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct OnBehalfOf {
+    pub access_token: Option<String>,
+    pub headers: Vec<(String, String)>,
+}
+
+impl OnBehalfOf {
+    /// Acts as the user that `access_token` was issued to.
+    pub fn user_token(access_token: &str) -> Self {
+        OnBehalfOf {
+            access_token: Some(access_token.to_string()),
+            headers: Vec::new(),
+        }
+    }
+
+    /// Reads the user's token from the headers a Databricks App receives, returning `None` if
+    /// the request carries no `x-forwarded-access-token`.
+    pub fn from_forwarded_headers(headers: &HeaderMap) -> Option<Self> {
+        headers
+            .get(FORWARDED_ACCESS_TOKEN_HEADER)
+            .and_then(|value| value.to_str().ok())
+            .map(str::trim)
+            .filter(|token| !token.is_empty())
+            .map(Self::user_token)
+    }
+
+    /// Adds a header sent with every request, e.g. `X-Databricks-Org-Id`.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
@@ -0,0 +1,272 @@
+use crate::{
+    errors::HttpError,
+    models::{ChunkValidator, ExternalLink, ResultData, SqlStatementRequest, SqlStatementResponse},
+    services::DatabricksSession,
+};
+use arrow_ipc::reader::StreamReader;
+use arrow_schema::Schema;
+use futures::TryStreamExt;
+use parquet::{arrow::ArrowWriter, errors::ParquetError, file::properties::WriterProperties};
+use std::{
+    io::{self, BufReader, Read, Write},
+    mem,
+    sync::{
+        atomic::{AtomicI64, Ordering},
+        Arc,
+    },
+};
+use tokio::time::Instant;
+use tokio_util::io::{StreamReader as AsyncStreamReader, SyncIoBridge};
+
+/// Writes Arrow IPC streams, such as the chunks of an `ARROW_STREAM` statement result, into a
+/// single Parquet file as they arrive.
+///
+/// The Parquet schema is taken from the first stream; every later stream must have the same
+/// schema. Record batches are encoded as they are read, so only the current row group is held in
+/// memory.
+pub struct ParquetSink<W: Write + Send> {
+    state: SinkState<W>,
+    properties: Option<WriterProperties>,
+    rows_written: i64,
+}
+
+enum SinkState<W: Write + Send> {
+    Pending(W),
+    Writing(Box<ArrowWriter<W>>),
+    Failed,
+}
+
+impl<W: Write + Send> ParquetSink<W> {
+    /// Creates a sink writing to `writer` with the default Parquet writer properties.
+    pub fn new(writer: W) -> Self {
+        ParquetSink {
+            state: SinkState::Pending(writer),
+            properties: None,
+            rows_written: 0,
+        }
+    }
+
+    /// Sets the Parquet writer properties, e.g. the compression codec or the row group size.
+    pub fn with_properties(mut self, properties: WriterProperties) -> Self {
+        self.properties = Some(properties);
+        self
+    }
+
+    /// The number of rows written so far.
+    pub fn rows_written(&self) -> i64 {
+        self.rows_written
+    }
+
+    /// Reads one Arrow IPC stream to its end and appends its record batches to the file.
+    ///
+    /// Parameters:
+    /// - `reader`: The IPC stream, starting with its schema message.
+    ///
+    /// Returns:
+    /// - A `Result` containing the number of rows read from the stream, or a `ParquetError` if the
+    ///   stream cannot be decoded or written. The sink cannot be used after an error.
+    pub fn write_ipc_stream<R: Read>(&mut self, reader: R) -> Result<i64, ParquetError> {
+        let batches: StreamReader<R> = StreamReader::try_new(reader, None)?;
+        let mut writer: Box<ArrowWriter<W>> = match mem::replace(&mut self.state, SinkState::Failed)
+        {
+            SinkState::Pending(inner) => Box::new(ArrowWriter::try_new(
+                inner,
+                batches.schema(),
+                self.properties.clone(),
+            )?),
+            SinkState::Writing(writer) => writer,
+            SinkState::Failed => {
+                return Err(ParquetError::General(
+                    "Parquet sink failed on an earlier stream".to_string(),
+                ))
+            }
+        };
+
+        let mut rows: i64 = 0;
+        for batch in batches {
+            let batch = batch?;
+            writer.write(&batch)?;
+            rows += batch.num_rows() as i64;
+        }
+        self.rows_written += rows;
+        self.state = SinkState::Writing(writer);
+        Ok(rows)
+    }
+
+    /// Writes the Parquet footer and returns the underlying writer.
+    ///
+    /// If no stream was written, the file has an empty schema and no rows.
+    ///
+    /// Returns:
+    /// - A `Result` containing the writer, or a `ParquetError` if the footer cannot be written.
+    pub fn finish(self) -> Result<W, ParquetError> {
+        match self.state {
+            SinkState::Pending(inner) => {
+                ArrowWriter::try_new(inner, Arc::new(Schema::empty()), self.properties)?
+                    .into_inner()
+            }
+            SinkState::Writing(writer) => writer.into_inner(),
+            SinkState::Failed => Err(ParquetError::General(
+                "Parquet sink failed on an earlier stream".to_string(),
+            )),
+        }
+    }
+}
+
+impl DatabricksSession {
+    /// Executes a SQL statement and streams its result into a Parquet file.
+    ///
+    /// The statement is run with the `ARROW_STREAM` format and `EXTERNAL_LINKS` disposition, and
+    /// waited for if it does not finish within the request's wait timeout. Each result chunk is
+    /// downloaded and transcoded as it arrives, so the result is never held in memory as a whole.
+    ///
+    /// Parameters:
+    /// - `request_body`: The statement to execute; its format and disposition are overridden.
+    /// - `sink`: The `ParquetSink` to write into.
+    ///
+    /// Returns:
+    /// - A `Result` containing the sink after the last chunk, ready to be `finish`ed, or an
+    ///   `HttpError` if the statement fails, a chunk cannot be downloaded or writing fails.
+    pub async fn execute_sql_to_parquet<W: Write + Send + 'static>(
+        &self,
+        request_body: SqlStatementRequest,
+        sink: ParquetSink<W>,
+    ) -> Result<ParquetSink<W>, HttpError> {
+        let deadline: Option<Instant> = self.operation_deadline();
+        let response: SqlStatementResponse = self
+            .execute_sql_statement_and_wait_until(
+                request_body
+                    .with_format("ARROW_STREAM")
+                    .with_disposition("EXTERNAL_LINKS"),
+                deadline,
+                "execute_sql_to_parquet",
+            )
+            .await?;
+        self.write_result_to_parquet_until(&response, sink, deadline, "execute_sql_to_parquet")
+            .await
+    }
+
+    /// Streams the result of a finished `ARROW_STREAM`/`EXTERNAL_LINKS` statement into a
+    /// Parquet file, chunk by chunk.
+    ///
+    /// Parameters:
+    /// - `response`: The finished statement, as returned by `execute_sql_statement_and_wait`.
+    /// - `sink`: The `ParquetSink` to write into.
+    ///
+    /// Returns:
+    /// - Same as `execute_sql_to_parquet`.
+    pub async fn write_result_to_parquet<W: Write + Send + 'static>(
+        &self,
+        response: &SqlStatementResponse,
+        sink: ParquetSink<W>,
+    ) -> Result<ParquetSink<W>, HttpError> {
+        self.write_result_to_parquet_until(
+            response,
+            sink,
+            self.operation_deadline(),
+            "write_result_to_parquet",
+        )
+        .await
+    }
+
+    async fn write_result_to_parquet_until<W: Write + Send + 'static>(
+        &self,
+        response: &SqlStatementResponse,
+        mut sink: ParquetSink<W>,
+        deadline: Option<Instant>,
+        operation: &str,
+    ) -> Result<ParquetSink<W>, HttpError> {
+        let statement_id: &str = response.statement_id.as_deref().ok_or_else(|| {
+            HttpError::InternalServerError("Statement response has no statement_id".to_string())
+        })?;
+        let total_chunks: i32 = response
+            .manifest
+            .as_ref()
+            .map(|manifest| manifest.total_chunk_count)
+            .unwrap_or_default();
+
+        let mut validator: Option<ChunkValidator> = ChunkValidator::for_response(response);
+        let mut result: Option<ResultData> = response.result.clone();
+        let mut next_chunk: Option<i32> = (total_chunks > 0).then_some(0);
+        while let Some(chunk_index) = next_chunk {
+            let data: ResultData = match result.take() {
+                Some(data) if data.external_links.is_some() => data,
+                _ => {
+                    self.before_deadline(
+                        deadline,
+                        operation,
+                        self.get_sql_statement_result_chunk(statement_id, chunk_index),
+                    )
+                    .await?
+                }
+            };
+            let links: Vec<ExternalLink> = data.external_links.unwrap_or_default();
+            if links.is_empty() {
+                return Err(HttpError::BadRequest(format!(
+                    "Chunk {} of statement {} has no external links; use the EXTERNAL_LINKS disposition",
+                    chunk_index, statement_id
+                )));
+            }
+
+            next_chunk = None;
+            for link in links {
+                next_chunk = link.next_chunk_index;
+                if let Some(validator) = &mut validator {
+                    validator
+                        .record_link(&link)
+                        .map_err(HttpError::InvalidChunk)?;
+                }
+                let (written, bytes_received) = self
+                    .before_deadline(deadline, operation, self.write_link_to_parquet(&link, sink))
+                    .await?;
+                sink = written;
+                if let Some(validator) = &validator {
+                    validator
+                        .check_download(&link, bytes_received)
+                        .map_err(HttpError::InvalidChunk)?;
+                }
+            }
+        }
+        if let Some(validator) = &validator {
+            validator.finish().map_err(HttpError::InvalidChunk)?;
+        }
+        Ok(sink)
+    }
+
+    /// Downloads one presigned chunk and transcodes it on a blocking thread while it streams in,
+    /// returning the sink and the number of bytes downloaded.
+    async fn write_link_to_parquet<W: Write + Send + 'static>(
+        &self,
+        link: &ExternalLink,
+        mut sink: ParquetSink<W>,
+    ) -> Result<(ParquetSink<W>, i64), HttpError> {
+        // Presigned URLs must be fetched without the workspace token.
+        let response: reqwest::Response = self
+            .send_raw_request(self.client.get(&link.external_link))
+            .await?;
+        let received: Arc<AtomicI64> = Arc::new(AtomicI64::new(0));
+        let stream = response.bytes_stream().map_err(io::Error::other).map_ok({
+            let received: Arc<AtomicI64> = Arc::clone(&received);
+            move |chunk| {
+                received.fetch_add(chunk.len() as i64, Ordering::Relaxed);
+                chunk
+            }
+        });
+        let reader = SyncIoBridge::new(AsyncStreamReader::new(stream));
+
+        let (sink, result) = tokio::task::spawn_blocking(move || {
+            let mut reader = BufReader::new(reader);
+            // Drain anything after the end-of-stream marker so the byte count covers the body.
+            let result: Result<i64, ParquetError> =
+                sink.write_ipc_stream(&mut reader).and_then(|rows| {
+                    io::copy(&mut reader, &mut io::sink())?;
+                    Ok(rows)
+                });
+            (sink, result)
+        })
+        .await
+        .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+        result.map_err(|err| HttpError::InternalError(Box::new(err)))?;
+        Ok((sink, received.load(Ordering::Relaxed)))
+    }
+}
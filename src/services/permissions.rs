@@ -0,0 +1,248 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        AccessControlRequest, ObjectPermissions, ObjectPermissionsRequest, PermissionLevel,
+        PermissionObjectType, Principal,
+    },
+    services::DatabricksSession,
+};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Retrieves the access control list of a workspace object, including inherited permissions.
+    ///
+    /// Parameters:
+    /// - `object_type`: The kind of object (job, cluster, notebook, ...).
+    /// - `object_id`: The ID of the object, e.g. a job ID or cluster ID.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `ObjectPermissions`, or an `HttpError` if the request fails.
+    pub async fn get_object_permissions(
+        &self,
+        object_type: PermissionObjectType,
+        object_id: &str,
+    ) -> Result<ObjectPermissions, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &format!(
+                "api/2.0/permissions/{}/{}",
+                object_type.as_path_segment(),
+                object_id
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Replaces all direct permissions on a workspace object.
+    ///
+    /// Entries that are left out are removed; objects with an owner (such as jobs) must keep an
+    /// `IS_OWNER` entry.
+    ///
+    /// Parameters:
+    /// - `object_type`: The kind of object.
+    /// - `object_id`: The ID of the object.
+    /// - `access_control_list`: The complete set of direct permissions.
+    ///
+    /// Returns:
+    /// - Same as `get_object_permissions`.
+    pub async fn set_object_permissions(
+        &self,
+        object_type: PermissionObjectType,
+        object_id: &str,
+        access_control_list: Vec<AccessControlRequest>,
+    ) -> Result<ObjectPermissions, HttpError> {
+        self.send_databricks_request(
+            Method::PUT,
+            &format!(
+                "api/2.0/permissions/{}/{}",
+                object_type.as_path_segment(),
+                object_id
+            ),
+            Some(ObjectPermissionsRequest {
+                access_control_list,
+            }),
+        )
+        .await
+    }
+
+    /// Adds direct permissions to a workspace object, leaving other entries in place.
+    ///
+    /// The API merges the entries into the existing list but never lowers or removes a
+    /// principal's permissions; use `grant_permission` or `revoke_permission` for that.
+    ///
+    /// Parameters:
+    /// - `object_type`: The kind of object.
+    /// - `object_id`: The ID of the object.
+    /// - `access_control_list`: The permissions to add.
+    ///
+    /// Returns:
+    /// - Same as `get_object_permissions`.
+    pub async fn update_object_permissions(
+        &self,
+        object_type: PermissionObjectType,
+        object_id: &str,
+        access_control_list: Vec<AccessControlRequest>,
+    ) -> Result<ObjectPermissions, HttpError> {
+        self.send_databricks_request(
+            Method::PATCH,
+            &format!(
+                "api/2.0/permissions/{}/{}",
+                object_type.as_path_segment(),
+                object_id
+            ),
+            Some(ObjectPermissionsRequest {
+                access_control_list,
+            }),
+        )
+        .await
+    }
+
+    /// Gives a principal exactly one direct permission level on a workspace object.
+    ///
+    /// The current list is fetched first. A principal without direct permissions is added with a
+    /// `PATCH`, which leaves concurrent changes to other entries intact. A principal that already
+    /// holds a different direct level is replaced with a `PUT` of the merged list, since `PATCH`
+    /// cannot lower a level. Nothing is sent if the principal already holds exactly `level`.
+    ///
+    /// Parameters:
+    /// - `object_type`: The kind of object.
+    /// - `object_id`: The ID of the object.
+    /// - `principal`: The user, group or service principal.
+    /// - `level`: The permission level to grant.
+    ///
+    /// Returns:
+    /// - Same as `get_object_permissions`.
+    pub async fn grant_permission(
+        &self,
+        object_type: PermissionObjectType,
+        object_id: &str,
+        principal: Principal<'_>,
+        level: PermissionLevel,
+    ) -> Result<ObjectPermissions, HttpError> {
+        let current: ObjectPermissions =
+            self.get_object_permissions(object_type, object_id).await?;
+        let direct: Vec<&PermissionLevel> = current
+            .entry_of(principal)
+            .map(|entry| entry.direct_levels().collect())
+            .unwrap_or_default();
+
+        if direct.is_empty() {
+            return self
+                .update_object_permissions(
+                    object_type,
+                    object_id,
+                    vec![AccessControlRequest::new(principal, level)],
+                )
+                .await;
+        }
+        if direct == [&level] {
+            return Ok(current);
+        }
+        if direct.contains(&&PermissionLevel::IsOwner) {
+            return Err(HttpError::BadRequest(format!(
+                "{} owns {}/{}; transfer ownership before changing its permission",
+                principal, object_type, object_id
+            )));
+        }
+
+        let mut access_control_list: Vec<AccessControlRequest> =
+            merged_without(&current, principal);
+        access_control_list.push(AccessControlRequest::new(principal, level));
+        self.set_object_permissions(object_type, object_id, access_control_list)
+            .await
+    }
+
+    /// Removes every direct permission of a principal on a workspace object.
+    ///
+    /// The current list is fetched and written back without the principal's entries, keeping all
+    /// other direct permissions. Permissions the principal inherits from a parent object are
+    /// unaffected. Nothing is sent if the principal holds no direct permission.
+    ///
+    /// Parameters:
+    /// - `object_type`: The kind of object.
+    /// - `object_id`: The ID of the object.
+    /// - `principal`: The user, group or service principal.
+    ///
+    /// Returns:
+    /// - Same as `get_object_permissions`. Revoking the owner fails with `HttpError::BadRequest`.
+    pub async fn revoke_permission(
+        &self,
+        object_type: PermissionObjectType,
+        object_id: &str,
+        principal: Principal<'_>,
+    ) -> Result<ObjectPermissions, HttpError> {
+        let current: ObjectPermissions =
+            self.get_object_permissions(object_type, object_id).await?;
+        let Some(entry) = current.entry_of(principal) else {
+            return Ok(current);
+        };
+        let direct: Vec<&PermissionLevel> = entry.direct_levels().collect();
+        if direct.is_empty() {
+            return Ok(current);
+        }
+        if direct.contains(&&PermissionLevel::IsOwner) {
+            return Err(HttpError::BadRequest(format!(
+                "{} owns {}/{}; transfer ownership before revoking its permission",
+                principal, object_type, object_id
+            )));
+        }
+
+        let access_control_list: Vec<AccessControlRequest> = merged_without(&current, principal);
+        self.set_object_permissions(object_type, object_id, access_control_list)
+            .await
+    }
+
+    /// Gives a principal exactly one direct permission level on a job.
+    ///
+    /// Parameters:
+    /// - `job_id`: The ID of the job.
+    /// - `principal`: The user, group or service principal, e.g. `Principal::Group("data-eng")`.
+    /// - `level`: The permission level to grant, e.g. `PermissionLevel::CanManageRun`.
+    ///
+    /// Returns:
+    /// - Same as `grant_permission`.
+    pub async fn grant_job_permission(
+        &self,
+        job_id: i64,
+        principal: Principal<'_>,
+        level: PermissionLevel,
+    ) -> Result<ObjectPermissions, HttpError> {
+        self.grant_permission(
+            PermissionObjectType::Jobs,
+            &job_id.to_string(),
+            principal,
+            level,
+        )
+        .await
+    }
+
+    /// Removes every direct permission of a principal on a job.
+    ///
+    /// Parameters:
+    /// - `job_id`: The ID of the job.
+    /// - `principal`: The user, group or service principal.
+    ///
+    /// Returns:
+    /// - Same as `revoke_permission`.
+    pub async fn revoke_job_permission(
+        &self,
+        job_id: i64,
+        principal: Principal<'_>,
+    ) -> Result<ObjectPermissions, HttpError> {
+        self.revoke_permission(PermissionObjectType::Jobs, &job_id.to_string(), principal)
+            .await
+    }
+}
+
+/// The direct permissions of every principal other than `principal`.
+fn merged_without(
+    current: &ObjectPermissions,
+    principal: Principal<'_>,
+) -> Vec<AccessControlRequest> {
+    current
+        .direct_access_control_list()
+        .into_iter()
+        .filter(|entry| entry.principal() != Some(principal))
+        .collect()
+}
@@ -0,0 +1,44 @@
+use crate::{
+    errors::HttpError,
+    models::{ObjectAcl, ObjectPermissions, PermissionObjectType, PermissionsSnapshot},
+    services::{databricks_session::retry_rate_limited, DatabricksSession},
+};
+use futures::{StreamExt, TryStreamExt};
+
+impl DatabricksSession {
+    /// Exports the access control lists of a set of objects, such as jobs, clusters, warehouses
+    /// and folders, into a `PermissionsSnapshot`.
+    ///
+    /// The lists are fetched concurrently, at most `concurrency` at a time, and each request is
+    /// retried with backoff when the workspace rate-limits it.
+    ///
+    /// Parameters:
+    /// - `objects`: The objects to export, e.g. `(PermissionObjectType::Directories, "1234")`.
+    /// - `concurrency`: The number of lists fetched at the same time.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `PermissionsSnapshot`, or an `HttpError` if a request fails.
+    pub async fn snapshot_permissions(
+        &self,
+        objects: &[(PermissionObjectType, &str)],
+        concurrency: usize,
+    ) -> Result<PermissionsSnapshot, HttpError> {
+        let mut acls: Vec<ObjectAcl> = futures::stream::iter(objects.iter().copied())
+            .map(|(object_type, object_id)| async move {
+                let permissions: ObjectPermissions =
+                    retry_rate_limited(5, || self.get_object_permissions(object_type, object_id))
+                        .await?;
+                Ok::<_, HttpError>(ObjectAcl::new(object_type, object_id, &permissions))
+            })
+            .buffer_unordered(concurrency.max(1))
+            .try_collect()
+            .await?;
+        acls.sort_by(|a, b| (a.object_type, &a.object_id).cmp(&(b.object_type, &b.object_id)));
+
+        Ok(PermissionsSnapshot {
+            host: self.config.databricks_host.clone(),
+            taken_at: chrono::Utc::now(),
+            objects: acls,
+        })
+    }
+}
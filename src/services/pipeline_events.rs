@@ -0,0 +1,76 @@
+use crate::{
+    errors::HttpError,
+    models::{ListPipelineEventsResponse, PipelineEvent},
+    services::DatabricksSession,
+    utils::encode_query_value,
+};
+use futures::{Stream, TryStreamExt};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Streams the event log of a Delta Live Tables pipeline, newest events first.
+    ///
+    /// Pages are fetched lazily as the stream is consumed.
+    ///
+    /// Parameters:
+    /// - `pipeline_id`: The id of the pipeline.
+    ///
+    /// Returns:
+    /// - A stream of `PipelineEvent`s, yielding an `HttpError` if a page request fails.
+    pub fn list_pipeline_events<'a>(
+        &'a self,
+        pipeline_id: &'a str,
+    ) -> impl Stream<Item = Result<PipelineEvent, HttpError>> + 'a {
+        self.list_pipeline_events_filtered(pipeline_id, None)
+    }
+
+    /// Streams the event log of a pipeline, restricted by a filter expression.
+    ///
+    /// Parameters:
+    /// - `pipeline_id`: The id of the pipeline.
+    /// - `filter`: An optional SQL-like filter, e.g. `level='ERROR'` or `timestamp > '2024-01-01T00:00:00Z'`.
+    ///
+    /// Returns:
+    /// - Same as `list_pipeline_events`.
+    pub fn list_pipeline_events_filtered<'a>(
+        &'a self,
+        pipeline_id: &'a str,
+        filter: Option<&'a str>,
+    ) -> impl Stream<Item = Result<PipelineEvent, HttpError>> + 'a {
+        futures::stream::try_unfold(
+            Some(None),
+            move |page_token: Option<Option<String>>| async move {
+                let page_token: Option<String> = match page_token {
+                    Some(page_token) => page_token,
+                    None => return Ok(None),
+                };
+
+                // The API rejects `filter` together with `page_token`; the token already encodes it.
+                let endpoint: String = match (&page_token, filter) {
+                    (Some(token), _) => format!(
+                        "api/2.0/pipelines/{}/events?page_token={}",
+                        pipeline_id,
+                        encode_query_value(token)
+                    ),
+                    (None, Some(filter)) => format!(
+                        "api/2.0/pipelines/{}/events?filter={}",
+                        pipeline_id,
+                        encode_query_value(filter)
+                    ),
+                    (None, None) => format!("api/2.0/pipelines/{}/events", pipeline_id),
+                };
+                let page: ListPipelineEventsResponse = self
+                    .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                    .await?;
+
+                let next: Option<Option<String>> = match page.next_page_token {
+                    Some(token) if !token.is_empty() => Some(Some(token)),
+                    _ => None,
+                };
+                Ok(Some((page.events, next)))
+            },
+        )
+        .map_ok(|events: Vec<PipelineEvent>| futures::stream::iter(events.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
@@ -0,0 +1,103 @@
+use crate::{
+    errors::HttpError,
+    models::{statement_tag_comment, ListQueryHistoryResponse, QueryHistoryEntry},
+    services::DatabricksSession,
+    utils::encode_query_value,
+};
+use chrono::{DateTime, Utc};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Retrieves the profile of a query from the query history service, including its execution
+    /// metrics such as the task time breakdown, bytes read and bytes pruned.
+    ///
+    /// Metrics are only complete once the query has finished; the statement ID returned by the
+    /// statement execution API can be used as `query_id`.
+    ///
+    /// Parameters:
+    /// - `query_id`: The ID of the query.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `QueryHistoryEntry` with its `metrics` if successful, or an
+    ///   `HttpError` if the request fails or the query is not (yet) in the history.
+    pub async fn get_query_profile(&self, query_id: &str) -> Result<QueryHistoryEntry, HttpError> {
+        let response: ListQueryHistoryResponse = self
+            .send_databricks_request(
+                Method::GET,
+                &format!(
+                    "api/2.0/sql/history/queries?include_metrics=true&filter_by.statement_ids={}",
+                    encode_query_value(query_id)
+                ),
+                None::<()>,
+            )
+            .await?;
+        response
+            .res
+            .into_iter()
+            .find(|entry| entry.query_id == query_id)
+            .ok_or_else(|| {
+                HttpError::NotFound(format!(
+                    "Query {} was not found in the query history",
+                    query_id
+                ))
+            })
+    }
+
+    /// Finds the query-history entry of a statement tagged with `SqlStatementRequest::with_tag`,
+    /// correlating a client-side request with its execution on the warehouse.
+    ///
+    /// The history of `warehouse_id` since `since` is searched page by page, newest first, for a
+    /// query whose text carries the tag. Entries can take a few seconds to appear after a
+    /// statement is submitted.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The warehouse the statement was submitted to.
+    /// - `tag`: The tag passed to `with_tag`.
+    /// - `since`: A time at or before the submission of the statement.
+    ///
+    /// Returns:
+    /// - A `Result` containing the matching `QueryHistoryEntry` with its `metrics`, `None` if no
+    ///   query carries the tag, or an `HttpError` if any page request fails.
+    pub async fn find_tagged_query(
+        &self,
+        warehouse_id: &str,
+        tag: &str,
+        since: DateTime<Utc>,
+    ) -> Result<Option<QueryHistoryEntry>, HttpError> {
+        let marker: String = statement_tag_comment(tag);
+        let mut page_token: Option<String> = None;
+
+        loop {
+            // Filters may not be combined with a page token; the token carries them forward.
+            let endpoint: String = match &page_token {
+                Some(token) => format!(
+                    "api/2.0/sql/history/queries?include_metrics=true&page_token={}",
+                    encode_query_value(token)
+                ),
+                None => format!(
+                    "api/2.0/sql/history/queries?include_metrics=true&filter_by.warehouse_ids={}&filter_by.query_start_time_range.start_time_ms={}",
+                    encode_query_value(warehouse_id),
+                    since.timestamp_millis()
+                ),
+            };
+            let page: ListQueryHistoryResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            if let Some(entry) = page.res.into_iter().find(|entry| {
+                entry
+                    .query_text
+                    .as_deref()
+                    .is_some_and(|text| text.contains(&marker))
+            }) {
+                return Ok(Some(entry));
+            }
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() && page.has_next_page != Some(false) => {
+                    page_token = Some(token)
+                }
+                _ => return Ok(None),
+            }
+        }
+    }
+}
@@ -0,0 +1,130 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        CreateRegistryWebhookRequest, ListRegistryWebhooksResponse, RegistryWebhook,
+        RegistryWebhookEvent, RegistryWebhookResponse, RegistryWebhookTestResult,
+        TestRegistryWebhookRequest, TestRegistryWebhookResponse,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+    utils::encode_query_value,
+};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Creates a Model Registry webhook that calls a URL or triggers a job on registry events.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateRegistryWebhookRequest` describing the events and the target.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `RegistryWebhook` if successful, or an `HttpError` if the request fails.
+    pub async fn create_registry_webhook(
+        &self,
+        request_body: &CreateRegistryWebhookRequest,
+    ) -> Result<RegistryWebhook, HttpError> {
+        let response: RegistryWebhookResponse = self
+            .send_databricks_request(
+                Method::POST,
+                "api/2.0/mlflow/registry-webhooks/create",
+                Some(request_body),
+            )
+            .await?;
+        Ok(response.webhook)
+    }
+
+    /// Lists registry webhooks, following pagination until exhausted.
+    ///
+    /// Parameters:
+    /// - `model_name`: Only list webhooks of this registered model, or `None` for all webhooks.
+    /// - `events`: Only list webhooks subscribed to at least one of these events; empty for any.
+    ///
+    /// Returns:
+    /// - A `Result` containing every matching `RegistryWebhook`, or an `HttpError` if any page request fails.
+    pub async fn list_registry_webhooks(
+        &self,
+        model_name: Option<&str>,
+        events: &[RegistryWebhookEvent],
+    ) -> Result<Vec<RegistryWebhook>, HttpError> {
+        let mut query: Vec<String> = Vec::new();
+        if let Some(model_name) = model_name {
+            query.push(format!("model_name={}", encode_query_value(model_name)));
+        }
+        for event in events {
+            let event: String = serde_json::to_value(event)
+                .ok()
+                .and_then(|value| value.as_str().map(str::to_string))
+                .unwrap_or_default();
+            query.push(format!("events={}", event));
+        }
+
+        let mut webhooks: Vec<RegistryWebhook> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut params: Vec<String> = query.clone();
+            if let Some(token) = &page_token {
+                params.push(format!("page_token={}", encode_query_value(token)));
+            }
+            let mut endpoint: String = "api/2.0/mlflow/registry-webhooks/list".to_string();
+            if !params.is_empty() {
+                endpoint.push('?');
+                endpoint.push_str(&params.join("&"));
+            }
+            let page: ListRegistryWebhooksResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            webhooks.extend(page.webhooks);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(webhooks),
+            }
+        }
+    }
+
+    /// Sends a test event to a registry webhook, whatever its status.
+    ///
+    /// Parameters:
+    /// - `id`: The ID of the webhook.
+    /// - `event`: The event to simulate, or `None` for the first event the webhook subscribes to.
+    ///
+    /// Returns:
+    /// - A `Result` containing the status code and body the endpoint answered with, or an `HttpError` if the request fails.
+    pub async fn test_registry_webhook(
+        &self,
+        id: &str,
+        event: Option<RegistryWebhookEvent>,
+    ) -> Result<RegistryWebhookTestResult, HttpError> {
+        let response: TestRegistryWebhookResponse = self
+            .send_databricks_request(
+                Method::POST,
+                "api/2.0/mlflow/registry-webhooks/test",
+                Some(TestRegistryWebhookRequest {
+                    id: id.to_string(),
+                    event,
+                }),
+            )
+            .await?;
+        Ok(response.webhook)
+    }
+
+    /// Deletes a registry webhook.
+    ///
+    /// Parameters:
+    /// - `id`: The ID of the webhook.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_registry_webhook(&self, id: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!(
+                "api/2.0/mlflow/registry-webhooks/delete?id={}",
+                encode_query_value(id)
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+}
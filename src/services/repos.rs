@@ -0,0 +1,37 @@
+use crate::{
+    errors::HttpError,
+    models::{ListReposResponse, RepoInfo},
+    services::DatabricksSession,
+    utils::encode_query_value,
+};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Lists all Git folders (repos) the caller can access, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `RepoInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_repos(&self) -> Result<Vec<RepoInfo>, HttpError> {
+        let mut repos: Vec<RepoInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => format!(
+                    "api/2.0/repos?next_page_token={}",
+                    encode_query_value(token)
+                ),
+                None => "api/2.0/repos".to_string(),
+            };
+            let page: ListReposResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            repos.extend(page.repos);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(repos),
+            }
+        }
+    }
+}
@@ -0,0 +1,51 @@
+use reqwest::header::HeaderMap;
+
+/// A successfully deserialized response together with the metadata carried in its headers.
+///
+/// Returned by the `*_with_meta` variants of session methods, so callers can log request IDs for
+/// support cases or slow down before the workspace starts rejecting requests.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseMeta<T> {
+    /// The deserialized response body.
+    pub value: T,
+    /// The `x-request-id` assigned by Databricks, useful when reporting issues.
+    pub request_id: Option<String>,
+    /// The number of requests allowed in the current rate-limit window (`x-ratelimit-limit`).
+    pub rate_limit_limit: Option<u64>,
+    /// The number of requests left in the current rate-limit window (`x-ratelimit-remaining`).
+    pub rate_limit_remaining: Option<u64>,
+    /// The number of seconds until the rate-limit window resets (`x-ratelimit-reset`).
+    pub rate_limit_reset: Option<u64>,
+}
+
+impl<T> ResponseMeta<T> {
+    /// Collects the metadata headers of a response around its deserialized body.
+    pub(crate) fn new(value: T, headers: &HeaderMap) -> Self {
+        let header = |name: &str| -> Option<&str> {
+            headers
+                .get(name)
+                .and_then(|value| value.to_str().ok())
+                .map(str::trim)
+        };
+        let number =
+            |name: &str| -> Option<u64> { header(name).and_then(|value| value.parse().ok()) };
+
+        ResponseMeta {
+            value,
+            request_id: header("x-request-id").map(str::to_string),
+            rate_limit_limit: number("x-ratelimit-limit"),
+            rate_limit_remaining: number("x-ratelimit-remaining"),
+            rate_limit_reset: number("x-ratelimit-reset"),
+        }
+    }
+
+    /// Discards the metadata and returns the response body.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Whether the workspace reported that no requests are left in the current rate-limit window.
+    pub fn is_rate_limit_exhausted(&self) -> bool {
+        self.rate_limit_remaining == Some(0)
+    }
+}
@@ -0,0 +1,141 @@
+use crate::{
+    config::ApiService,
+    errors::HttpError,
+    models::{JobRunRequest, JobRunResponse, Run, RunOutput, RunOutputEvent, SubmitRunRequest},
+    services::DatabricksSession,
+};
+use futures::Stream;
+use reqwest::Method;
+use std::time::Duration;
+
+impl DatabricksSession {
+    /// Triggers a run of an existing job and returns a handle to it.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `JobRunRequest` as for `execute_job_run`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `RunHandle`, or an `HttpError` if the request fails.
+    pub async fn start_job_run(
+        &self,
+        request_body: JobRunRequest,
+    ) -> Result<RunHandle<'_>, HttpError> {
+        let response: JobRunResponse = self.execute_job_run(request_body).await?;
+        Ok(RunHandle::new(self, response.run_id))
+    }
+
+    /// Submits a one-time run of tasks that are not defined as a job, and returns a handle to it.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `SubmitRunRequest` describing the tasks to run.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `RunHandle`, or an `HttpError` if the request fails.
+    pub async fn submit_run(
+        &self,
+        request_body: SubmitRunRequest,
+    ) -> Result<RunHandle<'_>, HttpError> {
+        let response: JobRunResponse = self
+            .send_databricks_request(
+                Method::POST,
+                &self.api_endpoint(ApiService::Jobs, "jobs/runs/submit"),
+                Some(request_body),
+            )
+            .await?;
+        Ok(RunHandle::new(self, response.run_id))
+    }
+}
+
+/// A job run, returned by `DatabricksSession::start_job_run` and `DatabricksSession::submit_run`.
+pub struct RunHandle<'a> {
+    session: &'a DatabricksSession,
+    run_id: i64,
+}
+
+impl<'a> RunHandle<'a> {
+    /// Creates a handle to an existing run, e.g. one started elsewhere.
+    pub fn new(session: &'a DatabricksSession, run_id: i64) -> Self {
+        RunHandle { session, run_id }
+    }
+
+    /// The ID of the run.
+    pub fn run_id(&self) -> i64 {
+        self.run_id
+    }
+
+    /// Retrieves the current state of the run and its tasks.
+    ///
+    /// Returns:
+    /// - Same as `DatabricksSession::get_run`.
+    pub async fn status(&self) -> Result<Run, HttpError> {
+        self.session.get_run(self.run_id).await
+    }
+
+    /// Requests cancellation of the run.
+    ///
+    /// Returns:
+    /// - Same as `DatabricksSession::cancel_run`.
+    pub async fn cancel(&self) -> Result<(), HttpError> {
+        self.session.cancel_run(self.run_id).await
+    }
+
+    /// Polls the run every `poll_interval` until it reaches a terminal state.
+    ///
+    /// A run that fails is not an error; check `Run::result_state` of the returned run.
+    ///
+    /// Parameters:
+    /// - `poll_interval`: The time between polls.
+    ///
+    /// Returns:
+    /// - A `Result` containing the terminated `Run`, or an `HttpError` if a poll fails.
+    pub async fn wait(&self, poll_interval: Duration) -> Result<Run, HttpError> {
+        loop {
+            let run: Run = self.status().await?;
+            if run
+                .life_cycle_state()
+                .is_some_and(|state| state.is_terminal())
+            {
+                return Ok(run);
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Retrieves the output of each task of the run that has started.
+    ///
+    /// Returns:
+    /// - A `Result` containing the task keys and their `RunOutput`, in task order, or an
+    ///   `HttpError` if a request fails.
+    pub async fn output(&self) -> Result<Vec<(String, RunOutput)>, HttpError> {
+        let run: Run = self.status().await?;
+        if run.tasks.is_empty() {
+            let output: RunOutput = self.session.get_run_output(self.run_id).await?;
+            return Ok(vec![(run.run_name.unwrap_or_default(), output)]);
+        }
+
+        let mut outputs: Vec<(String, RunOutput)> = Vec::new();
+        for task in run.tasks {
+            let started: bool = task
+                .state
+                .as_ref()
+                .and_then(|state| state.life_cycle_state)
+                .is_some_and(|state| state.has_started());
+            if let (Some(task_run_id), true) = (task.run_id, started) {
+                let output: RunOutput = self.session.get_run_output(task_run_id).await?;
+                outputs.push((task.task_key, output));
+            }
+        }
+        Ok(outputs)
+    }
+
+    /// Follows the run's output until it terminates.
+    ///
+    /// Returns:
+    /// - Same as `DatabricksSession::stream_run_output`.
+    pub fn stream_output(
+        &self,
+        poll_interval: Duration,
+    ) -> impl Stream<Item = Result<RunOutputEvent, HttpError>> + 'a {
+        self.session.stream_run_output(self.run_id, poll_interval)
+    }
+}
@@ -0,0 +1,237 @@
+use crate::{
+    config::ApiService,
+    errors::HttpError,
+    models::{
+        JobRunRequest, JobRunResponse, RepairRunRequest, RepairRunResponse, Run, RunAttempt,
+        RunRetryOutcome, RunRetryPolicy, RunRetryStrategy, SubmitRunRequest,
+    },
+    services::{DatabricksSession, RunHandle},
+};
+use reqwest::Method;
+use sha2::{Digest, Sha256};
+use std::{future::Future, time::Duration};
+
+impl DatabricksSession {
+    /// Repairs a finished job run by re-running its failed tasks and the tasks depending on them.
+    ///
+    /// Parameters:
+    /// - `run_id`: The ID of the job run.
+    /// - `latest_repair_id`: The ID of the run's most recent repair, required if it was repaired before.
+    ///
+    /// Returns:
+    /// - A `Result` containing the ID of the new repair, or an `HttpError` if the request fails.
+    pub async fn repair_run(
+        &self,
+        run_id: i64,
+        latest_repair_id: Option<i64>,
+    ) -> Result<i64, HttpError> {
+        let response: RepairRunResponse = self
+            .send_databricks_request(
+                Method::POST,
+                &self.api_endpoint(ApiService::Jobs, "jobs/runs/repair"),
+                Some(RepairRunRequest {
+                    run_id,
+                    rerun_all_failed_tasks: true,
+                    latest_repair_id,
+                }),
+            )
+            .await?;
+        Ok(response.repair_id)
+    }
+
+    /// Triggers a job run and retries it according to `policy` until it succeeds, fails in a way
+    /// the policy does not retry, or runs out of retries.
+    ///
+    /// When the policy resubmits and `request_body` carries an idempotency token, each retry uses
+    /// the token suffixed with `-retry-<n>`, so retrying the whole call stays idempotent. Tokens
+    /// too long for the suffix are replaced by a prefix of their hash first, so that the derived
+    /// token stays within the API's 64-character limit.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `JobRunRequest` as for `execute_job_run`.
+    /// - `policy`: The `RunRetryPolicy` deciding which failures are retried, how, and how often.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `RunRetryOutcome` with every attempt, whether or not the run
+    ///   finally succeeded, or an `HttpError` if a request fails or a repair does not finish within
+    ///   the session's `operation_timeout` (`HttpError::OperationTimedOut`).
+    pub async fn run_job_with_retries(
+        &self,
+        request_body: JobRunRequest,
+        policy: &RunRetryPolicy,
+    ) -> Result<RunRetryOutcome, HttpError> {
+        self.run_with_retries(policy, |attempt: u32| {
+            let mut request: JobRunRequest = request_body.clone();
+            request.idempotency_token = retry_token(request.idempotency_token, attempt);
+            async move {
+                let response: JobRunResponse = self.execute_job_run(request).await?;
+                Ok(response.run_id)
+            }
+        })
+        .await
+    }
+
+    /// Submits a one-time run and retries it according to `policy`, like `run_job_with_retries`.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `SubmitRunRequest` describing the tasks to run.
+    /// - `policy`: The `RunRetryPolicy` deciding which failures are retried, how, and how often.
+    ///
+    /// Returns:
+    /// - Same as `run_job_with_retries`.
+    pub async fn submit_run_with_retries(
+        &self,
+        request_body: SubmitRunRequest,
+        policy: &RunRetryPolicy,
+    ) -> Result<RunRetryOutcome, HttpError> {
+        self.run_with_retries(policy, |attempt: u32| {
+            let mut request: SubmitRunRequest = request_body.clone();
+            request.idempotency_token = retry_token(request.idempotency_token, attempt);
+            async move {
+                let handle: RunHandle<'_> = self.submit_run(request).await?;
+                Ok(handle.run_id())
+            }
+        })
+        .await
+    }
+
+    /// Starts a run with `start`, then waits for it and retries it while `policy` allows.
+    async fn run_with_retries<F, Fut>(
+        &self,
+        policy: &RunRetryPolicy,
+        start: F,
+    ) -> Result<RunRetryOutcome, HttpError>
+    where
+        F: Fn(u32) -> Fut,
+        Fut: Future<Output = Result<i64, HttpError>>,
+    {
+        let mut run_id: i64 = start(0).await?;
+        let mut repair_id: Option<i64> = None;
+        let mut run: Run = RunHandle::new(self, run_id)
+            .wait(policy.poll_interval)
+            .await?;
+        let mut attempts: Vec<RunAttempt> = vec![run_attempt(0, &run, None)];
+
+        for retry in 1..=policy.max_retries {
+            if !policy.should_retry(&run) {
+                break;
+            }
+            let backoff: Duration = policy.backoff(retry);
+            log::warn!(
+                "Run {} ended in {:?}; retrying in {:?} ({} of {})",
+                run_id,
+                run.result_state()
+                    .map(|state| format!("{:?}", state))
+                    .or_else(|| run.life_cycle_state().map(|state| format!("{:?}", state)))
+                    .unwrap_or_default(),
+                backoff,
+                retry,
+                policy.max_retries
+            );
+            tokio::time::sleep(backoff).await;
+
+            match policy.strategy {
+                RunRetryStrategy::Repair => {
+                    let id: i64 = self.repair_run(run_id, repair_id).await?;
+                    repair_id = Some(id);
+                    run = self
+                        .wait_for_repair(run_id, id, policy.poll_interval)
+                        .await?;
+                }
+                RunRetryStrategy::Resubmit => {
+                    run_id = start(retry).await?;
+                    repair_id = None;
+                    run = RunHandle::new(self, run_id)
+                        .wait(policy.poll_interval)
+                        .await?;
+                }
+            }
+            attempts.push(run_attempt(retry, &run, repair_id));
+        }
+
+        Ok(RunRetryOutcome { attempts, run })
+    }
+
+    /// Polls a run's repair history until the given repair has finished, bounded by the
+    /// session's `operation_timeout`, if set.
+    async fn wait_for_repair(
+        &self,
+        run_id: i64,
+        repair_id: i64,
+        poll_interval: Duration,
+    ) -> Result<Run, HttpError> {
+        self.before_deadline(
+            self.operation_deadline(),
+            "wait_for_repair",
+            self.poll_repair(run_id, repair_id, poll_interval),
+        )
+        .await
+    }
+
+    async fn poll_repair(
+        &self,
+        run_id: i64,
+        repair_id: i64,
+        poll_interval: Duration,
+    ) -> Result<Run, HttpError> {
+        loop {
+            let run: Run = self
+                .send_databricks_request(
+                    Method::GET,
+                    &self.api_endpoint(
+                        ApiService::Jobs,
+                        &format!("jobs/runs/get?run_id={}&include_history=true", run_id),
+                    ),
+                    None::<()>,
+                )
+                .await?;
+            let finished: bool = run
+                .repair_history
+                .iter()
+                .find(|item| item.id == Some(repair_id))
+                .and_then(|item| item.state.as_ref())
+                .and_then(|state| state.life_cycle_state)
+                .is_some_and(|state| state.is_terminal());
+            if finished {
+                return Ok(run);
+            }
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+}
+
+/// The longest idempotency token the Jobs API accepts.
+const MAX_IDEMPOTENCY_TOKEN_LEN: usize = 64;
+
+/// The idempotency token for attempt `attempt`: the original token for the first attempt, and a
+/// derived one for each retry so it starts a new run. A derived token that would be too long
+/// replaces the original token with a prefix of its SHA-256 hash.
+fn retry_token(token: Option<String>, attempt: u32) -> Option<String> {
+    let token: String = token?;
+    if attempt == 0 {
+        return Some(token);
+    }
+    let derived: String = format!("{}-retry-{}", token, attempt);
+    if derived.len() <= MAX_IDEMPOTENCY_TOKEN_LEN {
+        return Some(derived);
+    }
+    let hash: String = Sha256::digest(token.as_bytes())
+        .iter()
+        .take(20)
+        .map(|byte| format!("{:02x}", byte))
+        .collect();
+    Some(format!("{}-retry-{}", hash, attempt))
+}
+
+fn run_attempt(attempt: u32, run: &Run, repair_id: Option<i64>) -> RunAttempt {
+    RunAttempt {
+        attempt,
+        run_id: run.run_id,
+        repair_id,
+        result_state: run.result_state(),
+        state_message: run
+            .state
+            .as_ref()
+            .and_then(|state| state.state_message.clone()),
+    }
+}
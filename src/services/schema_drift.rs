@@ -0,0 +1,40 @@
+use crate::{
+    errors::HttpError,
+    models::{ExpectedSchema, ExpectedTableSchema, SchemaDrift, TableInfo},
+    services::DatabricksSession,
+};
+
+impl DatabricksSession {
+    /// Compares a table's Unity Catalog column metadata against an expected schema.
+    ///
+    /// Parameters:
+    /// - `table_full_name`: The full name of the table, e.g. `main.sales.orders`.
+    /// - `expected`: The `ExpectedSchema` the table should match.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `SchemaDrift` (empty if the table matches), or an `HttpError`
+    ///   if the table cannot be retrieved.
+    pub async fn check_schema_drift(
+        &self,
+        table_full_name: &str,
+        expected: &ExpectedSchema,
+    ) -> Result<SchemaDrift, HttpError> {
+        let table: TableInfo = self.get_table(table_full_name).await?;
+        Ok(expected.drift(table_full_name, &table.columns))
+    }
+
+    /// Compares a table against the schema declared by the row type `T`.
+    ///
+    /// Parameters:
+    /// - `table_full_name`: The full name of the table.
+    ///
+    /// Returns:
+    /// - Same as `check_schema_drift`.
+    pub async fn check_schema_drift_for<T: ExpectedTableSchema>(
+        &self,
+        table_full_name: &str,
+    ) -> Result<SchemaDrift, HttpError> {
+        self.check_schema_drift(table_full_name, &T::expected_schema())
+            .await
+    }
+}
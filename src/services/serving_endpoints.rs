@@ -0,0 +1,174 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        AiGatewayConfig, CreateServingEndpointRequest, EndpointCoreConfigInput,
+        ListServingEndpointsResponse, ServingEndpointDetailed,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+};
+use reqwest::Method;
+use std::time::{Duration, Instant};
+
+impl DatabricksSession {
+    /// Creates a model serving endpoint.
+    ///
+    /// The endpoint is provisioned asynchronously; use `wait_until_serving_endpoint_ready` to block
+    /// until it can take traffic.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateServingEndpointRequest` with the served entities and traffic split.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `ServingEndpointDetailed` if successful, or an `HttpError` if the request fails.
+    pub async fn create_serving_endpoint(
+        &self,
+        request_body: CreateServingEndpointRequest,
+    ) -> Result<ServingEndpointDetailed, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            "api/2.0/serving-endpoints",
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Retrieves a serving endpoint by name.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the serving endpoint.
+    ///
+    /// Returns:
+    /// - Same as `create_serving_endpoint`.
+    pub async fn get_serving_endpoint(
+        &self,
+        name: &str,
+    ) -> Result<ServingEndpointDetailed, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &format!("api/2.0/serving-endpoints/{}", name),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Replaces the served entities, traffic split and capture settings of an endpoint.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the serving endpoint.
+    /// - `config`: The new `EndpointCoreConfigInput`.
+    ///
+    /// Returns:
+    /// - Same as `create_serving_endpoint`.
+    pub async fn update_serving_endpoint_config(
+        &self,
+        name: &str,
+        config: EndpointCoreConfigInput,
+    ) -> Result<ServingEndpointDetailed, HttpError> {
+        self.send_databricks_request(
+            Method::PUT,
+            &format!("api/2.0/serving-endpoints/{}/config", name),
+            Some(config),
+        )
+        .await
+    }
+
+    /// Replaces the AI Gateway settings of an endpoint: rate limits, guardrails, usage tracking,
+    /// inference tables and fallbacks. Settings not included in `config` are turned off.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the serving endpoint.
+    /// - `config`: The new `AiGatewayConfig`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `AiGatewayConfig` now in effect, or an `HttpError` if the request fails.
+    pub async fn put_ai_gateway(
+        &self,
+        name: &str,
+        config: &AiGatewayConfig,
+    ) -> Result<AiGatewayConfig, HttpError> {
+        self.send_databricks_request(
+            Method::PUT,
+            &format!("api/2.0/serving-endpoints/{}/ai-gateway", name),
+            Some(config),
+        )
+        .await
+    }
+
+    /// Retrieves the AI Gateway settings of an endpoint.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the serving endpoint.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `AiGatewayConfig`, empty if the gateway was never configured,
+    ///   or an `HttpError` if the request fails.
+    pub async fn get_ai_gateway(&self, name: &str) -> Result<AiGatewayConfig, HttpError> {
+        let endpoint: ServingEndpointDetailed = self.get_serving_endpoint(name).await?;
+        Ok(endpoint.ai_gateway.unwrap_or_default())
+    }
+
+    /// Deletes a serving endpoint.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the serving endpoint.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_serving_endpoint(&self, name: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!("api/2.0/serving-endpoints/{}", name),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Lists all serving endpoints in the workspace.
+    ///
+    /// Returns:
+    /// - A `Result` containing the endpoints, or an `HttpError` if the request fails.
+    pub async fn list_serving_endpoints(&self) -> Result<Vec<ServingEndpointDetailed>, HttpError> {
+        let response: ListServingEndpointsResponse = self
+            .send_databricks_request(Method::GET, "api/2.0/serving-endpoints", None::<()>)
+            .await?;
+        Ok(response.endpoints)
+    }
+
+    /// Polls a serving endpoint until it is ready and no configuration update is pending.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the serving endpoint.
+    /// - `timeout`: The maximum time to wait.
+    ///
+    /// Returns:
+    /// - A `Result` containing the ready `ServingEndpointDetailed`, or an `HttpError` if polling fails,
+    ///   the configuration update fails, or the timeout elapses.
+    pub async fn wait_until_serving_endpoint_ready(
+        &self,
+        name: &str,
+        timeout: Duration,
+    ) -> Result<ServingEndpointDetailed, HttpError> {
+        let started: Instant = Instant::now();
+
+        loop {
+            let endpoint: ServingEndpointDetailed = self.get_serving_endpoint(name).await?;
+            if endpoint.is_ready() {
+                return Ok(endpoint);
+            }
+            if endpoint.update_failed() {
+                return Err(HttpError::BadRequest(format!(
+                    "Configuration update of serving endpoint {} failed",
+                    name
+                )));
+            }
+            if started.elapsed() >= timeout {
+                return Err(HttpError::TemporarilyUnavailable(format!(
+                    "Serving endpoint {} was not ready after {:?}",
+                    name, timeout
+                )));
+            }
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use crate::{
+    errors::HttpError,
+    models::{QueryServingEndpointRequest, QueryServingEndpointResponse},
+    services::{databricks_session::decode_json, sse::llm_sse_events, DatabricksSession},
+};
+use futures::{Stream, StreamExt};
+use reqwest::Method;
+use serde_json::Value;
+
+impl DatabricksSession {
+    /// Queries a serving endpoint with a tabular or tensor payload.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the serving endpoint.
+    /// - `request_body`: A `QueryServingEndpointRequest` in one of the supported input formats
+    ///   (`dataframe_split`, `dataframe_records`, `instances` or `inputs`).
+    ///
+    /// Returns:
+    /// - A `Result` containing the `QueryServingEndpointResponse`, or an `HttpError` if the request fails.
+    pub async fn query_serving_endpoint(
+        &self,
+        name: &str,
+        request_body: QueryServingEndpointRequest,
+    ) -> Result<QueryServingEndpointResponse, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &format!("serving-endpoints/{}/invocations", name),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Queries a serving endpoint with an arbitrary JSON payload (e.g. an LLM chat request).
+    ///
+    /// Parameters:
+    /// - `name`: The name of the serving endpoint.
+    /// - `payload`: The raw request body.
+    ///
+    /// Returns:
+    /// - A `Result` containing the raw JSON response, or an `HttpError` if the request fails.
+    pub async fn query_serving_endpoint_raw(
+        &self,
+        name: &str,
+        payload: Value,
+    ) -> Result<Value, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &format!("serving-endpoints/{}/invocations", name),
+            Some(payload),
+        )
+        .await
+    }
+
+    /// Queries an LLM serving endpoint with `"stream": true` and yields each streamed chunk.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the serving endpoint.
+    /// - `payload`: The request body (e.g. `{"messages": [...]}`); `stream` is set automatically.
+    ///
+    /// Returns:
+    /// - A `Result` containing a stream of JSON chunks, or an `HttpError` if the request is rejected.
+    pub async fn query_serving_endpoint_stream(
+        &self,
+        name: &str,
+        mut payload: Value,
+    ) -> Result<impl Stream<Item = Result<Value, HttpError>>, HttpError> {
+        if let Value::Object(map) = &mut payload {
+            map.insert("stream".to_string(), Value::Bool(true));
+        }
+        let invocations: String = format!("serving-endpoints/{}/invocations", name);
+        if self.plan_unread_request(&Method::POST, &invocations, Some(&payload))? {
+            return Ok(futures::stream::empty().left_stream());
+        }
+        let response: reqwest::Response = self
+            .send_databricks_request_streaming(Method::POST, &invocations, Some(payload))
+            .await?;
+
+        let path: String = response.url().path().to_string();
+        Ok(llm_sse_events(response)
+            .map(move |event| {
+                let event = event?;
+                decode_json::<Value>(&path, event.data.as_bytes())
+            })
+            .right_stream())
+    }
+}
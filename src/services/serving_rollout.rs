@@ -0,0 +1,231 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        EndpointCoreConfigInput, ServedEntityInput, ServingEndpointDetailed, TrafficConfig,
+        TrafficRollout, TrafficRolloutOutcome, TrafficRoute,
+    },
+    services::DatabricksSession,
+};
+use std::time::{Duration, Instant};
+
+impl DatabricksSession {
+    /// Shifts a serving endpoint's traffic to a new version of its served model step by step.
+    ///
+    /// The new version is served next to the model currently taking the most traffic, with the
+    /// same workload settings. After each step the endpoint is polled until it is ready and checked
+    /// again after the rollout's soak time. If a step fails, the endpoint's original configuration
+    /// is restored before the error is returned; if that also fails, both errors are reported.
+    /// When the last step reaches 100%, the previous version is no longer served.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the serving endpoint.
+    /// - `rollout`: The `TrafficRollout` with the new version and the traffic steps.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `TrafficRolloutOutcome`, or an `HttpError` if the rollout is
+    ///   invalid for the endpoint or a step fails (after rolling back, or failing to).
+    pub async fn rollout_serving_endpoint(
+        &self,
+        name: &str,
+        rollout: &TrafficRollout,
+    ) -> Result<TrafficRolloutOutcome, HttpError> {
+        rollout.validate().map_err(HttpError::BadRequest)?;
+
+        let endpoint: ServingEndpointDetailed = self.get_serving_endpoint(name).await?;
+        let config = endpoint.config.as_ref().ok_or_else(|| {
+            HttpError::BadRequest(format!(
+                "Serving endpoint {} has no active configuration",
+                name
+            ))
+        })?;
+        let original: EndpointCoreConfigInput = EndpointCoreConfigInput::from(config);
+
+        let routes: Vec<TrafficRoute> = match &config.traffic_config {
+            Some(traffic) if !traffic.routes.is_empty() => traffic.routes.clone(),
+            _ => match config.served_entities.as_slice() {
+                [entity] => vec![TrafficRoute {
+                    served_model_name: entity.name.clone(),
+                    traffic_percentage: 100,
+                }],
+                _ => Vec::new(),
+            },
+        };
+        let previous_route: TrafficRoute = routes
+            .iter()
+            .max_by_key(|route| route.traffic_percentage)
+            .cloned()
+            .ok_or_else(|| {
+                HttpError::BadRequest(format!(
+                    "Serving endpoint {} has no traffic routes to shift",
+                    name
+                ))
+            })?;
+        let previous_entity = config
+            .served_entities
+            .iter()
+            .find(|entity| entity.name == previous_route.served_model_name)
+            .ok_or_else(|| {
+                HttpError::BadRequest(format!(
+                    "Serving endpoint {} routes traffic to unknown entity {}",
+                    name, previous_route.served_model_name
+                ))
+            })?;
+        if previous_entity.entity_version.as_deref() == Some(rollout.new_version.as_str()) {
+            return Err(HttpError::BadRequest(format!(
+                "Serving endpoint {} already serves version {}",
+                name, rollout.new_version
+            )));
+        }
+        if let Some(&last) = rollout.steps.last() {
+            if last > previous_route.traffic_percentage {
+                return Err(HttpError::BadRequest(format!(
+                    "Cannot shift {}% of traffic: {} only receives {}%",
+                    last, previous_route.served_model_name, previous_route.traffic_percentage
+                )));
+            }
+        }
+
+        let mut new_entity: ServedEntityInput = ServedEntityInput::from(previous_entity);
+        let model_name: &str = new_entity
+            .entity_name
+            .rsplit('.')
+            .next()
+            .unwrap_or_default();
+        let served_entity_name: String = format!("{}-{}", model_name, rollout.new_version);
+        new_entity.name = Some(served_entity_name.clone());
+        new_entity.entity_version = Some(rollout.new_version.clone());
+
+        let mut completed_steps: Vec<i32> = Vec::new();
+        let mut latest: ServingEndpointDetailed = endpoint.clone();
+        for &step in &rollout.steps {
+            let previous_share: i32 = previous_route.traffic_percentage - step;
+            let mut served_entities: Vec<ServedEntityInput> = original
+                .served_entities
+                .iter()
+                .filter(|entity| {
+                    previous_share > 0
+                        || entity.name.as_deref() != Some(previous_route.served_model_name.as_str())
+                })
+                .cloned()
+                .collect();
+            served_entities.push(new_entity.clone());
+
+            let mut step_routes: Vec<TrafficRoute> = routes
+                .iter()
+                .filter(|route| route.served_model_name != previous_route.served_model_name)
+                .cloned()
+                .collect();
+            if previous_share > 0 {
+                step_routes.push(TrafficRoute {
+                    served_model_name: previous_route.served_model_name.clone(),
+                    traffic_percentage: previous_share,
+                });
+            }
+            step_routes.push(TrafficRoute {
+                served_model_name: served_entity_name.clone(),
+                traffic_percentage: step,
+            });
+
+            let step_config: EndpointCoreConfigInput = EndpointCoreConfigInput {
+                served_entities,
+                traffic_config: Some(TrafficConfig {
+                    routes: step_routes,
+                }),
+                auto_capture_config: original.auto_capture_config.clone(),
+            };
+            log::info!(
+                "Shifting {}% of traffic on serving endpoint {} to {}",
+                step,
+                name,
+                served_entity_name
+            );
+
+            match self.apply_rollout_step(name, step_config, rollout).await {
+                Ok(endpoint) => {
+                    completed_steps.push(step);
+                    latest = endpoint;
+                }
+                Err(err) => {
+                    log::warn!(
+                        "Rollout step to {}% on serving endpoint {} failed, rolling back: {}",
+                        step,
+                        name,
+                        err
+                    );
+                    if let Err(rollback_err) = self
+                        .roll_back_serving_endpoint(name, original, rollout)
+                        .await
+                    {
+                        log::error!(
+                            "Could not restore the configuration of serving endpoint {}: {}",
+                            name,
+                            rollback_err
+                        );
+                        return Err(HttpError::InternalServerError(format!(
+                            "Rollout step to {}% on serving endpoint {} failed ({}) and restoring \
+                             its original configuration also failed: {}",
+                            step, name, err, rollback_err
+                        )));
+                    }
+                    return Err(err);
+                }
+            }
+        }
+
+        Ok(TrafficRolloutOutcome {
+            served_entity_name,
+            previous_served_entity_name: previous_route.served_model_name,
+            completed_steps,
+            endpoint: latest,
+        })
+    }
+
+    /// Applies one rollout step and checks the endpoint's health after it.
+    async fn apply_rollout_step(
+        &self,
+        name: &str,
+        config: EndpointCoreConfigInput,
+        rollout: &TrafficRollout,
+    ) -> Result<ServingEndpointDetailed, HttpError> {
+        self.update_serving_endpoint_config(name, config).await?;
+        self.wait_until_serving_endpoint_ready(name, rollout.step_timeout)
+            .await?;
+        tokio::time::sleep(rollout.soak_time).await;
+
+        let endpoint: ServingEndpointDetailed = self.get_serving_endpoint(name).await?;
+        if !endpoint.is_ready() {
+            return Err(HttpError::TemporarilyUnavailable(format!(
+                "Serving endpoint {} became unhealthy during the soak time",
+                name
+            )));
+        }
+        Ok(endpoint)
+    }
+
+    /// Restores the configuration an endpoint had before a rollout.
+    ///
+    /// A step that timed out may still have its update in progress, and the endpoint rejects a new
+    /// configuration until that update settles, so this first waits for it to finish.
+    async fn roll_back_serving_endpoint(
+        &self,
+        name: &str,
+        original: EndpointCoreConfigInput,
+        rollout: &TrafficRollout,
+    ) -> Result<(), HttpError> {
+        let started: Instant = Instant::now();
+        while self.get_serving_endpoint(name).await?.is_updating() {
+            if started.elapsed() >= rollout.step_timeout {
+                return Err(HttpError::TemporarilyUnavailable(format!(
+                    "The pending configuration update of serving endpoint {} did not settle \
+                     after {:?}",
+                    name, rollout.step_timeout
+                )));
+            }
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        }
+        self.update_serving_endpoint_config(name, original).await?;
+        self.wait_until_serving_endpoint_ready(name, rollout.step_timeout)
+            .await
+            .map(|_| ())
+    }
+}
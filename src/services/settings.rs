@@ -0,0 +1,216 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        AutomaticClusterUpdateSetting, ComplianceSecurityProfileSetting, DefaultNamespaceSetting,
+        RestrictWorkspaceAdminsSetting, RestrictWorkspaceAdminsStatus, UpdateSettingRequest,
+        WorkspaceSetting,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+    utils::encode_query_value,
+};
+use reqwest::Method;
+
+/// How often an update is retried when another writer changed the setting in between.
+const MAX_ETAG_ATTEMPTS: usize = 3;
+
+impl DatabricksSession {
+    /// Retrieves the default namespace (catalog) of the workspace.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `DefaultNamespaceSetting`, or an `HttpError` if the request fails.
+    ///   A workspace that never set a default namespace yields `HttpError::NotFound`.
+    pub async fn get_default_namespace_setting(
+        &self,
+    ) -> Result<DefaultNamespaceSetting, HttpError> {
+        self.get_setting().await
+    }
+
+    /// Sets the default namespace (catalog) of the workspace.
+    ///
+    /// Parameters:
+    /// - `catalog_name`: The catalog to use for unqualified names.
+    ///
+    /// Returns:
+    /// - A `Result` containing the updated `DefaultNamespaceSetting`, or an `HttpError` if the request fails.
+    pub async fn update_default_namespace_setting(
+        &self,
+        catalog_name: &str,
+    ) -> Result<DefaultNamespaceSetting, HttpError> {
+        self.update_setting(|setting: &mut DefaultNamespaceSetting| {
+            setting.namespace.value = Some(catalog_name.to_string());
+        })
+        .await
+    }
+
+    /// Removes the default namespace, reverting to `hive_metastore`.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_default_namespace_setting(&self) -> Result<(), HttpError> {
+        self.delete_setting::<DefaultNamespaceSetting>().await
+    }
+
+    /// Retrieves the restrict-workspace-admins setting.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `RestrictWorkspaceAdminsSetting`, or an `HttpError` if the request fails.
+    pub async fn get_restrict_workspace_admins_setting(
+        &self,
+    ) -> Result<RestrictWorkspaceAdminsSetting, HttpError> {
+        self.get_setting().await
+    }
+
+    /// Updates the restrict-workspace-admins setting.
+    ///
+    /// Parameters:
+    /// - `status`: The new `RestrictWorkspaceAdminsStatus`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the updated `RestrictWorkspaceAdminsSetting`, or an `HttpError` if the request fails.
+    pub async fn update_restrict_workspace_admins_setting(
+        &self,
+        status: RestrictWorkspaceAdminsStatus,
+    ) -> Result<RestrictWorkspaceAdminsSetting, HttpError> {
+        self.update_setting(|setting: &mut RestrictWorkspaceAdminsSetting| {
+            setting.restrict_workspace_admins.status = status;
+        })
+        .await
+    }
+
+    /// Resets the restrict-workspace-admins setting to its default (`ALLOW_ALL`).
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_restrict_workspace_admins_setting(&self) -> Result<(), HttpError> {
+        self.delete_setting::<RestrictWorkspaceAdminsSetting>()
+            .await
+    }
+
+    /// Retrieves the automatic cluster update setting.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `AutomaticClusterUpdateSetting`, or an `HttpError` if the request fails.
+    pub async fn get_automatic_cluster_update_setting(
+        &self,
+    ) -> Result<AutomaticClusterUpdateSetting, HttpError> {
+        self.get_setting().await
+    }
+
+    /// Updates the automatic cluster update setting.
+    ///
+    /// Parameters:
+    /// - `modify`: A closure applying the desired changes to the current setting, e.g. enabling it
+    ///   or changing the maintenance window.
+    ///
+    /// Returns:
+    /// - A `Result` containing the updated `AutomaticClusterUpdateSetting`, or an `HttpError` if the request fails.
+    pub async fn update_automatic_cluster_update_setting<F>(
+        &self,
+        modify: F,
+    ) -> Result<AutomaticClusterUpdateSetting, HttpError>
+    where
+        F: Fn(&mut AutomaticClusterUpdateSetting),
+    {
+        self.update_setting(modify).await
+    }
+
+    /// Retrieves the compliance security profile setting.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `ComplianceSecurityProfileSetting`, or an `HttpError` if the request fails.
+    pub async fn get_compliance_security_profile_setting(
+        &self,
+    ) -> Result<ComplianceSecurityProfileSetting, HttpError> {
+        self.get_setting().await
+    }
+
+    /// Updates the compliance security profile setting. Once enabled, the profile cannot be disabled.
+    ///
+    /// Parameters:
+    /// - `is_enabled`: Whether the profile is enabled.
+    /// - `compliance_standards`: The compliance standards to enforce, e.g. `["HIPAA"]`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the updated `ComplianceSecurityProfileSetting`, or an `HttpError` if the request fails.
+    pub async fn update_compliance_security_profile_setting(
+        &self,
+        is_enabled: bool,
+        compliance_standards: Vec<String>,
+    ) -> Result<ComplianceSecurityProfileSetting, HttpError> {
+        self.update_setting(|setting: &mut ComplianceSecurityProfileSetting| {
+            setting.compliance_security_profile_workspace.is_enabled = is_enabled;
+            setting
+                .compliance_security_profile_workspace
+                .compliance_standards = compliance_standards.clone();
+        })
+        .await
+    }
+
+    async fn get_setting<T: WorkspaceSetting>(&self) -> Result<T, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &format!("api/2.0/settings/types/{}/names/default", T::SETTING_TYPE),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Performs the read-modify-write cycle: the current setting (and its etag) is read, `modify`
+    /// is applied and the result is written back. On an etag conflict the cycle is repeated.
+    async fn update_setting<T, F>(&self, modify: F) -> Result<T, HttpError>
+    where
+        T: WorkspaceSetting,
+        F: Fn(&mut T),
+    {
+        let mut attempt: usize = 1;
+
+        loop {
+            let mut setting: T = match self.get_setting::<T>().await {
+                Ok(setting) => setting,
+                Err(HttpError::NotFound(_)) => T::default(),
+                Err(err) => return Err(err),
+            };
+            modify(&mut setting);
+
+            let result: Result<T, HttpError> = self
+                .send_databricks_request(
+                    Method::PATCH,
+                    &format!("api/2.0/settings/types/{}/names/default", T::SETTING_TYPE),
+                    Some(UpdateSettingRequest {
+                        allow_missing: true,
+                        field_mask: T::FIELD_MASK,
+                        setting: &setting,
+                    }),
+                )
+                .await;
+
+            match result {
+                Err(HttpError::Conflict(_)) if attempt < MAX_ETAG_ATTEMPTS => attempt += 1,
+                result => return result,
+            }
+        }
+    }
+
+    /// Deletes (resets) a setting using its current etag, retrying on an etag conflict.
+    async fn delete_setting<T: WorkspaceSetting>(&self) -> Result<(), HttpError> {
+        let mut attempt: usize = 1;
+
+        loop {
+            let setting: T = self.get_setting::<T>().await?;
+            let mut endpoint: String =
+                format!("api/2.0/settings/types/{}/names/default", T::SETTING_TYPE);
+            if let Some(etag) = setting.etag() {
+                endpoint.push_str(&format!("?etag={}", encode_query_value(etag)));
+            }
+
+            let result: Result<EmptyResponse, HttpError> = self
+                .send_databricks_request(Method::DELETE, &endpoint, None::<()>)
+                .await;
+
+            match result {
+                Err(HttpError::Conflict(_)) if attempt < MAX_ETAG_ATTEMPTS => attempt += 1,
+                result => return result.map(|_| ()),
+            }
+        }
+    }
+}
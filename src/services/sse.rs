@@ -0,0 +1,114 @@
+use crate::errors::HttpError;
+use futures::{future, Stream, StreamExt};
+use serde::de::DeserializeOwned;
+
+/// A single server-sent event.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[non_exhaustive]
+pub struct SseEvent {
+    pub event: Option<String>, // The event type; `None` for the default "message" type
+    pub data: String,
+    pub id: Option<String>,
+    pub retry: Option<u64>, // Reconnection delay requested by the server, in milliseconds
+}
+
+impl SseEvent {
+    /// Deserializes the event's data as JSON into a typed value.
+    pub fn data_as<T: DeserializeOwned>(&self) -> Result<T, serde_json::Error> {
+        serde_json::from_str(&self.data)
+    }
+
+    /// Returns `true` for the OpenAI-style `data: [DONE]` sentinel that ends LLM streams.
+    pub fn is_done(&self) -> bool {
+        self.data == "[DONE]"
+    }
+}
+
+/// Parses a response body in `text/event-stream` format into a stream of events.
+///
+/// Comment lines are skipped, multiple `data:` lines are joined with newlines, and the stream ends
+/// when the body ends.
+pub(crate) fn sse_events(
+    response: reqwest::Response,
+) -> impl Stream<Item = Result<SseEvent, HttpError>> {
+    let bytes = Box::pin(response.bytes_stream());
+
+    futures::stream::unfold(
+        (bytes, Vec::<u8>::new(), false),
+        |(mut bytes, mut buffer, mut finished)| async move {
+            loop {
+                if let Some(end) = find_event_end(&buffer) {
+                    let raw: Vec<u8> = buffer.drain(..end).collect();
+                    match parse_event(&String::from_utf8_lossy(&raw)) {
+                        Some(event) => return Some((Ok(event), (bytes, buffer, finished))),
+                        None => continue,
+                    }
+                }
+                if finished {
+                    return None;
+                }
+
+                match bytes.next().await {
+                    Some(Ok(chunk)) => buffer.extend(chunk.iter().filter(|byte| **byte != b'\r')),
+                    Some(Err(err)) => {
+                        return Some((
+                            Err(HttpError::InternalServerError(err.to_string())),
+                            (bytes, buffer, true),
+                        ))
+                    }
+                    None => {
+                        // Treat a trailing event without a blank line as complete.
+                        finished = true;
+                        if !buffer.is_empty() {
+                            buffer.extend_from_slice(b"\n\n");
+                        }
+                    }
+                }
+            }
+        },
+    )
+}
+
+/// Like `sse_events`, but ends the stream at the `data: [DONE]` sentinel of LLM endpoints.
+pub(crate) fn llm_sse_events(
+    response: reqwest::Response,
+) -> impl Stream<Item = Result<SseEvent, HttpError>> {
+    sse_events(response)
+        .take_while(|event| future::ready(!matches!(event, Ok(event) if event.is_done())))
+}
+
+/// Returns the index just past the blank line terminating the first complete event.
+fn find_event_end(buffer: &[u8]) -> Option<usize> {
+    buffer
+        .windows(2)
+        .position(|window| window == b"\n\n")
+        .map(|position| position + 2)
+}
+
+fn parse_event(raw: &str) -> Option<SseEvent> {
+    let mut event: SseEvent = SseEvent::default();
+    let mut data_lines: Vec<&str> = Vec::new();
+
+    for line in raw.lines() {
+        if line.is_empty() || line.starts_with(':') {
+            continue;
+        }
+        let (field, value) = match line.split_once(':') {
+            Some((field, value)) => (field, value.strip_prefix(' ').unwrap_or(value)),
+            None => (line, ""),
+        };
+        match field {
+            "data" => data_lines.push(value),
+            "event" => event.event = Some(value.to_string()),
+            "id" => event.id = Some(value.to_string()),
+            "retry" => event.retry = value.parse().ok(),
+            _ => {}
+        }
+    }
+
+    if data_lines.is_empty() && event.event.is_none() {
+        return None;
+    }
+    event.data = data_lines.join("\n");
+    Some(event)
+}
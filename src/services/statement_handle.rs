@@ -0,0 +1,213 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        ChunkValidator, ResultData, SqlStatementRequest, SqlStatementResponse, StatementState,
+    },
+    services::DatabricksSession,
+};
+use futures::{Stream, TryStreamExt};
+use reqwest::Method;
+use tokio::time::Instant;
+
+impl DatabricksSession {
+    /// Submits a SQL statement and returns a handle that owns it, instead of a bare statement ID.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `SqlStatementRequest` struct containing the SQL statement to be executed.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `StatementHandle`, or an `HttpError` under the same conditions
+    ///   as `execute_sql_statement`.
+    pub async fn submit_sql_statement(
+        &self,
+        request_body: SqlStatementRequest,
+    ) -> Result<StatementHandle<'_>, HttpError> {
+        let response: SqlStatementResponse = self.execute_sql_statement(request_body).await?;
+        let statement_id: String = response.statement_id.clone().ok_or_else(|| {
+            HttpError::InternalServerError("Statement response has no statement_id".to_string())
+        })?;
+
+        Ok(StatementHandle {
+            session: self,
+            statement_id,
+            response,
+            cancel_on_drop: false,
+            deadline: self.operation_deadline(),
+        })
+    }
+}
+
+/// A submitted SQL statement, returned by `DatabricksSession::submit_sql_statement`.
+///
+/// With `cancel_on_drop(true)`, dropping the handle before the statement has finished (e.g.
+/// because a surrounding `tokio::time::timeout` elapsed) cancels the statement in the background.
+pub struct StatementHandle<'a> {
+    session: &'a DatabricksSession,
+    statement_id: String,
+    response: SqlStatementResponse,
+    cancel_on_drop: bool,
+    deadline: Option<Instant>,
+}
+
+impl<'a> StatementHandle<'a> {
+    /// The ID of the statement.
+    pub fn statement_id(&self) -> &str {
+        &self.statement_id
+    }
+
+    /// The most recent response received for the statement.
+    pub fn last_response(&self) -> &SqlStatementResponse {
+        &self.response
+    }
+
+    /// Sets whether dropping the handle cancels the statement if it has not finished.
+    pub fn cancel_on_drop(mut self, cancel_on_drop: bool) -> Self {
+        self.cancel_on_drop = cancel_on_drop;
+        self
+    }
+
+    /// Refreshes the statement's status.
+    ///
+    /// Returns:
+    /// - A `Result` containing the current `StatementState`, or an `HttpError` if the request fails.
+    pub async fn status(&mut self) -> Result<StatementState, HttpError> {
+        self.response = self
+            .session
+            .get_sql_statement_status(&self.statement_id)
+            .await?;
+        Ok(self.response.state().unwrap_or_default())
+    }
+
+    /// Requests cancellation of the statement.
+    ///
+    /// Returns:
+    /// - Same as `DatabricksSession::cancel_sql_statement`.
+    pub async fn cancel(&self) -> Result<(), HttpError> {
+        self.session.cancel_sql_statement(&self.statement_id).await
+    }
+
+    /// Waits for the statement to reach a terminal state.
+    ///
+    /// Returns:
+    /// - Same as `DatabricksSession::execute_sql_statement_and_wait`.
+    pub async fn wait(mut self) -> Result<SqlStatementResponse, HttpError> {
+        let session: &DatabricksSession = self.session;
+        // The last polled response stays in the handle, so that on failure `Drop` only cancels
+        // a statement that has not finished.
+        let result: Result<(), HttpError> = session
+            .before_deadline(
+                self.deadline,
+                "StatementHandle::wait",
+                session.wait_for_statement(&mut self.response),
+            )
+            .await;
+        match result {
+            Ok(()) => {
+                self.cancel_on_drop = false;
+                Ok(std::mem::take(&mut self.response))
+            }
+            // A statement that outlived the operation timeout is canceled when the handle drops.
+            Err(err @ HttpError::OperationTimedOut { .. }) => {
+                self.cancel_on_drop = true;
+                Err(err)
+            }
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Waits for the statement to succeed and streams the rows of its inline result, fetching
+    /// further result chunks as the stream is consumed.
+    ///
+    /// Returns:
+    /// - A stream of rows, yielding an `HttpError` if the statement fails or a chunk request fails.
+    pub fn stream_rows(self) -> impl Stream<Item = Result<Vec<Option<String>>, HttpError>> + 'a {
+        let session: &'a DatabricksSession = self.session;
+        let statement_id: String = self.statement_id.clone();
+        let deadline: Option<Instant> = self.deadline;
+
+        futures::stream::try_unfold(
+            RowsState::Waiting(Box::new(self)),
+            move |state: RowsState<'a>| {
+                let statement_id: String = statement_id.clone();
+                async move {
+                    let (chunk, mut validator): (ResultData, Option<ChunkValidator>) = match state {
+                        RowsState::Waiting(handle) => {
+                            let response: SqlStatementResponse = handle.wait().await?;
+                            let validator: Option<ChunkValidator> =
+                                ChunkValidator::for_response(&response);
+                            (response.result.unwrap_or_default(), validator)
+                        }
+                        RowsState::Chunk(Some(chunk_index), validator) => (
+                            session
+                                .before_deadline(
+                                    deadline,
+                                    "StatementHandle::stream_rows",
+                                    session
+                                        .get_sql_statement_result_chunk(&statement_id, chunk_index),
+                                )
+                                .await?,
+                            validator,
+                        ),
+                        RowsState::Chunk(None, validator) => {
+                            if let Some(validator) = &validator {
+                                validator.finish().map_err(HttpError::InvalidChunk)?;
+                            }
+                            return Ok(None);
+                        }
+                    };
+                    if let Some(validator) = &mut validator {
+                        validator
+                            .record_data(&chunk)
+                            .map_err(HttpError::InvalidChunk)?;
+                    }
+                    Ok(Some((
+                        chunk.data_array.unwrap_or_default(),
+                        RowsState::Chunk(chunk.next_chunk_index, validator),
+                    )))
+                }
+            },
+        )
+        .map_ok(|rows: Vec<Vec<Option<String>>>| futures::stream::iter(rows.into_iter().map(Ok)))
+        .try_flatten()
+    }
+}
+
+impl Drop for StatementHandle<'_> {
+    fn drop(&mut self) {
+        let finished: bool = self
+            .response
+            .state()
+            .is_some_and(|state| state.is_terminal());
+        if !self.cancel_on_drop || finished {
+            return;
+        }
+        // Without a runtime there is nothing to run the request on; the statement then runs to
+        // completion (or to the warehouse's statement timeout).
+        let endpoint: String = format!("api/2.0/sql/statements/{}/cancel", self.statement_id);
+        if self.session.is_planned(&Method::POST) {
+            self.session.plan_request(&Method::POST, &endpoint, None);
+            return;
+        }
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let request: reqwest::RequestBuilder =
+                match self.session.authorized_request(Method::POST, &endpoint) {
+                    Ok(request) => request,
+                    Err(err) => {
+                        log::warn!("Could not cancel dropped statement: {}", err);
+                        return;
+                    }
+                };
+            runtime.spawn(async move {
+                if let Err(err) = request.send().await {
+                    log::warn!("Could not cancel dropped statement: {}", err);
+                }
+            });
+        }
+    }
+}
+
+/// Where `StatementHandle::stream_rows` is in the result.
+enum RowsState<'a> {
+    Waiting(Box<StatementHandle<'a>>),
+    Chunk(Option<i32>, Option<ChunkValidator>),
+}
@@ -0,0 +1,69 @@
+use crate::{
+    errors::HttpError,
+    models::{AuditLogRecord, BillingUsageRecord, QueryHistoryRecord, SystemTableFilter},
+    services::DatabricksSession,
+};
+
+impl DatabricksSession {
+    /// Queries `system.billing.usage` and returns typed usage records, newest first.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the query on.
+    /// - `filter`: A `SystemTableFilter`; time bounds apply to `usage_start_time`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `BillingUsageRecord`s, or an `HttpError` if the query fails.
+    pub async fn query_billing_usage(
+        &self,
+        warehouse_id: &str,
+        filter: &SystemTableFilter,
+    ) -> Result<Vec<BillingUsageRecord>, HttpError> {
+        let statement: String = filter.to_sql(
+            "system.billing.usage",
+            BillingUsageRecord::COLUMNS,
+            "usage_start_time",
+        );
+        self.query_rows(warehouse_id, &statement).await
+    }
+
+    /// Queries `system.access.audit` and returns typed audit events, newest first.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the query on.
+    /// - `filter`: A `SystemTableFilter`; time bounds apply to `event_time`. Use
+    ///   `equals("service_name", ...)` or `equals("action_name", ...)` to narrow the events.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `AuditLogRecord`s, or an `HttpError` if the query fails.
+    pub async fn query_audit_logs(
+        &self,
+        warehouse_id: &str,
+        filter: &SystemTableFilter,
+    ) -> Result<Vec<AuditLogRecord>, HttpError> {
+        let statement: String =
+            filter.to_sql("system.access.audit", AuditLogRecord::COLUMNS, "event_time");
+        self.query_rows(warehouse_id, &statement).await
+    }
+
+    /// Queries `system.query.history` and returns typed query records, newest first.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the query on.
+    /// - `filter`: A `SystemTableFilter`; time bounds apply to `start_time`. Use
+    ///   `equals("executed_by", ...)` or `equals("compute.warehouse_id", ...)` to narrow the queries.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `QueryHistoryRecord`s, or an `HttpError` if the query fails.
+    pub async fn query_query_history(
+        &self,
+        warehouse_id: &str,
+        filter: &SystemTableFilter,
+    ) -> Result<Vec<QueryHistoryRecord>, HttpError> {
+        let statement: String = filter.to_sql(
+            "system.query.history",
+            QueryHistoryRecord::COLUMNS,
+            "start_time",
+        );
+        self.query_rows(warehouse_id, &statement).await
+    }
+}
@@ -0,0 +1,71 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        AnalyzeCommand, OptimizeCommand, OptimizeMetrics, OptimizeResultRow, VacuumCommand,
+        VacuumOutcome, VacuumResultRow,
+    },
+    services::DatabricksSession,
+};
+
+impl DatabricksSession {
+    /// Runs `OPTIMIZE` on a Delta table and returns the compaction metrics.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the statement on.
+    /// - `command`: The `OptimizeCommand` describing the table, partitions and Z-order columns.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `OptimizeMetrics`, or an `HttpError` if the statement fails or
+    ///   its result cannot be parsed.
+    pub async fn optimize_table(
+        &self,
+        warehouse_id: &str,
+        command: &OptimizeCommand,
+    ) -> Result<OptimizeMetrics, HttpError> {
+        let rows: Vec<OptimizeResultRow> = self.query_rows(warehouse_id, &command.to_sql()).await?;
+        Ok(rows
+            .into_iter()
+            .next()
+            .map(|row| row.metrics)
+            .unwrap_or_default())
+    }
+
+    /// Runs `VACUUM` on a Delta table.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the statement on.
+    /// - `command`: The `VacuumCommand` describing the table, retention and dry-run mode.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `VacuumOutcome`, or an `HttpError` if the statement fails.
+    pub async fn vacuum_table(
+        &self,
+        warehouse_id: &str,
+        command: &VacuumCommand,
+    ) -> Result<VacuumOutcome, HttpError> {
+        let rows: Vec<VacuumResultRow> = self.query_rows(warehouse_id, &command.to_sql()).await?;
+        let paths: Vec<String> = rows.into_iter().filter_map(|row| row.path).collect();
+        Ok(VacuumOutcome {
+            dry_run: command.dry_run,
+            paths,
+        })
+    }
+
+    /// Runs `ANALYZE TABLE ... COMPUTE STATISTICS` on a table.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the statement on.
+    /// - `command`: The `AnalyzeCommand` describing the table and the statistics to compute.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the statement fails.
+    pub async fn analyze_table(
+        &self,
+        warehouse_id: &str,
+        command: &AnalyzeCommand,
+    ) -> Result<(), HttpError> {
+        self.execute_sql(warehouse_id, &command.to_sql())
+            .await
+            .map(|_| ())
+    }
+}
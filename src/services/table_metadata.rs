@@ -0,0 +1,179 @@
+use crate::{
+    config::ApiService,
+    errors::HttpError,
+    models::{CreateTableConstraintRequest, SqlStatementResponse, TableConstraint},
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+    utils::{quote_identifier, quote_literal, quote_qualified_name},
+};
+use reqwest::Method;
+use std::collections::HashMap;
+
+impl DatabricksSession {
+    /// Adds a primary key, foreign key or named constraint to a table.
+    ///
+    /// Parameters:
+    /// - `table_full_name`: The full name of the table, e.g. `main.sales.orders`.
+    /// - `constraint`: The `TableConstraint` to add.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `TableConstraint`, or an `HttpError` if the request fails.
+    pub async fn create_table_constraint(
+        &self,
+        table_full_name: &str,
+        constraint: TableConstraint,
+    ) -> Result<TableConstraint, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/constraints"),
+            Some(CreateTableConstraintRequest {
+                full_name_arg: table_full_name.to_string(),
+                constraint,
+            }),
+        )
+        .await
+    }
+
+    /// Removes a constraint from a table.
+    ///
+    /// Parameters:
+    /// - `table_full_name`: The full name of the table.
+    /// - `constraint_name`: The name of the constraint to remove.
+    /// - `cascade`: Whether to also drop foreign keys referencing a removed primary key.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_table_constraint(
+        &self,
+        table_full_name: &str,
+        constraint_name: &str,
+        cascade: bool,
+    ) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/constraints/{}?constraint_name={}&cascade={}",
+                    table_full_name, constraint_name, cascade
+                ),
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Sets (or clears, with `None`) the comment of a table by running `COMMENT ON TABLE`.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the statement on.
+    /// - `table_full_name`: The full name of the table.
+    /// - `comment`: The new comment, or `None` to remove it.
+    ///
+    /// Returns:
+    /// - The `SqlStatementResponse` of the executed statement, or an `HttpError` if it fails.
+    pub async fn set_table_comment(
+        &self,
+        warehouse_id: &str,
+        table_full_name: &str,
+        comment: Option<&str>,
+    ) -> Result<SqlStatementResponse, HttpError> {
+        let statement: String = format!(
+            "COMMENT ON TABLE {} IS {}",
+            quote_qualified_name(table_full_name),
+            comment.map(quote_literal).unwrap_or("NULL".to_string())
+        );
+        self.execute_sql(warehouse_id, &statement).await
+    }
+
+    /// Sets the comment of a column by running `ALTER TABLE ... ALTER COLUMN ... COMMENT`.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the statement on.
+    /// - `table_full_name`: The full name of the table.
+    /// - `column_name`: The column to comment.
+    /// - `comment`: The new comment.
+    ///
+    /// Returns:
+    /// - Same as `set_table_comment`.
+    pub async fn set_column_comment(
+        &self,
+        warehouse_id: &str,
+        table_full_name: &str,
+        column_name: &str,
+        comment: &str,
+    ) -> Result<SqlStatementResponse, HttpError> {
+        let statement: String = format!(
+            "ALTER TABLE {} ALTER COLUMN {} COMMENT {}",
+            quote_qualified_name(table_full_name),
+            quote_identifier(column_name),
+            quote_literal(comment)
+        );
+        self.execute_sql(warehouse_id, &statement).await
+    }
+
+    /// Sets tags on a table (or on one of its columns when `column_name` is given).
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the statement on.
+    /// - `table_full_name`: The full name of the table.
+    /// - `column_name`: The column to tag, or `None` to tag the table itself.
+    /// - `tags`: The tag keys and values to set.
+    ///
+    /// Returns:
+    /// - Same as `set_table_comment`.
+    pub async fn set_tags(
+        &self,
+        warehouse_id: &str,
+        table_full_name: &str,
+        column_name: Option<&str>,
+        tags: &HashMap<String, String>,
+    ) -> Result<SqlStatementResponse, HttpError> {
+        let mut pairs: Vec<String> = tags
+            .iter()
+            .map(|(key, value)| format!("{} = {}", quote_literal(key), quote_literal(value)))
+            .collect();
+        pairs.sort();
+        let statement: String = format!(
+            "ALTER TABLE {}{} SET TAGS ({})",
+            quote_qualified_name(table_full_name),
+            column_clause(column_name),
+            pairs.join(", ")
+        );
+        self.execute_sql(warehouse_id, &statement).await
+    }
+
+    /// Removes tags from a table (or from one of its columns when `column_name` is given).
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The SQL warehouse to run the statement on.
+    /// - `table_full_name`: The full name of the table.
+    /// - `column_name`: The column to untag, or `None` to untag the table itself.
+    /// - `tag_keys`: The tag keys to remove.
+    ///
+    /// Returns:
+    /// - Same as `set_table_comment`.
+    pub async fn unset_tags(
+        &self,
+        warehouse_id: &str,
+        table_full_name: &str,
+        column_name: Option<&str>,
+        tag_keys: &[&str],
+    ) -> Result<SqlStatementResponse, HttpError> {
+        let keys: Vec<String> = tag_keys.iter().map(|key| quote_literal(key)).collect();
+        let statement: String = format!(
+            "ALTER TABLE {}{} UNSET TAGS ({})",
+            quote_qualified_name(table_full_name),
+            column_clause(column_name),
+            keys.join(", ")
+        );
+        self.execute_sql(warehouse_id, &statement).await
+    }
+}
+
+fn column_clause(column_name: Option<&str>) -> String {
+    match column_name {
+        Some(column_name) => format!(" ALTER COLUMN {}", quote_identifier(column_name)),
+        None => String::new(),
+    }
+}
@@ -0,0 +1,73 @@
+use crate::{
+    errors::HttpError,
+    models::{TableInfo, TableOperationReport, TableProgress},
+    services::DatabricksSession,
+};
+use futures::StreamExt;
+use std::future::Future;
+
+impl DatabricksSession {
+    /// Lists the tables of a schema and runs an async operation on each, e.g. `OPTIMIZE` or
+    /// `ANALYZE` through the SQL client, with at most `max_concurrency` running at a time.
+    ///
+    /// A failing table does not stop the others; its error is collected in the report.
+    ///
+    /// Parameters:
+    /// - `catalog_name`: The catalog containing the schema.
+    /// - `schema_name`: The schema whose tables to process.
+    /// - `max_concurrency`: The maximum number of tables processed at the same time.
+    /// - `operation`: The operation to run on each table.
+    /// - `on_progress`: Called after each table completes.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `TableOperationReport`, or an `HttpError` if the tables cannot
+    ///   be listed.
+    pub async fn for_each_table<T, F, Fut>(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+        max_concurrency: usize,
+        operation: F,
+        mut on_progress: impl FnMut(&TableProgress),
+    ) -> Result<TableOperationReport<T>, HttpError>
+    where
+        F: Fn(TableInfo) -> Fut,
+        Fut: Future<Output = Result<T, HttpError>>,
+    {
+        let tables: Vec<TableInfo> = self.list_tables(catalog_name, schema_name).await?;
+        let total: usize = tables.len();
+
+        let mut results = futures::stream::iter(tables)
+            .map(|table: TableInfo| {
+                let name: String = table.full_name.clone().unwrap_or_else(|| {
+                    format!(
+                        "{}.{}.{}",
+                        table.catalog_name, table.schema_name, table.name
+                    )
+                });
+                let result: Fut = operation(table);
+                async move { (name, result.await) }
+            })
+            .buffer_unordered(max_concurrency.max(1));
+
+        let mut report: TableOperationReport<T> = TableOperationReport {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        };
+        while let Some((table, result)) = results.next().await {
+            let succeeded: bool = result.is_ok();
+            match result {
+                Ok(value) => report.succeeded.push((table.clone(), value)),
+                Err(err) => report.failed.push((table.clone(), err)),
+            }
+            on_progress(&TableProgress {
+                table,
+                succeeded,
+                completed: report.succeeded.len() + report.failed.len(),
+                failed: report.failed.len(),
+                total,
+            });
+        }
+        Ok(report)
+    }
+}
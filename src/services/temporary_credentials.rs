@@ -0,0 +1,59 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        GenerateTemporaryTableCredentialRequest, TableInfo, TableOperation,
+        TemporaryTableCredentials,
+    },
+    services::DatabricksSession,
+};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Generates short-lived cloud credentials for accessing a table's storage location directly.
+    ///
+    /// The metastore must have external data access enabled and the caller needs the
+    /// `EXTERNAL USE SCHEMA` privilege on the table's schema.
+    ///
+    /// Parameters:
+    /// - `table_id`: The UUID of the table (see `TableInfo::table_id`).
+    /// - `operation`: Whether read-only or read-write access is requested.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `TemporaryTableCredentials` if successful, or an `HttpError` if the request fails.
+    pub async fn generate_temporary_table_credentials(
+        &self,
+        table_id: &str,
+        operation: TableOperation,
+    ) -> Result<TemporaryTableCredentials, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            "api/2.0/unity-catalog/temporary-table-credentials",
+            Some(GenerateTemporaryTableCredentialRequest {
+                table_id: table_id.to_string(),
+                operation,
+            }),
+        )
+        .await
+    }
+
+    /// Looks up a table by full name and generates temporary credentials for it.
+    ///
+    /// Parameters:
+    /// - `full_name`: The full name of the table, e.g. `main.sales.orders`.
+    /// - `operation`: Whether read-only or read-write access is requested.
+    ///
+    /// Returns:
+    /// - Same as `generate_temporary_table_credentials`.
+    pub async fn generate_temporary_table_credentials_by_name(
+        &self,
+        full_name: &str,
+        operation: TableOperation,
+    ) -> Result<TemporaryTableCredentials, HttpError> {
+        let table: TableInfo = self.get_table(full_name).await?;
+        let table_id: String = table
+            .table_id
+            .ok_or_else(|| HttpError::NotFound(format!("Table {} has no table_id", full_name)))?;
+        self.generate_temporary_table_credentials(&table_id, operation)
+            .await
+    }
+}
@@ -0,0 +1,43 @@
+use crate::{config::read_token_file, services::CredentialsProvider};
+use std::{fs, path::PathBuf, sync::Mutex, time::SystemTime};
+
+/// A token read from a file, such as a Kubernetes secret mounted into the pod, that is read again
+/// whenever the file's modification time changes so rotated credentials are picked up.
+pub(crate) struct TokenFile {
+    path: PathBuf,
+    cached: Mutex<(Option<SystemTime>, String)>,
+}
+
+impl TokenFile {
+    /// Creates a `TokenFile` for `path`, whose current contents are `token`.
+    pub(crate) fn new(path: PathBuf, token: String) -> Self {
+        let modified: Option<SystemTime> = fs::metadata(&path)
+            .and_then(|metadata| metadata.modified())
+            .ok();
+        TokenFile {
+            path,
+            cached: Mutex::new((modified, token)),
+        }
+    }
+}
+
+impl CredentialsProvider for TokenFile {
+    /// The current token. If the file changed but cannot be read, the previous token is kept and
+    /// a warning is logged.
+    fn token(&self) -> String {
+        let mut cached = self.cached.lock().unwrap_or_else(|err| err.into_inner());
+        match fs::metadata(&self.path).and_then(|metadata| metadata.modified()) {
+            Ok(modified) if cached.0 != Some(modified) => match read_token_file(&self.path) {
+                Ok(token) => *cached = (Some(modified), token),
+                Err(err) => log::warn!("{}; using the previous token", err),
+            },
+            Ok(_) => {}
+            Err(err) => log::warn!(
+                "Could not check token file {}: {}; using the previous token",
+                self.path.display(),
+                err
+            ),
+        }
+        cached.1.clone()
+    }
+}
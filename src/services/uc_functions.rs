@@ -0,0 +1,140 @@
+use crate::{
+    config::ApiService,
+    errors::HttpError,
+    models::{
+        CreateFunction, CreateFunctionRequest, FunctionInfo, ListFunctionsResponse,
+        UpdateFunctionRequest,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+    utils::encode_query_value,
+};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Creates a Unity Catalog function (SQL or Python UDF).
+    ///
+    /// Parameters:
+    /// - `function_info`: A `CreateFunction` describing the routine, its parameters and return type.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `FunctionInfo` if successful, or an `HttpError` if the request fails.
+    pub async fn create_function(
+        &self,
+        function_info: CreateFunction,
+    ) -> Result<FunctionInfo, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/functions"),
+            Some(CreateFunctionRequest { function_info }),
+        )
+        .await
+    }
+
+    /// Retrieves a function by its full name (`catalog.schema.function`).
+    ///
+    /// Parameters:
+    /// - `full_name`: The full name of the function.
+    ///
+    /// Returns:
+    /// - Same as `create_function`.
+    pub async fn get_function(&self, full_name: &str) -> Result<FunctionInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/functions/{}", encode_query_value(full_name)),
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Updates a function. Only the owner can currently be changed.
+    ///
+    /// Parameters:
+    /// - `full_name`: The full name of the function.
+    /// - `request_body`: An `UpdateFunctionRequest` containing the fields to change.
+    ///
+    /// Returns:
+    /// - Same as `create_function`.
+    pub async fn update_function(
+        &self,
+        full_name: &str,
+        request_body: UpdateFunctionRequest,
+    ) -> Result<FunctionInfo, HttpError> {
+        self.send_databricks_request(
+            Method::PATCH,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/functions/{}", encode_query_value(full_name)),
+            ),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Deletes a function.
+    ///
+    /// Parameters:
+    /// - `full_name`: The full name of the function.
+    /// - `force`: Whether to delete the function regardless of its dependencies.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_function(&self, full_name: &str, force: bool) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/functions/{}?force={}",
+                    encode_query_value(full_name),
+                    force
+                ),
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Lists all functions in a schema, following pagination until exhausted.
+    ///
+    /// Parameters:
+    /// - `catalog_name`: The parent catalog.
+    /// - `schema_name`: The parent schema.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `FunctionInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_functions(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+    ) -> Result<Vec<FunctionInfo>, HttpError> {
+        let mut functions: Vec<FunctionInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut endpoint: String = self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/functions?catalog_name={}&schema_name={}",
+                    encode_query_value(catalog_name),
+                    encode_query_value(schema_name)
+                ),
+            );
+            if let Some(token) = &page_token {
+                endpoint.push_str(&format!("&page_token={}", encode_query_value(token)));
+            }
+            let page: ListFunctionsResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            functions.extend(page.functions);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(functions),
+            }
+        }
+    }
+}
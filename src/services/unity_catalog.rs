@@ -0,0 +1,307 @@
+use crate::{
+    config::ApiService,
+    errors::HttpError,
+    models::{
+        CatalogInfo, CreateCatalogRequest, CreateSchemaRequest, ListCatalogsResponse,
+        ListSchemasResponse, ListTablesResponse, SchemaInfo, TableInfo, UpdateCatalogRequest,
+        UpdateSchemaRequest,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+    utils::encode_query_value,
+};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Creates a new Unity Catalog catalog.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateCatalogRequest` describing the catalog to create.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `CatalogInfo` if successful, or an `HttpError` if the request fails.
+    pub async fn create_catalog(
+        &self,
+        request_body: CreateCatalogRequest,
+    ) -> Result<CatalogInfo, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/catalogs"),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Retrieves a catalog by name.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the catalog.
+    ///
+    /// Returns:
+    /// - Same as `create_catalog`.
+    pub async fn get_catalog(&self, name: &str) -> Result<CatalogInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/catalogs/{}", encode_query_value(name)),
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Updates the owner, comment, properties, isolation mode or name of a catalog.
+    ///
+    /// Parameters:
+    /// - `name`: The current name of the catalog.
+    /// - `request_body`: An `UpdateCatalogRequest` containing the fields to change.
+    ///
+    /// Returns:
+    /// - Same as `create_catalog`.
+    pub async fn update_catalog(
+        &self,
+        name: &str,
+        request_body: UpdateCatalogRequest,
+    ) -> Result<CatalogInfo, HttpError> {
+        self.send_databricks_request(
+            Method::PATCH,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/catalogs/{}", encode_query_value(name)),
+            ),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Deletes a catalog.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the catalog.
+    /// - `force`: Whether to delete the catalog even if it is not empty.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_catalog(&self, name: &str, force: bool) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/catalogs/{}?force={}",
+                    encode_query_value(name),
+                    force
+                ),
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Lists all catalogs visible to the caller, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `CatalogInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_catalogs(&self) -> Result<Vec<CatalogInfo>, HttpError> {
+        let mut catalogs: Vec<CatalogInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => self.api_endpoint(
+                    ApiService::UnityCatalog,
+                    &format!(
+                        "unity-catalog/catalogs?page_token={}",
+                        encode_query_value(token)
+                    ),
+                ),
+                None => self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/catalogs"),
+            };
+            let page: ListCatalogsResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            catalogs.extend(page.catalogs);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(catalogs),
+            }
+        }
+    }
+
+    /// Creates a new schema within a catalog.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateSchemaRequest` describing the schema to create.
+    ///
+    /// Returns:
+    /// - A `Result` containing the created `SchemaInfo` if successful, or an `HttpError` if the request fails.
+    pub async fn create_schema(
+        &self,
+        request_body: CreateSchemaRequest,
+    ) -> Result<SchemaInfo, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &self.api_endpoint(ApiService::UnityCatalog, "unity-catalog/schemas"),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Retrieves a schema by its full name (`catalog.schema`).
+    ///
+    /// Parameters:
+    /// - `full_name`: The full name of the schema.
+    ///
+    /// Returns:
+    /// - Same as `create_schema`.
+    pub async fn get_schema(&self, full_name: &str) -> Result<SchemaInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/schemas/{}", encode_query_value(full_name)),
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Updates the owner, comment, properties or name of a schema.
+    ///
+    /// Parameters:
+    /// - `full_name`: The full name of the schema.
+    /// - `request_body`: An `UpdateSchemaRequest` containing the fields to change.
+    ///
+    /// Returns:
+    /// - Same as `create_schema`.
+    pub async fn update_schema(
+        &self,
+        full_name: &str,
+        request_body: UpdateSchemaRequest,
+    ) -> Result<SchemaInfo, HttpError> {
+        self.send_databricks_request(
+            Method::PATCH,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/schemas/{}", encode_query_value(full_name)),
+            ),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Deletes a schema.
+    ///
+    /// Parameters:
+    /// - `full_name`: The full name of the schema.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_schema(&self, full_name: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/schemas/{}", encode_query_value(full_name)),
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Lists all schemas in a catalog, following pagination until exhausted.
+    ///
+    /// Parameters:
+    /// - `catalog_name`: The catalog whose schemas should be listed.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `SchemaInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_schemas(&self, catalog_name: &str) -> Result<Vec<SchemaInfo>, HttpError> {
+        let mut schemas: Vec<SchemaInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut endpoint: String = self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/schemas?catalog_name={}",
+                    encode_query_value(catalog_name)
+                ),
+            );
+            if let Some(token) = &page_token {
+                endpoint.push_str(&format!("&page_token={}", encode_query_value(token)));
+            }
+            let page: ListSchemasResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            schemas.extend(page.schemas);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(schemas),
+            }
+        }
+    }
+
+    /// Retrieves a table (or view) by its full name (`catalog.schema.table`), including its columns.
+    ///
+    /// Parameters:
+    /// - `full_name`: The full name of the table.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `TableInfo` if successful, or an `HttpError` if the request fails.
+    pub async fn get_table(&self, full_name: &str) -> Result<TableInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!("unity-catalog/tables/{}", encode_query_value(full_name)),
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Lists all tables and views in a schema, following pagination until exhausted.
+    ///
+    /// Parameters:
+    /// - `catalog_name`: The catalog containing the schema.
+    /// - `schema_name`: The schema whose tables should be listed.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `TableInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_tables(
+        &self,
+        catalog_name: &str,
+        schema_name: &str,
+    ) -> Result<Vec<TableInfo>, HttpError> {
+        let mut tables: Vec<TableInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut endpoint: String = self.api_endpoint(
+                ApiService::UnityCatalog,
+                &format!(
+                    "unity-catalog/tables?catalog_name={}&schema_name={}",
+                    encode_query_value(catalog_name),
+                    encode_query_value(schema_name)
+                ),
+            );
+            if let Some(token) = &page_token {
+                endpoint.push_str(&format!("&page_token={}", encode_query_value(token)));
+            }
+            let page: ListTablesResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            tables.extend(page.tables);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(tables),
+            }
+        }
+    }
+}
@@ -0,0 +1,296 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        CreateVectorIndexRequest, CreateVectorSearchEndpointRequest, DeleteVectorIndexDataRequest,
+        ListVectorIndexesResponse, ListVectorSearchEndpointsResponse, QueryVectorIndexRequest,
+        QueryVectorIndexResponse, UpsertVectorIndexDataRequest, VectorIndexInfo,
+        VectorIndexWriteResponse, VectorSearchEndpointInfo, VectorSearchEndpointType,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+    utils::encode_query_value,
+};
+use reqwest::Method;
+use serde::Serialize;
+
+impl DatabricksSession {
+    /// Creates a vector search endpoint. Provisioning continues in the background.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the endpoint.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `VectorSearchEndpointInfo` if successful, or an `HttpError` if the request fails.
+    pub async fn create_vector_search_endpoint(
+        &self,
+        name: &str,
+    ) -> Result<VectorSearchEndpointInfo, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            "api/2.0/vector-search/endpoints",
+            Some(CreateVectorSearchEndpointRequest {
+                name: name.to_string(),
+                endpoint_type: VectorSearchEndpointType::Standard,
+            }),
+        )
+        .await
+    }
+
+    /// Retrieves a vector search endpoint by name.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the endpoint.
+    ///
+    /// Returns:
+    /// - Same as `create_vector_search_endpoint`.
+    pub async fn get_vector_search_endpoint(
+        &self,
+        name: &str,
+    ) -> Result<VectorSearchEndpointInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &format!(
+                "api/2.0/vector-search/endpoints/{}",
+                encode_query_value(name)
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Deletes a vector search endpoint.
+    ///
+    /// Parameters:
+    /// - `name`: The name of the endpoint.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_vector_search_endpoint(&self, name: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!(
+                "api/2.0/vector-search/endpoints/{}",
+                encode_query_value(name)
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Lists all vector search endpoints, following pagination until exhausted.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `VectorSearchEndpointInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_vector_search_endpoints(
+        &self,
+    ) -> Result<Vec<VectorSearchEndpointInfo>, HttpError> {
+        let mut endpoints: Vec<VectorSearchEndpointInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let endpoint: String = match &page_token {
+                Some(token) => format!(
+                    "api/2.0/vector-search/endpoints?page_token={}",
+                    encode_query_value(token)
+                ),
+                None => "api/2.0/vector-search/endpoints".to_string(),
+            };
+            let page: ListVectorSearchEndpointsResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            endpoints.extend(page.endpoints);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(endpoints),
+            }
+        }
+    }
+
+    /// Creates a vector search index on an endpoint.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `CreateVectorIndexRequest` describing a Delta Sync or Direct Access index.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `VectorIndexInfo` if successful, or an `HttpError` if the request fails.
+    pub async fn create_vector_index(
+        &self,
+        request_body: CreateVectorIndexRequest,
+    ) -> Result<VectorIndexInfo, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            "api/2.0/vector-search/indexes",
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Retrieves a vector search index, including its readiness status.
+    ///
+    /// Parameters:
+    /// - `index_name`: The full name of the index.
+    ///
+    /// Returns:
+    /// - Same as `create_vector_index`.
+    pub async fn get_vector_index(&self, index_name: &str) -> Result<VectorIndexInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &format!(
+                "api/2.0/vector-search/indexes/{}",
+                encode_query_value(index_name)
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Lists all indexes on a vector search endpoint, following pagination until exhausted.
+    ///
+    /// Parameters:
+    /// - `endpoint_name`: The endpoint whose indexes should be listed.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `VectorIndexInfo`, or an `HttpError` if any page request fails.
+    pub async fn list_vector_indexes(
+        &self,
+        endpoint_name: &str,
+    ) -> Result<Vec<VectorIndexInfo>, HttpError> {
+        let mut indexes: Vec<VectorIndexInfo> = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut endpoint: String = format!(
+                "api/2.0/vector-search/indexes?endpoint_name={}",
+                encode_query_value(endpoint_name)
+            );
+            if let Some(token) = &page_token {
+                endpoint.push_str(&format!("&page_token={}", encode_query_value(token)));
+            }
+            let page: ListVectorIndexesResponse = self
+                .send_databricks_request(Method::GET, &endpoint, None::<()>)
+                .await?;
+            indexes.extend(page.vector_indexes);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => return Ok(indexes),
+            }
+        }
+    }
+
+    /// Deletes a vector search index.
+    ///
+    /// Parameters:
+    /// - `index_name`: The full name of the index.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_vector_index(&self, index_name: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!(
+                "api/2.0/vector-search/indexes/{}",
+                encode_query_value(index_name)
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Triggers a sync of a Delta Sync index with a `TRIGGERED` pipeline.
+    ///
+    /// Parameters:
+    /// - `index_name`: The full name of the index.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the sync was started, or an `HttpError` if the request fails.
+    pub async fn sync_vector_index(&self, index_name: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            &format!(
+                "api/2.0/vector-search/indexes/{}/sync",
+                encode_query_value(index_name)
+            ),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Runs a similarity search against an index.
+    ///
+    /// Parameters:
+    /// - `index_name`: The full name of the index.
+    /// - `request_body`: A `QueryVectorIndexRequest` with the query text or vector and any filters.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `QueryVectorIndexResponse`, or an `HttpError` if the request fails.
+    pub async fn query_vector_index(
+        &self,
+        index_name: &str,
+        request_body: QueryVectorIndexRequest,
+    ) -> Result<QueryVectorIndexResponse, HttpError> {
+        self.send_databricks_request(
+            Method::POST,
+            &format!(
+                "api/2.0/vector-search/indexes/{}/query",
+                encode_query_value(index_name)
+            ),
+            Some(request_body),
+        )
+        .await
+    }
+
+    /// Inserts or updates rows in a Direct Access index.
+    ///
+    /// Parameters:
+    /// - `index_name`: The full name of the index.
+    /// - `rows`: The rows to write; each must include the primary key and embedding or source columns.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `VectorIndexWriteResponse` with any failed primary keys, or an
+    ///   `HttpError` if the request fails.
+    pub async fn upsert_vector_index_data<T: Serialize>(
+        &self,
+        index_name: &str,
+        rows: &[T],
+    ) -> Result<VectorIndexWriteResponse, HttpError> {
+        let inputs_json: String = serde_json::to_string(rows)
+            .map_err(|err| HttpError::InternalServerError(err.to_string()))?;
+        self.send_databricks_request(
+            Method::POST,
+            &format!(
+                "api/2.0/vector-search/indexes/{}/upsert-data",
+                encode_query_value(index_name)
+            ),
+            Some(UpsertVectorIndexDataRequest { inputs_json }),
+        )
+        .await
+    }
+
+    /// Deletes rows from a Direct Access index by primary key.
+    ///
+    /// Parameters:
+    /// - `index_name`: The full name of the index.
+    /// - `primary_keys`: The primary keys of the rows to delete.
+    ///
+    /// Returns:
+    /// - Same as `upsert_vector_index_data`.
+    pub async fn delete_vector_index_data(
+        &self,
+        index_name: &str,
+        primary_keys: Vec<String>,
+    ) -> Result<VectorIndexWriteResponse, HttpError> {
+        self.send_databricks_request(
+            Method::DELETE,
+            &format!(
+                "api/2.0/vector-search/indexes/{}/delete-data",
+                encode_query_value(index_name)
+            ),
+            Some(DeleteVectorIndexDataRequest { primary_keys }),
+        )
+        .await
+    }
+}
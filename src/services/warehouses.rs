@@ -0,0 +1,209 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        ListWarehousesResponse, SqlStatementRequest, SqlStatementResponse, WarehouseInfo,
+        WarehouseStartProgress, WarehouseState,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+};
+use reqwest::Method;
+use std::time::{Duration, Instant};
+
+/// How many polls may still find a warehouse stopped right after it was asked to start.
+const MAX_STOPPED_POLLS_AFTER_START: u32 = 3;
+
+impl DatabricksSession {
+    /// Lists the SQL warehouses in the workspace.
+    ///
+    /// Returns:
+    /// - A `Result` containing every `WarehouseInfo`, or an `HttpError` if the request fails.
+    pub async fn list_warehouses(&self) -> Result<Vec<WarehouseInfo>, HttpError> {
+        let response: ListWarehousesResponse = self
+            .send_databricks_request(Method::GET, "api/2.0/sql/warehouses", None::<()>)
+            .await?;
+        Ok(response.warehouses)
+    }
+
+    /// Retrieves a SQL warehouse, including its current state.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The ID of the warehouse.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `WarehouseInfo`, or an `HttpError` if the request fails.
+    pub async fn get_warehouse(&self, warehouse_id: &str) -> Result<WarehouseInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &format!("api/2.0/sql/warehouses/{}", warehouse_id),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Starts a stopped SQL warehouse. The warehouse transitions through `STARTING` to `RUNNING` in
+    /// the background.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The ID of the warehouse.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the start was accepted, or an `HttpError` if the request fails.
+    pub async fn start_warehouse(&self, warehouse_id: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            &format!("api/2.0/sql/warehouses/{}/start", warehouse_id),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Stops a running SQL warehouse.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The ID of the warehouse.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the stop was accepted, or an `HttpError` if the request fails.
+    pub async fn stop_warehouse(&self, warehouse_id: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            &format!("api/2.0/sql/warehouses/{}/stop", warehouse_id),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Deletes a SQL warehouse.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The ID of the warehouse.
+    ///
+    /// Returns:
+    /// - An empty `Result` if the deletion was accepted, or an `HttpError` if the request fails.
+    pub async fn delete_warehouse(&self, warehouse_id: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::DELETE,
+            &format!("api/2.0/sql/warehouses/{}", warehouse_id),
+            None::<()>,
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Starts a warehouse if it is stopped and polls it until it is running.
+    ///
+    /// Waiting is bounded by the session's `operation_timeout`, if set. A warehouse that is
+    /// stopped again after the start request (e.g. because starting it failed) is an error, as is
+    /// a stopped warehouse in dry-run mode, where the start request is not sent.
+    ///
+    /// Parameters:
+    /// - `warehouse_id`: The ID of the warehouse.
+    /// - `poll_interval`: The time between polls.
+    /// - `on_progress`: Called with the warehouse state on every poll while it is not running.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `WarehouseInfo` of the running warehouse, or an `HttpError` if
+    ///   a request fails, the warehouse is being deleted or did not start
+    ///   (`HttpError::BadRequest`), or the operation timeout passes
+    ///   (`HttpError::OperationTimedOut`).
+    pub async fn wait_for_warehouse(
+        &self,
+        warehouse_id: &str,
+        poll_interval: Duration,
+        on_progress: impl FnMut(&WarehouseStartProgress),
+    ) -> Result<WarehouseInfo, HttpError> {
+        self.before_deadline(
+            self.operation_deadline(),
+            "wait_for_warehouse",
+            self.poll_warehouse_start(warehouse_id, poll_interval, on_progress),
+        )
+        .await
+    }
+
+    async fn poll_warehouse_start(
+        &self,
+        warehouse_id: &str,
+        poll_interval: Duration,
+        mut on_progress: impl FnMut(&WarehouseStartProgress),
+    ) -> Result<WarehouseInfo, HttpError> {
+        let started_at: Instant = Instant::now();
+        let mut start_requested: bool = false;
+        // Polls that still found the warehouse stopped after the start request, and whether it
+        // has been seen starting since; the state may take a moment to change after the request.
+        let mut stopped_polls: u32 = 0;
+        let mut seen_starting: bool = false;
+
+        loop {
+            let warehouse: WarehouseInfo = self.get_warehouse(warehouse_id).await?;
+            let state: WarehouseState = match warehouse.state {
+                Some(WarehouseState::Running) => return Ok(warehouse),
+                Some(state @ (WarehouseState::Deleting | WarehouseState::Deleted)) => {
+                    return Err(HttpError::BadRequest(format!(
+                        "Warehouse {} is {}",
+                        warehouse_id, state
+                    )))
+                }
+                Some(state) => state,
+                None => WarehouseState::Starting,
+            };
+
+            // A warehouse that is stopping has to finish stopping before it can be started.
+            if state == WarehouseState::Stopped {
+                if !start_requested {
+                    if self.is_planned(&Method::POST) {
+                        self.start_warehouse(warehouse_id).await?;
+                        return Err(HttpError::BadRequest(format!(
+                            "Warehouse {} is stopped and is not started in dry-run mode",
+                            warehouse_id
+                        )));
+                    }
+                    self.start_warehouse(warehouse_id).await?;
+                    start_requested = true;
+                } else {
+                    stopped_polls += 1;
+                    if seen_starting || stopped_polls >= MAX_STOPPED_POLLS_AFTER_START {
+                        return Err(HttpError::BadRequest(format!(
+                            "Warehouse {} stopped again after it was started",
+                            warehouse_id
+                        )));
+                    }
+                }
+            } else if start_requested {
+                seen_starting = true;
+            }
+            on_progress(&WarehouseStartProgress {
+                warehouse_id: warehouse_id.to_string(),
+                state,
+                elapsed: started_at.elapsed(),
+            });
+            tokio::time::sleep(poll_interval).await;
+        }
+    }
+
+    /// Executes a SQL statement like `execute_sql_statement_and_wait`, but first makes sure its
+    /// warehouse is running: a stopped warehouse is started and waited for, reporting progress,
+    /// so serverless or auto-stopped warehouses do not leave the statement queued silently.
+    ///
+    /// Parameters:
+    /// - `request_body`: A `SqlStatementRequest` struct containing the SQL statement to be executed.
+    /// - `on_progress`: Called every few seconds while the warehouse is starting.
+    ///
+    /// Returns:
+    /// - Same as `execute_sql_statement_and_wait`, or an `HttpError` if the warehouse cannot be
+    ///   started.
+    pub async fn execute_sql_statement_with_warehouse_start(
+        &self,
+        request_body: SqlStatementRequest,
+        on_progress: impl FnMut(&WarehouseStartProgress),
+    ) -> Result<SqlStatementResponse, HttpError> {
+        self.wait_for_warehouse(
+            &request_body.warehouse_id,
+            Duration::from_secs(5),
+            on_progress,
+        )
+        .await?;
+        self.execute_sql_statement_and_wait(request_body).await
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        DeleteWorkspaceObjectRequest, ExportFormat, ExportWorkspaceResponse,
+        ImportWorkspaceRequest, ListWorkspaceResponse, ObjectInfo, WorkspacePathRequest,
+    },
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+    utils::encode_query_value,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use reqwest::{
+    multipart::{Form, Part},
+    Body, Method, Response,
+};
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use tokio_util::io::ReaderStream;
+
+impl DatabricksSession {
+    /// Retrieves the type, language and identifiers of a workspace object.
+    ///
+    /// Parameters:
+    /// - `path`: The absolute workspace path.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `ObjectInfo`, or an `HttpError` if the request fails.
+    pub async fn get_workspace_status(&self, path: &str) -> Result<ObjectInfo, HttpError> {
+        self.send_databricks_request(
+            Method::GET,
+            &format!(
+                "api/2.0/workspace/get-status?path={}",
+                encode_query_value(path)
+            ),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Lists the direct children of a workspace directory.
+    ///
+    /// Parameters:
+    /// - `path`: The absolute workspace path of the directory.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `ObjectInfo` of each child, or an `HttpError` if the request fails.
+    pub async fn list_workspace(&self, path: &str) -> Result<Vec<ObjectInfo>, HttpError> {
+        let response: ListWorkspaceResponse = self
+            .send_databricks_request(
+                Method::GET,
+                &format!("api/2.0/workspace/list?path={}", encode_query_value(path)),
+                None::<()>,
+            )
+            .await?;
+        Ok(response.objects)
+    }
+
+    /// Exports a notebook, file or directory.
+    ///
+    /// Parameters:
+    /// - `path`: The absolute workspace path.
+    /// - `format`: The export format; directories can only be exported as `DBC`, and workspace
+    ///   files are exported as-is with `RAW`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the decoded content, or an `HttpError` if the request fails.
+    pub async fn export_workspace_object(
+        &self,
+        path: &str,
+        format: ExportFormat,
+    ) -> Result<Vec<u8>, HttpError> {
+        let format: String = serde_json::to_value(format)
+            .ok()
+            .and_then(|value| value.as_str().map(str::to_string))
+            .unwrap_or_else(|| "SOURCE".to_string());
+        let response: ExportWorkspaceResponse = self
+            .send_databricks_request(
+                Method::GET,
+                &format!(
+                    "api/2.0/workspace/export?path={}&format={}",
+                    encode_query_value(path),
+                    format
+                ),
+                None::<()>,
+            )
+            .await?;
+
+        STANDARD
+            .decode(response.content.as_bytes())
+            .map_err(|err| HttpError::InternalServerError(err.to_string()))
+    }
+
+    /// Imports a notebook, `DBC` archive or, with format `RAW`, a workspace file.
+    ///
+    /// Parameters:
+    /// - `request_body`: An `ImportWorkspaceRequest` with base64-encoded content.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn import_workspace_object(
+        &self,
+        request_body: ImportWorkspaceRequest,
+    ) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            "api/2.0/workspace/import",
+            Some(request_body),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Exports a workspace directory (or a single notebook) as a `DBC` archive, streaming the
+    /// archive into `writer` so large directories can be backed up without holding them in memory.
+    ///
+    /// Parameters:
+    /// - `path`: The absolute workspace path of the directory.
+    /// - `writer`: The destination, e.g. a `tokio::fs::File`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the number of bytes written, or an `HttpError` if the export fails.
+    pub async fn export_workspace_dbc<W: AsyncWrite + Unpin>(
+        &self,
+        path: &str,
+        writer: &mut W,
+    ) -> Result<u64, HttpError> {
+        let endpoint: String = format!(
+            "api/2.0/workspace/export?path={}&format=DBC&direct_download=true",
+            encode_query_value(path)
+        );
+        let mut response: Response = self
+            .send_raw_request(self.authorized_request(Method::GET, &endpoint)?)
+            .await?;
+
+        let mut written: u64 = 0;
+        while let Some(chunk) = response
+            .chunk()
+            .await
+            .map_err(|err| HttpError::InternalServerError(err.to_string()))?
+        {
+            writer
+                .write_all(&chunk)
+                .await
+                .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+            written += chunk.len() as u64;
+        }
+        writer
+            .flush()
+            .await
+            .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+        Ok(written)
+    }
+
+    /// Imports a `DBC` archive, streaming it from `reader` as a multipart upload instead of
+    /// base64-encoding it into a JSON body.
+    ///
+    /// The archive's contents are created under `path`, which must not exist yet: the API does
+    /// not overwrite objects when importing a `DBC` archive.
+    ///
+    /// Parameters:
+    /// - `path`: The absolute workspace path to import into.
+    /// - `reader`: The archive, e.g. a `tokio::fs::File`.
+    /// - `content_length`: The size of the archive, if known.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the import fails.
+    pub async fn import_workspace_dbc<R: AsyncRead + Send + Sync + 'static>(
+        &self,
+        path: &str,
+        reader: R,
+        content_length: Option<u64>,
+    ) -> Result<(), HttpError> {
+        let endpoint: &str = "api/2.0/workspace/import";
+        if self.is_planned(&Method::POST) {
+            self.plan_request(
+                &Method::POST,
+                endpoint,
+                Some(serde_json::json!({ "path": path, "format": "DBC" })),
+            );
+            return Ok(());
+        }
+        let body: Body = Body::wrap_stream(ReaderStream::new(reader));
+        let content: Part = match content_length {
+            Some(content_length) => Part::stream_with_length(body, content_length),
+            None => Part::stream(body),
+        };
+        let form: Form = Form::new()
+            .text("path", path.to_string())
+            .text("format", "DBC")
+            .part("content", content.file_name("archive.dbc"));
+
+        self.send_raw_request(
+            self.authorized_request(Method::POST, endpoint)?
+                .multipart(form),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Creates a workspace directory and any missing parents.
+    ///
+    /// Parameters:
+    /// - `path`: The absolute workspace path of the directory.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn mkdirs_workspace(&self, path: &str) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            "api/2.0/workspace/mkdirs",
+            Some(WorkspacePathRequest {
+                path: path.to_string(),
+            }),
+        )
+        .await
+        .map(|_| ())
+    }
+
+    /// Deletes a workspace object.
+    ///
+    /// Parameters:
+    /// - `path`: The absolute workspace path.
+    /// - `recursive`: Whether to delete non-empty directories.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn delete_workspace_object(
+        &self,
+        path: &str,
+        recursive: bool,
+    ) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::POST,
+            "api/2.0/workspace/delete",
+            Some(DeleteWorkspaceObjectRequest {
+                path: path.to_string(),
+                recursive,
+            }),
+        )
+        .await
+        .map(|_| ())
+    }
+}
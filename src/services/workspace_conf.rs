@@ -0,0 +1,51 @@
+use crate::{
+    errors::HttpError,
+    models::{WorkspaceConf, WorkspaceConfKey},
+    services::{databricks_session::EmptyResponse, DatabricksSession},
+    utils::encode_query_value,
+};
+use reqwest::Method;
+
+impl DatabricksSession {
+    /// Retrieves the current values of the given workspace configuration keys.
+    ///
+    /// Parameters:
+    /// - `keys`: The configuration keys to fetch.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `WorkspaceConf` if successful, or an `HttpError` if the request fails.
+    pub async fn get_workspace_conf(
+        &self,
+        keys: &[WorkspaceConfKey],
+    ) -> Result<WorkspaceConf, HttpError> {
+        let keys: Vec<String> = keys
+            .iter()
+            .map(|key| encode_query_value(key.as_str()))
+            .collect();
+        self.send_databricks_request(
+            Method::GET,
+            &format!("api/2.0/workspace-conf?keys={}", keys.join(",")),
+            None::<()>,
+        )
+        .await
+    }
+
+    /// Updates workspace configuration values.
+    ///
+    /// Only the keys present in `conf` are modified; all other settings are left untouched.
+    ///
+    /// Parameters:
+    /// - `conf`: The configuration values to set.
+    ///
+    /// Returns:
+    /// - An empty `Result` if successful, or an `HttpError` if the request fails.
+    pub async fn patch_workspace_conf(&self, conf: &WorkspaceConf) -> Result<(), HttpError> {
+        self.send_databricks_request::<EmptyResponse, _>(
+            Method::PATCH,
+            "api/2.0/workspace-conf",
+            Some(conf),
+        )
+        .await
+        .map(|_| ())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{
+    config::Config,
+    errors::HttpError,
+    models::{WorkspaceOperationReport, WorkspaceProgress},
+    services::DatabricksSession,
+};
+use futures::StreamExt;
+use std::{collections::BTreeMap, future::Future};
+
+/// A named set of workspace sessions, e.g. one per environment, for running the same operation
+/// across a fleet of workspaces.
+#[derive(Default)]
+pub struct WorkspaceRegistry {
+    workspaces: BTreeMap<String, DatabricksSession>,
+}
+
+impl WorkspaceRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers a session under `name`, replacing any session already registered under it.
+    pub fn with_workspace(mut self, name: &str, session: DatabricksSession) -> Self {
+        self.workspaces.insert(name.to_string(), session);
+        self
+    }
+
+    /// Registers one session per Databricks CLI configuration profile, named after the profile.
+    ///
+    /// Parameters:
+    /// - `profiles`: The profile names, e.g. `["dev", "staging", "prod"]`.
+    ///
+    /// Returns:
+    /// - A `Result` containing the `WorkspaceRegistry`, or an error if a profile cannot be read
+    ///   or its session cannot be created.
+    pub fn from_profiles(profiles: &[&str]) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut registry: WorkspaceRegistry = WorkspaceRegistry::new();
+        for profile in profiles {
+            let session: DatabricksSession =
+                DatabricksSession::new(Config::from_profile(profile)?)?;
+            registry = registry.with_workspace(profile, session);
+        }
+        Ok(registry)
+    }
+
+    /// The session registered under `name`.
+    pub fn get(&self, name: &str) -> Option<&DatabricksSession> {
+        self.workspaces.get(name)
+    }
+
+    /// The registered workspace names, in sorted order.
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.workspaces.keys().map(String::as_str)
+    }
+
+    pub fn len(&self) -> usize {
+        self.workspaces.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.workspaces.is_empty()
+    }
+
+    /// Runs an async operation on every registered workspace, e.g. listing clusters or rotating a
+    /// secret, with at most `max_concurrency` running at a time.
+    ///
+    /// A failing workspace does not stop the others; its error is collected in the report.
+    ///
+    /// Parameters:
+    /// - `max_concurrency`: The maximum number of workspaces processed at the same time.
+    /// - `operation`: The operation to run, given each workspace's name and session.
+    /// - `on_progress`: Called after each workspace completes.
+    ///
+    /// Returns:
+    /// - The `WorkspaceOperationReport` with the result of every workspace.
+    pub async fn for_each_workspace<'a, T, F, Fut>(
+        &'a self,
+        max_concurrency: usize,
+        operation: F,
+        mut on_progress: impl FnMut(&WorkspaceProgress),
+    ) -> WorkspaceOperationReport<T>
+    where
+        F: Fn(&'a str, &'a DatabricksSession) -> Fut,
+        Fut: Future<Output = Result<T, HttpError>> + 'a,
+    {
+        let total: usize = self.workspaces.len();
+        let mut results = futures::stream::iter(&self.workspaces)
+            .map(|(name, session)| {
+                let result: Fut = operation(name, session);
+                async move { (name.clone(), result.await) }
+            })
+            .buffer_unordered(max_concurrency.max(1));
+
+        let mut report: WorkspaceOperationReport<T> = WorkspaceOperationReport {
+            succeeded: Vec::new(),
+            failed: Vec::new(),
+        };
+        while let Some((workspace, result)) = results.next().await {
+            let succeeded: bool = result.is_ok();
+            match result {
+                Ok(value) => report.succeeded.push((workspace.clone(), value)),
+                Err(err) => report.failed.push((workspace.clone(), err)),
+            }
+            on_progress(&WorkspaceProgress {
+                workspace,
+                succeeded,
+                completed: report.succeeded.len() + report.failed.len(),
+                failed: report.failed.len(),
+                total,
+            });
+        }
+        report
+    }
+}
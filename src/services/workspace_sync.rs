@@ -0,0 +1,267 @@
+use crate::{
+    errors::HttpError,
+    models::{
+        ExportFormat, ImportWorkspaceRequest, ObjectInfo, ObjectType, WorkspaceSyncOptions,
+        WorkspaceSyncReport,
+    },
+    services::{databricks_session::retry_rate_limited, DatabricksSession},
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use futures::{StreamExt, TryStreamExt};
+use std::path::{Path, PathBuf};
+
+impl DatabricksSession {
+    /// Recursively exports the notebooks and workspace files under a workspace directory into a
+    /// local directory, mirroring the directory structure.
+    ///
+    /// Notebooks are written in `options.format`; workspace files are written as-is under their
+    /// own name, unless `options.skip_files` is set. Directories are listed one at a time, then
+    /// up to `options.concurrency` objects are exported at once; rate-limited requests are retried
+    /// with backoff.
+    ///
+    /// Parameters:
+    /// - `remote_dir`: The absolute workspace path of the directory to export.
+    /// - `local_dir`: The local directory to write into; created if missing.
+    /// - `options`: The notebook format and overwrite behavior.
+    ///
+    /// Returns:
+    /// - A `Result` containing a `WorkspaceSyncReport`, or an `HttpError` if listing, exporting or
+    ///   writing fails.
+    pub async fn export_dir(
+        &self,
+        remote_dir: &str,
+        local_dir: &Path,
+        options: &WorkspaceSyncOptions,
+    ) -> Result<WorkspaceSyncReport, HttpError> {
+        let remote_dir: &str = remote_dir.trim_end_matches('/');
+        let mut report: WorkspaceSyncReport = WorkspaceSyncReport::default();
+        let mut pending: Vec<String> = vec![remote_dir.to_string()];
+        let mut exports: Vec<(String, ExportFormat, PathBuf)> = Vec::new();
+
+        while let Some(directory) = pending.pop() {
+            let local_directory: PathBuf = local_dir.join(relative_path(remote_dir, &directory));
+            tokio::fs::create_dir_all(&local_directory)
+                .await
+                .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+
+            let objects: Vec<ObjectInfo> =
+                retry_rate_limited(options.max_rate_limit_retries, || {
+                    self.list_workspace(&directory)
+                })
+                .await?;
+            for object in objects {
+                let (format, target): (ExportFormat, PathBuf) = match object.object_type {
+                    Some(ObjectType::Directory) => {
+                        pending.push(object.path);
+                        continue;
+                    }
+                    Some(ObjectType::Notebook) => (
+                        options.format,
+                        local_directory.join(local_file_name(&object, options.format)),
+                    ),
+                    Some(ObjectType::File) if !options.skip_files => {
+                        let name: &str = object.path.rsplit('/').next().unwrap_or(&object.path);
+                        (ExportFormat::Raw, local_directory.join(name))
+                    }
+                    _ => {
+                        report.skipped.push(object.path);
+                        continue;
+                    }
+                };
+                if !options.overwrite && target.exists() {
+                    report.skipped.push(object.path);
+                    continue;
+                }
+                exports.push((object.path, format, target));
+            }
+        }
+
+        let transferred: Vec<String> = futures::stream::iter(exports)
+            .map(|(path, format, target)| async move {
+                let content: Vec<u8> = retry_rate_limited(options.max_rate_limit_retries, || {
+                    self.export_workspace_object(&path, format)
+                })
+                .await?;
+                tokio::fs::write(&target, content)
+                    .await
+                    .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+                Ok::<_, HttpError>(path)
+            })
+            .buffer_unordered(options.concurrency.max(1))
+            .try_collect()
+            .await?;
+        report.transferred.extend(transferred);
+
+        Ok(report)
+    }
+
+    /// Recursively imports the notebooks and other files in a local directory into a workspace
+    /// directory, mirroring the directory structure.
+    ///
+    /// With `SOURCE` format, files starting with a `Databricks notebook source` header become
+    /// notebooks (without their extension). With other formats, files with the format's extension
+    /// become notebooks. All other files are imported as workspace files under their own name,
+    /// unless `options.skip_files` is set. Up to `options.concurrency` files are imported at once;
+    /// rate-limited requests are retried with backoff.
+    ///
+    /// Parameters:
+    /// - `local_dir`: The local directory to import.
+    /// - `remote_dir`: The absolute workspace path to import into; created if missing.
+    /// - `options`: The notebook format and overwrite behavior.
+    ///
+    /// Returns:
+    /// - A `Result` containing a `WorkspaceSyncReport`, or an `HttpError` if reading or importing fails.
+    pub async fn import_dir(
+        &self,
+        local_dir: &Path,
+        remote_dir: &str,
+        options: &WorkspaceSyncOptions,
+    ) -> Result<WorkspaceSyncReport, HttpError> {
+        let remote_dir: &str = remote_dir.trim_end_matches('/');
+        let mut report: WorkspaceSyncReport = WorkspaceSyncReport::default();
+        let mut pending: Vec<(PathBuf, String)> =
+            vec![(local_dir.to_path_buf(), remote_dir.to_string())];
+        let mut imports: Vec<(PathBuf, String, String)> = Vec::new();
+
+        while let Some((local_directory, remote_directory)) = pending.pop() {
+            retry_rate_limited(options.max_rate_limit_retries, || {
+                self.mkdirs_workspace(&remote_directory)
+            })
+            .await?;
+
+            let mut entries = tokio::fs::read_dir(&local_directory)
+                .await
+                .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+            while let Some(entry) = entries
+                .next_entry()
+                .await
+                .map_err(|err| HttpError::InternalError(Box::new(err)))?
+            {
+                let local_path: PathBuf = entry.path();
+                let file_name: String = entry.file_name().to_string_lossy().into_owned();
+                if local_path.is_dir() {
+                    pending.push((local_path, format!("{}/{}", remote_directory, file_name)));
+                    continue;
+                }
+
+                imports.push((local_path, remote_directory.clone(), file_name));
+            }
+        }
+
+        let outcomes: Vec<(String, bool)> = futures::stream::iter(imports)
+            .map(|(local_path, remote_directory, file_name)| async move {
+                let content: Vec<u8> = tokio::fs::read(&local_path)
+                    .await
+                    .map_err(|err| HttpError::InternalError(Box::new(err)))?;
+                let Some(request) =
+                    import_request(&remote_directory, &file_name, &content, options)
+                else {
+                    return Ok((format!("{}/{}", remote_directory, file_name), false));
+                };
+
+                let path: String = request.path.clone();
+                let result: Result<(), HttpError> =
+                    retry_rate_limited(options.max_rate_limit_retries, || {
+                        self.import_workspace_object(request.clone())
+                    })
+                    .await;
+                match result {
+                    Ok(()) => Ok((path, true)),
+                    Err(HttpError::Conflict(_)) if !options.overwrite => Ok((path, false)),
+                    Err(err) => Err(err),
+                }
+            })
+            .buffer_unordered(options.concurrency.max(1))
+            .try_collect()
+            .await?;
+        for (path, imported) in outcomes {
+            if imported {
+                report.transferred.push(path);
+            } else {
+                report.skipped.push(path);
+            }
+        }
+
+        Ok(report)
+    }
+}
+
+/// The path of `path` relative to `root`, for mirroring workspace directories locally.
+fn relative_path(root: &str, path: &str) -> PathBuf {
+    let relative: &str = path
+        .strip_prefix(root)
+        .unwrap_or(path)
+        .trim_start_matches('/');
+    relative
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+        .collect()
+}
+
+/// The local file name of an exported notebook, e.g. `etl.py` or `etl.ipynb`.
+fn local_file_name(object: &ObjectInfo, format: ExportFormat) -> String {
+    let name: &str = object.path.rsplit('/').next().unwrap_or(&object.path);
+    let extension: &str = format
+        .extension()
+        .or_else(|| object.language.map(|language| language.source_extension()))
+        .unwrap_or("txt");
+    format!("{}.{}", name, extension)
+}
+
+/// The request importing a local file into `remote_directory`, or `None` if the file is skipped.
+fn import_request(
+    remote_directory: &str,
+    file_name: &str,
+    content: &[u8],
+    options: &WorkspaceSyncOptions,
+) -> Option<ImportWorkspaceRequest> {
+    let (path, format): (String, ExportFormat) = match options.format {
+        _ if !is_notebook(file_name, content, options.format) => {
+            if options.skip_files {
+                return None;
+            }
+            (
+                format!("{}/{}", remote_directory, file_name),
+                ExportFormat::Raw,
+            )
+        }
+        ExportFormat::Source | ExportFormat::Auto => (
+            format!("{}/{}", remote_directory, file_name),
+            ExportFormat::Auto,
+        ),
+        format => {
+            let stem: &str = file_name
+                .rsplit_once('.')
+                .map_or(file_name, |(stem, _)| stem);
+            (format!("{}/{}", remote_directory, stem), format)
+        }
+    };
+    Some(ImportWorkspaceRequest {
+        path,
+        format,
+        language: None,
+        content: STANDARD.encode(content),
+        overwrite: options.overwrite,
+    })
+}
+
+/// Whether a local file is a notebook in `format`: for `SOURCE` and `AUTO`, recognized by its
+/// header line, and for other formats by the format's extension.
+fn is_notebook(file_name: &str, content: &[u8], format: ExportFormat) -> bool {
+    match (format, format.extension()) {
+        (ExportFormat::Source | ExportFormat::Auto, _) => is_notebook_source(content),
+        (_, Some(expected)) => file_name
+            .rsplit_once('.')
+            .is_some_and(|(_, found)| found.eq_ignore_ascii_case(expected)),
+        (_, None) => false,
+    }
+}
+
+/// Whether a local file is a notebook exported in `SOURCE` format, recognized by its header line.
+fn is_notebook_source(content: &[u8]) -> bool {
+    let first_line: &[u8] = content
+        .split(|byte| *byte == b'\n')
+        .next()
+        .unwrap_or_default();
+    String::from_utf8_lossy(first_line).contains("Databricks notebook source")
+}
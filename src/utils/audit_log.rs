@@ -0,0 +1,28 @@
+use crate::models::AuditEvent;
+use std::io::{self, BufRead};
+
+/// Parses a delivered audit log file: one JSON event per line, blank lines ignored.
+///
+/// Parameters:
+/// - `reader`: The (decompressed) contents of the log file.
+///
+/// Returns:
+/// - An iterator over the events, yielding an `io::Error` of kind `InvalidData`, naming the line
+///   number, for a line that is not a valid event. Parsing continues with the next line.
+pub fn parse_audit_log<R: BufRead>(
+    reader: R,
+) -> impl Iterator<Item = Result<AuditEvent, io::Error>> {
+    reader
+        .lines()
+        .enumerate()
+        .filter_map(|(index, line)| match line {
+            Ok(line) if line.trim().is_empty() => None,
+            Ok(line) => Some(AuditEvent::from_json(&line).map_err(|err| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("Invalid audit event on line {}: {}", index + 1, err),
+                )
+            })),
+            Err(err) => Some(Err(err)),
+        })
+}
@@ -0,0 +1,156 @@
+use crate::models::{ClusterInfo, ClusterSummary, WarehouseInfo};
+use std::collections::HashMap;
+
+/// The pricing tier that DBUs of a compute resource are billed at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ComputeSku {
+    AllPurpose,
+    Jobs,
+    SqlClassic,
+    SqlPro,
+    SqlServerless,
+}
+
+/// The estimated cost of running a compute resource for one hour at its current size.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CostEstimate {
+    pub sku: ComputeSku,
+    pub dbus_per_hour: f64,
+    pub cost_per_hour: f64,
+}
+
+/// The DBU consumption of node types and warehouse sizes, and the price of a DBU per SKU.
+///
+/// Rates depend on cloud, region and contract, so every value is supplied by the caller; resources
+/// whose node type, size or SKU is missing from the table are not estimated.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DbuPriceTable {
+    pub price_per_dbu: HashMap<ComputeSku, f64>,
+    pub node_type_dbus_per_hour: HashMap<String, f64>,
+    pub warehouse_size_dbus_per_hour: HashMap<String, f64>,
+}
+
+impl DbuPriceTable {
+    /// Sets the price of one DBU for `sku`.
+    pub fn with_price(mut self, sku: ComputeSku, price_per_dbu: f64) -> Self {
+        self.price_per_dbu.insert(sku, price_per_dbu);
+        self
+    }
+
+    /// Sets the DBUs per hour that one node of `node_type_id` consumes.
+    pub fn with_node_type(mut self, node_type_id: &str, dbus_per_hour: f64) -> Self {
+        self.node_type_dbus_per_hour
+            .insert(node_type_id.to_string(), dbus_per_hour);
+        self
+    }
+
+    /// Sets the DBUs per hour that one cluster of a warehouse of `cluster_size` (e.g. `"Small"`)
+    /// consumes.
+    pub fn with_warehouse_size(mut self, cluster_size: &str, dbus_per_hour: f64) -> Self {
+        self.warehouse_size_dbus_per_hour
+            .insert(cluster_size.to_string(), dbus_per_hour);
+        self
+    }
+
+    /// Estimates the hourly cost of a cluster from its list entry, assuming the driver uses the
+    /// worker node type.
+    pub fn estimate_cluster(&self, cluster: &ClusterSummary) -> Option<CostEstimate> {
+        let node_type_id: &str = cluster.node_type_id.as_deref()?;
+        self.estimate_nodes(
+            cluster_sku(cluster.cluster_source.as_deref()),
+            node_type_id,
+            node_type_id,
+            cluster.num_workers.unwrap_or_default(),
+        )
+    }
+
+    /// Estimates the hourly cost of a cluster from its full details.
+    pub fn estimate_cluster_info(&self, cluster: &ClusterInfo) -> Option<CostEstimate> {
+        let driver_node_type_id: &str = match cluster.driver_node_type_id.as_str() {
+            "" => &cluster.node_type_id,
+            driver_node_type_id => driver_node_type_id,
+        };
+        self.estimate_nodes(
+            cluster_sku(Some(&cluster.cluster_source)),
+            &cluster.node_type_id,
+            driver_node_type_id,
+            cluster.num_workers,
+        )
+    }
+
+    /// Estimates the hourly cost of a SQL warehouse running its current number of clusters, or
+    /// its minimum if it is stopped.
+    pub fn estimate_warehouse(&self, warehouse: &WarehouseInfo) -> Option<CostEstimate> {
+        let sku: ComputeSku = if warehouse.enable_serverless_compute.unwrap_or(false) {
+            ComputeSku::SqlServerless
+        } else if warehouse.warehouse_type.as_deref() == Some("PRO") {
+            ComputeSku::SqlPro
+        } else {
+            ComputeSku::SqlClassic
+        };
+        let clusters: i32 = warehouse
+            .num_clusters
+            .filter(|clusters| *clusters > 0)
+            .or(warehouse.min_num_clusters)
+            .unwrap_or(1);
+        let dbus_per_hour: f64 = self
+            .warehouse_size_dbus_per_hour
+            .get(warehouse.cluster_size.as_deref()?)?
+            * f64::from(clusters);
+        self.estimate(sku, dbus_per_hour)
+    }
+
+    /// Pairs every cluster with its estimate, e.g. to add a cost column to a listing.
+    pub fn annotate_clusters<'a>(
+        &self,
+        clusters: &'a [ClusterSummary],
+    ) -> Vec<(&'a ClusterSummary, Option<CostEstimate>)> {
+        clusters
+            .iter()
+            .map(|cluster| (cluster, self.estimate_cluster(cluster)))
+            .collect()
+    }
+
+    /// Pairs every warehouse with its estimate, e.g. to add a cost column to a listing.
+    pub fn annotate_warehouses<'a>(
+        &self,
+        warehouses: &'a [WarehouseInfo],
+    ) -> Vec<(&'a WarehouseInfo, Option<CostEstimate>)> {
+        warehouses
+            .iter()
+            .map(|warehouse| (warehouse, self.estimate_warehouse(warehouse)))
+            .collect()
+    }
+
+    fn estimate_nodes(
+        &self,
+        sku: ComputeSku,
+        node_type_id: &str,
+        driver_node_type_id: &str,
+        num_workers: i32,
+    ) -> Option<CostEstimate> {
+        let worker_dbus: f64 = *self.node_type_dbus_per_hour.get(node_type_id)?;
+        let driver_dbus: f64 = *self.node_type_dbus_per_hour.get(driver_node_type_id)?;
+        self.estimate(
+            sku,
+            driver_dbus + worker_dbus * f64::from(num_workers.max(0)),
+        )
+    }
+
+    fn estimate(&self, sku: ComputeSku, dbus_per_hour: f64) -> Option<CostEstimate> {
+        let price_per_dbu: f64 = *self.price_per_dbu.get(&sku)?;
+        Some(CostEstimate {
+            sku,
+            dbus_per_hour,
+            cost_per_hour: dbus_per_hour * price_per_dbu,
+        })
+    }
+}
+
+/// Job clusters are billed as jobs compute, everything else as all-purpose compute.
+fn cluster_sku(cluster_source: Option<&str>) -> ComputeSku {
+    match cluster_source {
+        Some("JOB") => ComputeSku::Jobs,
+        _ => ComputeSku::AllPurpose,
+    }
+}
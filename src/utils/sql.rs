@@ -0,0 +1,44 @@
+/// Quotes a single SQL identifier with backticks, escaping embedded backticks.
+///
+/// `quote_identifier("my`col")` yields ``` `my``col` ```.
+pub fn quote_identifier(identifier: &str) -> String {
+    format!("`{}`", identifier.replace('`', "``"))
+}
+
+/// Quotes a dotted, multi-part name (e.g. `main.sales.orders`) part by part.
+///
+/// Parts that are already wrapped in backticks are left as they are.
+pub fn quote_qualified_name(name: &str) -> String {
+    split_qualified_name(name)
+        .iter()
+        .map(|part| quote_identifier(part))
+        .collect::<Vec<String>>()
+        .join(".")
+}
+
+/// Splits a dotted name into its parts, honouring backtick-quoted parts that contain dots.
+pub fn split_qualified_name(name: &str) -> Vec<String> {
+    let mut parts: Vec<String> = Vec::new();
+    let mut current: String = String::new();
+    let mut chars = name.chars().peekable();
+    let mut quoted: bool = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '`' if quoted && chars.peek() == Some(&'`') => {
+                current.push('`');
+                chars.next();
+            }
+            '`' => quoted = !quoted,
+            '.' if !quoted => parts.push(std::mem::take(&mut current)),
+            _ => current.push(c),
+        }
+    }
+    parts.push(current);
+    parts
+}
+
+/// Quotes a value as a SQL string literal, escaping backslashes and single quotes.
+pub fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\\', "\\\\").replace('\'', "\\'"))
+}
@@ -0,0 +1,15 @@
+/// Percent-encodes a value for use in a URL query string or path segment.
+///
+/// Unreserved characters (RFC 3986) are kept as-is; everything else, including `/`, is encoded.
+pub fn encode_query_value(value: &str) -> String {
+    let mut encoded: String = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
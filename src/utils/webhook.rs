@@ -0,0 +1,134 @@
+use crate::{
+    errors::WebhookError,
+    models::{JobWebhookPayload, RegistryWebhookPayload},
+};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// The header carrying the hex-encoded HMAC-SHA256 signature of a registry webhook's body.
+pub const WEBHOOK_SIGNATURE_HEADER: &str = "X-Databricks-Signature";
+
+/// Checks a webhook signature against the raw request body, in constant time.
+///
+/// Parameters:
+/// - `secret`: The shared secret configured on the webhook.
+/// - `body`: The request body exactly as received, before any parsing.
+/// - `signature`: The value of the `X-Databricks-Signature` header.
+///
+/// Returns:
+/// - An empty `Result` if the signature matches, or `WebhookError::InvalidSignature` if not.
+pub fn verify_webhook_signature(
+    secret: &str,
+    body: &[u8],
+    signature: &str,
+) -> Result<(), WebhookError> {
+    let expected: Vec<u8> = decode_hex(signature.trim()).ok_or(WebhookError::InvalidSignature)?;
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    mac.verify_slice(&expected)
+        .map_err(|_| WebhookError::InvalidSignature)
+}
+
+/// Verifies and deserializes a request sent by a Model Registry webhook.
+///
+/// Parameters:
+/// - `secret`: The webhook's shared secret, or `None` if it was created without one.
+/// - `body`: The request body exactly as received.
+/// - `signature`: The value of the `X-Databricks-Signature` header, if present.
+///
+/// Returns:
+/// - A `Result` containing the typed `RegistryWebhookPayload`, or a `WebhookError` if the
+///   signature is missing or wrong, or the body is not a registry event.
+pub fn parse_registry_webhook(
+    secret: Option<&str>,
+    body: &[u8],
+    signature: Option<&str>,
+) -> Result<RegistryWebhookPayload, WebhookError> {
+    if let Some(secret) = secret {
+        let signature: &str = signature.ok_or(WebhookError::MissingSignature)?;
+        verify_webhook_signature(secret, body, signature)?;
+    }
+    serde_json::from_slice(body).map_err(WebhookError::InvalidPayload)
+}
+
+/// Deserializes a job run notification sent to a webhook notification destination.
+///
+/// These requests are not signed; authenticate them with the destination's credentials instead.
+///
+/// Parameters:
+/// - `body`: The request body as received.
+///
+/// Returns:
+/// - A `Result` containing the typed `JobWebhookPayload`, or `WebhookError::InvalidPayload`.
+pub fn parse_job_webhook(body: &[u8]) -> Result<JobWebhookPayload, WebhookError> {
+    serde_json::from_slice(body).map_err(WebhookError::InvalidPayload)
+}
+
+fn decode_hex(text: &str) -> Option<Vec<u8>> {
+    if !text.len().is_multiple_of(2) || !text.is_ascii() {
+        return None;
+    }
+    (0..text.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(&text[index..index + 2], 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign(secret: &str, body: &[u8]) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).unwrap();
+        mac.update(body);
+        mac.finalize()
+            .into_bytes()
+            .iter()
+            .map(|byte| format!("{:02x}", byte))
+            .collect()
+    }
+
+    #[test]
+    fn accepts_a_matching_signature() {
+        let body: &[u8] = br#"{"event":"MODEL_VERSION_CREATED"}"#;
+        let signature: String = sign("secret", body);
+        assert!(verify_webhook_signature("secret", body, &signature).is_ok());
+        assert!(verify_webhook_signature("secret", body, &signature.to_uppercase()).is_ok());
+        assert!(verify_webhook_signature("secret", body, &format!(" {}\n", signature)).is_ok());
+    }
+
+    #[test]
+    fn rejects_a_wrong_signature() {
+        let body: &[u8] = br#"{"event":"MODEL_VERSION_CREATED"}"#;
+        let signature: String = sign("secret", body);
+        assert!(matches!(
+            verify_webhook_signature("other", body, &signature),
+            Err(WebhookError::InvalidSignature)
+        ));
+        assert!(matches!(
+            verify_webhook_signature("secret", b"{}", &signature),
+            Err(WebhookError::InvalidSignature)
+        ));
+    }
+
+    #[test]
+    fn rejects_malformed_hex() {
+        let body: &[u8] = b"{}";
+        let signature: String = sign("secret", body);
+        for malformed in [&signature[1..], "abc", "zz", "é0"] {
+            assert!(matches!(
+                verify_webhook_signature("secret", body, malformed),
+                Err(WebhookError::InvalidSignature)
+            ));
+        }
+    }
+
+    #[test]
+    fn requires_a_signature_when_a_secret_is_set() {
+        assert!(matches!(
+            parse_registry_webhook(Some("secret"), b"{}", None),
+            Err(WebhookError::MissingSignature)
+        ));
+    }
+}